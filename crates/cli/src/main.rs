@@ -1,6 +1,10 @@
 use anyhow::Result;
 use clap::{Parser, Subcommand, ValueEnum};
 use colored::*;
+use std::path::{Path, PathBuf};
+use tenraku_core::config::diagnostics::{Diagnostic, Diagnostics, Severity};
+use tenraku_core::config::json_schema;
+use tenraku_core::config::ConfigLoader;
 
 #[cfg(windows)]
 use anyhow::Context;
@@ -39,6 +43,12 @@ enum OutputFormat {
     Compact,
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum HistoryDirection {
+    Back,
+    Forward,
+}
+
 #[derive(Subcommand)]
 enum Commands {
     /// Get list of windows
@@ -57,8 +67,18 @@ enum Commands {
     /// Get list of monitors
     Monitors,
 
-    /// Get configuration info
-    Config,
+    /// Get list of tags (tag mode only)
+    Tags,
+
+    /// Get configuration info, check a configuration file or print its schema
+    Config {
+        #[command(subcommand)]
+        action: Option<ConfigAction>,
+    },
+
+    /// Check keybindings for conflicts
+    #[command(subcommand)]
+    Keybinds(KeybindsAction),
 
     /// Get version information
     Version,
@@ -73,6 +93,36 @@ enum Commands {
         id: usize,
     },
 
+    /// Switch back to the previously active workspace
+    WorkspaceBackAndForth,
+
+    /// Step back or forward through the workspace history
+    WorkspaceHistory {
+        /// Direction to step in
+        #[arg(value_enum)]
+        direction: HistoryDirection,
+    },
+
+    /// Switch to the next workspace
+    WorkspaceNext {
+        /// Skip workspaces without windows
+        #[arg(long)]
+        skip_empty: bool,
+        /// Stay on the current monitor
+        #[arg(long)]
+        same_monitor: bool,
+    },
+
+    /// Switch to the previous workspace
+    WorkspacePrev {
+        /// Skip workspaces without windows
+        #[arg(long)]
+        skip_empty: bool,
+        /// Stay on the current monitor
+        #[arg(long)]
+        same_monitor: bool,
+    },
+
     /// Close active or specified window
     Close {
         /// Window HWND (hex or decimal)
@@ -94,6 +144,18 @@ enum Commands {
         workspace: usize,
     },
 
+    /// Focus a window on another monitor
+    FocusMonitor {
+        /// Direction (left, right, up, down), monitor index or monitor name
+        target: String,
+    },
+
+    /// Move the active window to another monitor
+    MoveToMonitor {
+        /// Direction (left, right, up, down), monitor index or monitor name
+        target: String,
+    },
+
     /// Toggle floating for active or specified window
     ToggleFloat {
         /// Window HWND
@@ -131,6 +193,48 @@ enum Commands {
         name: String,
     },
 
+    /// Move the active workspace to another monitor
+    MoveWorkspaceToMonitor {
+        /// Direction (left, right, up, down), monitor index or monitor name
+        target: String,
+    },
+
+    /// Swap the workspaces of the focused monitor and another monitor
+    SwapMonitorWorkspaces {
+        /// Direction, monitor index or monitor name (default: next monitor)
+        target: Option<String>,
+    },
+
+    /// Switch every monitor to its workspace of a workspace group
+    SwitchGroup {
+        /// Group ID
+        id: usize,
+    },
+
+    /// View only one tag on the focused monitor (tag mode only)
+    ViewTag {
+        /// Tag number
+        tag: usize,
+    },
+
+    /// Add a tag to or remove it from the focused monitor's view
+    ToggleViewTag {
+        /// Tag number
+        tag: usize,
+    },
+
+    /// Give the active window only one tag
+    TagWindow {
+        /// Tag number
+        tag: usize,
+    },
+
+    /// Add a tag to or remove it from the active window
+    ToggleWindowTag {
+        /// Tag number
+        tag: usize,
+    },
+
     /// Set layout
     Layout {
         /// Layout name (dwindle, master)
@@ -166,7 +270,42 @@ enum ExecCommands {
     DecreaseMaster,
 }
 
+#[derive(Subcommand)]
+enum ConfigAction {
+    /// Check a configuration file and report every problem found
+    Check {
+        /// Configuration file (defaults to the window manager's config.toml)
+        path: Option<PathBuf>,
+    },
+
+    /// Print the JSON Schema of the configuration for editor completion
+    Schema {
+        /// Print the status bar's configuration schema instead
+        #[arg(long)]
+        bar: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum KeybindsAction {
+    /// Report reserved, duplicate and shadowed keybindings and unknown commands
+    Check {
+        /// Configuration file (defaults to the window manager's config.toml)
+        path: Option<PathBuf>,
+    },
+}
+
 fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    // Config actions work on files and don't need a running window manager
+    if let Commands::Config { action: Some(action) } = &cli.command {
+        return run_config_action(action, cli.format);
+    }
+    if let Commands::Keybinds(action) = &cli.command {
+        return run_keybinds_action(action, cli.format);
+    }
+
     // Platform check
     #[cfg(not(windows))]
     {
@@ -176,8 +315,6 @@ fn main() -> Result<()> {
 
     #[cfg(windows)]
     {
-        let cli = Cli::parse();
-
         // Connect to named pipe
         let mut client = connect_to_pipe(&cli.pipe)
             .context("Failed to connect to window manager. Is it running?")?;
@@ -208,6 +345,121 @@ fn main() -> Result<()> {
     }
 }
 
+fn run_config_action(action: &ConfigAction, format: OutputFormat) -> Result<()> {
+    match action {
+        ConfigAction::Check { path } => {
+            let path = config_path(path)?;
+            let diagnostics = ConfigLoader::from_path(path.clone()).check();
+            report_diagnostics(&path, &diagnostics, format)
+        }
+        ConfigAction::Schema { bar } => {
+            let schema = if *bar {
+                json_schema::bar_config_schema()
+            } else {
+                json_schema::config_schema()
+            };
+            println!("{}", serde_json::to_string_pretty(&schema)?);
+            Ok(())
+        }
+    }
+}
+
+fn run_keybinds_action(action: &KeybindsAction, format: OutputFormat) -> Result<()> {
+    match action {
+        KeybindsAction::Check { path } => {
+            let path = config_path(path)?;
+            let diagnostics = ConfigLoader::from_path(path.clone()).check_keybinds();
+            report_diagnostics(&path, &diagnostics, format)
+        }
+    }
+}
+
+/// Get the configuration file to check, defaulting to the window manager's
+fn config_path(path: &Option<PathBuf>) -> Result<PathBuf> {
+    match path {
+        Some(path) => Ok(path.clone()),
+        None => Ok(ConfigLoader::new()?.get_config_path().clone()),
+    }
+}
+
+/// Print the problems found in a configuration file, exiting with status 1
+/// if there are errors
+fn report_diagnostics(path: &Path, diagnostics: &Diagnostics, format: OutputFormat) -> Result<()> {
+
+    match format {
+        OutputFormat::Json => {
+            let report = serde_json::json!({
+                "path": path,
+                "valid": !diagnostics.has_errors(),
+                "errors": diagnostics.error_count(),
+                "warnings": diagnostics.warning_count(),
+                "diagnostics": diagnostics,
+            });
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        }
+        OutputFormat::Compact => {
+            for diagnostic in diagnostics.iter() {
+                println!(
+                    "{}: {}: {}",
+                    diagnostic_location(diagnostic).unwrap_or_else(|| path.display().to_string()),
+                    diagnostic.severity,
+                    diagnostic.message
+                );
+            }
+        }
+        OutputFormat::Table => {
+            for diagnostic in diagnostics.iter() {
+                let severity = match diagnostic.severity {
+                    Severity::Error => diagnostic.severity.to_string().red().bold(),
+                    Severity::Warning => diagnostic.severity.to_string().yellow().bold(),
+                };
+                println!("{}: {}", severity, diagnostic.message);
+                if let Some(location) = diagnostic_location(diagnostic) {
+                    println!("  {} {}", "-->".blue(), location);
+                }
+                if !diagnostic.path.is_empty() {
+                    println!("  at {}", diagnostic.path);
+                }
+                if let Some(ref suggestion) = diagnostic.suggestion {
+                    println!("  {} {}", "= help:".cyan(), suggestion);
+                }
+                println!();
+            }
+
+            if diagnostics.has_errors() {
+                println!(
+                    "{} {}: {} error(s), {} warning(s)",
+                    "✗".red(),
+                    path.display(),
+                    diagnostics.error_count(),
+                    diagnostics.warning_count()
+                );
+            } else {
+                println!(
+                    "{} {} is valid ({} warning(s))",
+                    "✓".green(),
+                    path.display(),
+                    diagnostics.warning_count()
+                );
+            }
+        }
+    }
+
+    if diagnostics.has_errors() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Format the file, line and column of a diagnostic
+fn diagnostic_location(diagnostic: &Diagnostic) -> Option<String> {
+    let file = diagnostic.file.as_ref()?;
+    Some(match diagnostic.span {
+        Some(span) => format!("{}:{}:{}", file.display(), span.line, span.column),
+        None => file.display().to_string(),
+    })
+}
+
 #[cfg(windows)]
 fn connect_to_pipe(pipe_path: &str) -> Result<std::fs::File> {
     // Windows-specific constant for FILE_FLAG_OVERLAPPED
@@ -245,7 +497,13 @@ fn build_request(command: &Commands) -> Result<Value> {
                 "type": "get_monitors"
             })
         }
-        Commands::Config => {
+        Commands::Tags => {
+            serde_json::json!({
+                "type": "get_tags"
+            })
+        }
+        Commands::Keybinds(_) => unreachable!("keybinds actions are handled locally"),
+        Commands::Config { .. } => {
             serde_json::json!({
                 "type": "get_config"
             })
@@ -261,6 +519,36 @@ fn build_request(command: &Commands) -> Result<Value> {
                 "id": id,
             })
         }
+        Commands::WorkspaceBackAndForth => {
+            serde_json::json!({
+                "type": "execute",
+                "command": "workspace-back-and-forth",
+            })
+        }
+        Commands::WorkspaceHistory { direction } => {
+            let command = match direction {
+                HistoryDirection::Back => "workspace-history-back",
+                HistoryDirection::Forward => "workspace-history-forward",
+            };
+            serde_json::json!({
+                "type": "execute",
+                "command": command,
+            })
+        }
+        Commands::WorkspaceNext { skip_empty, same_monitor } => {
+            serde_json::json!({
+                "type": "execute",
+                "command": "workspace-next",
+                "args": cycle_args(*skip_empty, *same_monitor),
+            })
+        }
+        Commands::WorkspacePrev { skip_empty, same_monitor } => {
+            serde_json::json!({
+                "type": "execute",
+                "command": "workspace-previous",
+                "args": cycle_args(*skip_empty, *same_monitor),
+            })
+        }
         Commands::Close { window } => {
             serde_json::json!({
                 "type": "close_window",
@@ -280,6 +568,20 @@ fn build_request(command: &Commands) -> Result<Value> {
                 "workspace": workspace,
             })
         }
+        Commands::FocusMonitor { target } => {
+            serde_json::json!({
+                "type": "execute",
+                "command": "focus-monitor",
+                "args": [target],
+            })
+        }
+        Commands::MoveToMonitor { target } => {
+            serde_json::json!({
+                "type": "execute",
+                "command": "move-to-monitor",
+                "args": [target],
+            })
+        }
         Commands::ToggleFloat { window } => {
             serde_json::json!({
                 "type": "toggle_floating",
@@ -312,6 +614,48 @@ fn build_request(command: &Commands) -> Result<Value> {
                 "name": name,
             })
         }
+        Commands::MoveWorkspaceToMonitor { target } => {
+            serde_json::json!({
+                "type": "move_workspace_to_monitor",
+                "target": target,
+            })
+        }
+        Commands::SwapMonitorWorkspaces { target } => {
+            serde_json::json!({
+                "type": "swap_monitor_workspaces",
+                "target": target,
+            })
+        }
+        Commands::SwitchGroup { id } => {
+            serde_json::json!({
+                "type": "switch_group",
+                "id": id,
+            })
+        }
+        Commands::ViewTag { tag } => {
+            serde_json::json!({
+                "type": "view_tag",
+                "tag": tag,
+            })
+        }
+        Commands::ToggleViewTag { tag } => {
+            serde_json::json!({
+                "type": "toggle_view_tag",
+                "tag": tag,
+            })
+        }
+        Commands::TagWindow { tag } => {
+            serde_json::json!({
+                "type": "tag_window",
+                "tag": tag,
+            })
+        }
+        Commands::ToggleWindowTag { tag } => {
+            serde_json::json!({
+                "type": "toggle_window_tag",
+                "tag": tag,
+            })
+        }
         Commands::Layout { name } => {
             serde_json::json!({
                 "type": "set_layout",
@@ -357,6 +701,19 @@ fn build_request(command: &Commands) -> Result<Value> {
     Ok(request)
 }
 
+/// Build the arguments of the workspace next/previous commands.
+#[cfg(windows)]
+fn cycle_args(skip_empty: bool, same_monitor: bool) -> Vec<&'static str> {
+    let mut args = Vec::new();
+    if skip_empty {
+        args.push("skip-empty");
+    }
+    if same_monitor {
+        args.push("same-monitor");
+    }
+    args
+}
+
 #[cfg(windows)]
 fn send_request<W>(writer: &mut W, request: &Value) -> Result<()>
 where
@@ -520,7 +877,7 @@ fn print_window_table(windows: &[Value]) {
     let mut table = Table::new();
     table
         .load_preset(UTF8_FULL)
-        .set_header(vec!["HWND", "Title", "Workspace", "State", "Focused"]);
+        .set_header(vec!["HWND", "Title", "Workspace", "State", "Mark", "Focused"]);
 
     for win in windows {
         if let (Some(hwnd), Some(title), Some(workspace), Some(state)) = (
@@ -533,6 +890,7 @@ fn print_window_table(windows: &[Value]) {
                 .get("focused")
                 .and_then(|v| v.as_bool())
                 .unwrap_or(false);
+            let mark = win.get("mark").and_then(|v| v.as_str()).unwrap_or("");
             let title_truncated: String = title.chars().take(MAX_TITLE_LENGTH).collect();
 
             table.add_row(vec![
@@ -540,6 +898,7 @@ fn print_window_table(windows: &[Value]) {
                 title_truncated,
                 workspace.to_string(),
                 state.to_string(),
+                mark.to_string(),
                 if focused {
                     "✓".green().to_string()
                 } else {
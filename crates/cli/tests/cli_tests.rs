@@ -60,6 +60,7 @@ mod command_parsing_tests {
             "active-window",
             "workspaces",
             "monitors",
+            "tags",
             "config",
             "version",
         ];
@@ -87,6 +88,8 @@ mod command_parsing_tests {
             "move",
             "toggle-float",
             "toggle-fullscreen",
+            "focus-monitor",
+            "move-to-monitor",
         ];
         
         for cmd in commands {
@@ -110,6 +113,17 @@ mod command_parsing_tests {
             "create-workspace",
             "delete-workspace",
             "rename-workspace",
+            "workspace-back-and-forth",
+            "workspace-history",
+            "workspace-next",
+            "workspace-prev",
+            "move-workspace-to-monitor",
+            "swap-monitor-workspaces",
+            "switch-group",
+            "view-tag",
+            "toggle-view-tag",
+            "tag-window",
+            "toggle-window-tag",
         ];
         
         for cmd in commands {
@@ -191,6 +205,47 @@ mod command_parsing_tests {
     }
 }
 
+#[cfg(test)]
+mod config_tests {
+    #[test]
+    fn test_config_subcommands_exist() {
+        let subcommands = vec![
+            "check",
+            "schema",
+        ];
+        
+        for subcmd in subcommands {
+            let output = std::process::Command::new("cargo")
+                .args(&["run", "--package", "tiling-wm-cli", "--bin", "twm", "--", "config", subcmd, "--help"])
+                .output();
+            
+            if let Ok(output) = output {
+                if output.status.success() {
+                    let stdout = String::from_utf8_lossy(&output.stdout);
+                    assert!(!stdout.is_empty(), "Help output should not be empty for config {}", subcmd);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod keybinds_tests {
+    #[test]
+    fn test_keybinds_check_exists() {
+        let output = std::process::Command::new("cargo")
+            .args(&["run", "--package", "tiling-wm-cli", "--bin", "twm", "--", "keybinds", "check", "--help"])
+            .output();
+        
+        if let Ok(output) = output {
+            if output.status.success() {
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                assert!(!stdout.is_empty(), "Help output should not be empty for keybinds check");
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod output_format_tests {
     #[test]
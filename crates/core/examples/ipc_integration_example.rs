@@ -16,7 +16,8 @@ use tokio::sync::Mutex;
 use tenraku_core::commands::CommandExecutor;
 use tenraku_core::ipc::{Event, EventBroadcaster, IpcServer, RequestHandler};
 use tenraku_core::window_manager::WindowManager;
-use tenraku_core::workspace::{WorkspaceConfig, WorkspaceManager};
+use tenraku_core::workspace::core::{WorkspaceConfig, WorkspaceEvent};
+use tenraku_core::workspace::WorkspaceManager;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -36,10 +37,19 @@ async fn main() -> anyhow::Result<()> {
     println!("2. Creating window manager and workspace manager...");
     let mut wm = WindowManager::new();
     wm.initialize()?;
+    let broadcaster = Arc::clone(&event_broadcaster);
+    wm.set_workspace_listener(move |event| broadcaster.emit(event.into()));
     let wm = Arc::new(Mutex::new(wm));
     
     let config = WorkspaceConfig::default();
-    let wsm = Arc::new(Mutex::new(WorkspaceManager::new(config)));
+    let mut wsm = WorkspaceManager::new(config);
+    let broadcaster = Arc::clone(&event_broadcaster);
+    // Workspace creation and deletion are reported by the window manager
+    wsm.set_event_listener(move |event| match event {
+        WorkspaceEvent::Created { .. } | WorkspaceEvent::Deleted { .. } => {}
+        event => broadcaster.emit(event.into()),
+    });
+    let wsm = Arc::new(Mutex::new(wsm));
     
     let executor = Arc::new(CommandExecutor::new());
     println!("   ✓ Window manager initialized\n");
@@ -107,7 +117,19 @@ async fn main() -> anyhow::Result<()> {
     println!("      }});\n");
     
     println!("      // In config reload handler:");
-    println!("      event_broadcaster.emit(Event::ConfigReloaded);\n");
+    println!("      config_watcher.set_reload_listener(move |event| {{");
+    println!("          event_broadcaster.emit(Event::from(event));");
+    println!("      }});\n");
+    
+    println!("      // When the binding mode changes:");
+    println!("      keybind_manager.set_mode_listener(move |mode| {{");
+    println!("          event_broadcaster.emit(Event::ModeChanged {{ mode: mode.to_string() }});");
+    println!("      }});\n");
+    
+    println!("      // When a key sequence is pending or ends:");
+    println!("      keybind_manager.set_chord_listener(move |event| {{");
+    println!("          event_broadcaster.emit(Event::from(event));");
+    println!("      }});\n");
     
     println!("8. Shutdown handling:");
     println!("   a) Call ipc_server.stop().await when application exits");
@@ -52,6 +52,7 @@ fn main() {
             height: 900,
         },
         focused: Some(true),
+        mark: Some("term".to_string()),
     };
     let response = Response::success_with_data(serde_json::to_value(&window_info).unwrap());
     println!("{}\n", serde_json::to_string_pretty(&response).unwrap());
@@ -71,6 +72,8 @@ fn main() {
         window_count: 3,
         active: true,
         visible: Some(true),
+        urgent: false,
+        special: false,
     };
     println!("{}\n", serde_json::to_string_pretty(&workspace_info).unwrap());
     
@@ -85,6 +88,8 @@ fn main() {
         scale: 1.0,
         primary: Some(true),
         active_workspace: Some(1),
+        parent: None,
+        tags: None,
     };
     println!("{}\n", serde_json::to_string_pretty(&monitor_info).unwrap());
     
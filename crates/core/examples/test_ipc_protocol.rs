@@ -135,6 +135,7 @@ fn test_data_structures() {
             height: 1080,
         },
         focused: Some(true),
+        mark: None,
     };
     let json = serde_json::to_string(&info).unwrap();
     let _deserialized: WindowInfo = serde_json::from_str(&json).unwrap();
@@ -148,6 +149,8 @@ fn test_data_structures() {
         window_count: 5,
         active: true,
         visible: Some(true),
+        urgent: false,
+        special: false,
     };
     let json = serde_json::to_string(&info).unwrap();
     let _deserialized: WorkspaceInfo = serde_json::from_str(&json).unwrap();
@@ -164,6 +167,8 @@ fn test_data_structures() {
         scale: 1.0,
         primary: Some(true),
         active_workspace: Some(1),
+        parent: None,
+        tags: None,
     };
     let json = serde_json::to_string(&info).unwrap();
     let _deserialized: MonitorInfo = serde_json::from_str(&json).unwrap();
@@ -227,6 +232,10 @@ fn test_events() {
         },
         Event::WindowClosed { hwnd: 1 },
         Event::WindowFocused { hwnd: 1 },
+        Event::WindowUrgent {
+            hwnd: 1,
+            urgent: true,
+        },
         Event::WindowMoved {
             hwnd: 1,
             from_workspace: 1,
@@ -243,11 +252,36 @@ fn test_events() {
             name: "Test".to_string(),
         },
         Event::WorkspaceDeleted { id: 1 },
-        Event::MonitorChanged,
-        Event::ConfigReloaded,
+        Event::MonitorAdded {
+            id: 1,
+            name: "\\\\.\\DISPLAY2".to_string(),
+            key: "DEL40F5".to_string(),
+            workspaces: vec![3],
+        },
+        Event::MonitorRemoved {
+            id: 1,
+            name: "\\\\.\\DISPLAY2".to_string(),
+            key: "DEL40F5".to_string(),
+            workspaces: vec![3],
+            fallback: 0,
+        },
+        Event::ConfigReloaded {
+            sections: vec!["general".to_string(), "keybinds".to_string()],
+        },
+        Event::ConfigReloadFailed {
+            error: "gaps_in must be non-negative".to_string(),
+        },
         Event::LayoutChanged {
             layout: "dwindle".to_string(),
         },
+        Event::ModeChanged {
+            mode: "resize".to_string(),
+        },
+        Event::ChordPending {
+            prefix: "Win+Space, W".to_string(),
+            continuations: vec![("3".to_string(), Some("workspace 3".to_string()))],
+        },
+        Event::ChordEnded { completed: true },
     ];
     
     for event in events {
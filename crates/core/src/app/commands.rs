@@ -6,6 +6,9 @@ use anyhow::Result;
 use tracing::{debug, warn};
 
 use crate::commands::{Command, CommandExecutor};
+use crate::keybinds::KeybindManager;
+use crate::window_manager::floating::SnapTarget;
+use crate::window_manager::monitor::MonitorTarget;
 use crate::window_manager::WindowManager;
 
 /// Execute a command from a string representation.
@@ -40,7 +43,8 @@ pub fn execute_command_from_string(
     // To add new commands:
     // 1. Add the variant to Command enum in commands.rs
     // 2. Add a case to this match statement
-    // 3. Document in KEYBINDINGS_GUIDE.md
+    // 3. Add the name to COMMAND_NAMES in commands.rs
+    // 4. Document in KEYBINDINGS_GUIDE.md
     let command = match command_str {
         // Window commands
         "close" => Command::CloseActiveWindow,
@@ -49,6 +53,54 @@ pub fn execute_command_from_string(
         "minimize" => Command::MinimizeActive,
         "restore" => Command::RestoreActive,
         
+        // Floating commands
+        "float-move" => match parse_int_pair(args) {
+            Some((dx, dy)) => Command::FloatMove(dx, dy),
+            None => {
+                warn!("float-move requires two integer arguments: <dx> <dy>");
+                return Ok(());
+            }
+        },
+        "float-resize" => match parse_int_pair(args) {
+            Some((dw, dh)) => Command::FloatResize(dw, dh),
+            None => {
+                warn!("float-resize requires two integer arguments: <dw> <dh>");
+                return Ok(());
+            }
+        },
+        "float-center" => Command::FloatCenter,
+        "float-snap" => match args.first().and_then(|name| SnapTarget::from_name(name)) {
+            Some(target) => Command::FloatSnap(target),
+            None => {
+                warn!("float-snap requires an edge, corner or half argument, got {:?}", args);
+                return Ok(());
+            }
+        },
+        
+        // Mark commands
+        "mark" => match args.first() {
+            Some(mark) => Command::Mark(mark.clone()),
+            None => {
+                warn!("mark requires a mark name argument");
+                return Ok(());
+            }
+        },
+        "unmark" => Command::Unmark(args.first().cloned()),
+        "focus-mark" => match args.first() {
+            Some(mark) => Command::FocusMark(mark.clone()),
+            None => {
+                warn!("focus-mark requires a mark name argument");
+                return Ok(());
+            }
+        },
+        "swap-with-mark" => match args.first() {
+            Some(mark) => Command::SwapWithMark(mark.clone()),
+            None => {
+                warn!("swap-with-mark requires a mark name argument");
+                return Ok(());
+            }
+        },
+        
         // Focus commands
         "focus-left" => Command::FocusLeft,
         "focus-right" => Command::FocusRight,
@@ -56,6 +108,14 @@ pub fn execute_command_from_string(
         "focus-down" => Command::FocusDown,
         "focus-previous" => Command::FocusPrevious,
         "focus-next" => Command::FocusNext,
+        "focus-urgent" => Command::FocusUrgent,
+        "focus-monitor" => match args.first() {
+            Some(target) => Command::FocusMonitor(MonitorTarget::parse(target)),
+            None => {
+                warn!("focus-monitor requires a direction, index or name argument");
+                return Ok(());
+            }
+        },
         
         // Move commands
         "move-left" => Command::MoveWindowLeft,
@@ -63,6 +123,13 @@ pub fn execute_command_from_string(
         "move-up" => Command::MoveWindowUp,
         "move-down" => Command::MoveWindowDown,
         "swap-master" => Command::SwapWithMaster,
+        "move-to-monitor" => match args.first() {
+            Some(target) => Command::MoveToMonitor(MonitorTarget::parse(target)),
+            None => {
+                warn!("move-to-monitor requires a direction, index or name argument");
+                return Ok(());
+            }
+        },
         
         // Layout commands
         "layout-dwindle" => Command::SetLayoutDwindle,
@@ -90,6 +157,88 @@ pub fn execute_command_from_string(
         "move-to-workspace-4" => Command::MoveToWorkspace(4),
         "move-to-workspace-5" => Command::MoveToWorkspace(5),
         
+        // Any workspace ID; workspaces are created on demand
+        "workspace" => match args.first().and_then(|id| id.parse::<usize>().ok()) {
+            Some(id) if id > 0 => Command::SwitchWorkspace(id),
+            _ => {
+                warn!("workspace requires a workspace ID argument, got {:?}", args);
+                return Ok(());
+            }
+        },
+        "move-to-workspace" => match args.first().and_then(|id| id.parse::<usize>().ok()) {
+            Some(id) if id > 0 => Command::MoveToWorkspace(id),
+            _ => {
+                warn!("move-to-workspace requires a workspace ID argument, got {:?}", args);
+                return Ok(());
+            }
+        },
+        
+        // Workspace navigation; next/previous accept "skip-empty" and "same-monitor"
+        "workspace-back-and-forth" => Command::WorkspaceBackAndForth,
+        "workspace-history-back" => Command::WorkspaceHistoryBack,
+        "workspace-history-forward" => Command::WorkspaceHistoryForward,
+        "workspace-next" => Command::SwitchWorkspaceNext {
+            skip_empty: args.iter().any(|arg| arg == "skip-empty"),
+            same_monitor: args.iter().any(|arg| arg == "same-monitor"),
+        },
+        "workspace-previous" | "workspace-prev" => Command::SwitchWorkspacePrevious {
+            skip_empty: args.iter().any(|arg| arg == "skip-empty"),
+            same_monitor: args.iter().any(|arg| arg == "same-monitor"),
+        },
+        
+        // Workspace groups switch every monitor at once
+        "switch-group" => match args.first().and_then(|id| id.parse::<usize>().ok()) {
+            Some(id) if id > 0 => Command::SwitchGroup(id),
+            _ => {
+                warn!("switch-group requires a group ID argument, got {:?}", args);
+                return Ok(());
+            }
+        },
+        
+        // Special workspaces
+        "toggle-special" => match args.first() {
+            Some(name) => Command::ToggleSpecial(name.clone()),
+            None => {
+                warn!("toggle-special requires a special workspace name argument");
+                return Ok(());
+            }
+        },
+        "move-to-special" => match args.first() {
+            Some(name) => Command::MoveToSpecial(name.clone()),
+            None => {
+                warn!("move-to-special requires a special workspace name argument");
+                return Ok(());
+            }
+        },
+        
+        // Workspace placement; targets are left/right/up/down, an index or a name
+        "move-workspace-to-monitor" => match args.first() {
+            Some(target) => Command::MoveWorkspaceToMonitor(MonitorTarget::parse(target)),
+            None => {
+                warn!("move-workspace-to-monitor requires a direction, index or name argument");
+                return Ok(());
+            }
+        },
+        "swap-monitor-workspaces" => {
+            Command::SwapMonitorWorkspaces(args.first().map(|target| MonitorTarget::parse(target)))
+        }
+        
+        // Tags (tag mode only); all take a tag number
+        "view-tag" | "toggle-view-tag" | "tag-window" | "toggle-window-tag" => {
+            match args.first().and_then(|tag| tag.parse::<usize>().ok()) {
+                Some(tag) if tag > 0 => match command_str {
+                    "view-tag" => Command::ViewTag(tag),
+                    "toggle-view-tag" => Command::ToggleViewTag(tag),
+                    "tag-window" => Command::TagWindow(tag),
+                    _ => Command::ToggleWindowTag(tag),
+                },
+                _ => {
+                    warn!("{} requires a tag number argument, got {:?}", command_str, args);
+                    return Ok(());
+                }
+            }
+        }
+        
         // System commands
         "reload-config" => Command::Reload,
         "exit" | "quit" => Command::Quit,
@@ -109,3 +258,34 @@ pub fn execute_command_from_string(
     
     Ok(())
 }
+
+/// Execute a binding mode command (`enter-mode <name>` or `exit-mode`) or
+/// `toggle-passthrough`.
+///
+/// These commands change which keybindings are registered, so they are
+/// handled here rather than by the CommandExecutor. Returns `false` if the
+/// command is not one of them.
+pub fn execute_mode_command(
+    keybind_manager: &mut KeybindManager,
+    command_str: &str,
+    args: &[String],
+) -> Result<bool> {
+    match command_str {
+        "enter-mode" => match args.first() {
+            Some(mode) => keybind_manager.enter_mode(mode)?,
+            None => warn!("enter-mode requires a mode name argument"),
+        },
+        "exit-mode" => keybind_manager.exit_mode()?,
+        "toggle-passthrough" => keybind_manager.toggle_passthrough()?,
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+/// Parse the first two arguments as a pair of integers.
+fn parse_int_pair(args: &[String]) -> Option<(i32, i32)> {
+    match args {
+        [first, second, ..] => Some((first.parse().ok()?, second.parse().ok()?)),
+        _ => None,
+    }
+}
@@ -9,20 +9,34 @@ use std::time::Duration;
 use tracing::{debug, error, info, warn};
 
 use crate::commands::CommandExecutor;
-use crate::config::{ConfigLoader, ConfigValidator, ConfigWatcher};
+use crate::config::diagnostics::Diagnostics;
+use crate::config::reload::{ConfigDiff, ConfigSection, ReloadEvent};
+use crate::config::{Config, ConfigLoader, ConfigSources, ConfigValidator, ConfigWatcher};
 use crate::event_loop::{EventLoop, WindowEvent};
-use crate::keybinds::KeybindManager;
+use crate::keybinds::{analyzer, KeybindManager};
 use crate::window_manager::{WindowManager, WindowState};
 
 #[cfg(target_os = "windows")]
 use crate::utils::win32::WindowHandle;
+#[cfg(target_os = "windows")]
+use crate::window_manager::ManagedWindow;
+
+/// Configuration hot-reload state
+pub struct HotReload {
+    /// Watcher reporting changes to the configuration and its includes
+    pub watcher: ConfigWatcher,
+    /// Loader the configuration is reloaded with
+    pub loader: ConfigLoader,
+    /// The running configuration, compared with reloaded ones
+    pub config: Config,
+}
 
 /// Main event loop that processes Windows events and manages windows.
 ///
 /// This is the core loop that:
 /// - Processes Windows messages
 /// - Polls for window events and hotkeys
-/// - Checks for configuration changes and reloads
+/// - Checks for configuration changes and applies the changed sections
 /// - Uses CommandExecutor for window operations
 /// - Logs all significant events and command executions
 pub fn run_event_loop(
@@ -31,23 +45,36 @@ pub fn run_event_loop(
     executor: &CommandExecutor,
     keybind_manager: &mut KeybindManager,
     running: &Arc<AtomicBool>,
-    mut config_watcher: Option<ConfigWatcher>,
-    config_loader: &ConfigLoader,
+    mut hot_reload: Option<HotReload>,
 ) -> Result<()> {
     debug!("Event loop started");
 
     while running.load(Ordering::SeqCst) {
         // Check for configuration changes
-        if let Some(ref mut watcher) = config_watcher {
+        if let Some(HotReload {
+            ref mut watcher,
+            ref loader,
+            ref mut config,
+        }) = hot_reload
+        {
             if watcher.check_for_changes() {
                 info!("Configuration changed, reloading...");
-                match reload_configuration(wm, keybind_manager, config_loader) {
-                    Ok(()) => {
-                        info!("✓ Configuration reloaded successfully");
+                match reload_configuration(wm, event_loop, keybind_manager, loader, config) {
+                    Ok((new_config, sources, diff)) => {
+                        info!("✓ Configuration reloaded successfully ({})", diff);
+                        // Includes may have changed, so watch the files just loaded
+                        watcher.watch_sources(&sources);
+                        watcher.notify_reload(ReloadEvent::Reloaded {
+                            sections: diff.sections().to_vec(),
+                        });
+                        *config = new_config;
                     }
                     Err(e) => {
                         error!("✗ Failed to reload configuration: {}", e);
                         error!("  Continuing with previous configuration");
+                        watcher.notify_reload(ReloadEvent::Rejected {
+                            error: e.to_string(),
+                        });
                     }
                 }
             }
@@ -65,6 +92,9 @@ pub fn run_event_loop(
             }
         }
 
+        // Drop a key sequence that was not continued in time
+        keybind_manager.check_chord_timeout();
+
         // Small sleep to prevent 100% CPU usage
         std::thread::sleep(Duration::from_millis(50));
     }
@@ -73,41 +103,53 @@ pub fn run_event_loop(
     Ok(())
 }
 
-/// Reload configuration from disk and apply to window manager
+/// Reload configuration from disk and apply the changed sections
 ///
 /// This function:
-/// 1. Loads the new configuration from disk
-/// 2. Validates the configuration
-/// 3. Applies it to the window manager
-/// 4. Updates rules and keybindings
+/// 1. Loads the new configuration and its included files from disk
+/// 2. Validates the configuration, rejecting it before anything is applied
+/// 3. Compares it with the running configuration section by section
+/// 4. Applies the changed sections to the window manager
+/// 5. Re-registers keybindings, binding modes and mouse bindings only if they changed
 ///
-/// If any step fails, the previous configuration remains active.
+/// If any step fails, the previous configuration remains active: sections
+/// already applied are restored from `current`. On success, returns the new
+/// configuration, the files it was merged from and what changed.
 fn reload_configuration(
     wm: &mut WindowManager,
+    event_loop: &mut EventLoop,
     keybind_manager: &mut KeybindManager,
     config_loader: &ConfigLoader,
-) -> Result<()> {
+    current: &Config,
+) -> Result<(Config, ConfigSources, ConfigDiff)> {
     use std::time::Instant;
 
     let start = Instant::now();
 
     // Load new configuration
-    let config = config_loader
-        .load()
+    let (config, sources) = config_loader
+        .load_with_sources()
         .map_err(|e| anyhow::anyhow!("Failed to load configuration: {}", e))?;
 
     // Validate configuration
-    ConfigValidator::validate(&config)
+    ConfigValidator::validate_with_sources(&config, &sources)
         .map_err(|e| anyhow::anyhow!("Configuration validation failed: {}", e))?;
+    report_keybind_conflicts(&config, &sources);
 
-    // Apply to window manager
-    wm.update_config(&config)
-        .map_err(|e| anyhow::anyhow!("Failed to apply configuration: {}", e))?;
+    let diff = ConfigDiff::between(current, &config);
+    if diff.is_empty() {
+        debug!("Configuration unchanged, nothing to apply");
+        return Ok((config, sources, diff));
+    }
+    debug!("Configuration changes: {}", diff);
 
-    // Update keybindings
-    keybind_manager
-        .register_keybinds(config.keybinds.clone())
-        .map_err(|e| anyhow::anyhow!("Failed to register keybindings: {}", e))?;
+    if let Err(e) = apply_changes(wm, event_loop, keybind_manager, &config, &diff) {
+        // Put back whatever was applied before the failure
+        if let Err(restore) = apply_changes(wm, event_loop, keybind_manager, current, &diff) {
+            warn!("Failed to restore previous configuration: {}", restore);
+        }
+        return Err(e);
+    }
 
     let elapsed = start.elapsed();
     info!("Configuration reload completed in {:?}", elapsed);
@@ -120,6 +162,55 @@ fn reload_configuration(
         );
     }
 
+    Ok((config, sources, diff))
+}
+
+/// Log the keybinding conflict report of a configuration
+///
+/// The report does not stop the configuration from loading; anything it
+/// finds that would is already rejected by validation.
+fn report_keybind_conflicts(config: &Config, sources: &ConfigSources) {
+    let mut diagnostics = Diagnostics::new();
+    analyzer::analyze(config, &mut diagnostics);
+    for diagnostic in diagnostics.iter() {
+        warn!(
+            "Keybinding {} at {}: {}{}",
+            diagnostic.severity,
+            diagnostic.path,
+            diagnostic.message,
+            diagnostic.origin(sources)
+        );
+    }
+}
+
+/// Apply the sections of a configuration listed in a diff
+fn apply_changes(
+    wm: &mut WindowManager,
+    event_loop: &mut EventLoop,
+    keybind_manager: &mut KeybindManager,
+    config: &Config,
+    diff: &ConfigDiff,
+) -> Result<()> {
+    // Apply to window manager
+    wm.apply_config_changes(config, diff)
+        .map_err(|e| anyhow::anyhow!("Failed to apply configuration: {}", e))?;
+
+    // Update keybindings; the active mode's are registered if one is active
+    if diff.contains_any(&[ConfigSection::Keybinds, ConfigSection::Modes, ConfigSection::Input]) {
+        keybind_manager.set_modes(config.modes.clone());
+        keybind_manager.set_chord_timeout(Duration::from_millis(config.input.chord_timeout));
+        keybind_manager
+            .register_keybinds(config.keybinds.clone())
+            .map_err(|e| anyhow::anyhow!("Failed to register keybindings: {}", e))?;
+    }
+
+    // Update mouse bindings
+    if diff.contains(ConfigSection::Mousebinds) {
+        event_loop
+            .set_mouse_binds(&config.mousebinds)
+            .map_err(|e| anyhow::anyhow!("Failed to register mouse bindings: {}", e))?;
+    }
+
     Ok(())
 }
 
@@ -131,7 +222,7 @@ fn reload_configuration(
 fn handle_event(
     wm: &mut WindowManager,
     executor: &CommandExecutor,
-    keybind_manager: &KeybindManager,
+    keybind_manager: &mut KeybindManager,
     event: WindowEvent,
 ) -> Result<()> {
     match event {
@@ -214,34 +305,82 @@ fn handle_event(
 
         WindowEvent::WindowFocused(hwnd) => {
             debug!("Window focused: {:?}", hwnd);
+            // A focused window no longer demands attention
+            wm.set_urgent(&WindowHandle::from_hwnd(hwnd), false);
+            // Register the keybindings whose only_when/except_when apply to it
+            if keybind_manager.has_conditions() {
+                // Unmanaged windows, such as fullscreen games, count too
+                let unmanaged;
+                let window = match wm.get_window(hwnd.0) {
+                    Some(window) => Some(window),
+                    None => {
+                        unmanaged = ManagedWindow::new(WindowHandle::from_hwnd(hwnd), 0, 0).ok();
+                        unmanaged.as_ref()
+                    }
+                };
+                keybind_manager.set_focused_window(window)?;
+            }
             // Track which window has focus
             // Future: Could integrate with FocusManager via command system
             // executor.execute(Command::FocusWindow(hwnd), wm)
         }
 
+        WindowEvent::WindowFlashed(hwnd) => {
+            debug!("Window flashed: {:?}", hwnd);
+            // The focused window flashing is not worth highlighting
+            let is_focused = wm
+                .get_active_window()
+                .is_some_and(|w| w.handle.hwnd() == hwnd);
+            if !is_focused && wm.set_urgent(&WindowHandle::from_hwnd(hwnd), true) {
+                info!("Window {:?} is demanding attention", hwnd);
+            }
+        }
+
         WindowEvent::MonitorChanged => {
             info!("Monitor configuration changed");
-            // Refresh monitor information and re-tile all workspaces
-            wm.refresh_monitors()?;
-            wm.tile_workspace(wm.get_active_workspace())?;
+            // Refresh monitor information, move windows to follow their
+            // monitors and re-tile all workspaces
+            wm.reconcile_monitors()?;
             info!("Monitors refreshed");
         }
 
         WindowEvent::HotkeyPressed(hotkey_id) => {
             debug!("Hotkey pressed: {}", hotkey_id);
 
-            // Look up the command for this hotkey
-            if let Some((command, args)) = keybind_manager.get_command(hotkey_id) {
+            // Look up the command for this hotkey; keys of a sequence only
+            // run one once the sequence is complete
+            if let Some((command, args)) = keybind_manager.press_hotkey(hotkey_id) {
                 debug!("Executing command: {} {:?}", command, args); // Parse and execute the command
-                if let Err(e) =
-                    crate::app::commands::execute_command_from_string(executor, wm, command, args)
-                {
+                let result = match crate::app::commands::execute_mode_command(
+                    keybind_manager,
+                    &command,
+                    &args,
+                ) {
+                    Ok(true) => Ok(()),
+                    Ok(false) => crate::app::commands::execute_command_from_string(
+                        executor, wm, &command, &args,
+                    ),
+                    Err(e) => Err(e),
+                };
+                if let Err(e) = result {
                     error!("Failed to execute hotkey command '{}': {}", command, e);
                 }
-            } else {
-                warn!("Received hotkey event for unknown ID: {}", hotkey_id);
             }
         }
+
+        WindowEvent::MouseDragStarted { action, x, y } => {
+            debug!("Mouse drag started: {:?} at ({}, {})", action, x, y);
+            wm.begin_drag(action, x, y)?;
+        }
+
+        WindowEvent::MouseDragMoved { x, y } => {
+            wm.update_drag(x, y)?;
+        }
+
+        WindowEvent::MouseDragEnded { x, y } => {
+            debug!("Mouse drag ended at ({}, {})", x, y);
+            wm.end_drag(x, y)?;
+        }
     }
 
     Ok(())
@@ -252,16 +391,16 @@ fn handle_event(
 fn handle_event(
     wm: &mut WindowManager,
     _executor: &CommandExecutor,
-    _keybind_manager: &KeybindManager,
+    _keybind_manager: &mut KeybindManager,
     event: WindowEvent,
 ) -> Result<()> {
     match event {
         WindowEvent::MonitorChanged => {
             info!("EVENT: Monitor configuration changed");
-            // Refresh monitor information and re-tile all workspaces
-            wm.refresh_monitors()?;
-            wm.tile_workspace(wm.get_active_workspace())?;
-            info!("RESULT: Monitors refreshed and workspace retiled");
+            // Refresh monitor information, move windows to follow their
+            // monitors and re-tile all workspaces
+            wm.reconcile_monitors()?;
+            info!("RESULT: Monitors refreshed and workspaces retiled");
         }
         WindowEvent::HotkeyPressed(_) => {
             // Hotkeys not supported on non-Windows platforms
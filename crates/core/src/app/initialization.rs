@@ -6,8 +6,9 @@
 use anyhow::Result;
 use tracing::{info, warn};
 
-use crate::config::{ConfigLoader, ConfigValidator};
+use crate::config::{ConfigLoader, ConfigSources, ConfigValidator};
 use crate::window_manager::WindowManager;
+use crate::workspace::persistence::PersistenceManager;
 
 /// Initialize logging with appropriate levels and formatting.
 ///
@@ -86,23 +87,58 @@ pub fn scan_and_manage_windows(wm: &mut WindowManager) -> Result<()> {
     Ok(())
 }
 
+/// Restore window marks saved by the previous session.
+///
+/// Marks are matched to the windows found by `scan_and_manage_windows`,
+/// so this should be called after the initial scan.
+pub fn restore_saved_marks(wm: &mut WindowManager) {
+    let persistence = PersistenceManager::new();
+
+    if !persistence.has_saved_state() {
+        return;
+    }
+
+    match persistence.load_state_with_fallback() {
+        Ok(state) => {
+            wm.restore_marks(&state.marks);
+        }
+        Err(e) => warn!("Failed to load saved marks: {}", e),
+    }
+}
+
+/// Save the current window marks for the next session.
+///
+/// The rest of the saved session state is preserved.
+pub fn save_marks(wm: &WindowManager) -> Result<()> {
+    let persistence = PersistenceManager::new();
+    let mut state = persistence.load_state_with_fallback().unwrap_or_default();
+    state.marks = wm.mark_states();
+
+    persistence.save_state(&state)?;
+    info!("Saved {} window mark(s)", state.marks.len());
+    Ok(())
+}
+
 /// Load and validate configuration.
 ///
-/// Returns the ConfigLoader and loaded configuration if successful.
-pub fn load_and_validate_config() -> Result<(ConfigLoader, crate::config::Config)> {
+/// Returns the ConfigLoader, the loaded configuration and the files it was
+/// merged from if successful.
+pub fn load_and_validate_config(
+) -> Result<(ConfigLoader, crate::config::Config, ConfigSources)> {
     info!("Loading configuration...");
     let config_loader = ConfigLoader::new()?;
-    let config = config_loader.load()?;
+    let (config, sources) = config_loader.load_with_sources()?;
     info!(
-        "Configuration loaded from: {:?}",
-        config_loader.get_config_path()
+        "Configuration loaded from: {:?} ({} file(s))",
+        config_loader.get_config_path(),
+        sources.files().len()
     );
 
     // Validate configuration
-    ConfigValidator::validate(&config)?;
+    ConfigValidator::validate_with_sources(&config, &sources)?;
     info!("Configuration validated successfully");
 
-    Ok((config_loader, config))
+    Ok((config_loader, config, sources))
 }
 
 /// Demonstrate the command system integration.
@@ -6,8 +6,8 @@ pub mod commands;
 pub mod event_handling;
 pub mod initialization;
 
-pub use event_handling::run_event_loop;
+pub use event_handling::{run_event_loop, HotReload};
 pub use initialization::{
     demonstrate_command_system, initialize_logging, load_and_validate_config,
-    scan_and_manage_windows,
+    restore_saved_marks, save_marks, scan_and_manage_windows,
 };
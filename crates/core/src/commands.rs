@@ -17,7 +17,9 @@
 //! executor.execute(Command::SetLayoutMaster, &mut wm).ok();
 //! ```
 
+use crate::window_manager::floating::SnapTarget;
 use crate::window_manager::focus::Direction;
+use crate::window_manager::monitor::MonitorTarget;
 use crate::window_manager::{LayoutType, WindowManager};
 use anyhow::Result;
 use tracing::{debug, error, info, warn};
@@ -40,6 +42,26 @@ pub enum Command {
     /// Restore a minimized window
     RestoreActive,
 
+    // Floating commands
+    /// Move the active floating window by (dx, dy) pixels
+    FloatMove(i32, i32),
+    /// Resize the active floating window by (dw, dh) pixels
+    FloatResize(i32, i32),
+    /// Center the active floating window on its monitor
+    FloatCenter,
+    /// Snap the active floating window to an edge, corner or half
+    FloatSnap(SnapTarget),
+
+    // Mark commands
+    /// Assign a unique mark to the active window
+    Mark(String),
+    /// Remove a mark, or all marks if none is given
+    Unmark(Option<String>),
+    /// Focus the window carrying a mark, switching workspace if needed
+    FocusMark(String),
+    /// Swap the active window with the window carrying a mark
+    SwapWithMark(String),
+
     // Focus commands
     /// Focus the window to the left
     FocusLeft,
@@ -53,6 +75,10 @@ pub enum Command {
     FocusPrevious,
     /// Focus the next window in history
     FocusNext,
+    /// Focus the window that has been demanding attention the longest
+    FocusUrgent,
+    /// Focus a window on another monitor
+    FocusMonitor(MonitorTarget),
 
     // Move commands
     /// Move active window left in the tree
@@ -65,6 +91,8 @@ pub enum Command {
     MoveWindowDown,
     /// Swap active window with master window
     SwapWithMaster,
+    /// Move active window onto the workspace of another monitor
+    MoveToMonitor(MonitorTarget),
 
     // Layout commands
     /// Switch to dwindle layout
@@ -87,6 +115,47 @@ pub enum Command {
     MoveToWorkspace(usize),
     /// Move active window to workspace and follow
     MoveToWorkspaceAndFollow(usize),
+    /// Switch to the previously active workspace
+    WorkspaceBackAndForth,
+    /// Go back in the workspace history
+    WorkspaceHistoryBack,
+    /// Go forward in the workspace history
+    WorkspaceHistoryForward,
+    /// Switch to the next workspace
+    SwitchWorkspaceNext {
+        /// Skip workspaces without windows
+        skip_empty: bool,
+        /// Stay on the current monitor
+        same_monitor: bool,
+    },
+    /// Switch to the previous workspace
+    SwitchWorkspacePrevious {
+        /// Skip workspaces without windows
+        skip_empty: bool,
+        /// Stay on the current monitor
+        same_monitor: bool,
+    },
+    /// Switch every monitor to its workspace of a workspace group
+    SwitchGroup(usize),
+    /// Show or hide a named special workspace on the focused monitor
+    ToggleSpecial(String),
+    /// Move active window to a named special workspace
+    MoveToSpecial(String),
+    /// Move the active workspace to another monitor
+    MoveWorkspaceToMonitor(MonitorTarget),
+    /// Swap the workspaces of the focused monitor and another monitor (the
+    /// next one if none is given)
+    SwapMonitorWorkspaces(Option<MonitorTarget>),
+
+    // Tag commands (tag mode only)
+    /// View only the given tag on the focused monitor
+    ViewTag(usize),
+    /// Add or remove a tag from the focused monitor's view
+    ToggleViewTag(usize),
+    /// Give the active window only the given tag
+    TagWindow(usize),
+    /// Add or remove a tag from the active window
+    ToggleWindowTag(usize),
 
     // System commands
     /// Reload configuration
@@ -97,6 +166,97 @@ pub enum Command {
     ShowCommandPalette,
 }
 
+/// Names of the commands keybindings can run.
+///
+/// Keybinding `command` strings are parsed by the application's command
+/// dispatcher; this list must name every command it accepts, including the
+/// binding mode commands and `toggle-passthrough`. It is used to report
+/// unknown commands in a configuration before they are run.
+pub const COMMAND_NAMES: &[&str] = &[
+    // Window commands
+    "close",
+    "toggle-floating",
+    "toggle-fullscreen",
+    "minimize",
+    "restore",
+    // Floating commands
+    "float-move",
+    "float-resize",
+    "float-center",
+    "float-snap",
+    // Mark commands
+    "mark",
+    "unmark",
+    "focus-mark",
+    "swap-with-mark",
+    // Focus commands
+    "focus-left",
+    "focus-right",
+    "focus-up",
+    "focus-down",
+    "focus-previous",
+    "focus-next",
+    "focus-urgent",
+    "focus-monitor",
+    // Move commands
+    "move-left",
+    "move-right",
+    "move-up",
+    "move-down",
+    "swap-master",
+    "move-to-monitor",
+    // Layout commands
+    "layout-dwindle",
+    "layout-master",
+    "increase-master",
+    "decrease-master",
+    "increase-master-factor",
+    "decrease-master-factor",
+    // Workspace commands
+    "workspace-1",
+    "workspace-2",
+    "workspace-3",
+    "workspace-4",
+    "workspace-5",
+    "workspace-6",
+    "workspace-7",
+    "workspace-8",
+    "workspace-9",
+    "workspace-10",
+    "move-to-workspace-1",
+    "move-to-workspace-2",
+    "move-to-workspace-3",
+    "move-to-workspace-4",
+    "move-to-workspace-5",
+    "workspace",
+    "move-to-workspace",
+    "workspace-back-and-forth",
+    "workspace-history-back",
+    "workspace-history-forward",
+    "workspace-next",
+    "workspace-previous",
+    "workspace-prev",
+    "switch-group",
+    "toggle-special",
+    "move-to-special",
+    "move-workspace-to-monitor",
+    "swap-monitor-workspaces",
+    // Tag commands
+    "view-tag",
+    "toggle-view-tag",
+    "tag-window",
+    "toggle-window-tag",
+    // System commands
+    "reload-config",
+    "exit",
+    "quit",
+    "show-command-palette",
+    // Mode commands
+    "enter-mode",
+    "exit-mode",
+    "toggle-passthrough",
+];
+
 /// Executes commands on the WindowManager.
 ///
 /// The CommandExecutor provides methods to execute commands, handling
@@ -174,6 +334,25 @@ impl CommandExecutor {
             Command::MinimizeActive => self.minimize_active(wm),
             Command::RestoreActive => self.restore_active(wm),
 
+            // Floating commands
+            Command::FloatMove(dx, dy) => self.float_move(wm, dx, dy),
+            Command::FloatResize(dw, dh) => self.float_resize(wm, dw, dh),
+            Command::FloatCenter => self.float_center(wm),
+            Command::FloatSnap(target) => self.float_snap(wm, target),
+
+            // Mark commands
+            Command::Mark(ref mark) => self.mark(wm, mark),
+            Command::Unmark(ref mark) => {
+                info!("Removing mark {:?}", mark);
+                wm.unmark(mark.as_deref());
+                Ok(())
+            }
+            Command::FocusMark(ref mark) => {
+                info!("Focusing mark '{}'", mark);
+                wm.focus_mark(mark)
+            }
+            Command::SwapWithMark(ref mark) => self.swap_with_mark(wm, mark),
+
             // Focus commands
             Command::FocusLeft => self.focus_direction(wm, Direction::Left),
             Command::FocusRight => self.focus_direction(wm, Direction::Right),
@@ -181,6 +360,11 @@ impl CommandExecutor {
             Command::FocusDown => self.focus_direction(wm, Direction::Down),
             Command::FocusPrevious => self.focus_previous(wm),
             Command::FocusNext => self.focus_next(wm),
+            Command::FocusUrgent => {
+                info!("Focusing oldest urgent window");
+                wm.focus_urgent()
+            }
+            Command::FocusMonitor(ref target) => self.focus_monitor(wm, target),
 
             // Move commands
             Command::MoveWindowLeft => self.move_window(wm, Direction::Left),
@@ -188,6 +372,7 @@ impl CommandExecutor {
             Command::MoveWindowUp => self.move_window(wm, Direction::Up),
             Command::MoveWindowDown => self.move_window(wm, Direction::Down),
             Command::SwapWithMaster => self.swap_with_master(wm),
+            Command::MoveToMonitor(ref target) => self.move_to_monitor(wm, target),
 
             // Layout commands
             Command::SetLayoutDwindle => {
@@ -206,10 +391,46 @@ impl CommandExecutor {
             // Workspace commands
             Command::SwitchWorkspace(id) => {
                 info!("Switching to workspace {}", id);
-                wm.switch_workspace(id)
+                wm.goto_workspace(id)
             }
             Command::MoveToWorkspace(id) => self.move_to_workspace(wm, id),
             Command::MoveToWorkspaceAndFollow(id) => self.move_to_workspace_and_follow(wm, id),
+            Command::WorkspaceBackAndForth => wm.workspace_back_and_forth(),
+            Command::WorkspaceHistoryBack => wm.workspace_history_back(),
+            Command::WorkspaceHistoryForward => wm.workspace_history_forward(),
+            Command::SwitchWorkspaceNext { skip_empty, same_monitor } => {
+                wm.cycle_workspace(true, skip_empty, same_monitor)
+            }
+            Command::SwitchWorkspacePrevious { skip_empty, same_monitor } => {
+                wm.cycle_workspace(false, skip_empty, same_monitor)
+            }
+            Command::SwitchGroup(id) => {
+                info!("Switching to workspace group {}", id);
+                wm.switch_group(id)
+            }
+            Command::ToggleSpecial(ref name) => {
+                info!("Toggling special workspace '{}'", name);
+                wm.toggle_special(name)
+            }
+            Command::MoveToSpecial(ref name) => self.move_to_special(wm, name),
+            Command::MoveWorkspaceToMonitor(ref target) => {
+                self.move_workspace_to_monitor(wm, target)
+            }
+            Command::SwapMonitorWorkspaces(ref target) => {
+                self.swap_monitor_workspaces(wm, target.as_ref())
+            }
+
+            // Tag commands
+            Command::ViewTag(tag) => {
+                info!("Viewing tag {}", tag);
+                wm.view_tag(tag)
+            }
+            Command::ToggleViewTag(tag) => {
+                info!("Toggling view of tag {}", tag);
+                wm.toggle_view_tag(tag)
+            }
+            Command::TagWindow(tag) => self.tag_window(wm, tag, false),
+            Command::ToggleWindowTag(tag) => self.tag_window(wm, tag, true),
 
             // System commands
             Command::Reload => {
@@ -307,6 +528,78 @@ impl CommandExecutor {
         }
     }
 
+    /// Move the active floating window by an offset.
+    fn float_move(&self, wm: &mut WindowManager, dx: i32, dy: i32) -> Result<()> {
+        if let Some(window) = wm.get_active_window() {
+            let handle = window.handle;
+            debug!("Moving floating window '{}' by ({}, {})", window.title, dx, dy);
+            wm.float_move(&handle, dx, dy)
+        } else {
+            warn!("No active window to move");
+            Ok(())
+        }
+    }
+
+    /// Resize the active floating window by a size delta.
+    fn float_resize(&self, wm: &mut WindowManager, dw: i32, dh: i32) -> Result<()> {
+        if let Some(window) = wm.get_active_window() {
+            let handle = window.handle;
+            debug!("Resizing floating window '{}' by ({}, {})", window.title, dw, dh);
+            wm.float_resize(&handle, dw, dh)
+        } else {
+            warn!("No active window to resize");
+            Ok(())
+        }
+    }
+
+    /// Center the active floating window on its monitor.
+    fn float_center(&self, wm: &mut WindowManager) -> Result<()> {
+        if let Some(window) = wm.get_active_window() {
+            let handle = window.handle;
+            debug!("Centering floating window: {}", window.title);
+            wm.float_center(&handle)
+        } else {
+            warn!("No active window to center");
+            Ok(())
+        }
+    }
+
+    /// Snap the active floating window to an edge, corner or half of its monitor.
+    fn float_snap(&self, wm: &mut WindowManager, target: SnapTarget) -> Result<()> {
+        if let Some(window) = wm.get_active_window() {
+            let handle = window.handle;
+            debug!("Snapping floating window '{}' to {:?}", window.title, target);
+            wm.float_snap(&handle, target)
+        } else {
+            warn!("No active window to snap");
+            Ok(())
+        }
+    }
+
+    /// Mark the active window.
+    fn mark(&self, wm: &mut WindowManager, mark: &str) -> Result<()> {
+        if let Some(window) = wm.get_active_window() {
+            let handle = window.handle;
+            debug!("Marking window '{}' as '{}'", window.title, mark);
+            wm.mark_window(&handle, mark)
+        } else {
+            warn!("No active window to mark");
+            Ok(())
+        }
+    }
+
+    /// Swap the active window with the window carrying a mark.
+    fn swap_with_mark(&self, wm: &mut WindowManager, mark: &str) -> Result<()> {
+        if let Some(window) = wm.get_active_window() {
+            let handle = window.handle;
+            debug!("Swapping window '{}' with mark '{}'", window.title, mark);
+            wm.swap_with_mark(&handle, mark)
+        } else {
+            warn!("No active window to swap");
+            Ok(())
+        }
+    }
+
     /// Focus a window in a specific direction.
     ///
     /// Uses the DirectionalFocus helper to find adjacent windows based on
@@ -324,11 +617,6 @@ impl CommandExecutor {
 
         let candidates = self.get_workspace_window_candidates(wm, current_window.hwnd());
 
-        if candidates.is_empty() {
-            debug!("No other windows to focus");
-            return Ok(());
-        }
-
         if let Some(target_hwnd) =
             DirectionalFocus::find_window_in_direction(&current_rect, direction, &candidates)
         {
@@ -339,6 +627,10 @@ impl CommandExecutor {
             let target_window =
                 win32::WindowHandle::from_hwnd(windows::Win32::Foundation::HWND(target_hwnd as _));
             wm.focus_manager_mut().focus_window(&target_window)?;
+        } else if let Some(monitor) = wm.monitor_in_direction(direction) {
+            // Nothing further this way on the workspace: continue on the next monitor
+            debug!("Focusing monitor {} in direction {:?}", monitor, direction);
+            wm.focus_monitor(monitor, Some((&current_rect, direction)))?;
         } else {
             debug!("No window found in direction {:?}", direction);
         }
@@ -346,6 +638,32 @@ impl CommandExecutor {
         Ok(())
     }
 
+    /// Focus a window on the monitor a target refers to.
+    fn focus_monitor(&self, wm: &mut WindowManager, target: &MonitorTarget) -> Result<()> {
+        match wm.resolve_monitor(target) {
+            Some(monitor) => {
+                debug!("Focusing monitor {}", monitor);
+                wm.focus_monitor(monitor, None)
+            }
+            None => anyhow::bail!("No monitor matches '{}'", target),
+        }
+    }
+
+    /// Move the active window onto the monitor a target refers to.
+    fn move_to_monitor(&self, wm: &mut WindowManager, target: &MonitorTarget) -> Result<()> {
+        let monitor = match wm.resolve_monitor(target) {
+            Some(monitor) => monitor,
+            None => anyhow::bail!("No monitor matches '{}'", target),
+        };
+        match self.get_current_window_and_rect() {
+            Some((window, _)) => wm.move_window_to_monitor(&window, monitor),
+            None => {
+                warn!("No active window to move");
+                Ok(())
+            }
+        }
+    }
+
     fn get_current_window_and_rect(
         &self,
     ) -> Option<(
@@ -456,11 +774,23 @@ impl CommandExecutor {
         };
 
         let current_hwnd = current_window.hwnd();
-        let candidates = self.get_workspace_window_candidates(wm, current_hwnd);
+        let mut candidates = self.get_workspace_window_candidates(wm, current_hwnd);
 
-        if candidates.is_empty() {
-            debug!("No other windows to swap with");
-            return Ok(());
+        // Only windows on the same monitor; past the last one is the screen edge
+        let center = |rect: &crate::window_manager::Rect| {
+            (rect.x + rect.width / 2, rect.y + rect.height / 2)
+        };
+        let (x, y) = center(&current_rect);
+        if let Some(area) = wm
+            .get_monitors()
+            .iter()
+            .map(|monitor| monitor.work_area)
+            .find(|area| area.contains_point(x, y))
+        {
+            candidates.retain(|(_, rect)| {
+                let (x, y) = center(rect);
+                area.contains_point(x, y)
+            });
         }
 
         if let Some(target_hwnd_val) =
@@ -474,6 +804,10 @@ impl CommandExecutor {
             // Note: This simplified implementation just retiles the workspace
             // A more sophisticated approach would manipulate the tree structure directly
             wm.retile_workspace(wm.get_active_workspace())?;
+        } else if let Some(monitor) = wm.monitor_in_direction(direction) {
+            // At the screen edge: carry the window over to the next monitor
+            debug!("Moving window to monitor {} in direction {:?}", monitor, direction);
+            wm.move_window_to_monitor(&current_window, monitor)?;
         } else {
             debug!("No window found in direction {:?} to swap with", direction);
         }
@@ -595,8 +929,8 @@ impl CommandExecutor {
 
         debug!("Moving window to workspace {}", workspace_id);
 
-        // Validate workspace ID
-        if workspace_id < 1 || workspace_id > 10 {
+        // Validate workspace ID (workspaces beyond the initial ones are created on demand)
+        if workspace_id < 1 {
             warn!("Invalid workspace ID: {}", workspace_id);
             return Ok(());
         }
@@ -643,6 +977,62 @@ impl CommandExecutor {
         Ok(())
     }
 
+    /// Move the active window to a special workspace.
+    fn move_to_special(&self, wm: &mut WindowManager, name: &str) -> Result<()> {
+        if let Some(window) = wm.get_active_window() {
+            let handle = window.handle;
+            debug!("Moving window '{}' to special workspace '{}'", window.title, name);
+            wm.move_to_special(&handle, name)
+        } else {
+            warn!("No active window to move");
+            Ok(())
+        }
+    }
+
+    /// Set or toggle a tag on the active window.
+    fn tag_window(&self, wm: &mut WindowManager, tag: usize, toggle: bool) -> Result<()> {
+        if let Some(window) = wm.get_active_window() {
+            let handle = window.handle;
+            debug!("Tagging window '{}' with tag {}", window.title, tag);
+            if toggle {
+                wm.toggle_window_tag(&handle, tag)
+            } else {
+                wm.tag_window(&handle, tag)
+            }
+        } else {
+            warn!("No active window to tag");
+            Ok(())
+        }
+    }
+
+    /// Move the active workspace to the monitor a target refers to.
+    fn move_workspace_to_monitor(
+        &self,
+        wm: &mut WindowManager,
+        target: &MonitorTarget,
+    ) -> Result<()> {
+        let monitor = match wm.resolve_monitor(target) {
+            Some(monitor) => monitor,
+            None => anyhow::bail!("No monitor matches '{}'", target),
+        };
+        let workspace_id = wm.get_active_workspace();
+        info!("Moving workspace {} to monitor {}", workspace_id, monitor);
+        wm.move_workspace_to_monitor(workspace_id, monitor)
+    }
+
+    /// Swap the workspaces of the focused monitor and another monitor.
+    fn swap_monitor_workspaces(
+        &self,
+        wm: &mut WindowManager,
+        target: Option<&MonitorTarget>,
+    ) -> Result<()> {
+        match (wm.resolve_swap_monitors(target), target) {
+            (Some((first, second)), _) => wm.swap_monitor_workspaces(first, second),
+            (None, Some(target)) => anyhow::bail!("No monitor matches '{}'", target),
+            (None, None) => anyhow::bail!("No monitor to swap with"),
+        }
+    }
+
     /// Move the active window to a different workspace and switch to it.
     fn move_to_workspace_and_follow(
         &self,
@@ -0,0 +1,737 @@
+//! Configuration diagnostics
+//!
+//! Checking a configuration collects every problem as a `Diagnostic` instead
+//! of stopping at the first one. Each diagnostic names the setting it is about
+//! as a path into the document (e.g. `keybinds[3].modifiers[0]`), which
+//! `Diagnostics::locate` resolves to the file and line/column span the setting
+//! was written at, using the spans reported by the `toml` parser.
+//!
+//! # Example
+//!
+//! ```
+//! use tenraku_core::config::diagnostics::Diagnostics;
+//! use tenraku_core::config::{Config, ConfigValidator};
+//!
+//! let mut config = Config::default();
+//! config.layouts.default = "mastr".to_string();
+//!
+//! let mut diagnostics = Diagnostics::new();
+//! ConfigValidator::diagnose(&config, &mut diagnostics);
+//!
+//! let error = diagnostics.first_error().unwrap();
+//! assert_eq!(error.path, "layouts.default");
+//! assert_eq!(error.suggestion.as_deref(), Some("did you mean 'master'?"));
+//! ```
+
+use super::include::{ConfigSources, FileContext};
+use super::schema::Config;
+use super::variables::SubstitutionError;
+use serde::de::{self, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::ops::Range;
+use std::path::PathBuf;
+use toml::{Spanned, Table, Value};
+
+/// How serious a diagnostic is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The configuration cannot be loaded
+    Error,
+
+    /// The configuration loads, but probably not as intended
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// A range of text in a configuration file, with 1-based lines and columns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Span {
+    /// Line the range starts on
+    pub line: usize,
+
+    /// Column the range starts at
+    pub column: usize,
+
+    /// Line the range ends on
+    pub end_line: usize,
+
+    /// Column just after the end of the range
+    pub end_column: usize,
+}
+
+impl Span {
+    /// Convert a byte range in a text to lines and columns
+    pub fn from_range(text: &str, range: Range<usize>) -> Self {
+        let (line, column) = line_column(text, range.start);
+        let (end_line, end_column) = line_column(text, range.end.max(range.start));
+        Self {
+            line,
+            column,
+            end_line,
+            end_column,
+        }
+    }
+}
+
+/// Get the 1-based line and column of a byte offset
+fn line_column(text: &str, offset: usize) -> (usize, usize) {
+    let before = &text[..offset.min(text.len())];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|index| index + 1).unwrap_or(0);
+    (line, before[line_start..].chars().count() + 1)
+}
+
+/// A problem found in a configuration
+#[derive(Debug, Clone, Serialize)]
+pub struct Diagnostic {
+    /// How serious the problem is
+    pub severity: Severity,
+
+    /// What is wrong
+    pub message: String,
+
+    /// Path of the setting in the merged document (e.g. `layouts.default`),
+    /// or empty if the problem is not about one setting
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub path: String,
+
+    /// File the setting was written in
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<PathBuf>,
+
+    /// Where in the file the setting was written
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+
+    /// How the problem might be fixed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+}
+
+impl Diagnostic {
+    /// Add a suggestion for fixing the problem
+    pub fn suggest(&mut self, suggestion: impl Into<String>) -> &mut Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+
+    /// Add a suggestion if there is one
+    pub fn suggest_opt(&mut self, suggestion: Option<String>) -> &mut Self {
+        if suggestion.is_some() {
+            self.suggestion = suggestion;
+        }
+        self
+    }
+
+    /// Describe the file the setting came from, for error messages
+    ///
+    /// # Returns
+    /// ` (from rules/browsers.toml)` for list items of a configuration merged
+    /// from more than one file, or an empty string otherwise
+    pub fn origin(&self, sources: &ConfigSources) -> String {
+        match split_list_item(&self.path) {
+            Some((list, index, _)) => sources.origin(list, index),
+            None => String::new(),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.severity, self.message)?;
+        match (&self.file, self.span) {
+            (Some(file), Some(span)) => write!(
+                f,
+                "\n  --> {}:{}:{}",
+                file.display(),
+                span.line,
+                span.column
+            )?,
+            (Some(file), None) => write!(f, "\n  --> {}", file.display())?,
+            _ => {}
+        }
+        if !self.path.is_empty() {
+            write!(f, "\n  at {}", self.path)?;
+        }
+        if let Some(ref suggestion) = self.suggestion {
+            write!(f, "\n  = help: {}", suggestion)?;
+        }
+        Ok(())
+    }
+}
+
+/// The problems found in a configuration
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(transparent)]
+pub struct Diagnostics {
+    items: Vec<Diagnostic>,
+}
+
+impl Diagnostics {
+    /// Create an empty collection
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Report an error about the setting at a path
+    pub fn error(
+        &mut self,
+        path: impl Into<String>,
+        message: impl Into<String>,
+    ) -> &mut Diagnostic {
+        self.push(Severity::Error, path.into(), message.into())
+    }
+
+    /// Report a warning about the setting at a path
+    pub fn warning(
+        &mut self,
+        path: impl Into<String>,
+        message: impl Into<String>,
+    ) -> &mut Diagnostic {
+        self.push(Severity::Warning, path.into(), message.into())
+    }
+
+    /// Report an error that stopped the configuration from loading
+    ///
+    /// Parse errors keep the file and span reported by the parser, and
+    /// substitution errors keep the path of the offending string.
+    pub fn load_error(&mut self, error: &anyhow::Error) -> &mut Diagnostic {
+        if let Some(substitution) = error.downcast_ref::<SubstitutionError>() {
+            return self.error(substitution.location.clone(), substitution.message.clone());
+        }
+
+        let parse = error
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<toml::de::Error>());
+        let file = error.downcast_ref::<FileContext>();
+        match (parse, file) {
+            (Some(parse), Some(file)) => {
+                let span = parse.span().and_then(|range| {
+                    fs::read_to_string(&file.path)
+                        .ok()
+                        .map(|text| Span::from_range(&text, range))
+                });
+                let diagnostic = self.error("", parse.message().trim());
+                diagnostic.file = Some(file.path.clone());
+                diagnostic.span = span;
+                diagnostic
+            }
+            _ => self.error("", format!("{:#}", error)),
+        }
+    }
+
+    /// Fill in the file and span of every diagnostic about a setting
+    ///
+    /// Settings that are not written anywhere (e.g. defaults) are located at
+    /// the closest enclosing table or list item that is.
+    pub fn locate(&mut self, sources: &ConfigSources) {
+        let map = SourceMap::new(sources);
+        for diagnostic in &mut self.items {
+            if diagnostic.file.is_some() || diagnostic.path.is_empty() {
+                continue;
+            }
+            if let Some((file, span)) = map.locate(sources, &diagnostic.path) {
+                diagnostic.file = Some(file.path.clone());
+                diagnostic.span = Some(span);
+            }
+        }
+    }
+
+    /// Iterate over the diagnostics in the order they were reported
+    pub fn iter(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.items.iter()
+    }
+
+    /// Get the number of diagnostics
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Check whether no problems were found
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Check whether any error was found
+    pub fn has_errors(&self) -> bool {
+        self.error_count() > 0
+    }
+
+    /// Get the number of errors
+    pub fn error_count(&self) -> usize {
+        self.count(Severity::Error)
+    }
+
+    /// Get the number of warnings
+    pub fn warning_count(&self) -> usize {
+        self.count(Severity::Warning)
+    }
+
+    /// Get the first error reported
+    pub fn first_error(&self) -> Option<&Diagnostic> {
+        self.items
+            .iter()
+            .find(|diagnostic| diagnostic.severity == Severity::Error)
+    }
+
+    fn count(&self, severity: Severity) -> usize {
+        self.items
+            .iter()
+            .filter(|diagnostic| diagnostic.severity == severity)
+            .count()
+    }
+
+    fn push(&mut self, severity: Severity, path: String, message: String) -> &mut Diagnostic {
+        self.items.push(Diagnostic {
+            severity,
+            message,
+            path,
+            file: None,
+            span: None,
+            suggestion: None,
+        });
+        self.items.last_mut().expect("diagnostic was just pushed")
+    }
+}
+
+/// Suggest the candidate closest to a misspelled word
+///
+/// # Returns
+/// `did you mean '<candidate>'?`, or `None` if no candidate is close
+pub fn did_you_mean<'a>(
+    word: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<String> {
+    let word = word.to_lowercase();
+    let limit = (word.chars().count() / 3).max(1);
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(&word, &candidate.to_lowercase()), candidate))
+        .filter(|&(distance, _)| distance <= limit)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, candidate)| format!("did you mean '{}'?", candidate))
+}
+
+/// Levenshtein distance between two words
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Warn about keys the configuration schema does not know
+///
+/// Unknown keys are ignored when loading, so a misspelled setting silently
+/// keeps its default. The document is compared against the parsed
+/// configuration serialized back to TOML.
+pub fn check_unknown_keys(document: &Table, config: &Config, diagnostics: &mut Diagnostics) {
+    match Value::try_from(config) {
+        Ok(Value::Table(mut known)) => {
+            allow_keybind_input_keys(&mut known);
+            unknown_keys(document, &known, "", diagnostics)
+        }
+        Ok(_) => {}
+        Err(e) => tracing::debug!("Cannot check for unknown config keys: {}", e),
+    }
+}
+
+/// Keys a keybinding accepts that are not serialized back, since the
+/// compact `bind` form is parsed into `modifiers`, `key` and `command`
+const KEYBIND_INPUT_KEYS: &[&str] = &["bind"];
+
+/// Add the keys keybindings only accept as input to the main and mode
+/// keybindings of a serialized configuration
+fn allow_keybind_input_keys(known: &mut Table) {
+    let mut lists = Vec::new();
+    for (key, value) in known.iter_mut() {
+        match (key.as_str(), value) {
+            ("keybinds", list) => lists.push(list),
+            ("modes", Value::Array(modes)) => {
+                lists.extend(modes.iter_mut().filter_map(|mode| mode.get_mut("keybinds")))
+            }
+            _ => {}
+        }
+    }
+
+    let keybinds = lists
+        .into_iter()
+        .filter_map(Value::as_array_mut)
+        .flatten()
+        .filter_map(Value::as_table_mut);
+    for keybind in keybinds {
+        for key in KEYBIND_INPUT_KEYS {
+            keybind.insert(key.to_string(), Value::Boolean(true));
+        }
+    }
+}
+
+fn unknown_keys(document: &Table, known: &Table, path: &str, diagnostics: &mut Diagnostics) {
+    for (key, value) in document {
+        let child = join_path(path, key);
+        match known.get(key) {
+            Some(known) => unknown_keys_in(value, known, &child, diagnostics),
+            None => {
+                let message = if path.is_empty() {
+                    format!("Unknown key `{}`", key)
+                } else {
+                    format!("Unknown key `{}` in `{}`", key, path)
+                };
+                diagnostics
+                    .warning(child, message)
+                    .suggest_opt(did_you_mean(key, known.keys().map(String::as_str)));
+            }
+        }
+    }
+}
+
+fn unknown_keys_in(value: &Value, known: &Value, path: &str, diagnostics: &mut Diagnostics) {
+    match (value, known) {
+        (Value::Table(table), Value::Table(known)) => unknown_keys(table, known, path, diagnostics),
+        (Value::Array(items), Value::Array(known)) if items.len() == known.len() => {
+            for (index, (item, known)) in items.iter().zip(known).enumerate() {
+                unknown_keys_in(item, known, &format!("{}[{}]", path, index), diagnostics);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Append a key to a dotted path
+fn join_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/// Split a path into its outermost list, the item index and the rest
+///
+/// `keybinds[3].modifiers[0]` becomes `("keybinds", 3, ".modifiers[0]")`.
+fn split_list_item(path: &str) -> Option<(&str, usize, &str)> {
+    let open = path.find('[')?;
+    let close = open + path[open..].find(']')?;
+    let index = path[open + 1..close].parse().ok()?;
+    Some((&path[..open], index, &path[close + 1..]))
+}
+
+/// Get the path of the table or list containing a setting
+fn parent_path(path: &str) -> Option<&str> {
+    let end = path.rfind(['.', '['])?;
+    (end > 0).then(|| &path[..end])
+}
+
+/// The spans of every setting in the files of a configuration
+struct SourceMap {
+    files: Vec<SourceFile>,
+}
+
+/// The spans of every setting in one file, by path
+struct SourceFile {
+    path: PathBuf,
+    text: String,
+    spans: HashMap<String, Range<usize>>,
+}
+
+impl SourceMap {
+    fn new(sources: &ConfigSources) -> Self {
+        let files = sources
+            .files()
+            .iter()
+            .map(|path| {
+                let text = fs::read_to_string(path).unwrap_or_default();
+                let mut spans = HashMap::new();
+                if let Ok(Node::Table(table)) = toml::from_str::<Node>(&text) {
+                    collect_spans(&table, "", &mut spans);
+                }
+                SourceFile {
+                    path: path.clone(),
+                    text,
+                    spans,
+                }
+            })
+            .collect();
+        Self { files }
+    }
+
+    /// Find the file and span a setting was written at
+    fn locate(&self, sources: &ConfigSources, path: &str) -> Option<(&SourceFile, Span)> {
+        // List items are looked up in the file they came from, where they
+        // may have a different index than in the merged list
+        if let Some((list, index, rest)) = split_list_item(path) {
+            if let Some((file, local)) = sources.item_location(list, index) {
+                let file = self.files.get(file)?;
+                let local_path = format!("{}[{}]{}", list, local, rest);
+                let mut candidate = Some(local_path.as_str());
+                while let Some(path) = candidate {
+                    if let Some(range) = file.spans.get(path) {
+                        return Some((file, Span::from_range(&file.text, range.clone())));
+                    }
+                    candidate = parent_path(path);
+                }
+                return None;
+            }
+        }
+
+        // Other settings come from the last file that sets them
+        let mut candidate = Some(path);
+        while let Some(path) = candidate {
+            for file in self.files.iter().rev() {
+                if let Some(range) = file.spans.get(path) {
+                    return Some((file, Span::from_range(&file.text, range.clone())));
+                }
+            }
+            candidate = parent_path(path);
+        }
+        None
+    }
+}
+
+/// Record the span of every value in a table by path
+fn collect_spans(
+    table: &[(String, Spanned<Node>)],
+    path: &str,
+    spans: &mut HashMap<String, Range<usize>>,
+) {
+    for (key, value) in table {
+        let child = join_path(path, key);
+        spans.insert(child.clone(), value.span());
+        collect_node(value.get_ref(), &child, spans);
+    }
+}
+
+fn collect_node(node: &Node, path: &str, spans: &mut HashMap<String, Range<usize>>) {
+    match node {
+        Node::Table(table) => collect_spans(table, path, spans),
+        Node::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                let child = format!("{}[{}]", path, index);
+                spans.insert(child.clone(), item.span());
+                collect_node(item.get_ref(), &child, spans);
+            }
+        }
+        Node::Value => {}
+    }
+}
+
+/// The shape of a TOML document with the span of every value
+enum Node {
+    Table(Vec<(String, Spanned<Node>)>),
+    Array(Vec<Spanned<Node>>),
+    Value,
+}
+
+impl<'de> Deserialize<'de> for Node {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(NodeVisitor)
+    }
+}
+
+struct NodeVisitor;
+
+impl<'de> Visitor<'de> for NodeVisitor {
+    type Value = Node;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a TOML value")
+    }
+
+    fn visit_bool<E: de::Error>(self, _: bool) -> Result<Node, E> {
+        Ok(Node::Value)
+    }
+
+    fn visit_i64<E: de::Error>(self, _: i64) -> Result<Node, E> {
+        Ok(Node::Value)
+    }
+
+    fn visit_u64<E: de::Error>(self, _: u64) -> Result<Node, E> {
+        Ok(Node::Value)
+    }
+
+    fn visit_f64<E: de::Error>(self, _: f64) -> Result<Node, E> {
+        Ok(Node::Value)
+    }
+
+    fn visit_str<E: de::Error>(self, _: &str) -> Result<Node, E> {
+        Ok(Node::Value)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Node, A::Error> {
+        let mut items = Vec::new();
+        while let Some(item) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(Node::Array(items))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Node, A::Error> {
+        let mut table = Vec::new();
+        while let Some(key) = map.next_key::<String>()? {
+            table.push((key, map.next_value()?));
+        }
+        Ok(Node::Table(table))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::include::load_layers;
+    use std::path::Path;
+
+    fn write(dir: &Path, name: &str, content: &str) {
+        fs::write(dir.join(name), content).unwrap();
+    }
+
+    #[test]
+    fn test_did_you_mean() {
+        let layouts = ["dwindle", "master"];
+        assert_eq!(
+            did_you_mean("mastr", layouts).as_deref(),
+            Some("did you mean 'master'?")
+        );
+        assert_eq!(
+            did_you_mean("Dwindel", layouts).as_deref(),
+            Some("did you mean 'dwindle'?")
+        );
+        assert_eq!(did_you_mean("spiral", layouts), None);
+    }
+
+    #[test]
+    fn test_span_from_range() {
+        let text = "[general]\ngaps_in = 5\n";
+        let span = Span::from_range(text, 20..21);
+        assert_eq!((span.line, span.column), (2, 11));
+        assert_eq!((span.end_line, span.end_column), (2, 12));
+    }
+
+    #[test]
+    fn test_locate_list_items_in_their_file() {
+        let dir = tempfile::tempdir().unwrap();
+        write(
+            dir.path(),
+            "config.toml",
+            "include = [\"keys.toml\"]\n\n[layouts]\ndefault = \"mastr\"\n",
+        );
+        write(
+            dir.path(),
+            "keys.toml",
+            "[[keybinds]]\nmodifiers = [\"Win\"]\nkey = \"Q\"\ncommand = \"close\"\n\n[[keybinds]]\nmodifiers = [\"Super\"]\nkey = \"E\"\ncommand = \"exec\"\n",
+        );
+        let (_, sources) = load_layers(&dir.path().join("config.toml"), None).unwrap();
+
+        let mut diagnostics = Diagnostics::new();
+        diagnostics.error("keybinds[1].modifiers[0]", "Invalid modifier: Super");
+        diagnostics.error(
+            "layouts.default",
+            "default layout must be 'dwindle' or 'master'",
+        );
+        diagnostics.error("general.gaps_in", "not written anywhere");
+        diagnostics.locate(&sources);
+
+        let located: Vec<_> = diagnostics
+            .iter()
+            .map(|d| {
+                (
+                    d.file
+                        .as_ref()
+                        .and_then(|f| f.file_name())
+                        .map(|f| f.to_string_lossy().to_string()),
+                    d.span.map(|s| (s.line, s.column)),
+                )
+            })
+            .collect();
+        assert_eq!(located[0], (Some("keys.toml".to_string()), Some((7, 14))));
+        assert_eq!(located[1], (Some("config.toml".to_string()), Some((4, 11))));
+        assert_eq!(located[2], (None, None));
+    }
+
+    #[test]
+    fn test_unknown_keys() {
+        let text = "[general]\ngaps_inn = 5\n\n[[keybinds]]\nmodifiers = [\"Win\"]\nkey = \"Q\"\ncommand = \"close\"\ncomand = \"x\"\n";
+        let document: Table = toml::from_str(text).unwrap();
+        let config: Config = toml::from_str(text).unwrap();
+
+        let mut diagnostics = Diagnostics::new();
+        check_unknown_keys(&document, &config, &mut diagnostics);
+
+        let warnings: Vec<_> = diagnostics.iter().collect();
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].path, "general.gaps_inn");
+        assert_eq!(
+            warnings[0].suggestion.as_deref(),
+            Some("did you mean 'gaps_in'?")
+        );
+        assert_eq!(warnings[1].path, "keybinds[0].comand");
+        assert!(!diagnostics.has_errors());
+    }
+
+    #[test]
+    fn test_unknown_keys_in_compact_keybinds() {
+        let text = r#"
+            [[keybinds]]
+            bind = ["Win+Shift+Q", "close"]
+            except_when = { match_process = "game\\.exe", match_titel = "x" }
+
+            [[keybinds]]
+            bind = ["Win+Space, W, 1", "workspace", "1"]
+            only_when = { match_class = "Chrome" }
+            argz = ["2"]
+
+            [[modes]]
+            name = "resize"
+
+            [[modes.keybinds]]
+            bind = ["H", "decrease-master-factor"]
+            "#;
+        let document: Table = toml::from_str(text).unwrap();
+        let config: Config = toml::from_str(text).unwrap();
+
+        let mut diagnostics = Diagnostics::new();
+        check_unknown_keys(&document, &config, &mut diagnostics);
+
+        let paths: Vec<_> = diagnostics.iter().map(|d| d.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            ["keybinds[0].except_when.match_titel", "keybinds[1].argz"]
+        );
+        assert_eq!(
+            diagnostics.iter().nth(1).unwrap().suggestion.as_deref(),
+            Some("did you mean 'args'?")
+        );
+    }
+
+    #[test]
+    fn test_load_error_keeps_span() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "config.toml", "[general]\ngaps_in = \n");
+
+        let error = load_layers(&dir.path().join("config.toml"), None).unwrap_err();
+        let mut diagnostics = Diagnostics::new();
+        diagnostics.load_error(&error);
+
+        let diagnostic = diagnostics.first_error().unwrap();
+        assert!(diagnostic.file.is_some());
+        assert_eq!(diagnostic.span.map(|s| s.line), Some(2));
+    }
+}
@@ -0,0 +1,568 @@
+//! Layered configuration loading
+//!
+//! A configuration file can pull in other files with a top-level `include`
+//! list, and an optional per-host overlay can sit next to the main file.
+//! All files are merged into one TOML document before it is parsed into a
+//! `Config`.
+//!
+//! # Merge order
+//! - The files a file includes are merged first, in the order listed; the
+//!   files matched by one wildcard pattern are merged in name order
+//! - The including file is merged on top of its includes
+//! - The host overlay (`config.<hostname>.toml`) is merged last
+//!
+//! # Merge semantics
+//! - Tables are merged key by key
+//! - Lists are appended, unless the layer names them in its top-level
+//!   `replace` list (e.g. `replace = ["keybinds"]`), which replaces them
+//! - Any other value replaces the earlier one
+//!
+//! Include paths are relative to the including file and may use `*` and `?`
+//! wildcards in any path component. Include cycles are reported as errors;
+//! a file included twice without a cycle is only merged once.
+
+use anyhow::{Context, Result};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use toml::{Table, Value};
+
+/// Key listing the files a configuration file includes
+const INCLUDE_KEY: &str = "include";
+
+/// Key listing the lists a configuration file replaces instead of appending to
+const REPLACE_KEY: &str = "replace";
+
+/// Where the parts of a layered configuration came from
+///
+/// Records every file that was merged and, for each list, the file every
+/// item came from, so that errors can name the file to fix.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigSources {
+    /// Directory of the main configuration file, used to shorten paths
+    base_dir: Option<PathBuf>,
+
+    /// Merged files, in merge order
+    files: Vec<PathBuf>,
+
+    /// Directories searched for wildcard includes
+    include_dirs: Vec<PathBuf>,
+
+    /// Index into `files` of each list item, by dotted list path
+    items: HashMap<String, Vec<usize>>,
+}
+
+impl ConfigSources {
+    /// Get the merged files in merge order
+    pub fn files(&self) -> &[PathBuf] {
+        &self.files
+    }
+
+    /// Get the directories searched for wildcard includes
+    ///
+    /// Files created in these directories later can change the configuration.
+    pub fn include_dirs(&self) -> &[PathBuf] {
+        &self.include_dirs
+    }
+
+    /// Get the file an item of a list came from
+    ///
+    /// # Arguments
+    /// * `list` - Dotted path of the list (e.g. `window_rules`, `dialogs.classes`)
+    /// * `index` - Index of the item in the merged list
+    pub fn item_source(&self, list: &str, index: usize) -> Option<&Path> {
+        let file = *self.items.get(list)?.get(index)?;
+        self.files.get(file).map(PathBuf::as_path)
+    }
+
+    /// Get the file an item of a list came from and its index in that file
+    ///
+    /// # Returns
+    /// The index into `files()` and the index of the item in the file's list
+    pub fn item_location(&self, list: &str, index: usize) -> Option<(usize, usize)> {
+        let items = self.items.get(list)?;
+        let file = *items.get(index)?;
+        let local = items[..index].iter().filter(|&&other| other == file).count();
+        Some((file, local))
+    }
+
+    /// Describe where an item of a list came from, for error messages
+    ///
+    /// # Returns
+    /// ` (from rules/browsers.toml)` if the configuration was merged from more
+    /// than one file, or an empty string otherwise
+    pub fn origin(&self, list: &str, index: usize) -> String {
+        if self.files.len() < 2 {
+            return String::new();
+        }
+        match self.item_source(list, index) {
+            Some(path) => format!(" (from {})", self.display_path(path)),
+            None => String::new(),
+        }
+    }
+
+    /// Shorten a path to be relative to the main configuration file
+    pub fn display_path(&self, path: &Path) -> String {
+        self.base_dir
+            .as_deref()
+            .and_then(|base| path.strip_prefix(base).ok())
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    }
+
+    /// Record a merged file and get its index
+    fn add_file(&mut self, path: PathBuf) -> usize {
+        self.files.push(path);
+        self.files.len() - 1
+    }
+
+    /// Record the origin of the lists in a value set by a file
+    ///
+    /// Lists inside the value replace any origins recorded before.
+    fn record(&mut self, path: &str, value: &Value, file: usize) {
+        match value {
+            Value::Array(items) => {
+                self.items.insert(path.to_string(), vec![file; items.len()]);
+            }
+            Value::Table(table) => {
+                for (key, value) in table {
+                    self.record(&format!("{}.{}", path, key), value, file);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Record the origin of items appended to a list
+    fn append(&mut self, path: &str, count: usize, file: usize) {
+        self.items
+            .entry(path.to_string())
+            .or_default()
+            .extend(std::iter::repeat_n(file, count));
+    }
+}
+
+/// The configuration file an error happened in
+///
+/// Attached as context to errors parsing a file, so callers can find the file
+/// and the underlying `toml::de::Error` with its span.
+#[derive(Debug, Clone)]
+pub struct FileContext {
+    /// The file that failed to parse
+    pub path: PathBuf,
+}
+
+impl FileContext {
+    /// Create the context of an error parsing a file
+    pub fn parse(path: &Path) -> Self {
+        Self {
+            path: path.to_path_buf(),
+        }
+    }
+}
+
+impl fmt::Display for FileContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Failed to parse config file: {:?}", self.path)
+    }
+}
+
+/// Load a configuration file with its includes and an optional overlay
+///
+/// # Arguments
+/// * `path` - The main configuration file
+/// * `overlay` - A file merged on top of everything else, if it exists
+///
+/// # Returns
+/// The merged document without `include` and `replace` keys, and the
+/// sources it was merged from
+pub fn load_layers(path: &Path, overlay: Option<&Path>) -> Result<(Table, ConfigSources)> {
+    let mut layering = Layering {
+        merged: Table::new(),
+        sources: ConfigSources {
+            base_dir: path
+                .canonicalize()
+                .ok()
+                .and_then(|path| path.parent().map(Path::to_path_buf)),
+            ..ConfigSources::default()
+        },
+        stack: Vec::new(),
+        loaded: HashSet::new(),
+    };
+
+    layering.load_file(path)?;
+    if let Some(overlay) = overlay.filter(|overlay| overlay.exists()) {
+        tracing::info!("Applying host configuration overlay {:?}", overlay);
+        layering.load_file(overlay)?;
+    }
+
+    Ok((layering.merged, layering.sources))
+}
+
+/// State of a layered load
+struct Layering {
+    /// Document merged so far
+    merged: Table,
+
+    /// Where the merged parts came from
+    sources: ConfigSources,
+
+    /// Files being loaded, outermost first, for cycle detection
+    stack: Vec<PathBuf>,
+
+    /// Files loaded so far
+    loaded: HashSet<PathBuf>,
+}
+
+impl Layering {
+    /// Merge a file, after the files it includes
+    fn load_file(&mut self, path: &Path) -> Result<()> {
+        let path = path
+            .canonicalize()
+            .with_context(|| format!("Failed to read config file: {:?}", path))?;
+
+        if let Some(start) = self.stack.iter().position(|file| *file == path) {
+            let cycle: Vec<_> = self.stack[start..]
+                .iter()
+                .chain(std::iter::once(&path))
+                .map(|file| self.sources.display_path(file))
+                .collect();
+            anyhow::bail!("Config include cycle: {}", cycle.join(" -> "));
+        }
+
+        if !self.loaded.insert(path.clone()) {
+            tracing::debug!("Config file {:?} is already included, skipping", path);
+            return Ok(());
+        }
+
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read config file: {:?}", path))?;
+        let mut layer: Table =
+            toml::from_str(&content).with_context(|| FileContext::parse(&path))?;
+
+        let includes = take_string_list(&mut layer, INCLUDE_KEY)
+            .with_context(|| format!("Invalid config file: {:?}", path))?;
+        let replace = take_string_list(&mut layer, REPLACE_KEY)
+            .with_context(|| format!("Invalid config file: {:?}", path))?;
+
+        let dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        self.stack.push(path.clone());
+        for pattern in &includes {
+            let files = self.expand_include(&dir, pattern)?;
+            if files.is_empty() {
+                tracing::warn!("Include '{}' in {:?} matched no files", pattern, path);
+            }
+            for file in files {
+                self.load_file(&file)?;
+            }
+        }
+        self.stack.pop();
+
+        tracing::debug!("Merging config file {:?}", path);
+        let file = self.sources.add_file(path);
+        merge_table(&mut self.merged, layer, "", &replace, file, &mut self.sources);
+        Ok(())
+    }
+
+    /// Resolve an include pattern to files
+    ///
+    /// Plain paths must exist; wildcard patterns may match nothing.
+    fn expand_include(&mut self, dir: &Path, pattern: &str) -> Result<Vec<PathBuf>> {
+        let pattern_path = dir.join(pattern);
+        if !has_wildcard(pattern) {
+            if !pattern_path.is_file() {
+                anyhow::bail!("Included config file not found: {:?}", pattern_path);
+            }
+            return Ok(vec![pattern_path]);
+        }
+
+        let mut candidates = vec![PathBuf::new()];
+        for component in pattern_path.components() {
+            let name = match component {
+                Component::Normal(name) => name.to_string_lossy(),
+                other => {
+                    for candidate in candidates.iter_mut() {
+                        candidate.push(other.as_os_str());
+                    }
+                    continue;
+                }
+            };
+
+            if !has_wildcard(&name) {
+                for candidate in candidates.iter_mut() {
+                    candidate.push(name.as_ref());
+                }
+                continue;
+            }
+
+            let mut matches = Vec::new();
+            for candidate in &candidates {
+                let entries = match fs::read_dir(candidate) {
+                    Ok(entries) => entries,
+                    Err(_) => continue,
+                };
+                if !self.sources.include_dirs.contains(candidate) {
+                    self.sources.include_dirs.push(candidate.clone());
+                }
+                for entry in entries.flatten() {
+                    if wildcard_match(&name, &entry.file_name().to_string_lossy()) {
+                        matches.push(entry.path());
+                    }
+                }
+            }
+            matches.sort();
+            candidates = matches;
+        }
+
+        candidates.retain(|candidate| candidate.is_file());
+        Ok(candidates)
+    }
+}
+
+/// Remove a list of strings from the top level of a layer
+fn take_string_list(layer: &mut Table, key: &str) -> Result<Vec<String>> {
+    match layer.remove(key) {
+        None => Ok(Vec::new()),
+        Some(Value::Array(items)) => items
+            .into_iter()
+            .map(|item| match item {
+                Value::String(item) => Ok(item),
+                other => anyhow::bail!("'{}' entries must be strings, found {}", key, other),
+            })
+            .collect(),
+        Some(other) => anyhow::bail!("'{}' must be a list of strings, found {}", key, other),
+    }
+}
+
+/// Merge a layer into the document merged so far
+fn merge_table(
+    merged: &mut Table,
+    layer: Table,
+    prefix: &str,
+    replace: &[String],
+    file: usize,
+    sources: &mut ConfigSources,
+) {
+    for (key, value) in layer {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+
+        match (merged.get_mut(&key), value) {
+            (Some(Value::Table(merged_table)), Value::Table(table)) => {
+                merge_table(merged_table, table, &path, replace, file, sources);
+            }
+            (Some(Value::Array(merged_items)), Value::Array(items))
+                if !replace.contains(&path) =>
+            {
+                sources.append(&path, items.len(), file);
+                merged_items.extend(items);
+            }
+            (_, value) => {
+                sources.record(&path, &value, file);
+                merged.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Check whether an include pattern contains wildcards
+fn has_wildcard(pattern: &str) -> bool {
+    pattern.contains(['*', '?'])
+}
+
+/// Match a file name against a pattern with `*` and `?` wildcards
+///
+/// Matching ignores case, like file names on Windows.
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    let name: Vec<char> = name.to_lowercase().chars().collect();
+
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    n = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(dir: &Path, name: &str, content: &str) -> PathBuf {
+        let path = dir.join(name);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).unwrap();
+        }
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("*.toml", "browsers.toml"));
+        assert!(wildcard_match("rule?.TOML", "rule1.toml"));
+        assert!(wildcard_match("a*b*c", "aXbYbc"));
+        assert!(!wildcard_match("*.toml", "browsers.toml.bak"));
+        assert!(!wildcard_match("rule?.toml", "rule10.toml"));
+    }
+
+    #[test]
+    fn test_includes_merge_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let main = write(
+            dir.path(),
+            "config.toml",
+            r#"
+            include = ["keybinds.toml", "rules/*.toml"]
+
+            [general]
+            gaps_in = 8
+
+            [[window_rules]]
+            match_process = "main"
+            actions = ["float"]
+            "#,
+        );
+        write(
+            dir.path(),
+            "keybinds.toml",
+            r#"
+            [general]
+            gaps_in = 2
+            gaps_out = 4
+            "#,
+        );
+        write(
+            dir.path(),
+            "rules/browsers.toml",
+            r#"
+            [[window_rules]]
+            match_process = "firefox"
+            actions = ["tile"]
+            "#,
+        );
+        write(
+            dir.path(),
+            "rules/apps.toml",
+            r#"
+            [[window_rules]]
+            match_process = "steam"
+            actions = ["float"]
+            "#,
+        );
+
+        let (merged, sources) = load_layers(&main, None).unwrap();
+
+        assert_eq!(merged["general"]["gaps_in"].as_integer(), Some(8));
+        assert_eq!(merged["general"]["gaps_out"].as_integer(), Some(4));
+        assert!(merged.get(INCLUDE_KEY).is_none());
+
+        let rules: Vec<_> = merged["window_rules"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|rule| rule["match_process"].as_str().unwrap())
+            .collect();
+        assert_eq!(rules, vec!["steam", "firefox", "main"]);
+        assert_eq!(sources.files().len(), 4);
+        assert_eq!(sources.include_dirs().len(), 1);
+        assert!(sources
+            .item_source("window_rules", 1)
+            .unwrap()
+            .ends_with("browsers.toml"));
+        assert_eq!(
+            sources.origin("window_rules", 1),
+            format!(" (from {})", Path::new("rules").join("browsers.toml").display())
+        );
+    }
+
+    #[test]
+    fn test_overlay_replaces_lists() {
+        let dir = tempfile::tempdir().unwrap();
+        let main = write(
+            dir.path(),
+            "config.toml",
+            r#"
+            [[monitors]]
+            name = "DISPLAY1"
+
+            [[keybinds]]
+            modifiers = ["Win"]
+            key = "Q"
+            command = "close"
+            "#,
+        );
+        let overlay = write(
+            dir.path(),
+            "config.laptop.toml",
+            r#"
+            replace = ["monitors"]
+
+            [[monitors]]
+            name = "eDP"
+
+            [[keybinds]]
+            modifiers = ["Win"]
+            key = "B"
+            command = "exec"
+            "#,
+        );
+
+        let (merged, sources) = load_layers(&main, Some(&overlay)).unwrap();
+
+        let monitors = merged["monitors"].as_array().unwrap();
+        assert_eq!(monitors.len(), 1);
+        assert_eq!(monitors[0]["name"].as_str(), Some("eDP"));
+        assert_eq!(merged["keybinds"].as_array().unwrap().len(), 2);
+        assert!(merged.get(REPLACE_KEY).is_none());
+        assert_eq!(sources.item_source("keybinds", 0), Some(sources.files()[0].as_path()));
+        assert_eq!(sources.item_source("monitors", 0), Some(sources.files()[1].as_path()));
+    }
+
+    #[test]
+    fn test_include_cycle_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let main = write(dir.path(), "config.toml", r#"include = ["a.toml"]"#);
+        write(dir.path(), "a.toml", r#"include = ["b.toml"]"#);
+        write(dir.path(), "b.toml", r#"include = ["a.toml"]"#);
+
+        let error = format!("{:#}", load_layers(&main, None).unwrap_err());
+        assert!(error.contains("Config include cycle: a.toml -> b.toml -> a.toml"));
+    }
+
+    #[test]
+    fn test_missing_include_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let main = write(
+            dir.path(),
+            "config.toml",
+            r#"include = ["missing.toml", "rules/*.toml"]"#,
+        );
+
+        let error = format!("{:#}", load_layers(&main, None).unwrap_err());
+        assert!(error.contains("not found"));
+    }
+}
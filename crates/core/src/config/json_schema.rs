@@ -0,0 +1,1188 @@
+//! JSON Schema for the configuration files
+//!
+//! Describes `config.toml` (`Config`) and the status bar's configuration file
+//! (`BarConfig` in the status bar crate) as JSON Schema (draft-07), with every
+//! nested table, the defaults and the ranges enforced by `ConfigValidator`.
+//! Editors with a TOML language server (e.g. taplo / Even Better TOML) use it
+//! to complete and check settings while typing.
+//!
+//! The bar's schema is kept here so that `tenrakuctl` can print it without
+//! linking the bar. Its defaults come from the `tenraku-defaults` crate, which
+//! the bar uses too; the bar's tests check its settings against `BarConfig`.
+//!
+//! # Example
+//!
+//! ```
+//! use tenraku_core::config::json_schema::config_schema;
+//!
+//! let schema = config_schema();
+//! let gaps_in = &schema["definitions"]["GeneralConfig"]["properties"]["gaps_in"];
+//! assert_eq!(gaps_in["minimum"], 0);
+//! assert_eq!(gaps_in["default"], 5);
+//! ```
+
+use super::ranges;
+use super::schema::*;
+use crate::keybinds::keys::Modifier;
+use serde_json::{json, Map, Value};
+use std::ops::RangeInclusive;
+use tenraku_defaults::bar as bar_defaults;
+
+/// Draft of JSON Schema the schemas use
+const DRAFT: &str = "http://json-schema.org/draft-07/schema#";
+
+/// Pattern of colors accepted by `ConfigValidator::validate_color`
+const COLOR_PATTERN: &str = "^#([0-9a-fA-F]{3}|[0-9a-fA-F]{6}|[0-9a-fA-F]{8})$";
+
+/// A JSON Schema under construction
+#[derive(Debug, Clone, Default)]
+struct Schema(Map<String, Value>);
+
+impl Schema {
+    fn typed(kind: &str) -> Self {
+        Self::default().with("type", kind)
+    }
+
+    fn integer() -> Self {
+        Self::typed("integer")
+    }
+
+    fn number() -> Self {
+        Self::typed("number")
+    }
+
+    fn string() -> Self {
+        Self::typed("string")
+    }
+
+    fn boolean() -> Self {
+        Self::typed("boolean")
+    }
+
+    fn color() -> Self {
+        Self::string().with("pattern", COLOR_PATTERN)
+    }
+
+    fn array(items: Schema) -> Self {
+        Self::typed("array").with("items", items.build())
+    }
+
+    fn string_array() -> Self {
+        Self::array(Self::string())
+    }
+
+    fn enumeration<T: Into<Value>>(values: impl IntoIterator<Item = T>) -> Self {
+        let values: Vec<Value> = values.into_iter().map(Into::into).collect();
+        Self::default().with("enum", values)
+    }
+
+    fn reference(definition: &str) -> Self {
+        Self::default().with("$ref", format!("#/definitions/{}", definition))
+    }
+
+    fn any_of(schemas: impl IntoIterator<Item = Schema>) -> Self {
+        let schemas: Vec<Value> = schemas.into_iter().map(Schema::build).collect();
+        Self::default().with("anyOf", schemas)
+    }
+
+    /// A table with the given keys and no others
+    fn table(properties: impl IntoIterator<Item = (&'static str, Schema)>) -> Self {
+        let properties: Map<String, Value> = properties
+            .into_iter()
+            .map(|(key, schema)| (key.to_string(), schema.build()))
+            .collect();
+        Self::typed("object")
+            .with("properties", properties)
+            .with("additionalProperties", false)
+    }
+
+    fn with(mut self, key: &str, value: impl Into<Value>) -> Self {
+        self.0.insert(key.to_string(), value.into());
+        self
+    }
+
+    fn description(self, description: &str) -> Self {
+        self.with("description", description)
+    }
+
+    fn default_value(self, value: impl serde::Serialize) -> Self {
+        let value = serde_json::to_value(value).unwrap_or(Value::Null);
+        self.with("default", value)
+    }
+
+    fn minimum(self, minimum: impl Into<Value>) -> Self {
+        self.with("minimum", minimum)
+    }
+
+    fn maximum(self, maximum: impl Into<Value>) -> Self {
+        self.with("maximum", maximum)
+    }
+
+    fn range<T: Copy + Into<Value>>(self, range: RangeInclusive<T>) -> Self {
+        self.minimum(*range.start()).maximum(*range.end())
+    }
+
+    fn required(self, keys: &[&str]) -> Self {
+        self.with("required", keys.to_vec())
+    }
+
+    fn build(self) -> Value {
+        Value::Object(self.0)
+    }
+}
+
+/// Build a root schema from its table and definitions
+fn root(title: &str, table: Schema, definitions: Vec<(&str, Schema)>) -> Value {
+    let definitions: Map<String, Value> = definitions
+        .into_iter()
+        .map(|(name, schema)| (name.to_string(), schema.build()))
+        .collect();
+    let mut schema = table.with("$schema", DRAFT).with("title", title).build();
+    schema["definitions"] = Value::Object(definitions);
+    schema
+}
+
+/// Get the JSON Schema of `config.toml`
+pub fn config_schema() -> Value {
+    let table = Schema::table([
+        (
+            "include",
+            Schema::string_array()
+                .description("Files merged before this one, relative to it; `*` and `?` wildcards are allowed"),
+        ),
+        (
+            "replace",
+            Schema::string_array()
+                .description("Lists this file replaces instead of appending to (e.g. \"keybinds\")"),
+        ),
+        (
+            "variables",
+            Schema::typed("object")
+                .description("Values referenced as $name or ${name} in strings; ${env:VAR:-default} reads environment variables"),
+        ),
+        ("general", Schema::reference("GeneralConfig").description("General window manager settings")),
+        ("decoration", Schema::reference("DecorationConfig").description("Window decoration settings")),
+        ("animations", Schema::reference("AnimationsConfig").description("Animation settings")),
+        ("input", Schema::reference("InputConfig").description("Input and keyboard settings")),
+        ("layouts", Schema::reference("LayoutsConfig").description("Layout-specific settings")),
+        (
+            "window_rules",
+            Schema::array(Schema::reference("WindowRule"))
+                .description("Window rules for automatic window management"),
+        ),
+        ("dialogs", Schema::reference("DialogsConfig").description("Dialog and transient window handling")),
+        (
+            "workspace_rules",
+            Schema::array(Schema::reference("WorkspaceRule")).description("Workspace assignment rules"),
+        ),
+        (
+            "workspace_groups",
+            Schema::array(Schema::reference("WorkspaceGroup"))
+                .description("Workspace groups switched together on all monitors"),
+        ),
+        ("keybinds", Schema::array(Schema::reference("Keybind")).description("Keybinding configuration")),
+        (
+            "modes",
+            Schema::array(Schema::reference("BindingMode"))
+                .description("Binding modes with their own keybindings, entered with `enter-mode <name>`"),
+        ),
+        ("mousebinds", Schema::array(Schema::reference("MouseBind")).description("Mouse binding configuration")),
+        ("monitors", Schema::array(Schema::reference("MonitorConfig")).description("Monitor configuration")),
+    ])
+    .description("Tenraku window manager configuration");
+
+    root(
+        "Tenraku configuration",
+        table,
+        vec![
+            ("GeneralConfig", general_schema()),
+            ("DecorationConfig", decoration_schema()),
+            ("AnimationsConfig", animations_schema()),
+            ("InputConfig", input_schema()),
+            ("LayoutsConfig", layouts_schema()),
+            ("DwindleConfig", dwindle_schema()),
+            ("MasterConfig", master_schema()),
+            ("WindowRule", window_rule_schema()),
+            ("RuleAction", rule_action_schema()),
+            ("WindowMatch", window_match_schema()),
+            ("DialogsConfig", dialogs_schema()),
+            ("WorkspaceRule", workspace_rule_schema()),
+            ("WorkspaceGroup", workspace_group_schema()),
+            ("GroupWorkspace", group_workspace_schema()),
+            ("MonitorRef", monitor_ref_schema()),
+            ("Keybind", keybind_schema()),
+            ("BindingMode", binding_mode_schema()),
+            ("MouseBind", mousebind_schema()),
+            ("MonitorConfig", monitor_schema()),
+            ("MonitorRegion", monitor_region_schema()),
+        ],
+    )
+}
+
+fn general_schema() -> Schema {
+    let defaults = GeneralConfig::default();
+    Schema::table([
+        (
+            "gaps_in",
+            Schema::integer()
+                .description("Gap size between windows (pixels)")
+                .minimum(0)
+                .default_value(defaults.gaps_in),
+        ),
+        (
+            "gaps_out",
+            Schema::integer()
+                .description("Gap size around screen edges (pixels)")
+                .minimum(0)
+                .default_value(defaults.gaps_out),
+        ),
+        (
+            "border_size",
+            Schema::integer()
+                .description("Border size around windows (pixels)")
+                .minimum(0)
+                .default_value(defaults.border_size),
+        ),
+        (
+            "active_border_color",
+            Schema::color()
+                .description("Active window border color (hex)")
+                .default_value(&defaults.active_border_color),
+        ),
+        (
+            "inactive_border_color",
+            Schema::color()
+                .description("Inactive window border color (hex)")
+                .default_value(&defaults.inactive_border_color),
+        ),
+        (
+            "auto_tile",
+            Schema::boolean()
+                .description("Enable auto-tiling for new windows")
+                .default_value(defaults.auto_tile),
+        ),
+        (
+            "snap_threshold",
+            Schema::integer()
+                .description(
+                    "Distance within which floating windows snap to edges (pixels, 0 disables)",
+                )
+                .minimum(0)
+                .default_value(defaults.snap_threshold),
+        ),
+        (
+            "auto_back_and_forth",
+            Schema::boolean()
+                .description("Switching to the current workspace returns to the previous one")
+                .default_value(defaults.auto_back_and_forth),
+        ),
+        (
+            "special_workspace_scale",
+            Schema::number()
+                .description("Fraction of the work area used by special workspaces")
+                .range(ranges::SPECIAL_WORKSPACE_SCALE)
+                .default_value(defaults.special_workspace_scale),
+        ),
+        (
+            "workspace_mode",
+            Schema::enumeration(["workspaces", "tags"])
+                .description("How windows are grouped: exclusive workspaces or dwm-style tags")
+                .default_value(defaults.workspace_mode),
+        ),
+        (
+            "tag_count",
+            Schema::integer()
+                .description("Number of tags in tag mode")
+                .range(ranges::TAG_COUNT)
+                .default_value(defaults.tag_count),
+        ),
+    ])
+    .description("General window manager settings")
+}
+
+fn decoration_schema() -> Schema {
+    let defaults = DecorationConfig::default();
+    Schema::table([
+        (
+            "rounding",
+            Schema::integer()
+                .description("Corner rounding radius (pixels)")
+                .minimum(0)
+                .default_value(defaults.rounding),
+        ),
+        (
+            "active_opacity",
+            Schema::number()
+                .description("Active window opacity")
+                .range(ranges::OPACITY)
+                .default_value(defaults.active_opacity),
+        ),
+        (
+            "inactive_opacity",
+            Schema::number()
+                .description("Inactive window opacity")
+                .range(ranges::OPACITY)
+                .default_value(defaults.inactive_opacity),
+        ),
+        (
+            "shadows",
+            Schema::boolean()
+                .description("Enable window shadows")
+                .default_value(defaults.shadows),
+        ),
+        (
+            "shadow_color",
+            Schema::color()
+                .description("Shadow color (hex with alpha)")
+                .default_value(&defaults.shadow_color),
+        ),
+    ])
+    .description("Window decoration settings")
+}
+
+fn animations_schema() -> Schema {
+    let defaults = AnimationsConfig::default();
+    Schema::table([
+        (
+            "enabled",
+            Schema::boolean()
+                .description("Enable animations globally")
+                .default_value(defaults.enabled),
+        ),
+        (
+            "speed",
+            Schema::number()
+                .description("Animation speed multiplier (1.0 = normal)")
+                .with("exclusiveMinimum", 0.0)
+                .maximum(ranges::MAX_ANIMATION_SPEED)
+                .default_value(defaults.speed),
+        ),
+        (
+            "curve",
+            Schema::enumeration(["linear", "easein", "easeout", "easeinout"])
+                .description("Animation curve type")
+                .default_value(&defaults.curve),
+        ),
+    ])
+    .description("Animation settings")
+}
+
+fn input_schema() -> Schema {
+    let defaults = InputConfig::default();
+    Schema::table([
+        (
+            "repeat_rate",
+            Schema::integer()
+                .description("Keyboard repeat rate (characters per second)")
+                .minimum(0)
+                .default_value(defaults.repeat_rate),
+        ),
+        (
+            "repeat_delay",
+            Schema::integer()
+                .description("Keyboard repeat delay (milliseconds)")
+                .minimum(0)
+                .default_value(defaults.repeat_delay),
+        ),
+        (
+            "follow_mouse",
+            Schema::boolean()
+                .description("Follow mouse focus")
+                .default_value(defaults.follow_mouse),
+        ),
+        (
+            "chord_timeout",
+            Schema::integer()
+                .description(
+                    "How long to wait for the next key of a keybinding sequence \
+                     (milliseconds); 0 waits indefinitely",
+                )
+                .minimum(0)
+                .default_value(defaults.chord_timeout),
+        ),
+    ])
+    .description("Input and keyboard settings")
+}
+
+fn layouts_schema() -> Schema {
+    let defaults = LayoutsConfig::default();
+    Schema::table([
+        (
+            "default",
+            Schema::enumeration(["dwindle", "master"])
+                .description("Default layout for new workspaces")
+                .default_value(&defaults.default),
+        ),
+        (
+            "dwindle",
+            Schema::reference("DwindleConfig").description("Dwindle layout configuration"),
+        ),
+        (
+            "master",
+            Schema::reference("MasterConfig").description("Master layout configuration"),
+        ),
+    ])
+    .description("Layout-specific settings")
+}
+
+fn dwindle_schema() -> Schema {
+    let defaults = DwindleConfig::default();
+    Schema::table([
+        (
+            "smart_split",
+            Schema::boolean()
+                .description("Automatically choose split direction based on window dimensions")
+                .default_value(defaults.smart_split),
+        ),
+        (
+            "no_gaps_when_only",
+            Schema::boolean()
+                .description("Remove gaps when only one window")
+                .default_value(defaults.no_gaps_when_only),
+        ),
+        (
+            "split_ratio",
+            Schema::number()
+                .description("Split ratio")
+                .range(ranges::SPLIT_RATIO)
+                .default_value(defaults.split_ratio),
+        ),
+    ])
+    .description("Dwindle layout configuration")
+}
+
+fn master_schema() -> Schema {
+    let defaults = MasterConfig::default();
+    Schema::table([
+        (
+            "master_factor",
+            Schema::number()
+                .description("Size ratio for master window")
+                .range(ranges::SPLIT_RATIO)
+                .default_value(defaults.master_factor),
+        ),
+        (
+            "master_count",
+            Schema::integer()
+                .description("Number of windows in master area")
+                .minimum(1)
+                .default_value(defaults.master_count),
+        ),
+    ])
+    .description("Master layout configuration")
+}
+
+/// Match fields shared by window rules and keybinding conditions
+fn window_match_properties() -> [(&'static str, Schema); 3] {
+    [
+        (
+            "match_process",
+            Schema::string().description("Regex matched against the process name"),
+        ),
+        (
+            "match_title",
+            Schema::string().description("Regex matched against the window title"),
+        ),
+        (
+            "match_class",
+            Schema::string().description("Regex matched against the window class"),
+        ),
+    ]
+}
+
+/// Requires at least one of the match fields
+fn window_match_required() -> Vec<Value> {
+    vec![
+        json!({ "required": ["match_process"] }),
+        json!({ "required": ["match_title"] }),
+        json!({ "required": ["match_class"] }),
+    ]
+}
+
+fn window_rule_schema() -> Schema {
+    let actions = (
+        "actions",
+        Schema::array(Schema::reference("RuleAction"))
+            .description("Actions to apply when window matches")
+            .with("minItems", 1),
+    );
+    Schema::table(window_match_properties().into_iter().chain([actions]))
+        .required(&["actions"])
+        .with("anyOf", window_match_required())
+        .description("Window rule for automatic window management; needs at least one match condition")
+}
+
+fn window_match_schema() -> Schema {
+    Schema::table(window_match_properties())
+        .with("anyOf", window_match_required())
+        .description("Focused windows a keybinding applies to; every field given must match")
+}
+
+/// The `only_when` and `except_when` conditions of a keybinding
+fn keybind_condition_properties() -> [(&'static str, Schema); 2] {
+    [
+        (
+            "only_when",
+            Schema::reference("WindowMatch")
+                .description("Only register the keybinding while the focused window matches"),
+        ),
+        (
+            "except_when",
+            Schema::reference("WindowMatch")
+                .description("Pass the keys through to the focused window when it matches"),
+        ),
+    ]
+}
+
+fn rule_action_schema() -> Schema {
+    /// An action with a value, written as a table with one key
+    fn action(name: &'static str, value: Schema) -> Schema {
+        Schema::table([(name, value)]).required(&[name])
+    }
+
+    Schema::any_of([
+        Schema::enumeration([
+            "float",
+            "tile",
+            "fullscreen",
+            "no_focus",
+            "no_manage",
+            "pin",
+            "dialog",
+            "no_dialog",
+        ])
+        .description("Action without a value"),
+        action(
+            "workspace",
+            Schema::integer()
+                .minimum(1)
+                .description("Assign to specific workspace"),
+        ),
+        action(
+            "monitor",
+            Schema::integer()
+                .minimum(0)
+                .description("Assign to specific monitor (0-based)"),
+        ),
+        action(
+            "opacity",
+            Schema::number()
+                .range(ranges::OPACITY)
+                .description("Set opacity"),
+        ),
+        action(
+            "mark",
+            Schema::string()
+                .with("pattern", "^\\S+$")
+                .description("Assign a mark to the window (no whitespace)"),
+        ),
+        action(
+            "special_workspace",
+            Schema::string()
+                .with("minLength", 1)
+                .description("Send window to a named special workspace"),
+        ),
+    ])
+    .description("Action applied by a window rule")
+}
+
+fn dialogs_schema() -> Schema {
+    let defaults = DialogsConfig::default();
+    Schema::table([
+        (
+            "float_dialogs",
+            Schema::boolean()
+                .description(
+                    "Float owned windows and known dialog classes centered over their owner",
+                )
+                .default_value(defaults.float_dialogs),
+        ),
+        (
+            "classes",
+            Schema::array(Schema::string().with("minLength", 1))
+                .description(
+                    "Window classes always treated as dialogs or splash screens (case-insensitive)",
+                )
+                .default_value(&defaults.classes),
+        ),
+    ])
+    .description("Dialog and transient window handling")
+}
+
+fn workspace_rule_schema() -> Schema {
+    Schema::table([
+        (
+            "id",
+            Schema::integer().minimum(1).description("Workspace ID"),
+        ),
+        (
+            "monitor",
+            Schema::reference("MonitorRef")
+                .description("Monitor to assign workspace to (index or monitor name)"),
+        ),
+        (
+            "default",
+            Schema::boolean()
+                .description("Make this the default workspace for the monitor")
+                .default_value(false),
+        ),
+        (
+            "name",
+            Schema::string().description("Custom name for workspace"),
+        ),
+    ])
+    .required(&["id", "monitor"])
+    .description("Workspace assignment rule")
+}
+
+fn workspace_group_schema() -> Schema {
+    Schema::table([
+        ("id", Schema::integer().minimum(1).description("Group ID")),
+        (
+            "name",
+            Schema::string().description("Custom name for the group"),
+        ),
+        (
+            "workspaces",
+            Schema::array(Schema::reference("GroupWorkspace"))
+                .with("minItems", 1)
+                .description("Workspace shown on each monitor when the group is switched to"),
+        ),
+    ])
+    .required(&["id", "workspaces"])
+    .description("A set of workspaces, one per monitor, switched together")
+}
+
+fn group_workspace_schema() -> Schema {
+    Schema::table([
+        (
+            "monitor",
+            Schema::reference("MonitorRef")
+                .description("Monitor to show the workspace on (index or monitor name)"),
+        ),
+        (
+            "workspace",
+            Schema::integer().minimum(1).description("Workspace ID"),
+        ),
+    ])
+    .required(&["monitor", "workspace"])
+    .description("A workspace of a group and the monitor it is shown on")
+}
+
+fn monitor_ref_schema() -> Schema {
+    Schema::any_of([
+        Schema::integer()
+            .minimum(0)
+            .description("Monitor index (0-based)"),
+        Schema::string()
+            .with("minLength", 1)
+            .description("Monitor name (e.g. \"\\\\.\\DISPLAY2\")"),
+    ])
+    .description("Reference to a monitor by index or by name")
+}
+
+fn modifiers_schema() -> Schema {
+    Schema::array(Schema::string().with("pattern", modifier_pattern()))
+        .with("uniqueItems", true)
+        .description(
+            "Modifier keys (Win, Ctrl, Alt, Shift), in any case or by an alias \
+             such as Super or Control, optionally prefixed with L/R or Left/Right",
+        )
+}
+
+/// Pattern matching the modifier names in any case, since JSON Schema
+/// regular expressions have no case-insensitive flag
+fn modifier_pattern() -> String {
+    let any_case = |word: &str| -> String {
+        word.chars()
+            .map(|c| format!("[{}{}]", c.to_ascii_lowercase(), c.to_ascii_uppercase()))
+            .collect()
+    };
+    let names: Vec<String> = Modifier::ALL
+        .iter()
+        .flat_map(|modifier| modifier.aliases().iter().map(|alias| any_case(alias)))
+        .collect();
+    format!(
+        "^(?:{}(?:{})?|{}(?:{})?)?(?:{})$",
+        any_case("l"),
+        any_case("eft"),
+        any_case("r"),
+        any_case("ight"),
+        names.join("|")
+    )
+}
+
+fn keybind_schema() -> Schema {
+    Schema::any_of([keybind_table_schema(), keybind_compact_schema()])
+        .description("Keybinding configuration, as a table or in the compact `bind` form")
+}
+
+fn keybind_table_schema() -> Schema {
+    let properties = [
+        ("modifiers", modifiers_schema()),
+        (
+            "key",
+            Schema::string().description(
+                "Key to bind: a letter, digit, F1-F24, or a name such as Space, \
+                 Escape, PageUp, Numpad1, Comma, VolumeUp or MediaPlayPause",
+            ),
+        ),
+        (
+            "sequence",
+            Schema::string_array()
+                .description(
+                    "Further keys to press after modifiers+key, e.g. [\"W\", \"3\"] \
+                     or [\"Shift+Q\"]",
+                )
+                .default_value(Vec::<String>::new()),
+        ),
+        (
+            "command",
+            Schema::string()
+                .with("minLength", 1)
+                .description("Command to execute"),
+        ),
+        (
+            "args",
+            Schema::string_array()
+                .description("Optional arguments for command")
+                .default_value(Vec::<String>::new()),
+        ),
+    ];
+    Schema::table(properties.into_iter().chain(keybind_condition_properties()))
+        .required(&["modifiers", "key", "command"])
+        .description("Keybinding configuration")
+}
+
+fn keybind_compact_schema() -> Schema {
+    let bind = (
+        "bind",
+        Schema::string_array()
+            .with("minItems", 2)
+            .description(
+                "Keys, command and arguments, e.g. [\"Win+Shift+Q\", \"close\"]; \
+                 further keys of a sequence follow commas: \"Win+Space, W, 3\"",
+            ),
+    );
+    Schema::table(std::iter::once(bind).chain(keybind_condition_properties()))
+        .required(&["bind"])
+        .description("Compact keybinding")
+}
+
+fn binding_mode_schema() -> Schema {
+    Schema::table([
+        (
+            "name",
+            Schema::string()
+                .with("minLength", 1)
+                .with("not", json!({ "const": DEFAULT_MODE }))
+                .description("Name of the mode"),
+        ),
+        (
+            "escape",
+            Schema::string()
+                .with("minLength", 1)
+                .description("Key without modifiers that leaves the mode")
+                .default_value("Escape"),
+        ),
+        (
+            "keybinds",
+            Schema::array(Schema::reference("Keybind"))
+                .description("Keybindings active while in the mode")
+                .default_value(Vec::<String>::new()),
+        ),
+    ])
+    .required(&["name"])
+    .description(
+        "Binding mode; while active only its keybindings and the escape key are registered",
+    )
+}
+
+fn mousebind_schema() -> Schema {
+    Schema::table([
+        ("modifiers", modifiers_schema().with("minItems", 1)),
+        (
+            "button",
+            Schema::enumeration(["left", "right", "middle"]).description("Mouse button that starts the drag"),
+        ),
+        (
+            "action",
+            Schema::enumeration(["move", "resize"]).description("Action performed while dragging"),
+        ),
+    ])
+    .required(&["modifiers", "button", "action"])
+    .description("Mouse binding; holding the modifiers while dragging with the button acts on the window under the cursor")
+}
+
+fn monitor_schema() -> Schema {
+    Schema::table([
+        (
+            "name",
+            Schema::string().description("Monitor name or identifier"),
+        ),
+        (
+            "resolution",
+            Schema::string()
+                .with("pattern", "^[0-9]+x[0-9]+$")
+                .description("Resolution (e.g., \"1920x1080\")"),
+        ),
+        (
+            "position",
+            Schema::string()
+                .with("pattern", "^(auto|-?[0-9]+x-?[0-9]+)$")
+                .description("Position (e.g., \"0x0\" or \"auto\")"),
+        ),
+        (
+            "scale",
+            Schema::number()
+                .with("exclusiveMinimum", 0.0)
+                .maximum(ranges::MAX_MONITOR_SCALE)
+                .description("DPI scale factor"),
+        ),
+        (
+            "refresh_rate",
+            Schema::integer()
+                .minimum(0)
+                .description("Refresh rate (Hz)"),
+        ),
+        (
+            "rotation",
+            Schema::enumeration([0, 90, 180, 270]).description("Rotation"),
+        ),
+        (
+            "regions",
+            Schema::array(Schema::reference("MonitorRegion")).description(
+                "Regions splitting the monitor into logical monitors, from left to right",
+            ),
+        ),
+    ])
+    .required(&["name"])
+    .description("Monitor configuration")
+}
+
+fn monitor_region_schema() -> Schema {
+    Schema::table([
+        (
+            "name",
+            Schema::string()
+                .with("minLength", 1)
+                .description("Name of the logical monitor (defaults to \"<monitor>:<index>\")"),
+        ),
+        (
+            "width",
+            Schema::any_of([
+                Schema::integer().minimum(1).description("Width in pixels"),
+                Schema::number()
+                    .with("exclusiveMinimum", 0.0)
+                    .maximum(1.0)
+                    .description("Fraction of the monitor width"),
+            ])
+            .description("Width of the region; regions without a width share the remaining space"),
+        ),
+    ])
+    .description("A logical monitor carved out of a physical monitor")
+}
+
+/// Get the JSON Schema of the status bar's configuration file
+pub fn bar_config_schema() -> Value {
+    let table = Schema::table([
+        (
+            "bar",
+            Schema::reference("BarSettings")
+                .description("Status bar positioning and appearance settings"),
+        ),
+        (
+            "style",
+            Schema::reference("StyleSettings").description("Global styling settings"),
+        ),
+        (
+            "modules",
+            Schema::reference("ModulesConfig").description("Module positioning configuration"),
+        ),
+    ])
+    .description("Tenraku status bar configuration");
+
+    let bar = Schema::table([
+        (
+            "height",
+            Schema::integer()
+                .minimum(1)
+                .description("Bar height in pixels")
+                .default_value(bar_defaults::HEIGHT),
+        ),
+        (
+            "position",
+            Schema::enumeration(["top", "bottom"])
+                .description("Bar position (top or bottom)")
+                .default_value(bar_defaults::POSITION),
+        ),
+        (
+            "monitor",
+            Schema::integer()
+                .minimum(0)
+                .description("Monitor to display on (0-based index, all monitors if unset)"),
+        ),
+        (
+            "always_on_top",
+            Schema::boolean()
+                .description("Layer (always on top)")
+                .default_value(bar_defaults::ALWAYS_ON_TOP),
+        ),
+        (
+            "reserve_space",
+            Schema::boolean()
+                .description("Reserve screen space (no windows overlap)")
+                .default_value(bar_defaults::RESERVE_SPACE),
+        ),
+    ])
+    .description("Status bar positioning and appearance settings");
+
+    let style = Schema::table([
+        (
+            "background_color",
+            Schema::color()
+                .description("Background color (hex)")
+                .default_value(bar_defaults::BACKGROUND_COLOR),
+        ),
+        (
+            "foreground_color",
+            Schema::color()
+                .description("Foreground/text color (hex)")
+                .default_value(bar_defaults::FOREGROUND_COLOR),
+        ),
+        (
+            "font_family",
+            Schema::string()
+                .description("Font family")
+                .default_value(bar_defaults::FONT_FAMILY),
+        ),
+        (
+            "font_size",
+            Schema::number()
+                .with("exclusiveMinimum", 0.0)
+                .description("Font size")
+                .default_value(bar_defaults::FONT_SIZE),
+        ),
+        (
+            "border_color",
+            Schema::color().description("Border color (hex)"),
+        ),
+        (
+            "border_width",
+            Schema::integer()
+                .minimum(0)
+                .description("Border width (pixels)")
+                .default_value(bar_defaults::BORDER_WIDTH),
+        ),
+    ])
+    .description("Global styling settings");
+
+    let module_list = || {
+        Schema::string_array().with(
+            "examples",
+            vec![json!([
+                "workspaces",
+                "mode",
+                "window_title",
+                "cpu",
+                "memory",
+                "battery",
+                "clock"
+            ])],
+        )
+    };
+    let modules = Schema::table([
+        (
+            "left",
+            module_list()
+                .description("Modules on the left side")
+                .default_value(bar_defaults::LEFT_MODULES),
+        ),
+        (
+            "center",
+            module_list()
+                .description("Modules in the center")
+                .default_value(Vec::<String>::new()),
+        ),
+        (
+            "right",
+            module_list()
+                .description("Modules on the right side")
+                .default_value(bar_defaults::RIGHT_MODULES),
+        ),
+        (
+            "module_configs",
+            Schema::typed("object")
+                .with("additionalProperties", json!({ "type": "object" }))
+                .description("Module-specific configurations, by module name"),
+        ),
+    ])
+    .description("Module positioning configuration");
+
+    root(
+        "Tenraku status bar configuration",
+        table,
+        vec![
+            ("BarSettings", bar),
+            ("StyleSettings", style),
+            ("ModulesConfig", modules),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Resolve a `$ref` against the root schema
+    fn resolve<'a>(root: &'a Value, schema: &'a Value) -> &'a Value {
+        match schema["$ref"].as_str() {
+            Some(reference) => {
+                let name = reference.trim_start_matches("#/definitions/");
+                &root["definitions"][name]
+            }
+            None => schema,
+        }
+    }
+
+    /// Check that every key of a serialized value is described and, for a
+    /// serialized default, that it matches the default the schema gives
+    fn assert_described(root: &Value, schema: &Value, value: &Value, path: &str, defaults: bool) {
+        let mut schema = resolve(root, schema);
+        if let (Some(variants), Value::Object(map)) = (schema["anyOf"].as_array(), value) {
+            // Tables of a union are checked against the variant with their keys
+            if let Some(variant) = variants.iter().find(|variant| {
+                map.keys()
+                    .all(|key| variant["properties"].get(key).is_some())
+            }) {
+                schema = variant;
+            }
+        }
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map {
+                    let property = &schema["properties"][key];
+                    assert!(!property.is_null(), "{}.{} is not in the schema", path, key);
+                    let property = resolve(root, property);
+                    if let Some(default) = property.get("default").filter(|_| defaults) {
+                        assert_eq!(default, value, "default of {}.{}", path, key);
+                    }
+                    assert_described(
+                        root,
+                        property,
+                        value,
+                        &format!("{}.{}", path, key),
+                        defaults,
+                    );
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    assert_described(root, &schema["items"], item, path, defaults);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn test_schema_describes_defaults() {
+        let schema = config_schema();
+        let defaults = serde_json::to_value(Config::default()).unwrap();
+        assert_described(&schema, &schema, &defaults, "config", true);
+    }
+
+    #[test]
+    fn test_schema_describes_lists() {
+        let config: Config = toml::from_str(
+            r#"
+            [[window_rules]]
+            match_process = "firefox"
+            actions = ["float", { workspace = 2 }, { mark = "web" }]
+
+            [[workspace_groups]]
+            id = 1
+            workspaces = [{ monitor = 0, workspace = 1 }]
+
+            [[keybinds]]
+            modifiers = ["Win"]
+            key = "Q"
+            command = "close"
+
+            [[keybinds]]
+            modifiers = ["Win"]
+            key = "Space"
+            sequence = ["W", "3"]
+            command = "workspace"
+            args = ["3"]
+            except_when = { match_process = "mstsc\\.exe" }
+
+            [[modes]]
+            name = "resize"
+            keybinds = [{ modifiers = [], key = "H", command = "float-resize", args = ["-20", "0"] }]
+
+            [[mousebinds]]
+            modifiers = ["Win"]
+            button = "left"
+            action = "move"
+
+            [[monitors]]
+            name = "DISPLAY1"
+            regions = [{ name = "left", width = 0.5 }]
+            "#,
+        )
+        .unwrap();
+
+        let schema = config_schema();
+        let value = serde_json::to_value(&config).unwrap();
+        assert_described(&schema, &schema, &value, "config", false);
+    }
+
+    #[test]
+    fn test_rule_actions_are_described() {
+        let actions = [
+            RuleAction::Float,
+            RuleAction::Tile,
+            RuleAction::Workspace(1),
+            RuleAction::Monitor(0),
+            RuleAction::Fullscreen,
+            RuleAction::NoFocus,
+            RuleAction::NoManage,
+            RuleAction::Opacity(0.5),
+            RuleAction::Pin,
+            RuleAction::Dialog,
+            RuleAction::NoDialog,
+            RuleAction::Mark("a".to_string()),
+            RuleAction::SpecialWorkspace("s".to_string()),
+        ];
+        let schema = config_schema();
+        let variants = schema["definitions"]["RuleAction"]["anyOf"]
+            .as_array()
+            .unwrap();
+
+        for action in actions {
+            let value = serde_json::to_value(&action).unwrap();
+            let described = variants.iter().any(|variant| match &value {
+                Value::String(_) => variant["enum"]
+                    .as_array()
+                    .is_some_and(|names| names.contains(&value)),
+                Value::Object(map) => map
+                    .keys()
+                    .all(|key| variant["properties"].get(key).is_some()),
+                _ => false,
+            });
+            assert!(described, "{:?} is not in the schema", action);
+        }
+    }
+
+    #[test]
+    fn test_modifier_pattern_accepts_aliases() {
+        let pattern = regex::Regex::new(&modifier_pattern()).unwrap();
+        for name in ["Win", "super", "MOD4", "Control", "LCtrl", "RightShift", "lalt"] {
+            assert!(pattern.is_match(name), "{} should match", name);
+        }
+        for name in ["Hyper", "Left", "Ctrl+Alt", ""] {
+            assert!(!pattern.is_match(name), "{} should not match", name);
+        }
+    }
+}
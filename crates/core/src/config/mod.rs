@@ -3,15 +3,27 @@
 //! This module provides comprehensive configuration management including:
 //! - TOML-based configuration schema with serde support
 //! - Configuration file parsing and validation
-//! - Hot-reload capability with file watching
+//! - Diagnostics reporting every problem with its file and line
+//! - Included files and per-host overlays merged in layers
+//! - JSON Schema of the configuration for editor completion
+//! - Allowed ranges of numeric settings shared by validation and schema
+//! - Variables and environment substitution in string fields
+//! - Hot-reload capability with file watching, applying only changed sections
 //! - Default configuration generation
 
 pub mod schema;
+pub mod diagnostics;
+pub mod include;
+pub mod json_schema;
 pub mod parser;
+pub mod ranges;
+pub mod reload;
 pub mod validator;
+pub mod variables;
 pub mod watcher;
 
 pub use schema::*;
+pub use include::ConfigSources;
 pub use parser::ConfigLoader;
 pub use validator::ConfigValidator;
 pub use watcher::ConfigWatcher;
@@ -1,11 +1,20 @@
 //! Configuration file parser
 //! 
 //! This module provides functionality for loading and parsing TOML configuration files.
+//! Included files and the per-host overlay are merged as described in the
+//! `include` module, and variables are substituted as described in the
+//! `variables` module.
 
+use super::diagnostics::{self, Diagnostics};
+use super::include::{load_layers, ConfigSources, FileContext};
 use super::schema::Config;
-use std::path::PathBuf;
+use super::validator::ConfigValidator;
+use super::variables;
+use crate::keybinds::analyzer;
+use std::path::{Path, PathBuf};
 use std::fs;
 use anyhow::{Context, Result};
+use toml::Table;
 
 /// Configuration loader and parser
 pub struct ConfigLoader {
@@ -51,6 +60,14 @@ impl ConfigLoader {
     
     /// Load the configuration file
     pub fn load(&self) -> Result<Config> {
+        self.load_with_sources().map(|(config, _)| config)
+    }
+    
+    /// Load the configuration file, its includes and the host overlay
+    ///
+    /// # Returns
+    /// The merged configuration and the files it was merged from
+    pub fn load_with_sources(&self) -> Result<(Config, ConfigSources)> {
         if !self.config_path.exists() {
             tracing::info!(
                 "Configuration file not found at {:?}, creating default",
@@ -59,23 +76,119 @@ impl ConfigLoader {
             self.create_default_config()?;
         }
         
-        self.load_from_path(&self.config_path)
+        let overlay = self.host_overlay_path();
+        self.load_layered(&self.config_path, overlay.as_deref())
     }
     
-    /// Load configuration from a specific path
-    pub fn load_from_path(&self, path: &PathBuf) -> Result<Config> {
-        tracing::debug!("Loading configuration from {:?}", path);
+    /// Load configuration from a specific path, with its includes
+    pub fn load_from_path(&self, path: &Path) -> Result<Config> {
+        self.load_layered(path, None).map(|(config, _)| config)
+    }
+    
+    /// Get the per-host overlay merged on top of the configuration file
+    ///
+    /// The overlay sits next to the configuration file and is named after the
+    /// lowercase host name, e.g. `config.laptop.toml`. It does not need to exist.
+    pub fn host_overlay_path(&self) -> Option<PathBuf> {
+        let host = std::env::var("COMPUTERNAME")
+            .or_else(|_| std::env::var("HOSTNAME"))
+            .ok()
+            .filter(|host| !host.trim().is_empty())?;
+        let stem = self.config_path.file_stem()?.to_string_lossy();
+        Some(
+            self.config_path
+                .with_file_name(format!("{}.{}.toml", stem, host.trim().to_lowercase())),
+        )
+    }
+    
+    /// Check the configuration file, its includes and the host overlay
+    ///
+    /// Unlike `load`, this reports every problem found instead of the first
+    /// one: parse errors, undefined variables, validation errors and unknown
+    /// keys, each with the file and line it was found at. A missing file is
+    /// reported rather than created.
+    pub fn check(&self) -> Diagnostics {
+        self.check_with(|config, merged, diagnostics| {
+            ConfigValidator::diagnose(config, diagnostics);
+            diagnostics::check_unknown_keys(merged, config, diagnostics);
+        })
+    }
+    
+    /// Check the keybindings of the configuration for conflicts
+    ///
+    /// Reports reserved, duplicate and shadowed keybindings and unknown
+    /// commands, as described in the `keybinds::analyzer` module. Errors that
+    /// stop the configuration from loading are reported too.
+    pub fn check_keybinds(&self) -> Diagnostics {
+        self.check_with(|config, _, diagnostics| analyzer::analyze(config, diagnostics))
+    }
+    
+    /// Load the configuration for a check and run `checks` on it
+    fn check_with(&self, checks: impl FnOnce(&Config, &Table, &mut Diagnostics)) -> Diagnostics {
+        let mut diagnostics = Diagnostics::new();
+        let overlay = self.host_overlay_path();
+        let (mut merged, sources) = match load_layers(&self.config_path, overlay.as_deref()) {
+            Ok(layers) => layers,
+            Err(e) => {
+                diagnostics.load_error(&e);
+                return diagnostics;
+            }
+        };
         
-        let content = fs::read_to_string(path)
-            .with_context(|| format!("Failed to read config file: {:?}", path))?;
+        let parsed = variables::substitute(&mut merged, &sources).and_then(|substituted| {
+            Self::parse_merged(&self.config_path, &merged, &sources, substituted)
+        });
+        match parsed {
+            Ok(config) => checks(&config, &merged, &mut diagnostics),
+            Err(e) => {
+                diagnostics.load_error(&e);
+            }
+        }
         
-        let config: Config = toml::from_str(&content)
-            .with_context(|| format!("Failed to parse config file: {:?}", path))?;
+        diagnostics.locate(&sources);
+        diagnostics
+    }
+    
+    /// Merge a configuration file with its includes and an overlay and parse it
+    fn load_layered(&self, path: &Path, overlay: Option<&Path>) -> Result<(Config, ConfigSources)> {
+        tracing::debug!("Loading configuration from {:?}", path);
         
-        tracing::info!("Successfully loaded configuration");
+        let (mut merged, sources) = load_layers(path, overlay)?;
+        let substituted = variables::substitute(&mut merged, &sources)?;
+        let config = Self::parse_merged(path, &merged, &sources, substituted)?;
+        
+        tracing::info!(
+            "Successfully loaded configuration from {} file(s)",
+            sources.files().len()
+        );
         tracing::debug!("Config: {:?}", config);
         
-        Ok(config)
+        Ok((config, sources))
+    }
+    
+    /// Parse a merged document into a `Config`
+    fn parse_merged(
+        path: &Path,
+        merged: &Table,
+        sources: &ConfigSources,
+        substituted: bool,
+    ) -> Result<Config> {
+        // A single file without variables is parsed from its text so errors point at a line
+        if sources.files().len() == 1 && !substituted {
+            let content = fs::read_to_string(path)
+                .with_context(|| format!("Failed to read config file: {:?}", path))?;
+            return toml::from_str(&content)
+                .with_context(|| FileContext::parse(path));
+        }
+        
+        let files: Vec<_> = sources
+            .files()
+            .iter()
+            .map(|file| sources.display_path(file))
+            .collect();
+        toml::Value::Table(merged.clone())
+            .try_into()
+            .with_context(|| format!("Failed to parse config merged from {}", files.join(", ")))
     }
     
     /// Create default configuration file
@@ -136,4 +249,73 @@ impl Default for ConfigLoader {
     fn default() -> Self {
         Self::new().expect("Failed to create default ConfigLoader")
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_config(dir: &Path, content: &str) -> ConfigLoader {
+        let path = dir.join("config.toml");
+        fs::write(&path, content).unwrap();
+        ConfigLoader::from_path(path)
+    }
+
+    /// Get the first TOML example under a heading of the keybindings guide
+    fn guide_example(heading: &str) -> &'static str {
+        const GUIDE: &str = include_str!("../../../../KEYBINDINGS_GUIDE.md");
+        const FENCE: &str = "```toml\n";
+        let section = &GUIDE[GUIDE.find(heading).expect("heading is in the guide")..];
+        let start = section.find(FENCE).expect("section has an example") + FENCE.len();
+        let end = start + section[start..].find("```").unwrap();
+        &section[start..end]
+    }
+
+    #[test]
+    fn test_regex_anchors_are_not_variables() {
+        let dir = tempfile::tempdir().unwrap();
+        let loader = write_config(
+            dir.path(),
+            r#"
+            [variables]
+            browser = "firefox"
+
+            [[window_rules]]
+            match_process = "^firefox\\.exe$"
+            actions = ["float"]
+
+            [[window_rules]]
+            match_title = "^${browser} - .*$"
+            actions = ["float"]
+            "#,
+        );
+
+        let config = loader.load().unwrap();
+        assert_eq!(
+            config.window_rules[0].match_process.as_deref(),
+            Some(r"^firefox\.exe$")
+        );
+        assert_eq!(
+            config.window_rules[1].match_title.as_deref(),
+            Some("^firefox - .*$")
+        );
+        assert!(loader.check().is_empty());
+    }
+
+    #[test]
+    fn test_guide_keybind_conditions_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let loader = write_config(dir.path(), guide_example("## Per-Application Keybindings"));
+
+        let config = loader.load().unwrap();
+        let except_when = config.keybinds[0].except_when.as_ref().unwrap();
+        assert_eq!(
+            except_when.match_process.as_deref(),
+            Some(r"(?i)^mstsc\.exe$")
+        );
+        assert!(config.keybinds[1].only_when.is_some());
+
+        assert!(loader.check().is_empty());
+        assert!(loader.check_keybinds().is_empty());
+    }
+}
@@ -0,0 +1,24 @@
+//! Allowed ranges of numeric settings
+//!
+//! The validator checks settings against these ranges and the JSON Schema
+//! publishes them as `minimum` and `maximum`, so both always agree.
+
+use std::ops::RangeInclusive;
+
+/// Range of `general.special_workspace_scale`
+pub const SPECIAL_WORKSPACE_SCALE: RangeInclusive<f64> = 0.1..=1.0;
+
+/// Range of `general.tag_count`
+pub const TAG_COUNT: RangeInclusive<usize> = 1..=32;
+
+/// Range of window opacities, in decoration settings and window rules
+pub const OPACITY: RangeInclusive<f64> = 0.0..=1.0;
+
+/// Range of layout split ratios and master factors
+pub const SPLIT_RATIO: RangeInclusive<f64> = 0.1..=0.9;
+
+/// Largest `animations.speed`; the speed must also be positive
+pub const MAX_ANIMATION_SPEED: f64 = 10.0;
+
+/// Largest monitor `scale`; the scale must also be positive
+pub const MAX_MONITOR_SCALE: f64 = 4.0;
@@ -0,0 +1,291 @@
+//! Configuration reload support
+//!
+//! Hot reload parses and validates the edited configuration before touching
+//! anything, then compares it with the running one section by section so
+//! only the affected subsystems are reapplied: hotkeys when keybindings
+//! change, the rule matcher when window rules change, and a relayout only
+//! when settings that move windows change.
+//!
+//! # Example
+//!
+//! ```
+//! use tenraku_core::config::Config;
+//! use tenraku_core::config::reload::{ConfigDiff, ConfigSection};
+//!
+//! let old = Config::default();
+//! let mut new = old.clone();
+//! new.general.gaps_in = 10;
+//!
+//! let diff = ConfigDiff::between(&old, &new);
+//! assert_eq!(diff.sections(), &[ConfigSection::General]);
+//! assert_eq!(diff.changed_keys(), &["general.gaps_in"]);
+//! assert!(diff.needs_relayout());
+//! ```
+
+use super::schema::Config;
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+
+/// Settings outside the layout-related sections that move windows when changed
+const GEOMETRY_KEYS: [&str; 5] = [
+    "general.gaps_in",
+    "general.gaps_out",
+    "general.special_workspace_scale",
+    "general.workspace_mode",
+    "general.tag_count",
+];
+
+/// A top-level section of the configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigSection {
+    General,
+    Decoration,
+    Animations,
+    Input,
+    Layouts,
+    WindowRules,
+    Dialogs,
+    WorkspaceRules,
+    WorkspaceGroups,
+    Keybinds,
+    Modes,
+    Mousebinds,
+    Monitors,
+}
+
+impl ConfigSection {
+    /// Every section, in the order they appear in `Config`
+    pub const ALL: [ConfigSection; 13] = [
+        ConfigSection::General,
+        ConfigSection::Decoration,
+        ConfigSection::Animations,
+        ConfigSection::Input,
+        ConfigSection::Layouts,
+        ConfigSection::WindowRules,
+        ConfigSection::Dialogs,
+        ConfigSection::WorkspaceRules,
+        ConfigSection::WorkspaceGroups,
+        ConfigSection::Keybinds,
+        ConfigSection::Modes,
+        ConfigSection::Mousebinds,
+        ConfigSection::Monitors,
+    ];
+
+    /// Get the key of the section in the configuration file
+    pub fn key(self) -> &'static str {
+        match self {
+            ConfigSection::General => "general",
+            ConfigSection::Decoration => "decoration",
+            ConfigSection::Animations => "animations",
+            ConfigSection::Input => "input",
+            ConfigSection::Layouts => "layouts",
+            ConfigSection::WindowRules => "window_rules",
+            ConfigSection::Dialogs => "dialogs",
+            ConfigSection::WorkspaceRules => "workspace_rules",
+            ConfigSection::WorkspaceGroups => "workspace_groups",
+            ConfigSection::Keybinds => "keybinds",
+            ConfigSection::Modes => "modes",
+            ConfigSection::Mousebinds => "mousebinds",
+            ConfigSection::Monitors => "monitors",
+        }
+    }
+
+    /// Whether a change to the whole section moves windows
+    fn is_geometry(self) -> bool {
+        matches!(
+            self,
+            ConfigSection::Layouts
+                | ConfigSection::WorkspaceRules
+                | ConfigSection::WorkspaceGroups
+                | ConfigSection::Monitors
+        )
+    }
+}
+
+impl fmt::Display for ConfigSection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key())
+    }
+}
+
+/// Sections and settings that differ between two configurations
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConfigDiff {
+    sections: Vec<ConfigSection>,
+    changed_keys: Vec<String>,
+    relayout: bool,
+}
+
+impl ConfigDiff {
+    /// Compare the running configuration with a newly loaded one
+    pub fn between(old: &Config, new: &Config) -> Self {
+        let old = serde_json::to_value(old).unwrap_or(Value::Null);
+        let new = serde_json::to_value(new).unwrap_or(Value::Null);
+
+        let mut diff = Self::default();
+        for section in ConfigSection::ALL {
+            let key = section.key();
+            let (before, after) = (&old[key], &new[key]);
+            if before == after {
+                continue;
+            }
+
+            diff.sections.push(section);
+            match (before, after) {
+                // Tables list the settings that changed, lists only the section
+                (Value::Object(before), Value::Object(after)) => {
+                    for (name, value) in after {
+                        if before.get(name) != Some(value) {
+                            diff.changed_keys.push(format!("{}.{}", key, name));
+                        }
+                    }
+                }
+                _ => diff.changed_keys.push(key.to_string()),
+            }
+        }
+
+        diff.relayout = diff.sections.iter().any(|section| section.is_geometry())
+            || diff
+                .changed_keys
+                .iter()
+                .any(|key| GEOMETRY_KEYS.contains(&key.as_str()));
+        diff
+    }
+
+    /// A diff covering every section, used when a configuration is first applied
+    pub fn everything() -> Self {
+        Self {
+            sections: ConfigSection::ALL.to_vec(),
+            changed_keys: ConfigSection::ALL
+                .iter()
+                .map(|section| section.key().to_string())
+                .collect(),
+            relayout: true,
+        }
+    }
+
+    /// Get the sections that changed, in the order they appear in `Config`
+    pub fn sections(&self) -> &[ConfigSection] {
+        &self.sections
+    }
+
+    /// Get the changed settings of tables (e.g. `general.gaps_in`) and the
+    /// changed lists (e.g. `keybinds`)
+    pub fn changed_keys(&self) -> &[String] {
+        &self.changed_keys
+    }
+
+    /// Check if a section changed
+    pub fn contains(&self, section: ConfigSection) -> bool {
+        self.sections.contains(&section)
+    }
+
+    /// Check if any of the sections changed
+    pub fn contains_any(&self, sections: &[ConfigSection]) -> bool {
+        sections.iter().any(|section| self.contains(*section))
+    }
+
+    /// Check if the changes move windows, so workspaces must be retiled
+    pub fn needs_relayout(&self) -> bool {
+        self.relayout
+    }
+
+    /// Check if nothing changed
+    pub fn is_empty(&self) -> bool {
+        self.sections.is_empty()
+    }
+}
+
+impl fmt::Display for ConfigDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return f.write_str("no changes");
+        }
+        f.write_str(&self.changed_keys.join(", "))
+    }
+}
+
+/// Outcome of a configuration reload reported to the reload listener
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReloadEvent {
+    /// The configuration was applied; the listed sections changed
+    Reloaded { sections: Vec<ConfigSection> },
+    /// The configuration was rejected and the previous one is still active
+    Rejected { error: String },
+}
+
+/// Callback receiving the outcome of configuration reloads.
+pub type ReloadEventListener = Box<dyn Fn(ReloadEvent) + Send>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::schema::{Keybind, WindowRule};
+
+    #[test]
+    fn test_identical_configs_have_no_changes() {
+        let diff = ConfigDiff::between(&Config::default(), &Config::default());
+        assert!(diff.is_empty());
+        assert!(!diff.needs_relayout());
+        assert_eq!(diff.to_string(), "no changes");
+    }
+
+    #[test]
+    fn test_list_changes_are_reported_by_section() {
+        let old = Config::default();
+        let mut new = old.clone();
+        new.keybinds.push(Keybind {
+            modifiers: vec!["Win".to_string()],
+            key: "Q".to_string(),
+            sequence: vec![],
+            command: "close".to_string(),
+            args: vec![],
+            only_when: None,
+            except_when: None,
+        });
+        new.window_rules.push(WindowRule {
+            match_process: Some("firefox".to_string()),
+            match_title: None,
+            match_class: None,
+            actions: vec![],
+        });
+
+        let diff = ConfigDiff::between(&old, &new);
+        assert_eq!(
+            diff.sections(),
+            &[ConfigSection::WindowRules, ConfigSection::Keybinds]
+        );
+        assert_eq!(diff.changed_keys(), &["window_rules", "keybinds"]);
+        assert!(!diff.needs_relayout());
+    }
+
+    #[test]
+    fn test_only_geometry_changes_need_relayout() {
+        let old = Config::default();
+
+        let mut colors = old.clone();
+        colors.general.active_border_color = "#ff0000".to_string();
+        colors.decoration.rounding = 4;
+        let diff = ConfigDiff::between(&old, &colors);
+        assert_eq!(
+            diff.sections(),
+            &[ConfigSection::General, ConfigSection::Decoration]
+        );
+        assert!(!diff.needs_relayout());
+
+        let mut layouts = old.clone();
+        layouts.layouts.master.master_count = 2;
+        let diff = ConfigDiff::between(&old, &layouts);
+        assert_eq!(diff.changed_keys(), &["layouts.master"]);
+        assert!(diff.needs_relayout());
+    }
+
+    #[test]
+    fn test_everything_covers_all_sections() {
+        let diff = ConfigDiff::everything();
+        assert!(ConfigSection::ALL.iter().all(|section| diff.contains(*section)));
+        assert!(diff.needs_relayout());
+    }
+}
@@ -32,14 +32,30 @@ pub struct Config {
     #[serde(default)]
     pub window_rules: Vec<WindowRule>,
     
+    /// Dialog and transient window handling
+    #[serde(default)]
+    pub dialogs: DialogsConfig,
+    
     /// Workspace assignment rules
     #[serde(default)]
     pub workspace_rules: Vec<WorkspaceRule>,
     
+    /// Workspace groups switched together on all monitors
+    #[serde(default)]
+    pub workspace_groups: Vec<WorkspaceGroup>,
+    
     /// Keybinding configuration
     #[serde(default)]
     pub keybinds: Vec<Keybind>,
     
+    /// Binding modes with their own keybindings
+    #[serde(default)]
+    pub modes: Vec<BindingMode>,
+    
+    /// Mouse binding configuration
+    #[serde(default)]
+    pub mousebinds: Vec<MouseBind>,
+    
     /// Monitor configuration
     #[serde(default)]
     pub monitors: Vec<MonitorConfig>,
@@ -54,8 +70,12 @@ impl Default for Config {
             input: InputConfig::default(),
             layouts: LayoutsConfig::default(),
             window_rules: Vec::new(),
+            dialogs: DialogsConfig::default(),
             workspace_rules: Vec::new(),
+            workspace_groups: Vec::new(),
             keybinds: Vec::new(),
+            modes: Vec::new(),
+            mousebinds: Vec::new(),
             monitors: Vec::new(),
         }
     }
@@ -87,6 +107,37 @@ pub struct GeneralConfig {
     /// Enable auto-tiling for new windows
     #[serde(default = "default_true")]
     pub auto_tile: bool,
+    
+    /// Distance within which floating windows snap to edges (pixels, 0 disables)
+    #[serde(default = "default_snap_threshold")]
+    pub snap_threshold: i32,
+    
+    /// Switching to the current workspace returns to the previous one
+    #[serde(default)]
+    pub auto_back_and_forth: bool,
+    
+    /// Fraction of the work area used by special workspaces (0.1 - 1.0)
+    #[serde(default = "default_special_workspace_scale")]
+    pub special_workspace_scale: f32,
+    
+    /// How windows are grouped: exclusive workspaces or dwm-style tags
+    #[serde(default)]
+    pub workspace_mode: WorkspaceMode,
+    
+    /// Number of tags in tag mode (1 - 32)
+    #[serde(default = "default_tag_count")]
+    pub tag_count: usize,
+}
+
+/// How windows are grouped on monitors
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkspaceMode {
+    /// Each window belongs to exactly one workspace
+    #[default]
+    Workspaces,
+    /// Each window carries a set of tags and each monitor views one or more tags
+    Tags,
 }
 
 fn default_gaps_in() -> i32 { 5 }
@@ -95,6 +146,9 @@ fn default_border_size() -> i32 { 2 }
 fn default_active_border_color() -> String { "#89b4fa".to_string() }
 fn default_inactive_border_color() -> String { "#585b70".to_string() }
 fn default_true() -> bool { true }
+fn default_snap_threshold() -> i32 { 10 }
+fn default_special_workspace_scale() -> f32 { 0.8 }
+fn default_tag_count() -> usize { 9 }
 
 impl Default for GeneralConfig {
     fn default() -> Self {
@@ -105,6 +159,11 @@ impl Default for GeneralConfig {
             active_border_color: default_active_border_color(),
             inactive_border_color: default_inactive_border_color(),
             auto_tile: default_true(),
+            snap_threshold: default_snap_threshold(),
+            auto_back_and_forth: false,
+            special_workspace_scale: default_special_workspace_scale(),
+            workspace_mode: WorkspaceMode::default(),
+            tag_count: default_tag_count(),
         }
     }
 }
@@ -207,11 +266,17 @@ pub struct InputConfig {
     /// Follow mouse focus
     #[serde(default = "default_false")]
     pub follow_mouse: bool,
+    
+    /// How long to wait for the next key of a keybinding sequence
+    /// (milliseconds); 0 waits indefinitely
+    #[serde(default = "default_chord_timeout")]
+    pub chord_timeout: u64,
 }
 
 fn default_repeat_rate() -> u32 { 25 }
 fn default_repeat_delay() -> u32 { 600 }
 fn default_false() -> bool { false }
+fn default_chord_timeout() -> u64 { 1000 }
 
 impl Default for InputConfig {
     fn default() -> Self {
@@ -219,6 +284,7 @@ impl Default for InputConfig {
             repeat_rate: default_repeat_rate(),
             repeat_delay: default_repeat_delay(),
             follow_mouse: default_false(),
+            chord_timeout: default_chord_timeout(),
         }
     }
 }
@@ -319,6 +385,32 @@ pub struct WindowRule {
     pub actions: Vec<RuleAction>,
 }
 
+/// Window criteria, written with the match fields of a window rule
+///
+/// Every field given must match (regex); used by keybinding `only_when`
+/// and `except_when`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WindowMatch {
+    /// Match window by process name (regex)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub match_process: Option<String>,
+    
+    /// Match window by title (regex)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub match_title: Option<String>,
+    
+    /// Match window by class name (regex)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub match_class: Option<String>,
+}
+
+impl WindowMatch {
+    /// Check whether no match field is given
+    pub fn is_empty(&self) -> bool {
+        self.match_process.is_none() && self.match_title.is_none() && self.match_class.is_none()
+    }
+}
+
 /// Actions that can be applied by window rules
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -349,6 +441,59 @@ pub enum RuleAction {
     
     /// Pin window (show on all workspaces)
     Pin,
+    
+    /// Treat window as a dialog (float centered over its owner)
+    Dialog,
+    
+    /// Never treat window as a dialog, even if it is owned
+    NoDialog,
+    
+    /// Assign a mark to the window
+    Mark(String),
+    
+    /// Send window to a named special workspace
+    SpecialWorkspace(String),
+}
+
+/// Dialog and transient window handling
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DialogsConfig {
+    /// Float owned windows and known dialog classes centered over their owner
+    #[serde(default = "default_true")]
+    pub float_dialogs: bool,
+    
+    /// Window classes always treated as dialogs or splash screens (case-insensitive)
+    #[serde(default = "default_dialog_classes")]
+    pub classes: Vec<String>,
+}
+
+fn default_dialog_classes() -> Vec<String> {
+    [
+        "#32770",                // Standard Win32 dialog
+        "OperationStatusWindow", // Explorer file operation progress
+        "MsoSplash",             // Microsoft Office splash screen
+        "NUIDialog",             // Microsoft Office dialogs
+        "SunAwtDialog",          // Java AWT/Swing dialogs
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect()
+}
+
+impl DialogsConfig {
+    /// Check whether a window class is listed as a dialog class.
+    pub fn is_dialog_class(&self, class: &str) -> bool {
+        self.classes.iter().any(|c| c.eq_ignore_ascii_case(class))
+    }
+}
+
+impl Default for DialogsConfig {
+    fn default() -> Self {
+        Self {
+            float_dialogs: default_true(),
+            classes: default_dialog_classes(),
+        }
+    }
 }
 
 /// Workspace assignment rule
@@ -357,8 +502,8 @@ pub struct WorkspaceRule {
     /// Workspace ID
     pub id: usize,
     
-    /// Monitor to assign workspace to
-    pub monitor: usize,
+    /// Monitor to assign workspace to (index or monitor name)
+    pub monitor: MonitorRef,
     
     /// Make this the default workspace for the monitor
     #[serde(default)]
@@ -368,21 +513,277 @@ pub struct WorkspaceRule {
     pub name: Option<String>,
 }
 
+/// A set of workspaces, one per monitor, switched together
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkspaceGroup {
+    /// Group ID
+    pub id: usize,
+    
+    /// Custom name for the group
+    pub name: Option<String>,
+    
+    /// Workspace shown on each monitor when the group is switched to
+    pub workspaces: Vec<GroupWorkspace>,
+}
+
+/// A workspace of a group and the monitor it is shown on
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GroupWorkspace {
+    /// Monitor to show the workspace on (index or monitor name)
+    pub monitor: MonitorRef,
+    
+    /// Workspace ID
+    pub workspace: usize,
+}
+
+/// Reference to a monitor by index or by name
+///
+/// Indices follow the left-to-right order of connected monitors and can
+/// change when monitors are added or removed. Names (e.g. `"\\.\DISPLAY2"`)
+/// stay the same for a given display output.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MonitorRef {
+    /// Monitor index (0-based)
+    Index(usize),
+    
+    /// Monitor name
+    Name(String),
+}
+
+impl MonitorRef {
+    /// Check whether this reference matches a monitor.
+    ///
+    /// Names are compared case-insensitively.
+    pub fn matches(&self, index: usize, name: &str) -> bool {
+        match self {
+            MonitorRef::Index(i) => *i == index,
+            MonitorRef::Name(n) => n.eq_ignore_ascii_case(name),
+        }
+    }
+}
+
+impl std::fmt::Display for MonitorRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MonitorRef::Index(index) => write!(f, "{}", index),
+            MonitorRef::Name(name) => write!(f, "{}", name),
+        }
+    }
+}
+
 /// Keybinding configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// 
+/// Written either as a table of `modifiers`, `key`, `command` and `args`,
+/// or compactly as `bind = ["Win+Shift+Q", "close"]`: the keys, the command
+/// and its arguments. Either form can add `only_when` or `except_when` to
+/// limit the keybinding to some focused windows.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "KeybindEntry")]
 pub struct Keybind {
-    /// Modifier keys (Win, Ctrl, Alt, Shift)
+    /// Modifier keys (Win, Ctrl, Alt, Shift, in any case or by an alias
+    /// such as Super or Control)
     pub modifiers: Vec<String>,
     
     /// Key to bind
     pub key: String,
     
+    /// Further keys to press after `modifiers`+`key`, e.g. ["W", "3"] or
+    /// ["Shift+Q"]; the command runs once the whole sequence is pressed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sequence: Vec<String>,
+    
     /// Command to execute
     pub command: String,
     
     /// Optional arguments for command
     #[serde(default)]
     pub args: Vec<String>,
+    
+    /// Only register the keybinding while the focused window matches
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub only_when: Option<WindowMatch>,
+    
+    /// Pass the keys through to the focused window when it matches
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub except_when: Option<WindowMatch>,
+}
+
+impl Keybind {
+    /// Parse the compact form, e.g. `["Win+Shift+Q", "close"]` or
+    /// `["Win+Space, W, 3", "workspace", "3"]`
+    /// 
+    /// The first entry holds the keys, with the further keys of a sequence
+    /// separated by commas; the second the command; the rest its arguments.
+    pub fn from_bind(bind: &[String]) -> anyhow::Result<Self> {
+        let [keys, command, args @ ..] = bind else {
+            anyhow::bail!("`bind` needs the keys and a command, e.g. [\"Win+Shift+Q\", \"close\"]");
+        };
+        
+        let mut steps = keys.split(',').map(str::trim);
+        let first = steps.next().unwrap_or_default();
+        let (modifiers, key) = crate::keybinds::parser::parse_keybind_string(first)?;
+        if key.trim().is_empty() {
+            anyhow::bail!("Missing key in '{}'", keys);
+        }
+        let sequence: Vec<String> = steps.map(str::to_string).collect();
+        if sequence.iter().any(|step| step.is_empty()) {
+            anyhow::bail!("Empty key in sequence '{}'", keys);
+        }
+        
+        Ok(Self {
+            modifiers: modifiers.iter().map(|m| m.trim().to_string()).collect(),
+            key: key.trim().to_string(),
+            sequence,
+            command: command.clone(),
+            args: args.to_vec(),
+            only_when: None,
+            except_when: None,
+        })
+    }
+    
+    /// Check whether the keybinding depends on the focused window
+    pub fn is_conditional(&self) -> bool {
+        self.only_when.is_some() || self.except_when.is_some()
+    }
+}
+
+/// Keybinding as written in the configuration, in either form
+#[derive(Deserialize)]
+struct KeybindEntry {
+    #[serde(default)]
+    bind: Option<Vec<String>>,
+    #[serde(default)]
+    modifiers: Option<Vec<String>>,
+    #[serde(default)]
+    key: Option<String>,
+    #[serde(default)]
+    sequence: Vec<String>,
+    #[serde(default)]
+    command: Option<String>,
+    #[serde(default)]
+    args: Vec<String>,
+    #[serde(default)]
+    only_when: Option<WindowMatch>,
+    #[serde(default)]
+    except_when: Option<WindowMatch>,
+}
+
+impl TryFrom<KeybindEntry> for Keybind {
+    type Error = String;
+    
+    fn try_from(entry: KeybindEntry) -> Result<Self, Self::Error> {
+        let Some(bind) = entry.bind else {
+            return Ok(Keybind {
+                modifiers: entry.modifiers.ok_or("missing field `modifiers`")?,
+                key: entry.key.ok_or("missing field `key`")?,
+                sequence: entry.sequence,
+                command: entry.command.ok_or("missing field `command`")?,
+                args: entry.args,
+                only_when: entry.only_when,
+                except_when: entry.except_when,
+            });
+        };
+        
+        if entry.modifiers.is_some()
+            || entry.key.is_some()
+            || entry.command.is_some()
+            || !entry.sequence.is_empty()
+            || !entry.args.is_empty()
+        {
+            return Err("`bind` cannot be combined with modifiers, key, sequence, command or args".to_string());
+        }
+        let keybind = Keybind::from_bind(&bind).map_err(|e| e.to_string())?;
+        Ok(Keybind {
+            only_when: entry.only_when,
+            except_when: entry.except_when,
+            ..keybind
+        })
+    }
+}
+
+/// Binding mode, a named set of keybindings
+/// 
+/// While a mode is active (entered with `enter-mode <name>`) only its
+/// keybindings and the escape key are registered, so they can use keys
+/// without modifiers. `exit-mode` or the escape key returns to the main
+/// keybindings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BindingMode {
+    /// Name of the mode
+    pub name: String,
+    
+    /// Key without modifiers that leaves the mode
+    #[serde(default = "default_mode_escape")]
+    pub escape: String,
+    
+    /// Keybindings active while in the mode
+    #[serde(default)]
+    pub keybinds: Vec<Keybind>,
+}
+
+impl BindingMode {
+    /// Get the keybindings registered while the mode is active: its own
+    /// keybindings and the escape key, unless the mode binds that key itself.
+    pub fn active_keybinds(&self) -> Vec<Keybind> {
+        let mut keybinds = self.keybinds.clone();
+        let escape_bound = keybinds.iter().any(|keybind| {
+            keybind.modifiers.is_empty() && keybind.key.eq_ignore_ascii_case(&self.escape)
+        });
+        if !escape_bound {
+            keybinds.push(Keybind {
+                modifiers: Vec::new(),
+                key: self.escape.clone(),
+                sequence: vec![],
+                command: "exit-mode".to_string(),
+                args: Vec::new(),
+                only_when: None,
+                except_when: None,
+            });
+        }
+        keybinds
+    }
+}
+
+fn default_mode_escape() -> String { "Escape".to_string() }
+
+/// Name of the main keybindings, accepted by `enter-mode` to leave a mode
+pub const DEFAULT_MODE: &str = "default";
+
+/// Mouse binding configuration
+/// 
+/// Holding the modifiers while dragging with the button performs the action
+/// on the window under the cursor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MouseBind {
+    /// Modifier keys (Win, Ctrl, Alt, Shift)
+    pub modifiers: Vec<String>,
+    
+    /// Mouse button that starts the drag
+    pub button: MouseButton,
+    
+    /// Action performed while dragging
+    pub action: MouseAction,
+}
+
+/// Mouse buttons that can be bound
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MouseButton {
+    Left,
+    Right,
+    Middle,
+}
+
+/// Actions that can be bound to a mouse drag
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MouseAction {
+    /// Move the window (floating windows follow the pointer, tiled windows are dropped into the layout)
+    Move,
+    
+    /// Resize the window from its bottom-right corner
+    Resize,
 }
 
 /// Monitor configuration
@@ -405,4 +806,31 @@ pub struct MonitorConfig {
     
     /// Rotation (0, 90, 180, 270)
     pub rotation: Option<u32>,
+    
+    /// Regions splitting the monitor into logical monitors, from left to right
+    #[serde(default)]
+    pub regions: Vec<MonitorRegion>,
+}
+
+/// A logical monitor carved out of a physical monitor
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MonitorRegion {
+    /// Name of the logical monitor (defaults to "<monitor>:<index>")
+    #[serde(default)]
+    pub name: Option<String>,
+    
+    /// Width of the region; regions without a width share the remaining space
+    #[serde(default)]
+    pub width: Option<RegionSize>,
+}
+
+/// Width of a monitor region
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RegionSize {
+    /// Width in pixels (e.g., 1280)
+    Pixels(u32),
+    
+    /// Fraction of the monitor width (e.g., 0.25)
+    Fraction(f32),
 }
@@ -2,157 +2,281 @@
 //! 
 //! This module provides validation functionality for configuration values
 //! with helpful error messages.
+//!
+//! Validation reports every problem at once into a `Diagnostics` collector,
+//! each with the path of the offending setting and, where possible, a
+//! suggestion. `validate` keeps the simple interface of failing with the
+//! first error.
 
+use super::diagnostics::{did_you_mean, Diagnostics, Severity};
+use super::include::ConfigSources;
+use super::ranges;
 use super::schema::*;
-use anyhow::{Result, Context};
+use crate::keybinds::chord::{format_sequence, Chord};
+use crate::keybinds::keys::{self, Modifier};
+use anyhow::Result;
 use std::collections::HashSet;
 
+/// Layouts accepted as the default layout
+const LAYOUTS: [&str; 2] = ["dwindle", "master"];
+
 /// Configuration validator
 pub struct ConfigValidator;
 
 impl ConfigValidator {
     /// Validate an entire configuration
     pub fn validate(config: &Config) -> Result<()> {
-        Self::validate_general(&config.general)?;
-        Self::validate_decoration(&config.decoration)?;
-        Self::validate_animations(&config.animations)?;
-        Self::validate_layouts(&config.layouts)?;
-        Self::validate_window_rules(&config.window_rules)?;
-        Self::validate_workspace_rules(&config.workspace_rules)?;
-        Self::validate_keybinds(&config.keybinds)?;
-        Self::validate_monitors(&config.monitors)?;
+        Self::validate_with_sources(config, &ConfigSources::default())
+    }
+    
+    /// Validate a configuration merged from several files
+    ///
+    /// Errors about list entries name the file the entry came from.
+    /// Warnings are logged.
+    pub fn validate_with_sources(config: &Config, sources: &ConfigSources) -> Result<()> {
+        let mut diagnostics = Diagnostics::new();
+        Self::diagnose(config, &mut diagnostics);
         
-        Ok(())
+        for warning in diagnostics.iter().filter(|d| d.severity == Severity::Warning) {
+            tracing::warn!("Config warning at {}: {}", warning.path, warning.message);
+        }
+        
+        match diagnostics.first_error() {
+            Some(error) => anyhow::bail!("{}{}", error.message, error.origin(sources)),
+            None => Ok(()),
+        }
+    }
+    
+    /// Check an entire configuration, reporting every problem
+    ///
+    /// # Arguments
+    /// * `config` - The configuration to check
+    /// * `diagnostics` - Collects the errors and warnings found
+    pub fn diagnose(config: &Config, diagnostics: &mut Diagnostics) {
+        Self::validate_general(&config.general, diagnostics);
+        Self::validate_decoration(&config.decoration, diagnostics);
+        Self::validate_animations(&config.animations, diagnostics);
+        Self::validate_layouts(&config.layouts, diagnostics);
+        Self::validate_window_rules(&config.window_rules, diagnostics);
+        Self::validate_dialogs(&config.dialogs, diagnostics);
+        Self::validate_workspace_rules(&config.workspace_rules, diagnostics);
+        Self::validate_workspace_groups(&config.workspace_groups, diagnostics);
+        Self::validate_keybinds(&config.keybinds, "keybinds", diagnostics);
+        Self::validate_modes(&config.modes, diagnostics);
+        Self::validate_mode_commands(config, diagnostics);
+        Self::validate_mousebinds(&config.mousebinds, diagnostics);
+        Self::validate_monitors(&config.monitors, diagnostics);
     }
     
     /// Validate general configuration
-    fn validate_general(config: &GeneralConfig) -> Result<()> {
+    fn validate_general(config: &GeneralConfig, diagnostics: &mut Diagnostics) {
         if config.gaps_in < 0 {
-            anyhow::bail!("gaps_in must be non-negative");
+            diagnostics.error("general.gaps_in", "gaps_in must be non-negative");
         }
         
         if config.gaps_out < 0 {
-            anyhow::bail!("gaps_out must be non-negative");
+            diagnostics.error("general.gaps_out", "gaps_out must be non-negative");
         }
         
         if config.border_size < 0 {
-            anyhow::bail!("border_size must be non-negative");
+            diagnostics.error("general.border_size", "border_size must be non-negative");
         }
         
-        Self::validate_color(&config.active_border_color)
-            .context("Invalid active_border_color")?;
-        Self::validate_color(&config.inactive_border_color)
-            .context("Invalid inactive_border_color")?;
+        if config.snap_threshold < 0 {
+            diagnostics.error("general.snap_threshold", "snap_threshold must be non-negative");
+        }
         
-        Ok(())
+        if !ranges::SPECIAL_WORKSPACE_SCALE.contains(&f64::from(config.special_workspace_scale)) {
+            diagnostics.error(
+                "general.special_workspace_scale",
+                format!(
+                    "special_workspace_scale must be between {:?} and {:?}",
+                    ranges::SPECIAL_WORKSPACE_SCALE.start(),
+                    ranges::SPECIAL_WORKSPACE_SCALE.end(),
+                ),
+            );
+        }
+        
+        if !ranges::TAG_COUNT.contains(&config.tag_count) {
+            diagnostics.error(
+                "general.tag_count",
+                format!(
+                    "tag_count must be between {} and {}",
+                    ranges::TAG_COUNT.start(),
+                    ranges::TAG_COUNT.end(),
+                ),
+            );
+        }
+        
+        Self::check_color(diagnostics, "general", "active_border_color", &config.active_border_color);
+        Self::check_color(diagnostics, "general", "inactive_border_color", &config.inactive_border_color);
     }
     
     /// Validate decoration configuration
-    fn validate_decoration(config: &DecorationConfig) -> Result<()> {
+    fn validate_decoration(config: &DecorationConfig, diagnostics: &mut Diagnostics) {
         if config.rounding < 0 {
-            anyhow::bail!("rounding must be non-negative");
+            diagnostics.error("decoration.rounding", "rounding must be non-negative");
         }
         
-        if !(0.0..=1.0).contains(&config.active_opacity) {
-            anyhow::bail!("active_opacity must be between 0.0 and 1.0");
+        if !ranges::OPACITY.contains(&f64::from(config.active_opacity)) {
+            diagnostics.error(
+                "decoration.active_opacity",
+                format!(
+                    "active_opacity must be between {:?} and {:?}",
+                    ranges::OPACITY.start(),
+                    ranges::OPACITY.end(),
+                ),
+            );
         }
         
-        if !(0.0..=1.0).contains(&config.inactive_opacity) {
-            anyhow::bail!("inactive_opacity must be between 0.0 and 1.0");
+        if !ranges::OPACITY.contains(&f64::from(config.inactive_opacity)) {
+            diagnostics.error(
+                "decoration.inactive_opacity",
+                format!(
+                    "inactive_opacity must be between {:?} and {:?}",
+                    ranges::OPACITY.start(),
+                    ranges::OPACITY.end(),
+                ),
+            );
         }
         
-        Self::validate_color(&config.shadow_color)
-            .context("Invalid shadow_color")?;
-        
-        Ok(())
+        Self::check_color(diagnostics, "decoration", "shadow_color", &config.shadow_color);
     }
     
     /// Validate animations configuration
-    fn validate_animations(config: &AnimationsConfig) -> Result<()> {
+    fn validate_animations(config: &AnimationsConfig, diagnostics: &mut Diagnostics) {
         if config.speed <= 0.0 {
-            anyhow::bail!("animation speed must be positive");
+            diagnostics.error("animations.speed", "animation speed must be positive");
         }
         
-        if config.speed > 10.0 {
-            anyhow::bail!("animation speed should be reasonable (max 10.0)");
+        if f64::from(config.speed) > ranges::MAX_ANIMATION_SPEED {
+            diagnostics.error(
+                "animations.speed",
+                format!(
+                    "animation speed should be reasonable (max {:?})",
+                    ranges::MAX_ANIMATION_SPEED
+                ),
+            );
         }
-        
-        Ok(())
     }
     
     /// Validate layouts configuration
-    fn validate_layouts(config: &LayoutsConfig) -> Result<()> {
+    fn validate_layouts(config: &LayoutsConfig, diagnostics: &mut Diagnostics) {
         // Validate default layout
-        if config.default != "dwindle" && config.default != "master" {
-            anyhow::bail!("default layout must be 'dwindle' or 'master'");
+        if !LAYOUTS.contains(&config.default.as_str()) {
+            diagnostics
+                .error("layouts.default", "default layout must be 'dwindle' or 'master'")
+                .suggest_opt(did_you_mean(&config.default, LAYOUTS));
         }
         
         // Validate dwindle config
-        if !(0.1..=0.9).contains(&config.dwindle.split_ratio) {
-            anyhow::bail!("dwindle split_ratio must be between 0.1 and 0.9");
+        if !ranges::SPLIT_RATIO.contains(&f64::from(config.dwindle.split_ratio)) {
+            diagnostics.error(
+                "layouts.dwindle.split_ratio",
+                format!(
+                    "dwindle split_ratio must be between {:?} and {:?}",
+                    ranges::SPLIT_RATIO.start(),
+                    ranges::SPLIT_RATIO.end(),
+                ),
+            );
         }
         
         // Validate master config
-        if !(0.1..=0.9).contains(&config.master.master_factor) {
-            anyhow::bail!("master master_factor must be between 0.1 and 0.9");
+        if !ranges::SPLIT_RATIO.contains(&f64::from(config.master.master_factor)) {
+            diagnostics.error(
+                "layouts.master.master_factor",
+                format!(
+                    "master master_factor must be between {:?} and {:?}",
+                    ranges::SPLIT_RATIO.start(),
+                    ranges::SPLIT_RATIO.end(),
+                ),
+            );
         }
         
         if config.master.master_count == 0 {
-            anyhow::bail!("master master_count must be at least 1");
+            diagnostics.error("layouts.master.master_count", "master master_count must be at least 1");
         }
-        
-        Ok(())
     }
     
     /// Validate window rules
-    fn validate_window_rules(rules: &[WindowRule]) -> Result<()> {
+    fn validate_window_rules(rules: &[WindowRule], diagnostics: &mut Diagnostics) {
         for (i, rule) in rules.iter().enumerate() {
+            let path = format!("window_rules[{}]", i);
+            
             // Check that at least one match condition is specified
-            if rule.match_process.is_none() 
-                && rule.match_title.is_none() 
-                && rule.match_class.is_none() 
+            if rule.match_process.is_none()
+                && rule.match_title.is_none()
+                && rule.match_class.is_none()
             {
-                anyhow::bail!(
-                    "Window rule {} must have at least one match condition",
-                    i
-                );
+                diagnostics
+                    .error(&path, format!("Window rule {} must have at least one match condition", i))
+                    .suggest("add match_process, match_title or match_class");
             }
             
             // Validate regex patterns
-            if let Some(ref pattern) = rule.match_process {
-                regex::Regex::new(pattern)
-                    .with_context(|| format!("Invalid regex in rule {} match_process: '{}'", i, pattern))?;
+            let patterns = [
+                ("match_process", &rule.match_process),
+                ("match_title", &rule.match_title),
+                ("match_class", &rule.match_class),
+            ];
+            for (field, pattern) in patterns {
+                if let Some(pattern) = pattern {
+                    if let Err(e) = regex::Regex::new(pattern) {
+                        let detail = e.to_string();
+                        diagnostics
+                            .error(
+                                format!("{}.{}", path, field),
+                                format!("Invalid regex in rule {} {}: '{}'", i, field, pattern),
+                            )
+                            .suggest(detail.lines().last().unwrap_or_default().trim());
+                    }
+                }
             }
             
-            if let Some(ref pattern) = rule.match_title {
-                regex::Regex::new(pattern)
-                    .with_context(|| format!("Invalid regex in rule {} match_title: '{}'", i, pattern))?;
+            // Validate actions
+            if rule.actions.is_empty() {
+                diagnostics.error(&path, format!("Window rule {} must have at least one action", i));
             }
             
-            if let Some(ref pattern) = rule.match_class {
-                regex::Regex::new(pattern)
-                    .with_context(|| format!("Invalid regex in rule {} match_class: '{}'", i, pattern))?;
+            for (j, action) in rule.actions.iter().enumerate() {
+                if let Err(e) = Self::validate_rule_action(action) {
+                    diagnostics.error(
+                        format!("{}.actions[{}]", path, j),
+                        format!("Window rule {} has an invalid action: {}", i, e),
+                    );
+                }
             }
             
-            // Validate actions
-            if rule.actions.is_empty() {
-                anyhow::bail!("Window rule {} must have at least one action", i);
+            let dialog = rule.actions.iter().any(|a| matches!(a, RuleAction::Dialog));
+            let no_dialog = rule.actions.iter().any(|a| matches!(a, RuleAction::NoDialog));
+            if dialog && no_dialog {
+                diagnostics.error(
+                    format!("{}.actions", path),
+                    format!("Window rule {} cannot have both dialog and no_dialog actions", i),
+                );
             }
-            
-            for action in &rule.actions {
-                Self::validate_rule_action(action)?;
+        }
+    }
+    
+    /// Validate dialog configuration
+    fn validate_dialogs(config: &DialogsConfig, diagnostics: &mut Diagnostics) {
+        for (i, class) in config.classes.iter().enumerate() {
+            if class.trim().is_empty() {
+                diagnostics.error(format!("dialogs.classes[{}]", i), "Dialog class names must not be empty");
             }
         }
-        
-        Ok(())
     }
     
     /// Validate a single rule action
     fn validate_rule_action(action: &RuleAction) -> Result<()> {
         match action {
             RuleAction::Opacity(opacity) => {
-                if !(0.0..=1.0).contains(opacity) {
-                    anyhow::bail!("opacity must be between 0.0 and 1.0");
+                if !ranges::OPACITY.contains(&f64::from(*opacity)) {
+                    anyhow::bail!(
+                        "opacity must be between {:?} and {:?}",
+                        ranges::OPACITY.start(),
+                        ranges::OPACITY.end()
+                    );
                 }
             }
             RuleAction::Workspace(id) => {
@@ -163,6 +287,12 @@ impl ConfigValidator {
             RuleAction::Monitor(_id) => {
                 // Monitor IDs are 0-based, so no validation needed
             }
+            RuleAction::Mark(mark) if mark.is_empty() || mark.chars().any(char::is_whitespace) => {
+                anyhow::bail!("mark must be a non-empty name without whitespace");
+            }
+            RuleAction::SpecialWorkspace(name) if name.trim().is_empty() => {
+                anyhow::bail!("special_workspace name must not be empty");
+            }
             _ => {} // Other actions don't need validation
         }
         
@@ -170,91 +300,452 @@ impl ConfigValidator {
     }
     
     /// Validate workspace rules
-    fn validate_workspace_rules(rules: &[WorkspaceRule]) -> Result<()> {
+    fn validate_workspace_rules(rules: &[WorkspaceRule], diagnostics: &mut Diagnostics) {
         let mut workspace_ids = HashSet::new();
+        let mut default_monitors = HashSet::new();
         
-        for rule in rules {
+        for (i, rule) in rules.iter().enumerate() {
+            let path = format!("workspace_rules[{}]", i);
+            
             if rule.id == 0 {
-                anyhow::bail!("Workspace ID must be at least 1");
+                diagnostics.error(format!("{}.id", path), "Workspace ID must be at least 1");
             }
             
             if workspace_ids.contains(&rule.id) {
-                anyhow::bail!("Duplicate workspace ID: {}", rule.id);
+                diagnostics.error(format!("{}.id", path), format!("Duplicate workspace ID: {}", rule.id));
+            }
+            
+            if let MonitorRef::Name(name) = &rule.monitor {
+                if name.trim().is_empty() {
+                    diagnostics.error(
+                        format!("{}.monitor", path),
+                        format!("Workspace {} has an empty monitor name", rule.id),
+                    );
+                }
+            }
+            
+            if rule.default && !default_monitors.insert(rule.monitor.to_string().to_lowercase()) {
+                diagnostics.error(
+                    format!("{}.default", path),
+                    format!("Multiple default workspaces for monitor {}", rule.monitor),
+                );
             }
             
             workspace_ids.insert(rule.id);
         }
+    }
+    
+    /// Validate workspace groups
+    fn validate_workspace_groups(groups: &[WorkspaceGroup], diagnostics: &mut Diagnostics) {
+        let mut group_ids = HashSet::new();
         
-        Ok(())
+        for (i, group) in groups.iter().enumerate() {
+            let path = format!("workspace_groups[{}]", i);
+            
+            if group.id == 0 {
+                diagnostics.error(format!("{}.id", path), "Workspace group ID must be at least 1");
+            }
+            
+            if !group_ids.insert(group.id) {
+                diagnostics.error(
+                    format!("{}.id", path),
+                    format!("Duplicate workspace group ID: {}", group.id),
+                );
+            }
+            
+            if group.workspaces.is_empty() {
+                diagnostics.error(
+                    format!("{}.workspaces", path),
+                    format!("Workspace group {} has no workspaces", group.id),
+                );
+            }
+            
+            let mut monitors = HashSet::new();
+            let mut workspaces = HashSet::new();
+            for (j, member) in group.workspaces.iter().enumerate() {
+                let member_path = format!("{}.workspaces[{}]", path, j);
+                if member.workspace == 0 {
+                    diagnostics.error(format!("{}.workspace", member_path), "Workspace ID must be at least 1");
+                }
+                if !monitors.insert(member.monitor.to_string().to_lowercase()) {
+                    diagnostics.error(
+                        format!("{}.monitor", member_path),
+                        format!(
+                            "Workspace group {} has more than one workspace for monitor {}",
+                            group.id, member.monitor
+                        ),
+                    );
+                }
+                if !workspaces.insert(member.workspace) {
+                    diagnostics.error(
+                        format!("{}.workspace", member_path),
+                        format!(
+                            "Workspace group {} shows workspace {} on more than one monitor",
+                            group.id, member.workspace
+                        ),
+                    );
+                }
+            }
+        }
     }
     
-    /// Validate keybindings
-    fn validate_keybinds(keybinds: &[Keybind]) -> Result<()> {
+    /// Validate a list of keybindings found at `list`
+    fn validate_keybinds(keybinds: &[Keybind], list: &str, diagnostics: &mut Diagnostics) {
         let mut keybind_combinations = HashSet::new();
+        let mut sequences = Vec::new();
         
-        for keybind in keybinds {
-            // Validate modifiers
-            for modifier in &keybind.modifiers {
-                if !["Win", "Ctrl", "Alt", "Shift"].contains(&modifier.as_str()) {
-                    anyhow::bail!("Invalid modifier: {}", modifier);
+        for (i, keybind) in keybinds.iter().enumerate() {
+            let path = format!("{}[{}]", list, i);
+            let mut valid = true;
+            
+            // Validate modifiers and key
+            for (j, modifier) in keybind.modifiers.iter().enumerate() {
+                if Modifier::parse(modifier).is_none() {
+                    valid = false;
+                    diagnostics
+                        .error(format!("{}.modifiers[{}]", path, j), format!("Invalid modifier: {}", modifier))
+                        .suggest_opt(Self::modifier_suggestion(modifier));
                 }
             }
+            valid &= Self::validate_key(&keybind.key, &format!("{}.key", path), diagnostics);
             
-            // Check for duplicate keybindings
-            let combination = format!(
-                "{:?}+{}",
-                keybind.modifiers,
-                keybind.key
-            );
+            // Validate the further keys of a sequence
+            for (j, step) in keybind.sequence.iter().enumerate() {
+                let step_path = format!("{}.sequence[{}]", path, j);
+                match Chord::parse(step) {
+                    Ok(chord) => {
+                        for modifier in chord.modifiers() {
+                            if Modifier::parse(modifier).is_none() {
+                                valid = false;
+                                diagnostics
+                                    .error(&step_path, format!("Invalid modifier: {}", modifier))
+                                    .suggest_opt(Self::modifier_suggestion(modifier));
+                            }
+                        }
+                        valid &= Self::validate_key(chord.key(), &step_path, diagnostics);
+                    }
+                    Err(e) => {
+                        valid = false;
+                        diagnostics.error(&step_path, format!("Invalid key sequence step: {}", e));
+                    }
+                }
+            }
             
-            if keybind_combinations.contains(&combination) {
-                anyhow::bail!("Duplicate keybinding: {}", combination);
+            // Validate the windows of only_when and except_when
+            let conditions = [("only_when", &keybind.only_when), ("except_when", &keybind.except_when)];
+            for (field, criteria) in conditions {
+                if let Some(criteria) = criteria {
+                    Self::validate_window_match(criteria, &format!("{}.{}", path, field), diagnostics);
+                }
             }
             
-            keybind_combinations.insert(combination);
+            // Check for duplicate keybindings, however their keys are written.
+            // Keybindings with conditions may share keys, e.g. to override
+            // a keybinding for some applications.
+            if valid {
+                if let Ok(chords) = Chord::sequence_of(keybind) {
+                    let combination = format_sequence(&chords);
+                    if !keybind.is_conditional() && !keybind_combinations.insert(combination.clone()) {
+                        diagnostics.error(&path, format!("Duplicate keybinding: {}", combination));
+                    }
+                    sequences.push((i, chords));
+                }
+            }
             
             // Validate command is not empty
             if keybind.command.is_empty() {
-                anyhow::bail!("Keybind command cannot be empty");
+                diagnostics.error(format!("{}.command", path), "Keybind command cannot be empty");
             }
         }
         
-        Ok(())
+        // A keybinding that is the start of a sequence would run before the
+        // rest of the sequence could be pressed
+        for (a, (i, first)) in sequences.iter().enumerate() {
+            for (j, second) in &sequences[a + 1..] {
+                let (shorter, longer) = if first.len() <= second.len() {
+                    (first, second)
+                } else {
+                    (second, first)
+                };
+                if shorter.len() < longer.len() && longer.starts_with(shorter) {
+                    diagnostics.error(
+                        format!("{}[{}]", list, j),
+                        format!(
+                            "'{}' is both a keybinding and the start of the sequence '{}' ({}[{}])",
+                            format_sequence(shorter),
+                            format_sequence(longer),
+                            list,
+                            i
+                        ),
+                    );
+                }
+            }
+        }
+    }
+    
+    /// Validate the window criteria of a keybinding condition
+    fn validate_window_match(criteria: &WindowMatch, path: &str, diagnostics: &mut Diagnostics) {
+        if criteria.is_empty() {
+            diagnostics
+                .error(path, "Keybinding condition must have at least one match condition")
+                .suggest("add match_process, match_title or match_class");
+        }
+        
+        let patterns = [
+            ("match_process", &criteria.match_process),
+            ("match_title", &criteria.match_title),
+            ("match_class", &criteria.match_class),
+        ];
+        for (field, pattern) in patterns {
+            if let Some(pattern) = pattern {
+                if let Err(e) = regex::Regex::new(pattern) {
+                    let detail = e.to_string();
+                    diagnostics
+                        .error(
+                            format!("{}.{}", path, field),
+                            format!("Invalid regex in {}: '{}'", field, pattern),
+                        )
+                        .suggest(detail.lines().last().unwrap_or_default().trim());
+                }
+            }
+        }
+    }
+    
+    /// Validate binding modes
+    fn validate_modes(modes: &[BindingMode], diagnostics: &mut Diagnostics) {
+        let mut names = HashSet::new();
+        
+        for (i, mode) in modes.iter().enumerate() {
+            let path = format!("modes[{}]", i);
+            
+            if mode.name.trim().is_empty() {
+                diagnostics.error(format!("{}.name", path), "Mode name cannot be empty");
+            } else if mode.name == DEFAULT_MODE {
+                diagnostics.error(
+                    format!("{}.name", path),
+                    format!("Mode name '{}' is reserved for the main keybindings", DEFAULT_MODE),
+                );
+            } else if !names.insert(mode.name.as_str()) {
+                diagnostics.error(format!("{}.name", path), format!("Duplicate mode: {}", mode.name));
+            }
+            
+            if mode.escape.trim().is_empty() {
+                diagnostics.error(format!("{}.escape", path), "Mode escape key cannot be empty");
+            } else {
+                Self::validate_key(&mode.escape, &format!("{}.escape", path), diagnostics);
+            }
+            
+            Self::validate_keybinds(&mode.keybinds, &format!("{}.keybinds", path), diagnostics);
+        }
+    }
+    
+    /// Check that `enter-mode` keybindings name a defined mode
+    fn validate_mode_commands(config: &Config, diagnostics: &mut Diagnostics) {
+        let names: Vec<&str> = config.modes.iter().map(|mode| mode.name.as_str()).collect();
+        let lists = std::iter::once(("keybinds".to_string(), &config.keybinds)).chain(
+            config
+                .modes
+                .iter()
+                .enumerate()
+                .map(|(i, mode)| (format!("modes[{}].keybinds", i), &mode.keybinds)),
+        );
+        
+        for (list, keybinds) in lists {
+            for (i, keybind) in keybinds.iter().enumerate() {
+                if keybind.command != "enter-mode" {
+                    continue;
+                }
+                let path = format!("{}[{}].args", list, i);
+                match keybind.args.first() {
+                    None => {
+                        diagnostics.error(path, "enter-mode requires a mode name argument");
+                    }
+                    Some(name) if name != DEFAULT_MODE && !names.contains(&name.as_str()) => {
+                        diagnostics
+                            .error(path, format!("Unknown mode: {}", name))
+                            .suggest_opt(did_you_mean(name, names.iter().copied()));
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+    
+    /// Validate mouse binding configurations
+    fn validate_mousebinds(mousebinds: &[MouseBind], diagnostics: &mut Diagnostics) {
+        let mut combinations = HashSet::new();
+        
+        for (i, mousebind) in mousebinds.iter().enumerate() {
+            let path = format!("mousebinds[{}]", i);
+            
+            for (j, modifier) in mousebind.modifiers.iter().enumerate() {
+                if Modifier::parse(modifier).is_none() {
+                    diagnostics
+                        .error(
+                            format!("{}.modifiers[{}]", path, j),
+                            format!("Invalid modifier in mousebind: {}", modifier),
+                        )
+                        .suggest_opt(Self::modifier_suggestion(modifier));
+                }
+            }
+            
+            // Require a modifier so plain clicks keep working
+            if mousebind.modifiers.is_empty() {
+                diagnostics.error(
+                    format!("{}.modifiers", path),
+                    format!("Mousebind for {:?} button must have at least one modifier", mousebind.button),
+                );
+            }
+            
+            let mut modifiers: Vec<&str> = mousebind
+                .modifiers
+                .iter()
+                .map(|m| Modifier::parse(m).map_or(m.as_str(), |parsed| parsed.name()))
+                .collect();
+            modifiers.sort();
+            modifiers.dedup();
+            let combination = format!("{:?}+{:?}", modifiers, mousebind.button);
+            
+            if !combinations.insert(combination.clone()) {
+                diagnostics.error(&path, format!("Duplicate mousebinding: {}", combination));
+            }
+        }
+    }
+    
+    /// Suggest the modifier meant by an invalid one
+    fn modifier_suggestion(modifier: &str) -> Option<String> {
+        match modifier.to_lowercase().as_str() {
+            "cmd" | "command" => Some("did you mean 'Win'?".to_string()),
+            "menu" | "altgr" => Some("did you mean 'Alt'?".to_string()),
+            _ => did_you_mean(modifier, Modifier::ALL.map(Modifier::name)),
+        }
+    }
+    
+    /// Check that a key name is known, reporting it at `path` otherwise
+    fn validate_key(key: &str, path: &str, diagnostics: &mut Diagnostics) -> bool {
+        if keys::virtual_key(key).is_some() {
+            return true;
+        }
+        
+        if key.trim().is_empty() {
+            diagnostics.error(path, "Key cannot be empty");
+        } else {
+            // Single characters are close to every other one
+            let suggestion = if key.chars().count() > 1 {
+                did_you_mean(key, keys::key_names())
+            } else {
+                None
+            };
+            diagnostics
+                .error(path, format!("Unknown key: {}", key))
+                .suggest_opt(suggestion);
+        }
+        false
     }
     
     /// Validate monitor configurations
-    fn validate_monitors(monitors: &[MonitorConfig]) -> Result<()> {
-        for monitor in monitors {
+    fn validate_monitors(monitors: &[MonitorConfig], diagnostics: &mut Diagnostics) {
+        for (i, monitor) in monitors.iter().enumerate() {
+            let path = format!("monitors[{}]", i);
+            
             // Validate resolution format
             if let Some(ref res) = monitor.resolution {
                 if !Self::is_valid_resolution(res) {
-                    anyhow::bail!("Invalid resolution format: {}", res);
+                    diagnostics
+                        .error(format!("{}.resolution", path), format!("Invalid resolution format: {}", res))
+                        .suggest("use WIDTHxHEIGHT, e.g. \"1920x1080\"");
                 }
             }
             
             // Validate position format
             if let Some(ref pos) = monitor.position {
                 if pos != "auto" && !Self::is_valid_position(pos) {
-                    anyhow::bail!("Invalid position format: {}", pos);
+                    diagnostics
+                        .error(format!("{}.position", path), format!("Invalid position format: {}", pos))
+                        .suggest("use XxY, e.g. \"1920x0\", or \"auto\"");
                 }
             }
             
             // Validate scale
             if let Some(scale) = monitor.scale {
-                if scale <= 0.0 || scale > 4.0 {
-                    anyhow::bail!("Monitor scale must be between 0.0 and 4.0");
+                if scale <= 0.0 || f64::from(scale) > ranges::MAX_MONITOR_SCALE {
+                    diagnostics.error(
+                        format!("{}.scale", path),
+                        format!(
+                            "Monitor scale must be between 0.0 and {:?}",
+                            ranges::MAX_MONITOR_SCALE
+                        ),
+                    );
                 }
             }
             
             // Validate rotation
             if let Some(rotation) = monitor.rotation {
                 if ![0, 90, 180, 270].contains(&rotation) {
-                    anyhow::bail!("Monitor rotation must be 0, 90, 180, or 270");
+                    diagnostics.error(format!("{}.rotation", path), "Monitor rotation must be 0, 90, 180, or 270");
                 }
             }
+            
+            Self::validate_monitor_regions(monitor, &path, diagnostics);
         }
+    }
+    
+    /// Validate the regions splitting a monitor into logical monitors
+    fn validate_monitor_regions(monitor: &MonitorConfig, path: &str, diagnostics: &mut Diagnostics) {
+        let mut fractions = 0.0;
+        let mut names = HashSet::new();
         
-        Ok(())
+        for (i, region) in monitor.regions.iter().enumerate() {
+            let region_path = format!("{}.regions[{}]", path, i);
+            match region.width {
+                Some(RegionSize::Pixels(0)) => {
+                    diagnostics.error(
+                        format!("{}.width", region_path),
+                        format!("Region width of monitor '{}' must be greater than 0", monitor.name),
+                    );
+                }
+                Some(RegionSize::Fraction(fraction)) => {
+                    if fraction <= 0.0 || fraction > 1.0 {
+                        diagnostics.error(
+                            format!("{}.width", region_path),
+                            format!("Region fraction of monitor '{}' must be between 0.0 and 1.0", monitor.name),
+                        );
+                    }
+                    fractions += fraction;
+                }
+                _ => {}
+            }
+            
+            if let Some(ref name) = region.name {
+                if name.is_empty() {
+                    diagnostics.error(
+                        format!("{}.name", region_path),
+                        format!("Region name of monitor '{}' cannot be empty", monitor.name),
+                    );
+                } else if !names.insert(name.to_lowercase()) {
+                    diagnostics.error(
+                        format!("{}.name", region_path),
+                        format!("Duplicate region name '{}' on monitor '{}'", name, monitor.name),
+                    );
+                }
+            }
+        }
+        
+        if fractions > 1.0 + f32::EPSILON {
+            diagnostics.error(
+                format!("{}.regions", path),
+                format!("Region fractions of monitor '{}' add up to more than 1.0", monitor.name),
+            );
+        }
+    }
+    
+    /// Report an invalid color setting
+    fn check_color(diagnostics: &mut Diagnostics, section: &str, field: &str, color: &str) {
+        if let Err(e) = Self::validate_color(color) {
+            diagnostics
+                .error(format!("{}.{}", section, field), format!("Invalid {}: {}", field, e))
+                .suggest("use a hex color such as \"#89b4fa\"");
+        }
     }
     
     /// Validate color format (hex)
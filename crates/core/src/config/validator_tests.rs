@@ -506,6 +506,106 @@ mod tests {
         assert!(err_msg.contains("workspace") || err_msg.contains("ID"));
     }
     
+    #[test]
+    fn test_rule_conflicting_dialog_actions() {
+        let mut config = Config::default();
+        
+        config.window_rules.push(WindowRule {
+            match_process: Some("test.exe".to_string()),
+            match_title: None,
+            match_class: None,
+            actions: vec![RuleAction::Dialog, RuleAction::NoDialog],
+        });
+        
+        let result = ConfigValidator::validate(&config);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("no_dialog"));
+    }
+    
+    #[test]
+    fn test_rule_action_invalid_mark() {
+        let mut config = Config::default();
+        
+        config.window_rules.push(WindowRule {
+            match_process: Some("test.exe".to_string()),
+            match_title: None,
+            match_class: None,
+            actions: vec![RuleAction::Mark("two words".to_string())],
+        });
+        
+        let result = ConfigValidator::validate(&config);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("mark"));
+    }
+    
+    #[test]
+    fn test_rule_action_empty_special_workspace() {
+        let mut config = Config::default();
+        
+        config.window_rules.push(WindowRule {
+            match_process: Some("test.exe".to_string()),
+            match_title: None,
+            match_class: None,
+            actions: vec![RuleAction::SpecialWorkspace(" ".to_string())],
+        });
+        
+        let result = ConfigValidator::validate(&config);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("special_workspace"));
+    }
+    
+    #[test]
+    fn test_invalid_special_workspace_scale() {
+        let mut config = Config::default();
+        config.general.special_workspace_scale = 0.05;
+        
+        let result = ConfigValidator::validate(&config);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("special_workspace_scale"));
+    }
+    
+    #[test]
+    fn test_tag_mode() {
+        let config: Config = toml::from_str(
+            r#"
+            [general]
+            workspace_mode = "tags"
+            tag_count = 5
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.general.workspace_mode, WorkspaceMode::Tags);
+        assert!(ConfigValidator::validate(&config).is_ok());
+        
+        let mut config = Config::default();
+        assert_eq!(config.general.workspace_mode, WorkspaceMode::Workspaces);
+        config.general.tag_count = 33;
+        let result = ConfigValidator::validate(&config);
+        assert!(result.unwrap_err().to_string().contains("tag_count"));
+    }
+    
+    // ========================================
+    // Dialog Configuration Tests
+    // ========================================
+    
+    #[test]
+    fn test_empty_dialog_class() {
+        let mut config = Config::default();
+        config.dialogs.classes.push("  ".to_string());
+        
+        let result = ConfigValidator::validate(&config);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Dialog class"));
+    }
+    
+    #[test]
+    fn test_dialog_class_match_is_case_insensitive() {
+        let config = DialogsConfig::default();
+        assert!(config.is_dialog_class("#32770"));
+        assert!(config.is_dialog_class("msosplash"));
+        assert!(!config.is_dialog_class("Chrome_WidgetWin_1"));
+    }
+    
     // ========================================
     // Workspace Rule Tests
     // ========================================
@@ -516,7 +616,7 @@ mod tests {
         
         config.workspace_rules.push(WorkspaceRule {
             id: 0,
-            monitor: 0,
+            monitor: MonitorRef::Index(0),
             default: true,
             name: Some("Invalid".to_string()),
         });
@@ -534,14 +634,14 @@ mod tests {
         
         config.workspace_rules.push(WorkspaceRule {
             id: 1,
-            monitor: 0,
+            monitor: MonitorRef::Index(0),
             default: true,
             name: None,
         });
         
         config.workspace_rules.push(WorkspaceRule {
             id: 1,
-            monitor: 0,
+            monitor: MonitorRef::Index(0),
             default: false,
             name: None,
         });
@@ -559,21 +659,21 @@ mod tests {
         
         config.workspace_rules.push(WorkspaceRule {
             id: 1,
-            monitor: 0,
+            monitor: MonitorRef::Index(0),
             default: true,
             name: Some("Main".to_string()),
         });
         
         config.workspace_rules.push(WorkspaceRule {
             id: 2,
-            monitor: 0,
+            monitor: MonitorRef::Index(0),
             default: false,
             name: Some("Web".to_string()),
         });
         
         config.workspace_rules.push(WorkspaceRule {
             id: 3,
-            monitor: 1,
+            monitor: MonitorRef::Index(1),
             default: true,
             name: None,
         });
@@ -581,6 +681,91 @@ mod tests {
         assert!(ConfigValidator::validate(&config).is_ok());
     }
     
+    #[test]
+    fn test_duplicate_default_workspace_for_monitor() {
+        let mut config = Config::default();
+        
+        config.workspace_rules.push(WorkspaceRule {
+            id: 1,
+            monitor: MonitorRef::Name("\\\\.\\DISPLAY1".to_string()),
+            default: true,
+            name: None,
+        });
+        
+        config.workspace_rules.push(WorkspaceRule {
+            id: 2,
+            monitor: MonitorRef::Name("\\\\.\\display1".to_string()),
+            default: true,
+            name: None,
+        });
+        
+        let result = ConfigValidator::validate(&config);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Multiple default"));
+    }
+    
+    #[test]
+    fn test_workspace_rule_monitor_by_name() {
+        let config: Config = toml::from_str(
+            r#"
+            [[workspace_rules]]
+            id = 1
+            monitor = 0
+            
+            [[workspace_rules]]
+            id = 2
+            monitor = '\\.\DISPLAY2'
+            default = true
+            "#,
+        )
+        .unwrap();
+        
+        assert_eq!(config.workspace_rules[0].monitor, MonitorRef::Index(0));
+        assert_eq!(
+            config.workspace_rules[1].monitor,
+            MonitorRef::Name("\\\\.\\DISPLAY2".to_string())
+        );
+        assert!(config.workspace_rules[1].monitor.matches(1, "\\\\.\\display2"));
+        assert!(ConfigValidator::validate(&config).is_ok());
+    }
+    
+    #[test]
+    fn test_workspace_groups() {
+        let mut config: Config = toml::from_str(
+            r#"
+            [[workspace_groups]]
+            id = 2
+            name = "Dev"
+            workspaces = [
+                { monitor = 0, workspace = 2 },
+                { monitor = 1, workspace = 12 },
+                { monitor = '\\.\DISPLAY3', workspace = 22 },
+            ]
+            "#,
+        )
+        .unwrap();
+        
+        let group = &config.workspace_groups[0];
+        assert_eq!(group.name.as_deref(), Some("Dev"));
+        assert_eq!(group.workspaces[1].monitor, MonitorRef::Index(1));
+        assert_eq!(group.workspaces[2].workspace, 22);
+        assert!(ConfigValidator::validate(&config).is_ok());
+        
+        config.workspace_groups[0].workspaces[1].monitor = MonitorRef::Index(0);
+        let err_msg = ConfigValidator::validate(&config).unwrap_err().to_string();
+        assert!(err_msg.contains("more than one workspace for monitor"));
+        
+        config.workspace_groups[0].workspaces[1].monitor = MonitorRef::Index(1);
+        config.workspace_groups[0].workspaces[1].workspace = 2;
+        let err_msg = ConfigValidator::validate(&config).unwrap_err().to_string();
+        assert!(err_msg.contains("on more than one monitor"));
+        
+        config.workspace_groups[0].workspaces[1].workspace = 12;
+        config.workspace_groups.push(config.workspace_groups[0].clone());
+        let err_msg = ConfigValidator::validate(&config).unwrap_err().to_string();
+        assert!(err_msg.contains("Duplicate workspace group ID"));
+    }
+    
     // ========================================
     // Keybinding Tests
     // ========================================
@@ -590,17 +775,20 @@ mod tests {
         let mut config = Config::default();
         
         config.keybinds.push(Keybind {
-            modifiers: vec!["Super".to_string()], // Invalid modifier
+            modifiers: vec!["Hyper".to_string()], // Invalid modifier
             key: "q".to_string(),
+            sequence: vec![],
             command: "close".to_string(),
             args: vec![],
+            only_when: None,
+            except_when: None,
         });
         
         let result = ConfigValidator::validate(&config);
         assert!(result.is_err());
         let err_msg = result.unwrap_err().to_string();
         assert!(err_msg.contains("modifier"));
-        assert!(err_msg.contains("Super"));
+        assert!(err_msg.contains("Hyper"));
     }
     
     #[test]
@@ -610,8 +798,11 @@ mod tests {
         config.keybinds.push(Keybind {
             modifiers: vec!["Win".to_string()],
             key: "q".to_string(),
+            sequence: vec![],
             command: "".to_string(),
             args: vec![],
+            only_when: None,
+            except_when: None,
         });
         
         let result = ConfigValidator::validate(&config);
@@ -626,15 +817,21 @@ mod tests {
         config.keybinds.push(Keybind {
             modifiers: vec!["Win".to_string()],
             key: "q".to_string(),
+            sequence: vec![],
             command: "close".to_string(),
             args: vec![],
+            only_when: None,
+            except_when: None,
         });
         
         config.keybinds.push(Keybind {
             modifiers: vec!["Win".to_string()],
             key: "q".to_string(),
+            sequence: vec![],
             command: "quit".to_string(),
             args: vec![],
+            only_when: None,
+            except_when: None,
         });
         
         let result = ConfigValidator::validate(&config);
@@ -652,36 +849,457 @@ mod tests {
         config.keybinds.push(Keybind {
             modifiers: vec!["Win".to_string()],
             key: "q".to_string(),
+            sequence: vec![],
             command: "close".to_string(),
             args: vec![],
+            only_when: None,
+            except_when: None,
         });
         
         // Multiple modifiers
         config.keybinds.push(Keybind {
             modifiers: vec!["Win".to_string(), "Shift".to_string()],
             key: "q".to_string(),
+            sequence: vec![],
             command: "quit".to_string(),
             args: vec![],
+            only_when: None,
+            except_when: None,
         });
         
         // All valid modifiers
         config.keybinds.push(Keybind {
             modifiers: vec!["Ctrl".to_string()],
             key: "a".to_string(),
+            sequence: vec![],
             command: "focus".to_string(),
             args: vec![],
+            only_when: None,
+            except_when: None,
         });
         
         config.keybinds.push(Keybind {
             modifiers: vec!["Alt".to_string()],
             key: "b".to_string(),
+            sequence: vec![],
             command: "focus".to_string(),
             args: vec![],
+            only_when: None,
+            except_when: None,
         });
         
         assert!(ConfigValidator::validate(&config).is_ok());
     }
     
+    #[test]
+    fn test_modifier_aliases_and_duplicates() {
+        let mut config = Config::default();
+    
+        config.keybinds.push(Keybind {
+            modifiers: vec!["super".to_string(), "LControl".to_string()],
+            key: "esc".to_string(),
+            sequence: vec![],
+            command: "close".to_string(),
+            args: vec![],
+            only_when: None,
+            except_when: None,
+        });
+        assert!(ConfigValidator::validate(&config).is_ok());
+    
+        // The same keys written with canonical names
+        config.keybinds.push(Keybind {
+            modifiers: vec!["Ctrl".to_string(), "Win".to_string()],
+            key: "Escape".to_string(),
+            sequence: vec![],
+            command: "quit".to_string(),
+            args: vec![],
+            only_when: None,
+            except_when: None,
+        });
+        let result = ConfigValidator::validate(&config);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Duplicate keybinding: Win+Ctrl+Escape"));
+    }
+    
+    #[test]
+    fn test_unknown_key() {
+        let mut config = Config::default();
+    
+        config.keybinds.push(Keybind {
+            modifiers: vec!["Win".to_string()],
+            key: "VolumUp".to_string(),
+            sequence: vec![],
+            command: "exec".to_string(),
+            args: vec![],
+            only_when: None,
+            except_when: None,
+        });
+    
+        let mut diagnostics = crate::config::diagnostics::Diagnostics::new();
+        ConfigValidator::diagnose(&config, &mut diagnostics);
+        let error = diagnostics.first_error().unwrap();
+        assert_eq!(error.path, "keybinds[0].key");
+        assert_eq!(error.message, "Unknown key: VolumUp");
+        assert_eq!(error.suggestion.as_deref(), Some("did you mean 'VolumeUp'?"));
+    }
+    
+    #[test]
+    fn test_compact_keybind_syntax() {
+        let config: Config = toml::from_str(
+            r#"
+            [[keybinds]]
+            bind = ["Win+Shift+Q", "close"]
+    
+            [[keybinds]]
+            bind = ["Super+Space, W, 3", "workspace", "3"]
+    
+            [[keybinds]]
+            modifiers = ["Win"]
+            key = "Enter"
+            command = "exec"
+            args = ["wt.exe"]
+            "#,
+        )
+        .unwrap();
+    
+        let keybind = &config.keybinds[0];
+        assert_eq!(keybind.modifiers, vec!["Win", "Shift"]);
+        assert_eq!(keybind.key, "Q");
+        assert_eq!(keybind.command, "close");
+        assert!(keybind.args.is_empty());
+    
+        let keybind = &config.keybinds[1];
+        assert_eq!(keybind.key, "Space");
+        assert_eq!(keybind.sequence, vec!["W", "3"]);
+        assert_eq!(keybind.args, vec!["3"]);
+    
+        assert_eq!(config.keybinds[2].args, vec!["wt.exe"]);
+        assert!(ConfigValidator::validate(&config).is_ok());
+    }
+    
+    #[test]
+    fn test_invalid_compact_keybinds() {
+        let missing_command = toml::from_str::<Config>(
+            r#"
+            [[keybinds]]
+            bind = ["Win+Q"]
+            "#,
+        );
+        assert!(missing_command.unwrap_err().to_string().contains("needs the keys and a command"));
+    
+        let mixed = toml::from_str::<Config>(
+            r#"
+            [[keybinds]]
+            bind = ["Win+Q", "close"]
+            key = "W"
+            "#,
+        );
+        assert!(mixed.unwrap_err().to_string().contains("cannot be combined"));
+    
+        let missing_key = toml::from_str::<Config>(
+            r#"
+            [[keybinds]]
+            modifiers = ["Win"]
+            command = "close"
+            "#,
+        );
+        assert!(missing_key.unwrap_err().to_string().contains("missing field `key`"));
+    }
+    
+    #[test]
+    fn test_keybind_conditions() {
+        let config = toml::from_str::<Config>(
+            r#"
+            [[keybinds]]
+            modifiers = ["Win"]
+            key = "Tab"
+            command = "focus-next"
+            except_when = { match_process = "(?i)^(mstsc|game)\\.exe$" }
+    
+            [[keybinds]]
+            bind = ["Win+Tab", "focus-previous"]
+            only_when = { match_process = "mstsc\\.exe", match_title = "Remote" }
+    
+            [[keybinds]]
+            bind = ["Win+Shift+P", "toggle-passthrough"]
+            "#,
+        )
+        .unwrap();
+    
+        let except_when = config.keybinds[0].except_when.as_ref().unwrap();
+        assert_eq!(except_when.match_process.as_deref(), Some("(?i)^(mstsc|game)\\.exe$"));
+        let only_when = config.keybinds[1].only_when.as_ref().unwrap();
+        assert_eq!(only_when.match_title.as_deref(), Some("Remote"));
+        assert!(!config.keybinds[2].is_conditional());
+    
+        // Keybindings with conditions may share keys
+        assert!(ConfigValidator::validate(&config).is_ok());
+    }
+    
+    #[test]
+    fn test_invalid_keybind_conditions() {
+        let mut config = Config::default();
+        config.keybinds.push(Keybind {
+            only_when: Some(WindowMatch::default()),
+            ..Keybind::from_bind(&["Win+Q".to_string(), "close".to_string()]).unwrap()
+        });
+        let error = ConfigValidator::validate(&config).unwrap_err().to_string();
+        assert!(error.contains("must have at least one match condition"));
+    
+        config.keybinds[0].only_when = None;
+        config.keybinds[0].except_when = Some(WindowMatch {
+            match_class: Some("Game(".to_string()),
+            ..WindowMatch::default()
+        });
+        let mut diagnostics = crate::config::diagnostics::Diagnostics::new();
+        ConfigValidator::diagnose(&config, &mut diagnostics);
+        let error = diagnostics.first_error().unwrap();
+        assert_eq!(error.path, "keybinds[0].except_when.match_class");
+        assert_eq!(error.message, "Invalid regex in match_class: 'Game('");
+    
+        // Without conditions, the same keys are a duplicate
+        config.keybinds[0].except_when = None;
+        config.keybinds.push(config.keybinds[0].clone());
+        let error = ConfigValidator::validate(&config).unwrap_err().to_string();
+        assert!(error.contains("Duplicate keybinding: Win+Q"));
+    }
+    
+    // ========================================
+        // Binding Mode Tests
+    // ========================================
+    
+    fn enter_mode(key: &str, mode: &str) -> Keybind {
+        Keybind {
+            modifiers: vec!["Win".to_string()],
+            key: key.to_string(),
+            sequence: vec![],
+            command: "enter-mode".to_string(),
+            args: vec![mode.to_string()],
+            only_when: None,
+            except_when: None,
+        }
+    }
+    
+    fn resize_mode() -> BindingMode {
+        BindingMode {
+            name: "resize".to_string(),
+            escape: "Escape".to_string(),
+            keybinds: vec![Keybind {
+                modifiers: vec![],
+                key: "h".to_string(),
+                sequence: vec![],
+                command: "float-resize".to_string(),
+                args: vec!["-20".to_string(), "0".to_string()],
+                only_when: None,
+                except_when: None,
+            }],
+        }
+    }
+    
+    #[test]
+    fn test_valid_modes() {
+        let mut config = Config::default();
+        
+        config.keybinds.push(enter_mode("r", "resize"));
+        let mut mode = resize_mode();
+        mode.keybinds.push(enter_mode("d", "default"));
+        config.modes.push(mode);
+        
+        assert!(ConfigValidator::validate(&config).is_ok());
+    }
+    
+    #[test]
+    fn test_enter_unknown_mode() {
+        let mut config = Config::default();
+        
+        config.keybinds.push(enter_mode("r", "resze"));
+        config.modes.push(resize_mode());
+        
+        let result = ConfigValidator::validate(&config);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Unknown mode: resze"));
+    }
+    
+    #[test]
+    fn test_duplicate_and_reserved_mode_names() {
+        let mut config = Config::default();
+        config.modes.push(resize_mode());
+        config.modes.push(resize_mode());
+        
+        let result = ConfigValidator::validate(&config);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Duplicate mode"));
+        
+        let mut config = Config::default();
+        let mut mode = resize_mode();
+        mode.name = "default".to_string();
+        config.modes.push(mode);
+        
+        let result = ConfigValidator::validate(&config);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("reserved"));
+    }
+    
+    #[test]
+    fn test_mode_keybinds_are_validated() {
+        let mut config = Config::default();
+        let mut mode = resize_mode();
+        mode.keybinds.push(mode.keybinds[0].clone());
+        config.modes.push(mode);
+        
+        let mut diagnostics = crate::config::diagnostics::Diagnostics::new();
+        ConfigValidator::diagnose(&config, &mut diagnostics);
+        let error = diagnostics.first_error().unwrap();
+        assert_eq!(error.path, "modes[0].keybinds[1]");
+        assert!(error.message.contains("Duplicate keybinding"));
+    }
+    
+    #[test]
+    fn test_mode_active_keybinds_add_escape() {
+        let mode = resize_mode();
+        let keybinds = mode.active_keybinds();
+        
+        let commands: Vec<&str> = keybinds.iter().map(|k| k.command.as_str()).collect();
+        assert_eq!(commands, ["float-resize", "exit-mode"]);
+        assert_eq!(keybinds[1].key, "Escape");
+        assert!(keybinds[1].modifiers.is_empty());
+        
+        // A mode binding the escape key itself keeps its own binding
+        let mut mode = resize_mode();
+        mode.keybinds.push(Keybind {
+            modifiers: vec![],
+            key: "escape".to_string(),
+            sequence: vec![],
+            command: "enter-mode".to_string(),
+            args: vec!["default".to_string()],
+            only_when: None,
+            except_when: None,
+        });
+        assert_eq!(mode.active_keybinds().len(), 2);
+    }
+    
+    // ========================================
+    // Key Sequence Tests
+    // ========================================
+    
+    fn leader(sequence: &[&str], command: &str) -> Keybind {
+        Keybind {
+            modifiers: vec!["Win".to_string()],
+            key: "Space".to_string(),
+            sequence: sequence.iter().map(|s| s.to_string()).collect(),
+            command: command.to_string(),
+            args: vec![],
+            only_when: None,
+            except_when: None,
+        }
+    }
+    
+    #[test]
+    fn test_valid_sequences() {
+        let mut config = Config::default();
+    
+        // Sequences may share a prefix
+        config.keybinds.push(leader(&["w", "3"], "workspace"));
+        config.keybinds.push(leader(&["w", "4"], "workspace"));
+        config.keybinds.push(leader(&["Shift+Q"], "close"));
+    
+        assert!(ConfigValidator::validate(&config).is_ok());
+    }
+    
+    #[test]
+    fn test_invalid_sequence_step() {
+        let mut config = Config::default();
+        config.keybinds.push(leader(&["w", "Hyper+3"], "workspace"));
+    
+        let mut diagnostics = crate::config::diagnostics::Diagnostics::new();
+        ConfigValidator::diagnose(&config, &mut diagnostics);
+        let error = diagnostics.first_error().unwrap();
+        assert_eq!(error.path, "keybinds[0].sequence[1]");
+        assert!(error.message.contains("Invalid modifier: Hyper"));
+    }
+    
+    #[test]
+    fn test_sequence_prefix_conflicts() {
+        // A plain keybinding on the first key of a sequence
+        let mut config = Config::default();
+        config.keybinds.push(leader(&["w", "3"], "workspace"));
+        config.keybinds.push(leader(&[], "exec"));
+    
+        let mut diagnostics = crate::config::diagnostics::Diagnostics::new();
+        ConfigValidator::diagnose(&config, &mut diagnostics);
+        let error = diagnostics.first_error().unwrap();
+        assert_eq!(error.path, "keybinds[1]");
+        assert!(error.message.contains("'Win+Space' is both a keybinding and the start of the sequence 'Win+Space, W, 3'"));
+    
+        // A complete sequence that another one continues
+        let mut config = Config::default();
+        config.keybinds.push(leader(&["w"], "workspace"));
+        config.keybinds.push(leader(&["W", "3"], "workspace"));
+    
+        let result = ConfigValidator::validate(&config);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("start of the sequence"));
+    }
+    
+    // ========================================
+        // Mousebinding Tests
+    // ========================================
+    
+    #[test]
+    fn test_valid_mousebindings() {
+        let mut config = Config::default();
+        
+        config.mousebinds.push(MouseBind {
+            modifiers: vec!["Win".to_string()],
+            button: MouseButton::Left,
+            action: MouseAction::Move,
+        });
+        
+        config.mousebinds.push(MouseBind {
+            modifiers: vec!["Win".to_string()],
+            button: MouseButton::Right,
+            action: MouseAction::Resize,
+        });
+        
+        assert!(ConfigValidator::validate(&config).is_ok());
+    }
+    
+    #[test]
+    fn test_mousebind_requires_modifier() {
+        let mut config = Config::default();
+        
+        config.mousebinds.push(MouseBind {
+            modifiers: vec![],
+            button: MouseButton::Left,
+            action: MouseAction::Move,
+        });
+        
+        let result = ConfigValidator::validate(&config);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("modifier"));
+    }
+    
+    #[test]
+    fn test_duplicate_mousebindings() {
+        let mut config = Config::default();
+        
+        config.mousebinds.push(MouseBind {
+            modifiers: vec!["Win".to_string(), "Shift".to_string()],
+            button: MouseButton::Left,
+            action: MouseAction::Move,
+        });
+        
+        config.mousebinds.push(MouseBind {
+            modifiers: vec!["Shift".to_string(), "Win".to_string()],
+            button: MouseButton::Left,
+            action: MouseAction::Resize,
+        });
+        
+        let result = ConfigValidator::validate(&config);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Duplicate"));
+    }
+    
     // ========================================
     // Monitor Configuration Tests
     // ========================================
@@ -697,6 +1315,7 @@ mod tests {
             scale: None,
             refresh_rate: None,
             rotation: None,
+            regions: Vec::new(),
         });
         
         let result = ConfigValidator::validate(&config);
@@ -716,6 +1335,7 @@ mod tests {
             scale: None,
             refresh_rate: None,
             rotation: None,
+            regions: Vec::new(),
         });
         
         let result = ConfigValidator::validate(&config);
@@ -735,6 +1355,7 @@ mod tests {
             scale: Some(0.0),
             refresh_rate: None,
             rotation: None,
+            regions: Vec::new(),
         });
         
         let result = ConfigValidator::validate(&config);
@@ -753,6 +1374,7 @@ mod tests {
             scale: Some(5.0),
             refresh_rate: None,
             rotation: None,
+            regions: Vec::new(),
         });
         
         let result = ConfigValidator::validate(&config);
@@ -771,6 +1393,7 @@ mod tests {
             scale: None,
             refresh_rate: None,
             rotation: Some(45),
+            regions: Vec::new(),
         });
         
         let result = ConfigValidator::validate(&config);
@@ -792,6 +1415,7 @@ mod tests {
             scale: Some(1.0),
             refresh_rate: Some(60),
             rotation: Some(0),
+            regions: Vec::new(),
         });
         
         config.monitors.push(MonitorConfig {
@@ -801,6 +1425,7 @@ mod tests {
             scale: Some(1.25),
             refresh_rate: Some(144),
             rotation: Some(90),
+            regions: Vec::new(),
         });
         
         // Auto position
@@ -811,6 +1436,7 @@ mod tests {
             scale: Some(2.0),
             refresh_rate: Some(60),
             rotation: Some(180),
+            regions: Vec::new(),
         });
         
         // Valid rotation values
@@ -821,11 +1447,69 @@ mod tests {
             scale: None,
             refresh_rate: None,
             rotation: Some(270),
+            regions: Vec::new(),
         });
         
         assert!(ConfigValidator::validate(&config).is_ok());
     }
     
+    #[test]
+    fn test_monitor_regions() {
+        let region = |name: &str, width: Option<RegionSize>| MonitorRegion {
+            name: Some(name.to_string()),
+            width,
+        };
+        let mut config = Config::default();
+        config.monitors.push(MonitorConfig {
+            name: "Ultrawide".to_string(),
+            resolution: None,
+            position: None,
+            scale: None,
+            refresh_rate: None,
+            rotation: None,
+            regions: vec![
+                region("side-left", Some(RegionSize::Pixels(1280))),
+                region("center", None),
+                region("side-right", Some(RegionSize::Fraction(0.25))),
+            ],
+        });
+        assert!(ConfigValidator::validate(&config).is_ok());
+        
+        config.monitors[0].regions[1].width = Some(RegionSize::Fraction(0.8));
+        let err_msg = ConfigValidator::validate(&config).unwrap_err().to_string();
+        assert!(err_msg.contains("more than 1.0"));
+        
+        config.monitors[0].regions[1] = region("SIDE-LEFT", None);
+        let err_msg = ConfigValidator::validate(&config).unwrap_err().to_string();
+        assert!(err_msg.contains("Duplicate region name"));
+        
+        config.monitors[0].regions[1] = region("center", Some(RegionSize::Pixels(0)));
+        assert!(ConfigValidator::validate(&config).is_err());
+    }
+    
+    #[test]
+    fn test_monitor_regions_from_toml() {
+        let config: Config = toml::from_str(
+            r#"
+            [[monitors]]
+            name = '\\.\DISPLAY1'
+            regions = [
+                { name = "side-left", width = 1280 },
+                { name = "center" },
+                { width = 0.25 },
+            ]
+            "#,
+        )
+        .unwrap();
+        
+        let regions = &config.monitors[0].regions;
+        assert_eq!(regions[0].width, Some(RegionSize::Pixels(1280)));
+        assert_eq!(regions[1].width, None);
+        assert_eq!(regions[2].width, Some(RegionSize::Fraction(0.25)));
+        assert_eq!(regions[2].name, None);
+        assert!(ConfigValidator::validate(&config).is_ok());
+    }
+    
     // ========================================
     // Resolution and Position Format Tests
     // ========================================
@@ -0,0 +1,505 @@
+//! Configuration variables
+//!
+//! A top-level `[variables]` table defines values that string fields anywhere
+//! in the configuration can reference. Variables are substituted in the merged
+//! document before it is parsed into a `Config`.
+//!
+//! # Syntax
+//! - `$name` or `${name}` - the value of a variable (names use letters, digits
+//!   and `_`)
+//! - `${env:VAR}` - the value of an environment variable, which must be set
+//! - `${env:VAR:-default}` - the environment variable, or `default` if it is
+//!   unset or empty
+//! - `$$` - a literal `$`
+//!
+//! A `$` that is not followed by a name or `{` is kept as is, so regex
+//! anchors such as `match_process = "^firefox\\.exe$"` need no escaping.
+//!
+//! A string consisting of a single reference to a variable that is not a
+//! string (e.g. `hyper = ["Win", "Shift"]`) is replaced by that value, and a
+//! list value is spliced into the surrounding list, so
+//! `modifiers = ["$hyper", "Alt"]` becomes `["Win", "Shift", "Alt"]`.
+//! Variables may reference other variables and environment variables.
+//!
+//! # Example
+//!
+//! ```toml
+//! [variables]
+//! mod = "Win"
+//! term = "${env:TERMINAL:-wt.exe}"
+//!
+//! [[keybinds]]
+//! modifiers = ["$mod"]
+//! key = "Enter"
+//! command = "exec"
+//! args = ["${term}"]
+//! ```
+
+use super::include::ConfigSources;
+use anyhow::Result;
+use std::collections::HashMap;
+use std::fmt;
+use toml::{Table, Value};
+
+/// Key of the table defining variables
+const VARIABLES_KEY: &str = "variables";
+
+/// Substitute variables in a merged configuration document
+///
+/// Removes the `[variables]` table and replaces every reference in string
+/// values with its value.
+///
+/// # Arguments
+/// * `document` - The merged configuration document
+/// * `sources` - Where the document came from, to name the file in errors
+///
+/// # Returns
+/// `true` if the document changed, or an error naming the location of an
+/// undefined or invalid reference
+pub fn substitute(document: &mut Table, sources: &ConfigSources) -> Result<bool> {
+    let variables = match document.remove(VARIABLES_KEY) {
+        Some(Value::Table(variables)) => variables,
+        Some(_) => anyhow::bail!("`{}` must be a table", VARIABLES_KEY),
+        None => Table::new(),
+    };
+
+    let mut resolver = Resolver {
+        variables: &variables,
+        resolved: HashMap::new(),
+        stack: Vec::new(),
+    };
+    let mut walk = Walk {
+        resolver: &mut resolver,
+        sources,
+        changed: !variables.is_empty(),
+    };
+    walk.table(document, "", None)?;
+    Ok(walk.changed)
+}
+
+/// An undefined or invalid reference in the configuration
+#[derive(Debug, Clone)]
+pub struct SubstitutionError {
+    /// What is wrong with the reference
+    pub message: String,
+
+    /// Location of the string in the document (e.g. `keybinds[3].args[0]`)
+    pub location: String,
+
+    /// The file the string came from, if the document has several
+    origin: String,
+}
+
+impl fmt::Display for SubstitutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}{}", self.message, self.location, self.origin)
+    }
+}
+
+impl std::error::Error for SubstitutionError {}
+
+/// The parts of a string with references
+#[derive(Debug, Clone, PartialEq)]
+enum Part {
+    /// Text copied as is
+    Text(String),
+
+    /// A configuration variable
+    Variable(String),
+
+    /// An environment variable with an optional default
+    Env(String, Option<String>),
+}
+
+/// Split a string into text and references
+fn parse(text: &str) -> Result<Vec<Part>> {
+    let mut parts = Vec::new();
+    let mut literal = String::new();
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((_, c)) = chars.next() {
+        if c != '$' {
+            literal.push(c);
+            continue;
+        }
+
+        let reference = match chars.peek().copied() {
+            Some((_, '$')) => {
+                chars.next();
+                literal.push('$');
+                continue;
+            }
+            Some((start, '{')) => {
+                let end = text[start..]
+                    .find('}')
+                    .map(|end| start + end)
+                    .ok_or_else(|| anyhow::anyhow!("Unclosed `${{` in {:?}", text))?;
+                while chars.peek().is_some_and(|&(index, _)| index <= end) {
+                    chars.next();
+                }
+                parse_braced(&text[start + 1..end]).ok_or_else(|| {
+                    anyhow::anyhow!("Invalid reference `${}` in {:?}", &text[start..=end], text)
+                })?
+            }
+            Some((start, next)) if is_name_char(next) => {
+                let mut end = start;
+                while let Some(&(index, c)) = chars.peek() {
+                    if !is_name_char(c) {
+                        break;
+                    }
+                    end = index + c.len_utf8();
+                    chars.next();
+                }
+                Part::Variable(text[start..end].to_string())
+            }
+            // Not a reference, e.g. the end anchor of a regex
+            _ => {
+                literal.push('$');
+                continue;
+            }
+        };
+
+        if !literal.is_empty() {
+            parts.push(Part::Text(std::mem::take(&mut literal)));
+        }
+        parts.push(reference);
+    }
+
+    if !literal.is_empty() {
+        parts.push(Part::Text(literal));
+    }
+    Ok(parts)
+}
+
+/// Parse the inside of a `${...}` reference
+fn parse_braced(inner: &str) -> Option<Part> {
+    if let Some(env) = inner.strip_prefix("env:") {
+        let (name, default) = match env.split_once(":-") {
+            Some((name, default)) => (name, Some(default.to_string())),
+            None => (env, None),
+        };
+        return (!name.is_empty()).then(|| Part::Env(name.to_string(), default));
+    }
+    (!inner.is_empty() && inner.chars().all(is_name_char))
+        .then(|| Part::Variable(inner.to_string()))
+}
+
+/// Check whether a character can be part of a variable name
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Resolves variables, each at most once
+struct Resolver<'a> {
+    /// Variables as defined
+    variables: &'a Table,
+
+    /// Values of the variables resolved so far
+    resolved: HashMap<String, Value>,
+
+    /// Variables being resolved, for cycle detection
+    stack: Vec<String>,
+}
+
+impl Resolver<'_> {
+    /// Get the value of a variable with its references substituted
+    fn variable(&mut self, name: &str) -> Result<Value> {
+        if let Some(value) = self.resolved.get(name) {
+            return Ok(value.clone());
+        }
+        if self.stack.iter().any(|entry| entry == name) {
+            anyhow::bail!("Variable cycle: {} -> {}", self.stack.join(" -> "), name);
+        }
+        let Some(value) = self.variables.get(name) else {
+            anyhow::bail!("Undefined variable `${}`", name);
+        };
+
+        self.stack.push(name.to_string());
+        let value = self.value(value.clone());
+        self.stack.pop();
+
+        let value = value.map_err(|e| anyhow::anyhow!("{} (in variable `{}`)", e, name))?;
+        self.resolved.insert(name.to_string(), value.clone());
+        Ok(value)
+    }
+
+    /// Substitute the references in a value
+    fn value(&mut self, value: Value) -> Result<Value> {
+        match value {
+            Value::String(text) => self.string(&text),
+            Value::Array(items) => {
+                let mut result = Vec::with_capacity(items.len());
+                for item in items {
+                    match item {
+                        Value::String(text) => splice(&mut result, self.string(&text)?),
+                        item => result.push(self.value(item)?),
+                    }
+                }
+                Ok(Value::Array(result))
+            }
+            Value::Table(table) => {
+                let mut result = Table::new();
+                for (key, value) in table {
+                    result.insert(key, self.value(value)?);
+                }
+                Ok(Value::Table(result))
+            }
+            other => Ok(other),
+        }
+    }
+
+    /// Substitute the references in a string
+    fn string(&mut self, text: &str) -> Result<Value> {
+        let parts = parse(text)?;
+
+        // A lone variable keeps the type of its value
+        if let [Part::Variable(name)] = parts.as_slice() {
+            return self.variable(name);
+        }
+
+        let mut result = String::new();
+        for part in parts {
+            match part {
+                Part::Text(text) => result.push_str(&text),
+                Part::Variable(name) => match self.variable(&name)? {
+                    Value::String(value) => result.push_str(&value),
+                    Value::Integer(value) => result.push_str(&value.to_string()),
+                    Value::Float(value) => result.push_str(&value.to_string()),
+                    Value::Boolean(value) => result.push_str(&value.to_string()),
+                    _ => anyhow::bail!(
+                        "Variable `${}` is not a string and cannot be used inside {:?}",
+                        name,
+                        text
+                    ),
+                },
+                Part::Env(name, default) => match (std::env::var(&name), default) {
+                    (Ok(value), _) if !value.is_empty() => result.push_str(&value),
+                    (_, Some(default)) => result.push_str(&default),
+                    (Ok(value), None) => result.push_str(&value),
+                    (Err(_), None) => anyhow::bail!(
+                        "Environment variable `{}` is not set (use `${{env:{}:-default}}` for a default)",
+                        name,
+                        name
+                    ),
+                },
+            }
+        }
+        Ok(Value::String(result))
+    }
+}
+
+/// Add a substituted string to a list, splicing in the items of a list variable
+fn splice(list: &mut Vec<Value>, value: Value) {
+    match value {
+        Value::Array(items) => list.extend(items),
+        value => list.push(value),
+    }
+}
+
+/// Walks the configuration document, substituting references in place
+struct Walk<'a, 'b> {
+    /// Resolves the variables
+    resolver: &'a mut Resolver<'b>,
+
+    /// Where the document came from
+    sources: &'a ConfigSources,
+
+    /// Whether anything was substituted
+    changed: bool,
+}
+
+impl Walk<'_, '_> {
+    /// Substitute in a table at a dotted path
+    ///
+    /// `item` is the outermost list and index the table is in, if any, used
+    /// to look up which file the table came from.
+    fn table(&mut self, table: &mut Table, path: &str, item: Option<(&str, usize)>) -> Result<()> {
+        for (key, value) in table.iter_mut() {
+            let path = if path.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", path, key)
+            };
+            self.value(value, &path, item)?;
+        }
+        Ok(())
+    }
+
+    /// Substitute in a value at a location
+    fn value(
+        &mut self,
+        value: &mut Value,
+        location: &str,
+        item: Option<(&str, usize)>,
+    ) -> Result<()> {
+        match value {
+            Value::String(text) if text.contains('$') => {
+                *value = self.string(text, location, item)?;
+                self.changed = true;
+            }
+            Value::Table(table) => self.table(table, location, item)?,
+            Value::Array(items) => {
+                let list = location.to_string();
+                let mut result = Vec::with_capacity(items.len());
+                for (index, mut entry) in std::mem::take(items).into_iter().enumerate() {
+                    let entry_location = format!("{}[{}]", location, index);
+                    let entry_item = item.or(Some((list.as_str(), index)));
+                    match entry {
+                        Value::String(ref text) if text.contains('$') => {
+                            let substituted = self.string(text, &entry_location, entry_item)?;
+                            self.changed = true;
+                            splice(&mut result, substituted);
+                        }
+                        _ => {
+                            self.value(&mut entry, &entry_location, entry_item)?;
+                            result.push(entry);
+                        }
+                    }
+                }
+                *items = result;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Substitute in a string at a location
+    fn string(&mut self, text: &str, location: &str, item: Option<(&str, usize)>) -> Result<Value> {
+        self.resolver
+            .string(text)
+            .map_err(|e| self.error(e, location, item))
+    }
+
+    /// Add the location and the file it came from to an error
+    fn error(
+        &self,
+        error: anyhow::Error,
+        location: &str,
+        item: Option<(&str, usize)>,
+    ) -> anyhow::Error {
+        let origin = item
+            .map(|(list, index)| self.sources.origin(list, index))
+            .unwrap_or_default();
+        SubstitutionError {
+            message: error.to_string(),
+            location: location.to_string(),
+            origin,
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn substituted(text: &str) -> Result<Table> {
+        let mut document: Table = toml::from_str(text).unwrap();
+        substitute(&mut document, &ConfigSources::default())?;
+        Ok(document)
+    }
+
+    #[test]
+    fn test_parse_references() {
+        assert_eq!(
+            parse("$mod+${key} $$ ${env:HOME:-/}").unwrap(),
+            vec![
+                Part::Variable("mod".to_string()),
+                Part::Text("+".to_string()),
+                Part::Variable("key".to_string()),
+                Part::Text(" $ ".to_string()),
+                Part::Env("HOME".to_string(), Some("/".to_string())),
+            ]
+        );
+        assert!(parse("${unclosed").is_err());
+        assert!(parse("${bad name}").is_err());
+        assert_eq!(
+            parse(r"^firefox\.exe$|^(a$)").unwrap(),
+            vec![Part::Text(r"^firefox\.exe$|^(a$)".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_substitute_strings_and_lists() {
+        let document = substituted(
+            r#"
+            [variables]
+            mod = "Win"
+            hyper = ["Win", "Shift"]
+            term = "wt.exe"
+            shell = "${term} -p pwsh"
+            price = "$$5"
+
+            [[keybinds]]
+            modifiers = ["$hyper", "Alt"]
+            key = "Enter"
+            command = "exec"
+            args = ["$shell", "$price"]
+
+            [[keybinds]]
+            modifiers = ["$mod"]
+            key = "Q"
+            command = "close"
+            "#,
+        )
+        .unwrap();
+
+        assert!(!document.contains_key("variables"));
+        let keybinds = document["keybinds"].as_array().unwrap();
+        assert_eq!(
+            keybinds[0]["modifiers"],
+            Value::Array(vec!["Win".into(), "Shift".into(), "Alt".into()])
+        );
+        assert_eq!(keybinds[0]["args"][0].as_str(), Some("wt.exe -p pwsh"));
+        assert_eq!(keybinds[0]["args"][1].as_str(), Some("$5"));
+        assert_eq!(keybinds[1]["modifiers"][0].as_str(), Some("Win"));
+    }
+
+    #[test]
+    fn test_env_defaults() {
+        let document = substituted(
+            r#"
+            [general]
+            a = "${env:TENRAKU_TEST_UNSET_VARIABLE:-fallback}"
+            b = "${env:TENRAKU_TEST_UNSET_VARIABLE:-}"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(document["general"]["a"].as_str(), Some("fallback"));
+        assert_eq!(document["general"]["b"].as_str(), Some(""));
+
+        let err = substituted("x = \"${env:TENRAKU_TEST_UNSET_VARIABLE}\"").unwrap_err();
+        assert!(err.to_string().contains("TENRAKU_TEST_UNSET_VARIABLE"));
+    }
+
+    #[test]
+    fn test_undefined_variable_names_location() {
+        let err = substituted(
+            r#"
+            [[keybinds]]
+            key = "Enter"
+            args = ["ok", "$term"]
+            "#,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Undefined variable `$term` at keybinds[0].args[1]"
+        );
+    }
+
+    #[test]
+    fn test_variable_cycle() {
+        let err = substituted(
+            r#"
+            [variables]
+            a = "$b"
+            b = "x$a"
+            [general]
+            value = "$a"
+            "#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Variable cycle: a -> b -> a"));
+    }
+}
@@ -3,11 +3,14 @@
 //! This module provides file watching capability for configuration hot-reload.
 //! 
 //! # Features
-//! - Watches a single configuration file for changes
+//! - Watches the configuration file and every file it includes for changes
 //! - Debounces rapid file changes to prevent excessive reloads
 //! - Handles editor save patterns (atomic writes, temp files)
 //! - Non-blocking change detection via polling
+//! - Reports the outcome of reloads to a listener
 
+use super::include::ConfigSources;
+use super::reload::{ReloadEvent, ReloadEventListener};
 use notify::{Watcher, RecursiveMode, Event, EventKind};
 use notify::event::{ModifyKind, DataChange};
 use std::path::PathBuf;
@@ -16,8 +19,9 @@ use std::time::{Duration, Instant};
 
 /// Configuration file watcher
 /// 
-/// Watches a single configuration file for modifications and provides
-/// debounced change notifications. The watcher handles common editor
+/// Watches the configuration file, and the files it includes once
+/// `watch_sources` is called, for modifications and provides debounced
+/// change notifications. The watcher handles common editor
 /// save patterns including atomic writes and temporary file usage.
 pub struct ConfigWatcher {
     /// File system watcher
@@ -34,6 +38,12 @@ pub struct ConfigWatcher {
     
     /// Path being watched (for logging)
     config_path: PathBuf,
+    
+    /// Included files and directories watched besides the configuration file
+    included: Vec<PathBuf>,
+    
+    /// Listener notified when a reload is applied or rejected
+    reload_listener: Option<ReloadEventListener>,
 }
 
 impl ConfigWatcher {
@@ -75,6 +85,8 @@ impl ConfigWatcher {
             last_reload: None,
             debounce_duration: Duration::from_millis(500),
             config_path,
+            included: Vec::new(),
+            reload_listener: None,
         })
     }
     
@@ -174,6 +186,68 @@ impl ConfigWatcher {
         has_change
     }
     
+    /// Watch the files a configuration was merged from
+    /// 
+    /// Replaces the included files watched before, so this should be called
+    /// after every reload. Directories searched for wildcard includes are
+    /// watched too, so new matching files trigger a reload.
+    /// 
+    /// # Arguments
+    /// * `sources` - The sources of the loaded configuration
+    /// 
+    /// # Example
+    /// ```no_run
+    /// # use std::path::PathBuf;
+    /// # use tenraku_core::config::{ConfigLoader, ConfigWatcher};
+    /// 
+    /// let loader = ConfigLoader::new().unwrap();
+    /// let (_config, sources) = loader.load_with_sources().unwrap();
+    /// let mut watcher = ConfigWatcher::new(loader.get_config_path().clone()).unwrap();
+    /// watcher.watch_sources(&sources);
+    /// ```
+    pub fn watch_sources(&mut self, sources: &ConfigSources) {
+        let config_path = self.config_path.canonicalize().unwrap_or_else(|_| self.config_path.clone());
+        let config_dir = config_path.parent().map(|dir| dir.to_path_buf());
+        let wanted: Vec<PathBuf> = sources
+            .files()
+            .iter()
+            .filter(|file| **file != config_path)
+            .chain(
+                // The main directory also holds state files that change often
+                sources.include_dirs().iter().filter(|dir| Some(*dir) != config_dir.as_ref()),
+            )
+            .cloned()
+            .collect();
+        
+        for path in &self.included {
+            if !wanted.contains(path) {
+                let _ = self._watcher.unwatch(path);
+            }
+        }
+        for path in &wanted {
+            if self.included.contains(path) {
+                continue;
+            }
+            match self._watcher.watch(path, RecursiveMode::NonRecursive) {
+                Ok(()) => tracing::info!("Watching included configuration: {:?}", path),
+                Err(e) => tracing::warn!("Failed to watch {:?}: {}", path, e),
+            }
+        }
+        self.included = wanted;
+    }
+    
+    /// Set the listener notified when a reload is applied or rejected
+    pub fn set_reload_listener(&mut self, listener: impl Fn(ReloadEvent) + Send + 'static) {
+        self.reload_listener = Some(Box::new(listener));
+    }
+    
+    /// Report the outcome of a reload to the listener, if one is connected
+    pub fn notify_reload(&self, event: ReloadEvent) {
+        if let Some(ref listener) = self.reload_listener {
+            listener(event);
+        }
+    }
+    
     /// Get the path being watched
     pub fn config_path(&self) -> &PathBuf {
         &self.config_path
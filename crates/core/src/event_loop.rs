@@ -2,7 +2,8 @@
 //!
 //! This module provides a thread-safe event loop that monitors Windows events using
 //! the Win32 API's `SetWinEventHook` function. It detects window creation, destruction,
-//! focus changes, and other window state changes.
+//! focus changes, and other window state changes. Windows flashing their taskbar
+//! button are reported through a hidden shell hook window.
 //!
 //! # Platform Support
 //!
@@ -33,15 +34,31 @@
 // Windows-specific implementation
 #[cfg(target_os = "windows")]
 mod windows_impl {
+    use crate::config::{MouseAction, MouseBind, MouseButton};
+    use crate::keybinds::keys::Modifier;
+    use std::sync::atomic::{AtomicU32, Ordering};
     use std::sync::mpsc::{channel, Receiver, Sender, TryRecvError};
+    use std::sync::Mutex;
+    use std::thread::JoinHandle;
     use windows::{
-        Win32::Foundation::HWND,
+        core::w,
+        Win32::Foundation::{HWND, LPARAM, LRESULT, WPARAM},
+        Win32::System::Threading::GetCurrentThreadId,
         Win32::UI::Accessibility::{SetWinEventHook, UnhookWinEvent, HWINEVENTHOOK},
+        Win32::UI::Input::KeyboardAndMouse::{
+            GetAsyncKeyState, VK_CONTROL, VK_LWIN, VK_MENU, VK_RWIN, VK_SHIFT,
+        },
         Win32::UI::WindowsAndMessaging::{
-            DispatchMessageW, PeekMessageW, EVENT_OBJECT_CREATE, EVENT_OBJECT_DESTROY,
+            CallNextHookEx, CreateWindowExW, DefWindowProcW, DeregisterShellHookWindow,
+            DestroyWindow, DispatchMessageW, GetMessageW, PeekMessageW, PostThreadMessageW,
+            RegisterShellHookWindow, RegisterWindowMessageW, SetWindowLongPtrW,
+            SetWindowsHookExW, UnhookWindowsHookEx, EVENT_OBJECT_CREATE, EVENT_OBJECT_DESTROY,
             EVENT_OBJECT_HIDE, EVENT_OBJECT_LOCATIONCHANGE, EVENT_OBJECT_SHOW,
             EVENT_SYSTEM_FOREGROUND, EVENT_SYSTEM_MINIMIZEEND, EVENT_SYSTEM_MINIMIZESTART,
-            EVENT_SYSTEM_MOVESIZEEND, MSG, PM_REMOVE, WINEVENT_OUTOFCONTEXT,
+            EVENT_SYSTEM_MOVESIZEEND, GWLP_WNDPROC, HC_ACTION, HSHELL_HIGHBIT, HSHELL_REDRAW,
+            MSG, MSLLHOOKSTRUCT, PM_REMOVE, WH_MOUSE_LL, WINEVENT_OUTOFCONTEXT,
+            WM_LBUTTONDOWN, WM_LBUTTONUP, WM_MBUTTONDOWN, WM_MBUTTONUP, WM_MOUSEMOVE, WM_QUIT,
+            WM_RBUTTONDOWN, WM_RBUTTONUP, WNDPROC, WS_EX_TOOLWINDOW, WS_POPUP,
         },
     };
 
@@ -56,9 +73,17 @@ mod windows_impl {
         WindowMinimized(HWND),
         WindowRestored(HWND),
         WindowFocused(HWND),
+        /// Window is demanding attention (its taskbar button is flashing)
+        WindowFlashed(HWND),
         MonitorChanged,
         /// Hotkey was pressed with the given hotkey ID
         HotkeyPressed(i32),
+        /// A bound mouse button was pressed with its modifiers held
+        MouseDragStarted { action: MouseAction, x: i32, y: i32 },
+        /// The cursor moved during a mouse drag
+        MouseDragMoved { x: i32, y: i32 },
+        /// The bound mouse button was released
+        MouseDragEnded { x: i32, y: i32 },
     }
 
     /// Global sender for event communication from the Win32 callback.
@@ -124,11 +149,249 @@ mod windows_impl {
         let _ = sender.send(window_event);
     }
 
+    /// State shared with the low-level mouse hook callback.
+    struct MouseHookState {
+        sender: Sender<WindowEvent>,
+        binds: Vec<MouseBind>,
+        /// Button of the drag in progress, if any
+        drag_button: Option<MouseButton>,
+    }
+
+    /// Mouse bindings and event sender used by `mouse_hook_proc`.
+    ///
+    /// Low-level mouse hooks run on the thread that installed them, so the state
+    /// lives behind a mutex rather than the raw pointer used for WinEvent hooks.
+    static MOUSE_HOOK_STATE: Mutex<Option<MouseHookState>> = Mutex::new(None);
+
+    /// Low-level mouse hook callback.
+    ///
+    /// Button presses matching a mouse binding are swallowed so the target
+    /// application never sees them, and turned into drag events.
+    unsafe extern "system" fn mouse_hook_proc(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+        if code == HC_ACTION as i32 {
+            let info = &*(lparam.0 as *const MSLLHOOKSTRUCT);
+            if handle_mouse_message(wparam.0 as u32, info.pt.x, info.pt.y) {
+                return LRESULT(1);
+            }
+        }
+
+        CallNextHookEx(None, code, wparam, lparam)
+    }
+
+    /// Translate a mouse message into drag events.
+    ///
+    /// Returns `true` if the message should be swallowed.
+    fn handle_mouse_message(message: u32, x: i32, y: i32) -> bool {
+        let mut guard = match MOUSE_HOOK_STATE.lock() {
+            Ok(guard) => guard,
+            Err(_) => return false,
+        };
+        let state = match guard.as_mut() {
+            Some(state) => state,
+            None => return false,
+        };
+
+        let (button, pressed) = match message {
+            WM_LBUTTONDOWN => (MouseButton::Left, true),
+            WM_LBUTTONUP => (MouseButton::Left, false),
+            WM_RBUTTONDOWN => (MouseButton::Right, true),
+            WM_RBUTTONUP => (MouseButton::Right, false),
+            WM_MBUTTONDOWN => (MouseButton::Middle, true),
+            WM_MBUTTONUP => (MouseButton::Middle, false),
+            WM_MOUSEMOVE => {
+                if state.drag_button.is_some() {
+                    let _ = state.sender.send(WindowEvent::MouseDragMoved { x, y });
+                }
+                return false;
+            }
+            _ => return false,
+        };
+
+        match state.drag_button {
+            Some(active) if active == button => {
+                if !pressed {
+                    state.drag_button = None;
+                    let _ = state.sender.send(WindowEvent::MouseDragEnded { x, y });
+                }
+                true
+            }
+            Some(_) => false,
+            None if pressed => {
+                let held = held_modifiers();
+                let action = state
+                    .binds
+                    .iter()
+                    .find(|bind| {
+                        bind.button == button
+                            && bind.modifiers.len() == held.len()
+                            && bind.modifiers.iter().all(|m| held.contains(&m.as_str()))
+                    })
+                    .map(|bind| bind.action);
+
+                match action {
+                    Some(action) => {
+                        state.drag_button = Some(button);
+                        let _ = state
+                            .sender
+                            .send(WindowEvent::MouseDragStarted { action, x, y });
+                        true
+                    }
+                    None => false,
+                }
+            }
+            None => false,
+        }
+    }
+
+    /// Get the modifier keys that are currently held down.
+    fn held_modifiers() -> Vec<&'static str> {
+        let is_down = |vk: i32| unsafe { GetAsyncKeyState(vk) < 0 };
+        let mut held = Vec::new();
+
+        if is_down(VK_LWIN.0 as i32) || is_down(VK_RWIN.0 as i32) {
+            held.push("Win");
+        }
+        if is_down(VK_CONTROL.0 as i32) {
+            held.push("Ctrl");
+        }
+        if is_down(VK_MENU.0 as i32) {
+            held.push("Alt");
+        }
+        if is_down(VK_SHIFT.0 as i32) {
+            held.push("Shift");
+        }
+
+        held
+    }
+
+    /// Shell notification sent when a window flashes its taskbar button.
+    const HSHELL_FLASH: u32 = HSHELL_REDRAW | HSHELL_HIGHBIT;
+
+    /// Sender used by `shell_hook_proc` to report flashing windows.
+    static SHELL_HOOK_SENDER: Mutex<Option<Sender<WindowEvent>>> = Mutex::new(None);
+
+    /// Message ID registered for shell hook notifications.
+    static SHELL_HOOK_MESSAGE: AtomicU32 = AtomicU32::new(0);
+
+    /// Window procedure of the hidden shell hook window.
+    ///
+    /// Shell hook notifications arrive as the registered `SHELLHOOK` message,
+    /// with the notification code in `wparam` and the window in `lparam`.
+    unsafe extern "system" fn shell_hook_proc(
+        hwnd: HWND,
+        message: u32,
+        wparam: WPARAM,
+        lparam: LPARAM,
+    ) -> LRESULT {
+        let shell_message = SHELL_HOOK_MESSAGE.load(Ordering::SeqCst);
+        if shell_message == 0 || message != shell_message {
+            return DefWindowProcW(hwnd, message, wparam, lparam);
+        }
+
+        if wparam.0 as u32 == HSHELL_FLASH {
+            if let Ok(guard) = SHELL_HOOK_SENDER.lock() {
+                if let Some(sender) = guard.as_ref() {
+                    let _ = sender.send(WindowEvent::WindowFlashed(HWND(lparam.0)));
+                }
+            }
+        }
+
+        LRESULT(0)
+    }
+
+    /// Register a hidden shell hook window on a dedicated thread.
+    ///
+    /// Taskbar flashing is only reported through shell hook messages, which
+    /// require a window of our own to receive them.
+    fn spawn_shell_hook_thread() -> anyhow::Result<(JoinHandle<()>, u32)> {
+        let (ready_tx, ready_rx) = channel();
+
+        let handle = std::thread::spawn(move || unsafe {
+            let hwnd = CreateWindowExW(
+                WS_EX_TOOLWINDOW,
+                w!("STATIC"),
+                w!("Tenraku Shell Hook"),
+                WS_POPUP,
+                0,
+                0,
+                0,
+                0,
+                None,
+                None,
+                None,
+                None,
+            );
+            if hwnd.0 == 0 {
+                let _ = ready_tx.send(Err(anyhow::anyhow!("Failed to create shell hook window")));
+                return;
+            }
+
+            let window_proc: WNDPROC = Some(shell_hook_proc);
+            SetWindowLongPtrW(hwnd, GWLP_WNDPROC, window_proc.map_or(0, |f| f as usize as isize));
+            SHELL_HOOK_MESSAGE.store(RegisterWindowMessageW(w!("SHELLHOOK")), Ordering::SeqCst);
+
+            if !RegisterShellHookWindow(hwnd).as_bool() {
+                let _ = DestroyWindow(hwnd);
+                let _ = ready_tx.send(Err(anyhow::anyhow!("Failed to register shell hook window")));
+                return;
+            }
+            let _ = ready_tx.send(Ok(GetCurrentThreadId()));
+
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, None, 0, 0).as_bool() {
+                DispatchMessageW(&msg);
+            }
+
+            let _ = DeregisterShellHookWindow(hwnd);
+            let _ = DestroyWindow(hwnd);
+        });
+
+        let thread_id = ready_rx
+            .recv()
+            .map_err(|_| anyhow::anyhow!("Shell hook thread exited unexpectedly"))??;
+
+        Ok((handle, thread_id))
+    }
+
+    /// Install the low-level mouse hook on a dedicated thread.
+    ///
+    /// The hook thread runs its own message loop so mouse input is never
+    /// delayed by the main event loop's polling interval.
+    fn spawn_mouse_hook_thread() -> anyhow::Result<(JoinHandle<()>, u32)> {
+        let (ready_tx, ready_rx) = channel();
+
+        let handle = std::thread::spawn(move || unsafe {
+            let hook = match SetWindowsHookExW(WH_MOUSE_LL, Some(mouse_hook_proc), None, 0) {
+                Ok(hook) => hook,
+                Err(e) => {
+                    let _ = ready_tx.send(Err(anyhow::anyhow!("Failed to set mouse hook: {}", e)));
+                    return;
+                }
+            };
+            let _ = ready_tx.send(Ok(GetCurrentThreadId()));
+
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, None, 0, 0).as_bool() {
+                DispatchMessageW(&msg);
+            }
+
+            let _ = UnhookWindowsHookEx(hook);
+        });
+
+        let thread_id = ready_rx
+            .recv()
+            .map_err(|_| anyhow::anyhow!("Mouse hook thread exited unexpectedly"))??;
+
+        Ok((handle, thread_id))
+    }
+
     /// Event loop for monitoring Windows events
     pub struct EventLoop {
         event_tx: Sender<WindowEvent>,
         event_rx: Receiver<WindowEvent>,
         hooks: Vec<HWINEVENTHOOK>,
+        mouse_hook: Option<(JoinHandle<()>, u32)>,
+        shell_hook: Option<(JoinHandle<()>, u32)>,
         running: bool,
     }
 
@@ -140,6 +403,8 @@ mod windows_impl {
                 event_tx: tx,
                 event_rx: rx,
                 hooks: Vec::new(),
+                mouse_hook: None,
+                shell_hook: None,
                 running: false,
             }
         }
@@ -199,12 +464,23 @@ mod windows_impl {
                 }
             }
 
+            // Flash notifications are optional, so a failure only disables urgency tracking
+            if let Ok(mut sender) = SHELL_HOOK_SENDER.lock() {
+                *sender = Some(self.event_tx.clone());
+            }
+            match spawn_shell_hook_thread() {
+                Ok(shell_hook) => self.shell_hook = Some(shell_hook),
+                Err(e) => tracing::warn!("Window urgency tracking unavailable: {}", e),
+            }
+
             self.running = true;
             Ok(())
         }
 
         /// Stop the event loop and unregister all event hooks.
         pub fn stop(&mut self) -> anyhow::Result<()> {
+            self.stop_mouse_hook();
+
             if !self.running {
                 return Ok(());
             }
@@ -216,6 +492,16 @@ mod windows_impl {
                 }
             }
 
+            if let Ok(mut sender) = SHELL_HOOK_SENDER.lock() {
+                *sender = None;
+            }
+            if let Some((handle, thread_id)) = self.shell_hook.take() {
+                unsafe {
+                    let _ = PostThreadMessageW(thread_id, WM_QUIT, WPARAM(0), LPARAM(0));
+                }
+                let _ = handle.join();
+            }
+
             // Clear the global sender pointer
             unsafe {
                 EVENT_SENDER_PTR = std::ptr::null();
@@ -225,6 +511,59 @@ mod windows_impl {
             Ok(())
         }
 
+        /// Set the mouse bindings handled by the low-level mouse hook.
+        ///
+        /// The hook is only installed while at least one binding exists.
+        pub fn set_mouse_binds(&mut self, binds: &[MouseBind]) -> anyhow::Result<()> {
+            if binds.is_empty() {
+                self.stop_mouse_hook();
+                return Ok(());
+            }
+
+            if let Ok(mut state) = MOUSE_HOOK_STATE.lock() {
+                // Give modifiers their canonical names to compare with the held ones
+                let binds = binds
+                    .iter()
+                    .map(|bind| {
+                        let mut modifiers: Vec<Modifier> =
+                            bind.modifiers.iter().filter_map(|m| Modifier::parse(m)).collect();
+                        modifiers.sort();
+                        modifiers.dedup();
+                        MouseBind {
+                            modifiers: modifiers.iter().map(|m| m.name().to_string()).collect(),
+                            ..bind.clone()
+                        }
+                    })
+                    .collect();
+
+                *state = Some(MouseHookState {
+                    sender: self.event_tx.clone(),
+                    binds,
+                    drag_button: None,
+                });
+            }
+
+            if self.mouse_hook.is_none() {
+                self.mouse_hook = Some(spawn_mouse_hook_thread()?);
+            }
+
+            Ok(())
+        }
+
+        /// Remove the low-level mouse hook and stop its thread.
+        fn stop_mouse_hook(&mut self) {
+            if let Ok(mut state) = MOUSE_HOOK_STATE.lock() {
+                *state = None;
+            }
+
+            if let Some((handle, thread_id)) = self.mouse_hook.take() {
+                unsafe {
+                    let _ = PostThreadMessageW(thread_id, WM_QUIT, WPARAM(0), LPARAM(0));
+                }
+                let _ = handle.join();
+            }
+        }
+
         /// Poll for pending events from the event queue.
         pub fn poll_events(&self) -> impl Iterator<Item = WindowEvent> + '_ {
             std::iter::from_fn(move || match self.event_rx.try_recv() {
@@ -279,6 +618,7 @@ mod windows_impl {
 // Stub implementation for non-Windows platforms
 #[cfg(not(target_os = "windows"))]
 mod stub_impl {
+    use crate::config::MouseBind;
     use std::sync::mpsc::{channel, Receiver, TryRecvError};
 
     /// Stub WindowEvent for non-Windows platforms
@@ -308,6 +648,10 @@ mod stub_impl {
             Ok(())
         }
 
+        pub fn set_mouse_binds(&mut self, _binds: &[MouseBind]) -> anyhow::Result<()> {
+            Ok(())
+        }
+
         pub fn poll_events(&self) -> impl Iterator<Item = WindowEvent> + '_ {
             std::iter::from_fn(move || match self.event_rx.try_recv() {
                 Ok(event) => Some(event),
@@ -4,6 +4,9 @@
 //! to IPC clients about window manager state changes.
 
 use super::protocol::Response;
+use crate::config::reload::ReloadEvent;
+use crate::keybinds::chord::{format_sequence, ChordEvent};
+use crate::workspace::core::WorkspaceEvent;
 use serde_json::json;
 use tokio::sync::broadcast::{channel, Receiver, Sender};
 
@@ -27,6 +30,9 @@ pub enum Event {
     /// Window received focus
     WindowFocused { hwnd: isize },
 
+    /// Window started or stopped demanding attention
+    WindowUrgent { hwnd: isize, urgent: bool },
+
     /// Window was moved to a different workspace
     WindowMoved {
         hwnd: isize,
@@ -50,14 +56,51 @@ pub enum Event {
     /// Workspace was deleted
     WorkspaceDeleted { id: usize },
 
-    /// Monitor configuration changed
-    MonitorChanged,
+    /// Monitor was connected and the listed workspaces returned to it
+    MonitorAdded {
+        id: usize,
+        name: String,
+        key: String,
+        workspaces: Vec<usize>,
+    },
+
+    /// Monitor was disconnected and its workspaces moved to `fallback`
+    MonitorRemoved {
+        id: usize,
+        name: String,
+        key: String,
+        workspaces: Vec<usize>,
+        fallback: usize,
+    },
+
+    /// Workspace group was switched to, showing the listed workspaces
+    GroupChanged {
+        from: Option<usize>,
+        to: usize,
+        workspaces: Vec<usize>,
+    },
 
-    /// Configuration was reloaded
-    ConfigReloaded,
+    /// Configuration was reloaded; the listed sections changed
+    ConfigReloaded { sections: Vec<String> },
+
+    /// Reloaded configuration was rejected and the previous one kept
+    ConfigReloadFailed { error: String },
 
     /// Layout changed
     LayoutChanged { layout: String },
+
+    /// Binding mode changed; `default` is the main keybindings
+    ModeChanged { mode: String },
+
+    /// Keys of a sequence were pressed; each continuation is the next key
+    /// and the command it runs, or `None` if more keys follow
+    ChordPending {
+        prefix: String,
+        continuations: Vec<(String, Option<String>)>,
+    },
+
+    /// The pending key sequence ran its command, was cancelled or timed out
+    ChordEnded { completed: bool },
 }
 
 /// Event broadcaster for distributing events to multiple subscribers
@@ -103,6 +146,77 @@ impl Default for EventBroadcaster {
     }
 }
 
+impl From<WorkspaceEvent> for Event {
+    fn from(event: WorkspaceEvent) -> Self {
+        match event {
+            WorkspaceEvent::Created { id, name } => Event::WorkspaceCreated { id, name },
+            WorkspaceEvent::Deleted { id } => Event::WorkspaceDeleted { id },
+            WorkspaceEvent::MonitorAdded {
+                id,
+                name,
+                key,
+                workspaces,
+            } => Event::MonitorAdded {
+                id,
+                name,
+                key,
+                workspaces,
+            },
+            WorkspaceEvent::MonitorRemoved {
+                id,
+                name,
+                key,
+                workspaces,
+                fallback,
+            } => Event::MonitorRemoved {
+                id,
+                name,
+                key,
+                workspaces,
+                fallback,
+            },
+            WorkspaceEvent::GroupChanged {
+                from,
+                to,
+                workspaces,
+            } => Event::GroupChanged {
+                from,
+                to,
+                workspaces,
+            },
+        }
+    }
+}
+
+impl From<ReloadEvent> for Event {
+    fn from(event: ReloadEvent) -> Self {
+        match event {
+            ReloadEvent::Reloaded { sections } => Event::ConfigReloaded {
+                sections: sections.iter().map(|section| section.key().to_string()).collect(),
+            },
+            ReloadEvent::Rejected { error } => Event::ConfigReloadFailed { error },
+        }
+    }
+}
+
+impl From<ChordEvent> for Event {
+    fn from(event: ChordEvent) -> Self {
+        match event {
+            ChordEvent::Pending {
+                prefix,
+                continuations,
+            } => Event::ChordPending {
+                prefix: format_sequence(&prefix),
+                continuations: continuations
+                    .into_iter()
+                    .map(|continuation| (continuation.chord.to_string(), continuation.command))
+                    .collect(),
+            },
+            ChordEvent::Finished { completed } => Event::ChordEnded { completed },
+        }
+    }
+}
+
 impl Event {
     /// Convert event to an IPC Response
     pub fn to_response(&self) -> Response {
@@ -125,6 +239,10 @@ impl Event {
             Event::WindowFocused { hwnd } => {
                 ("window_focused", json!({ "hwnd": format!("{}", hwnd) }))
             }
+            Event::WindowUrgent { hwnd, urgent } => (
+                "window_urgent",
+                json!({ "hwnd": format!("{}", hwnd), "urgent": urgent }),
+            ),
             Event::WindowMoved {
                 hwnd,
                 from_workspace,
@@ -156,9 +274,72 @@ impl Event {
                 ("workspace_created", json!({ "id": id, "name": name }))
             }
             Event::WorkspaceDeleted { id } => ("workspace_deleted", json!({ "id": id })),
-            Event::MonitorChanged => ("monitor_changed", json!({})),
-            Event::ConfigReloaded => ("config_reloaded", json!({})),
+            Event::MonitorAdded {
+                id,
+                name,
+                key,
+                workspaces,
+            } => (
+                "monitor_added",
+                json!({
+                    "id": id,
+                    "name": name,
+                    "key": key,
+                    "workspaces": workspaces,
+                }),
+            ),
+            Event::MonitorRemoved {
+                id,
+                name,
+                key,
+                workspaces,
+                fallback,
+            } => (
+                "monitor_removed",
+                json!({
+                    "id": id,
+                    "name": name,
+                    "key": key,
+                    "workspaces": workspaces,
+                    "fallback": fallback,
+                }),
+            ),
+            Event::GroupChanged {
+                from,
+                to,
+                workspaces,
+            } => (
+                "group_changed",
+                json!({
+                    "from": from,
+                    "to": to,
+                    "workspaces": workspaces,
+                }),
+            ),
+            Event::ConfigReloaded { sections } => {
+                ("config_reloaded", json!({ "sections": sections }))
+            }
+            Event::ConfigReloadFailed { error } => {
+                ("config_reload_failed", json!({ "error": error }))
+            }
             Event::LayoutChanged { layout } => ("layout_changed", json!({ "layout": layout })),
+            Event::ModeChanged { mode } => ("mode_changed", json!({ "mode": mode })),
+            Event::ChordPending {
+                prefix,
+                continuations,
+            } => (
+                "chord_pending",
+                json!({
+                    "prefix": prefix,
+                    "continuations": continuations
+                        .iter()
+                        .map(|(keys, command)| json!({ "keys": keys, "command": command }))
+                        .collect::<Vec<_>>(),
+                }),
+            ),
+            Event::ChordEnded { completed } => {
+                ("chord_ended", json!({ "completed": completed }))
+            }
         };
 
         Response::Event {
@@ -173,14 +354,21 @@ impl Event {
             Event::WindowCreated { .. } => "window_created",
             Event::WindowClosed { .. } => "window_closed",
             Event::WindowFocused { .. } => "window_focused",
+            Event::WindowUrgent { .. } => "window_urgent",
             Event::WindowMoved { .. } => "window_moved",
             Event::WindowStateChanged { .. } => "window_state_changed",
             Event::WorkspaceChanged { .. } => "workspace_changed",
             Event::WorkspaceCreated { .. } => "workspace_created",
             Event::WorkspaceDeleted { .. } => "workspace_deleted",
-            Event::MonitorChanged => "monitor_changed",
-            Event::ConfigReloaded => "config_reloaded",
+            Event::MonitorAdded { .. } => "monitor_added",
+            Event::MonitorRemoved { .. } => "monitor_removed",
+            Event::GroupChanged { .. } => "group_changed",
+            Event::ConfigReloaded { .. } => "config_reloaded",
+            Event::ConfigReloadFailed { .. } => "config_reload_failed",
             Event::LayoutChanged { .. } => "layout_changed",
+            Event::ModeChanged { .. } => "mode_changed",
+            Event::ChordPending { .. } => "chord_pending",
+            Event::ChordEnded { .. } => "chord_ended",
         }
     }
 }
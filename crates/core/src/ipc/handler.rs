@@ -28,15 +28,19 @@
 //! ```
 
 use super::protocol::{
-    ConfigInfo, MonitorInfo, Request, Response, VersionInfo,
+    ConfigInfo, MonitorInfo, Request, Response, TagInfo, VersionInfo,
     WorkspaceInfo,
 };
 use crate::commands::{Command, CommandExecutor};
+use crate::window_manager::floating::SnapTarget;
+use crate::window_manager::monitor::{MonitorManager, MonitorTarget};
+use crate::window_manager::tags::{tag_mask, tags_in};
 use crate::window_manager::WindowManager;
 use crate::workspace::core::WorkspaceManager;
+use crate::workspace::switching::WorkspaceSwitch;
 use std::sync::Arc;
 use tokio::sync::Mutex;
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 
 /// Request handler that processes IPC requests and forwards them to the window manager.
 ///
@@ -113,6 +117,7 @@ impl RequestHandler {
             Request::GetMonitors => self.get_monitors().await,
             Request::GetConfig => self.get_config().await,
             Request::GetVersion => self.get_version().await,
+            Request::GetTags => self.get_tags().await,
             
             // Command execution
             Request::Execute { command, args } => self.execute_command(command, args).await,
@@ -129,6 +134,21 @@ impl RequestHandler {
             Request::CreateWorkspace { name, monitor } => self.create_workspace(name, monitor).await,
             Request::DeleteWorkspace { id } => self.delete_workspace(id).await,
             Request::RenameWorkspace { id, name } => self.rename_workspace(id, name).await,
+            Request::MoveWorkspaceToMonitor { target } => {
+                self.move_workspace_to_monitor(target).await
+            }
+            Request::SwapMonitorWorkspaces { target } => {
+                self.swap_monitor_workspaces(target).await
+            }
+            Request::SwitchGroup { id } => self.switch_group(id).await,
+            
+            // Tag commands
+            Request::ViewTag { tag } => self.tag_command(Command::ViewTag(tag)).await,
+            Request::ToggleViewTag { tag } => self.tag_command(Command::ToggleViewTag(tag)).await,
+            Request::TagWindow { tag } => self.tag_command(Command::TagWindow(tag)).await,
+            Request::ToggleWindowTag { tag } => {
+                self.tag_command(Command::ToggleWindowTag(tag)).await
+            }
             
             // Layout commands
             Request::SetLayout { layout } => self.set_layout(layout).await,
@@ -160,7 +180,9 @@ impl RequestHandler {
                 "title": window.title,
                 "exe": window.process_name,
                 "class": window.class,
-                "workspace": window.workspace
+                "workspace": window.workspace,
+                "mark": window.mark,
+                "tags": wm.is_tag_mode().then(|| tags_in(window.tags))
             }))
         } else {
             Response::error("No active window")
@@ -179,7 +201,9 @@ impl RequestHandler {
                 "title": w.title,
                 "exe": w.process_name,
                 "class": w.class,
-                "workspace": w.workspace
+                "workspace": w.workspace,
+                "mark": w.mark,
+                "tags": wm.is_tag_mode().then(|| tags_in(w.tags))
             })
         }).collect();
         
@@ -192,22 +216,40 @@ impl RequestHandler {
         let wsm = self.workspace_manager.lock().await;
         let active_workspace = wsm.active_workspace();
         
-        // Iterate through workspace IDs
-        // Note: We check up to 20 workspaces to accommodate various configurations
-        // Only workspaces that exist will be included in the response
-        let mut workspace_infos: Vec<WorkspaceInfo> = Vec::new();
+        let mut workspace_infos: Vec<WorkspaceInfo> = wsm
+            .workspaces()
+            .into_iter()
+            .map(|ws| WorkspaceInfo {
+                id: ws.id,
+                name: ws.name.clone(),
+                monitor: ws.monitor,
+                window_count: ws.windows.len(),
+                active: ws.id == active_workspace,
+                visible: Some(ws.visible),
+                urgent: false,
+                special: false,
+            })
+            .collect();
+        drop(wsm);
+        
+        // Urgency is tracked per window by the window manager
+        let wm = self.window_manager.lock().await;
+        for info in workspace_infos.iter_mut() {
+            info.urgent = wm.registry().has_urgent_in_workspace(info.id);
+        }
         
-        for id in 1..=20 {
-            if let Some(ws) = wsm.get_workspace(id) {
-                workspace_infos.push(WorkspaceInfo {
-                    id: ws.id,
-                    name: ws.name.clone(),
-                    monitor: ws.monitor,
-                    window_count: ws.windows.len(),
-                    active: ws.id == active_workspace,
-                    visible: Some(ws.visible),
-                });
-            }
+        // Special workspaces live in the window manager only
+        for special in wm.special_workspaces().all() {
+            workspace_infos.push(WorkspaceInfo {
+                id: special.id,
+                name: special.name.clone(),
+                monitor: special.monitor.unwrap_or(0),
+                window_count: wm.get_windows(Some(special.id)).len(),
+                active: false,
+                visible: Some(special.monitor.is_some()),
+                urgent: wm.registry().has_urgent_in_workspace(special.id),
+                special: true,
+            });
         }
         
         match serde_json::to_value(workspace_infos) {
@@ -240,6 +282,8 @@ impl RequestHandler {
                 // For now, we assume the first monitor is primary as a reasonable default
                 primary: Some(idx == 0),
                 active_workspace: mon.active_workspace,
+                parent: mon.parent.clone(),
+                tags: wm.is_tag_mode().then(|| tags_in(wm.viewed_tags(idx))),
             })
             .collect();
         
@@ -252,6 +296,39 @@ impl RequestHandler {
         }
     }
     
+    async fn get_tags(&self) -> Response {
+        debug!("Getting tags list");
+        
+        let wm = self.window_manager.lock().await;
+        if !wm.is_tag_mode() {
+            return Response::error("Tag mode is not enabled");
+        }
+        
+        let windows = wm.get_windows(Some(wm.get_active_workspace()));
+        let tag_infos: Vec<TagInfo> = (1..=wm.tag_count())
+            .filter_map(|tag| tag_mask(tag).map(|mask| (tag, mask)))
+            .map(|(tag, mask)| {
+                let tagged = windows.iter().filter(|w| w.tags & mask != 0);
+                TagInfo {
+                    id: tag,
+                    monitors: (0..wm.get_monitors().len())
+                        .filter(|&idx| wm.viewed_tags(idx) & mask != 0)
+                        .collect(),
+                    window_count: tagged.clone().count(),
+                    urgent: tagged.clone().any(|w| w.urgent),
+                }
+            })
+            .collect();
+        
+        match serde_json::to_value(tag_infos) {
+            Ok(data) => Response::success_with_data(data),
+            Err(e) => {
+                error!("Failed to serialize tags: {}", e);
+                Response::error(format!("Failed to serialize tags: {}", e))
+            }
+        }
+    }
+    
     async fn get_config(&self) -> Response {
         debug!("Getting configuration");
         
@@ -311,17 +388,106 @@ impl RequestHandler {
             "focus_right" | "focus-right" => Some(Command::FocusRight),
             "focus_up" | "focus-up" => Some(Command::FocusUp),
             "focus_down" | "focus-down" => Some(Command::FocusDown),
+            "focus_urgent" | "focus-urgent" => Some(Command::FocusUrgent),
+            "focus_monitor" | "focus-monitor" if !args.is_empty() => {
+                Some(Command::FocusMonitor(MonitorTarget::parse(&args[0])))
+            }
+            "move_to_monitor" | "move-to-monitor" if !args.is_empty() => {
+                Some(Command::MoveToMonitor(MonitorTarget::parse(&args[0])))
+            }
             "layout_dwindle" | "layout-dwindle" => Some(Command::SetLayoutDwindle),
             "layout_master" | "layout-master" => Some(Command::SetLayoutMaster),
             "increase_master" | "increase-master" => Some(Command::IncreaseMasterCount),
             "decrease_master" | "decrease-master" => Some(Command::DecreaseMasterCount),
-            "workspace" if !args.is_empty() => {
-                if let Ok(id) = args[0].parse::<usize>() {
-                    Some(Command::SwitchWorkspace(id))
-                } else {
-                    None
+            "float_center" | "float-center" => Some(Command::FloatCenter),
+            "float_move" | "float-move" if args.len() >= 2 => {
+                match (args[0].parse::<i32>(), args[1].parse::<i32>()) {
+                    (Ok(dx), Ok(dy)) => Some(Command::FloatMove(dx, dy)),
+                    _ => None,
+                }
+            }
+            "float_resize" | "float-resize" if args.len() >= 2 => {
+                match (args[0].parse::<i32>(), args[1].parse::<i32>()) {
+                    (Ok(dw), Ok(dh)) => Some(Command::FloatResize(dw, dh)),
+                    _ => None,
                 }
             }
+            "float_snap" | "float-snap" if !args.is_empty() => {
+                SnapTarget::from_name(&args[0]).map(Command::FloatSnap)
+            }
+            "mark" if !args.is_empty() => Some(Command::Mark(args[0].clone())),
+            "unmark" => Some(Command::Unmark(args.first().cloned())),
+            "focus_mark" | "focus-mark" if !args.is_empty() => {
+                Some(Command::FocusMark(args[0].clone()))
+            }
+            "swap_with_mark" | "swap-with-mark" if !args.is_empty() => {
+                Some(Command::SwapWithMark(args[0].clone()))
+            }
+            "toggle_special" | "toggle-special" if !args.is_empty() => {
+                Some(Command::ToggleSpecial(args[0].clone()))
+            }
+            "move_to_special" | "move-to-special" if !args.is_empty() => {
+                Some(Command::MoveToSpecial(args[0].clone()))
+            }
+            "workspace" if !args.is_empty() => {
+                // Accepts a workspace ID or name
+                return self
+                    .navigate_workspace(WorkspaceSwitch::To(args[0].clone()))
+                    .await;
+            }
+            "move_workspace_to_monitor" | "move-workspace-to-monitor" if !args.is_empty() => {
+                return self.move_workspace_to_monitor(args[0].clone()).await;
+            }
+            "swap_monitor_workspaces" | "swap-monitor-workspaces" => {
+                return self.swap_monitor_workspaces(args.first().cloned()).await;
+            }
+            "view_tag" | "view-tag" if !args.is_empty() => {
+                args[0].parse::<usize>().ok().map(Command::ViewTag)
+            }
+            "toggle_view_tag" | "toggle-view-tag" if !args.is_empty() => {
+                args[0].parse::<usize>().ok().map(Command::ToggleViewTag)
+            }
+            "tag_window" | "tag-window" if !args.is_empty() => {
+                args[0].parse::<usize>().ok().map(Command::TagWindow)
+            }
+            "toggle_window_tag" | "toggle-window-tag" if !args.is_empty() => {
+                args[0].parse::<usize>().ok().map(Command::ToggleWindowTag)
+            }
+            "switch_group" | "switch-group" if !args.is_empty() => {
+                return match args[0].parse::<usize>() {
+                    Ok(id) => self.switch_group(id).await,
+                    Err(_) => Response::error(format!("Invalid group ID: {}", args[0])),
+                };
+            }
+            "workspace_back_and_forth" | "workspace-back-and-forth" => {
+                return self.navigate_workspace(WorkspaceSwitch::BackAndForth).await;
+            }
+            "workspace_history_back" | "workspace-history-back" => {
+                return self.navigate_workspace(WorkspaceSwitch::HistoryBack).await;
+            }
+            "workspace_history_forward" | "workspace-history-forward" => {
+                return self.navigate_workspace(WorkspaceSwitch::HistoryForward).await;
+            }
+            "workspace_next" | "workspace-next" => {
+                let (skip_empty, same_monitor) = cycle_flags(&args);
+                return self
+                    .navigate_workspace(WorkspaceSwitch::Cycle {
+                        forward: true,
+                        skip_empty,
+                        same_monitor,
+                    })
+                    .await;
+            }
+            "workspace_previous" | "workspace-previous" | "workspace-prev" => {
+                let (skip_empty, same_monitor) = cycle_flags(&args);
+                return self
+                    .navigate_workspace(WorkspaceSwitch::Cycle {
+                        forward: false,
+                        skip_empty,
+                        same_monitor,
+                    })
+                    .await;
+            }
             _ => None,
         };
         
@@ -389,9 +555,21 @@ impl RequestHandler {
             Err(e) => return Response::error(format!("Invalid HWND format: {}", e))
         };
         
-        // Validate workspace ID
-        if workspace < 1 || workspace > 10 {
-            return Response::error(format!("Invalid workspace ID: {}", workspace));
+        // Validate workspace ID, creating the workspace on demand on the window's monitor
+        let monitor = self
+            .window_manager
+            .lock()
+            .await
+            .registry()
+            .get(hwnd_value)
+            .map_or(0, |w| w.monitor);
+        if let Err(e) = self
+            .workspace_manager
+            .lock()
+            .await
+            .ensure_workspace(workspace, monitor)
+        {
+            return Response::error(format!("Invalid workspace ID: {}", e));
         }
         
         let mut wm = self.window_manager.lock().await;
@@ -471,12 +649,40 @@ impl RequestHandler {
     async fn switch_workspace(&self, id: usize) -> Response {
         debug!("Switching to workspace {}", id);
         
-        let mut wm = self.window_manager.lock().await;
-        match self.command_executor.execute(Command::SwitchWorkspace(id), &mut wm) {
-            Ok(_) => {
-                info!("Switched to workspace {} successfully", id);
-                Response::success()
+        // Track the switch so workspaces are created on demand and
+        // destroyed once empty and left
+        self.navigate_workspace(WorkspaceSwitch::To(id.to_string())).await
+    }
+    
+    /// Bring the window manager onto the workspace a switch leads to, then
+    /// make the switch on the workspace manager.
+    ///
+    /// The workspace manager stays locked throughout and is only changed once
+    /// the window manager has switched, so a failed switch leaves both on the
+    /// same workspace.
+    async fn navigate_workspace(&self, switch: WorkspaceSwitch) -> Response {
+        let mut wsm = self.workspace_manager.lock().await;
+        let workspace_id = match wsm.switch_target(&switch) {
+            Ok(id) => id,
+            Err(e) => {
+                error!("Failed to switch workspace: {}", e);
+                return Response::error(format!("Failed to switch workspace: {}", e));
             }
+        };
+        
+        {
+            let mut wm = self.window_manager.lock().await;
+            if wm.get_active_workspace() != workspace_id {
+                if let Err(e) = wm.switch_workspace(workspace_id) {
+                    error!("Failed to switch workspace: {}", e);
+                    return Response::error(format!("Failed to switch workspace: {}", e));
+                }
+                info!("Switched to workspace {} successfully", workspace_id);
+            }
+        }
+        
+        match wsm.apply_switch(&switch) {
+            Ok(_) => Response::success(),
             Err(e) => {
                 error!("Failed to switch workspace: {}", e);
                 Response::error(format!("Failed to switch workspace: {}", e))
@@ -541,6 +747,98 @@ impl RequestHandler {
         }
     }
     
+    async fn move_workspace_to_monitor(&self, target: String) -> Response {
+        debug!("Moving active workspace to monitor {}", target);
+        
+        let target = MonitorTarget::parse(&target);
+        let mut wm = self.window_manager.lock().await;
+        let monitor = match wm.resolve_monitor(&target) {
+            Some(monitor) => monitor,
+            None => return Response::error(format!("No monitor matches '{}'", target)),
+        };
+        
+        let workspace_id = wm.get_active_workspace();
+        if let Err(e) = wm.move_workspace_to_monitor(workspace_id, monitor) {
+            error!("Failed to move workspace: {}", e);
+            return Response::error(format!("Failed to move workspace: {}", e));
+        }
+        let mut monitors = monitor_manager(wm.get_monitors());
+        drop(wm);
+        
+        let mut wsm = self.workspace_manager.lock().await;
+        if let Err(e) = wsm.move_workspace_to_monitor(workspace_id, monitor, &mut monitors) {
+            warn!("Workspace manager not updated: {}", e);
+        }
+        
+        info!("Moved workspace {} to monitor {}", workspace_id, monitor);
+        Response::success()
+    }
+    
+    async fn swap_monitor_workspaces(&self, target: Option<String>) -> Response {
+        debug!("Swapping monitor workspaces with {:?}", target);
+        
+        let target = target.as_deref().map(MonitorTarget::parse);
+        let mut wm = self.window_manager.lock().await;
+        let (first, second) = match wm.resolve_swap_monitors(target.as_ref()) {
+            Some(monitors) => monitors,
+            None => {
+                let target = target.map(|t| t.to_string()).unwrap_or_default();
+                return Response::error(format!("No monitor matches '{}'", target));
+            }
+        };
+        
+        if let Err(e) = wm.swap_monitor_workspaces(first, second) {
+            error!("Failed to swap monitor workspaces: {}", e);
+            return Response::error(format!("Failed to swap monitor workspaces: {}", e));
+        }
+        let mut monitors = monitor_manager(wm.get_monitors());
+        drop(wm);
+        
+        let mut wsm = self.workspace_manager.lock().await;
+        if let Err(e) = wsm.swap_monitor_workspaces(first, second, &mut monitors) {
+            warn!("Workspace manager not updated: {}", e);
+        }
+        
+        info!("Swapped workspaces of monitors {} and {}", first, second);
+        Response::success()
+    }
+    
+    async fn switch_group(&self, id: usize) -> Response {
+        debug!("Switching to workspace group {}", id);
+        
+        // The workspace manager checks every workspace of the group first and
+        // reports the switch, so it goes before the window manager
+        if let Err(e) = self.workspace_manager.lock().await.switch_group(id) {
+            error!("Failed to switch workspace group: {}", e);
+            return Response::error(format!("Failed to switch workspace group: {}", e));
+        }
+        
+        let mut wm = self.window_manager.lock().await;
+        match wm.switch_group(id) {
+            Ok(_) => {
+                info!("Switched to workspace group {} successfully", id);
+                Response::success()
+            }
+            Err(e) => {
+                error!("Failed to switch workspace group: {}", e);
+                Response::error(format!("Failed to switch workspace group: {}", e))
+            }
+        }
+    }
+    
+    async fn tag_command(&self, cmd: Command) -> Response {
+        debug!("Executing tag command: {:?}", cmd);
+        
+        let mut wm = self.window_manager.lock().await;
+        match self.command_executor.execute(cmd, &mut wm) {
+            Ok(_) => Response::success(),
+            Err(e) => {
+                error!("Tag command failed: {}", e);
+                Response::error(format!("Tag command failed: {}", e))
+            }
+        }
+    }
+    
     async fn set_layout(&self, layout: String) -> Response {
         debug!("Setting layout to {}", layout);
         
@@ -623,7 +921,7 @@ impl RequestHandler {
         debug!("Reloading configuration");
         
         // Load config from default location
-        use crate::config::ConfigLoader;
+        use crate::config::{ConfigLoader, ConfigValidator};
         
         let config_loader = match ConfigLoader::new() {
             Ok(loader) => loader,
@@ -641,6 +939,19 @@ impl RequestHandler {
             }
         };
         
+        // Reject an invalid configuration before any of it is applied
+        if let Err(e) = ConfigValidator::validate(&config) {
+            error!("Configuration validation failed: {}", e);
+            return Response::error(format!("Configuration validation failed: {}", e));
+        }
+        
+        // Apply workspace rules before the window manager retiles
+        {
+            let mut wsm = self.workspace_manager.lock().await;
+            wsm.set_workspace_rules(config.workspace_rules.clone());
+            wsm.set_workspace_groups(config.workspace_groups.clone());
+        }
+        
         // Apply configuration to window manager
         let mut wm = self.window_manager.lock().await;
         match wm.update_config(&config) {
@@ -661,4 +972,24 @@ impl RequestHandler {
         // This just returns success to acknowledge the request
         Response::success()
     }
-}
\ No newline at end of file
+}
+/// Build a monitor manager from the window manager's monitors.
+///
+/// The window manager numbers its monitors in order, so the IDs match.
+fn monitor_manager(monitors: &[crate::window_manager::MonitorInfo]) -> MonitorManager {
+    let mut manager = MonitorManager::new();
+    for monitor in monitors {
+        manager.add_monitor(monitor.clone());
+    }
+    manager
+}
+
+/// Parse the optional `skip-empty` and `same-monitor` flags of the workspace
+/// next/previous commands.
+fn cycle_flags(args: &[String]) -> (bool, bool) {
+    let has_flag = |flag: &str| {
+        args.iter()
+            .any(|arg| arg.trim_start_matches("--").replace('_', "-") == flag)
+    };
+    (has_flag("skip-empty"), has_flag("same-monitor"))
+}
@@ -42,7 +42,7 @@ mod client;
 pub use events::{Event, EventBroadcaster};
 pub use handler::RequestHandler;
 pub use protocol::{
-    ConfigInfo, MonitorInfo, ProtocolVersion, RectInfo, Request, Response, VersionInfo,
+    ConfigInfo, MonitorInfo, ProtocolVersion, RectInfo, Request, Response, TagInfo, VersionInfo,
     WindowInfo, WindowState, WorkspaceInfo, PROTOCOL_VERSION,
 };
 pub use server::IpcServer;
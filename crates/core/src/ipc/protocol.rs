@@ -48,6 +48,9 @@ pub enum Request {
     /// Get version information
     GetVersion,
     
+    /// Get the tags of tag mode and which monitors view them
+    GetTags,
+    
     // Command execution
     /// Execute a generic command with arguments
     ///
@@ -110,6 +113,48 @@ pub enum Request {
         name: String,
     },
     
+    /// Move the active workspace to another monitor
+    ///
+    /// The target is a direction from the focused monitor ("left", "right",
+    /// "up", "down"), a monitor index, or a monitor name.
+    MoveWorkspaceToMonitor {
+        target: String,
+    },
+    
+    /// Swap the workspaces of the focused monitor and another monitor
+    ///
+    /// Without a target, the next monitor is used.
+    SwapMonitorWorkspaces {
+        #[serde(default)]
+        target: Option<String>,
+    },
+    
+    /// Switch every monitor to its workspace of a workspace group
+    SwitchGroup {
+        id: usize,
+    },
+    
+    // Tag commands (tag mode only)
+    /// View only one tag on the focused monitor
+    ViewTag {
+        tag: usize,
+    },
+    
+    /// Add a tag to or remove it from the focused monitor's view
+    ToggleViewTag {
+        tag: usize,
+    },
+    
+    /// Give the active window only one tag
+    TagWindow {
+        tag: usize,
+    },
+    
+    /// Add a tag to or remove it from the active window
+    ToggleWindowTag {
+        tag: usize,
+    },
+    
     // Layout commands
     /// Set the layout for the current workspace
     ///
@@ -236,6 +281,10 @@ pub struct WindowInfo {
     /// Whether this window is currently focused
     #[serde(skip_serializing_if = "Option::is_none")]
     pub focused: Option<bool>,
+    
+    /// Mark assigned to this window
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mark: Option<String>,
 }
 
 /// Window state enum
@@ -292,6 +341,14 @@ pub struct WorkspaceInfo {
     /// Whether this workspace is visible on its monitor
     #[serde(skip_serializing_if = "Option::is_none")]
     pub visible: Option<bool>,
+    
+    /// Whether this workspace contains windows demanding attention
+    #[serde(default)]
+    pub urgent: bool,
+    
+    /// Whether this is a special workspace toggled over the normal ones
+    #[serde(default)]
+    pub special: bool,
 }
 
 /// Information about a monitor
@@ -325,6 +382,31 @@ pub struct MonitorInfo {
     /// Active workspace ID on this monitor
     #[serde(skip_serializing_if = "Option::is_none")]
     pub active_workspace: Option<usize>,
+    
+    /// Physical monitor this logical monitor is split from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    
+    /// Tags viewed on this monitor in tag mode
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<usize>>,
+}
+
+/// Information about a tag in tag mode
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagInfo {
+    /// Tag number (1-based)
+    pub id: usize,
+    
+    /// Monitors viewing this tag
+    pub monitors: Vec<usize>,
+    
+    /// Number of windows carrying this tag
+    pub window_count: usize,
+    
+    /// Whether a window carrying this tag is demanding attention
+    #[serde(default)]
+    pub urgent: bool,
 }
 
 /// Configuration information
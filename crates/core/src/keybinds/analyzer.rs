@@ -0,0 +1,373 @@
+//! Keybinding conflict and shadowing report.
+//!
+//! The validator rejects keybindings that cannot be registered at all. The
+//! analyzer looks for keybindings that load but may not do what was meant:
+//!
+//! - combinations Windows reserves for itself, such as `Win+L`
+//! - the same combination bound twice, including when the entries are
+//!   written with a different modifier order, key case or alias
+//! - mode keybindings that replace a main keybinding while the mode is active
+//! - commands the window manager does not know
+//!
+//! It runs on every configuration reload and as `tenrakuctl keybinds check`.
+
+use super::chord::{format_sequence, Chord};
+use crate::commands::COMMAND_NAMES;
+use crate::config::diagnostics::{did_you_mean, Diagnostics};
+use crate::config::schema::{Config, Keybind};
+
+/// Key combinations Windows handles itself, and what they do
+const RESERVED: &[(&str, &str)] = &[
+    ("Win+L", "locks the workstation"),
+    ("Ctrl+Alt+Delete", "opens the security screen"),
+    ("Ctrl+Shift+Escape", "opens Task Manager"),
+    ("Alt+Tab", "switches between windows"),
+    ("Win+Tab", "opens Task View"),
+    ("Win+D", "shows the desktop"),
+    ("Win+E", "opens File Explorer"),
+    ("Win+R", "opens the Run dialog"),
+];
+
+/// A keybinding with its location and parsed key sequence
+struct Entry<'a> {
+    path: String,
+    keybind: &'a Keybind,
+    chords: Vec<Chord>,
+}
+
+/// Report conflicting, shadowed and reserved keybindings and unknown commands
+///
+/// Reserved combinations, shadowed keybindings and unknown commands are
+/// warnings; duplicate combinations are errors. Keybindings whose keys fail
+/// to parse are skipped, since the validator reports them.
+///
+/// # Arguments
+/// * `config` - The configuration to analyze
+/// * `diagnostics` - Collects the problems found
+pub fn analyze(config: &Config, diagnostics: &mut Diagnostics) {
+    let main = entries(&config.keybinds, "keybinds");
+    check_list(&main, diagnostics);
+
+    for (i, mode) in config.modes.iter().enumerate() {
+        let list = entries(&mode.keybinds, &format!("modes[{}].keybinds", i));
+        check_list(&list, diagnostics);
+        check_shadowing(&mode.name, &list, &main, diagnostics);
+    }
+}
+
+/// Parse the key sequences of a list of keybindings
+fn entries<'a>(keybinds: &'a [Keybind], list: &str) -> Vec<Entry<'a>> {
+    keybinds
+        .iter()
+        .enumerate()
+        .filter_map(|(i, keybind)| {
+            Some(Entry {
+                path: format!("{}[{}]", list, i),
+                keybind,
+                chords: Chord::sequence_of(keybind).ok()?,
+            })
+        })
+        .collect()
+}
+
+/// Check the keybindings of one list: the main list or a mode
+fn check_list(entries: &[Entry], diagnostics: &mut Diagnostics) {
+    for (i, entry) in entries.iter().enumerate() {
+        check_reserved(entry, diagnostics);
+        check_command(entry, diagnostics);
+
+        // Keybindings with conditions may share keys with other keybindings
+        if entry.keybind.is_conditional() {
+            continue;
+        }
+        let Some(first) = entries[..i]
+            .iter()
+            .find(|other| other.chords == entry.chords && !other.keybind.is_conditional())
+        else {
+            continue;
+        };
+        let (written, first_written) = (written(entry.keybind), written(first.keybind));
+        if written == first_written {
+            diagnostics.error(
+                &entry.path,
+                format!(
+                    "Duplicate keybinding: {}, also bound at {}",
+                    written, first.path
+                ),
+            );
+        } else {
+            diagnostics
+                .error(
+                    &entry.path,
+                    format!(
+                        "{} is the same keybinding as {} at {}",
+                        written, first_written, first.path
+                    ),
+                )
+                .suggest(format!(
+                    "modifier order, key case and aliases do not matter; both are {}",
+                    format_sequence(&entry.chords)
+                ));
+        }
+    }
+}
+
+/// Warn about a keybinding whose first chord Windows reserves
+fn check_reserved(entry: &Entry, diagnostics: &mut Diagnostics) {
+    let reserved = RESERVED.iter().find(|(combination, _)| {
+        Chord::parse(combination).is_ok_and(|chord| chord == entry.chords[0])
+    });
+    if let Some((combination, action)) = reserved {
+        diagnostics
+            .warning(
+                &entry.path,
+                format!(
+                    "{} is reserved by Windows, which {} instead",
+                    combination, action
+                ),
+            )
+            .suggest("bind the command to another combination");
+    }
+}
+
+/// Warn about a command the window manager does not know
+fn check_command(entry: &Entry, diagnostics: &mut Diagnostics) {
+    let command = entry.keybind.command.as_str();
+    if !COMMAND_NAMES.contains(&command) {
+        diagnostics
+            .warning(
+                format!("{}.command", entry.path),
+                format!("Unknown command: {}", command),
+            )
+            .suggest_opt(did_you_mean(command, COMMAND_NAMES.iter().copied()));
+    }
+}
+
+/// Warn about mode keybindings that replace a main keybinding with another command
+fn check_shadowing(mode: &str, entries: &[Entry], main: &[Entry], diagnostics: &mut Diagnostics) {
+    for entry in entries {
+        let shadowed = main.iter().find(|other| {
+            other.chords == entry.chords
+                && command_line(other.keybind) != command_line(entry.keybind)
+        });
+        if let Some(shadowed) = shadowed {
+            diagnostics.warning(
+                &entry.path,
+                format!(
+                    "{} runs '{}' in mode '{}', shadowing '{}' from {}",
+                    format_sequence(&entry.chords),
+                    command_line(entry.keybind),
+                    mode,
+                    command_line(shadowed.keybind),
+                    shadowed.path
+                ),
+            );
+        }
+    }
+}
+
+/// Format a keybinding's keys the way they were written, e.g. `Ctrl+Win+q`
+fn written(keybind: &Keybind) -> String {
+    let first = keybind
+        .modifiers
+        .iter()
+        .map(String::as_str)
+        .chain(std::iter::once(keybind.key.as_str()))
+        .collect::<Vec<_>>()
+        .join("+");
+    std::iter::once(first)
+        .chain(keybind.sequence.iter().map(|step| step.trim().to_string()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Format a keybinding's command with its arguments
+fn command_line(keybind: &Keybind) -> String {
+    std::iter::once(keybind.command.as_str())
+        .chain(keybind.args.iter().map(String::as_str))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::diagnostics::Severity;
+    use crate::config::schema::{BindingMode, WindowMatch};
+
+    fn keybind(modifiers: &[&str], key: &str, command: &str) -> Keybind {
+        Keybind {
+            modifiers: modifiers.iter().map(|m| m.to_string()).collect(),
+            key: key.to_string(),
+            sequence: vec![],
+            command: command.to_string(),
+            args: vec![],
+            only_when: None,
+            except_when: None,
+        }
+    }
+
+    fn analyze_keybinds(keybinds: Vec<Keybind>, modes: Vec<BindingMode>) -> Diagnostics {
+        let config = Config {
+            keybinds,
+            modes,
+            ..Config::default()
+        };
+        let mut diagnostics = Diagnostics::new();
+        analyze(&config, &mut diagnostics);
+        diagnostics
+    }
+
+    #[test]
+    fn test_clean_keybinds() {
+        let diagnostics = analyze_keybinds(
+            vec![
+                keybind(&["Win"], "Q", "close"),
+                keybind(&["Win"], "1", "workspace-1"),
+                keybind(&["Win", "Shift"], "Q", "enter-mode"),
+            ],
+            vec![],
+        );
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_reserved_combinations() {
+        let diagnostics = analyze_keybinds(
+            vec![
+                keybind(&["Super"], "l", "close"),
+                keybind(&["Alt", "Ctrl"], "Del", "exit"),
+                keybind(&["Win", "Shift"], "L", "close"),
+            ],
+            vec![],
+        );
+        let messages: Vec<_> = diagnostics.iter().map(|d| d.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            [
+                "Win+L is reserved by Windows, which locks the workstation instead",
+                "Ctrl+Alt+Delete is reserved by Windows, which opens the security screen instead",
+            ]
+        );
+        assert_eq!(diagnostics.warning_count(), 2);
+    }
+
+    #[test]
+    fn test_duplicates_written_differently() {
+        let diagnostics = analyze_keybinds(
+            vec![
+                keybind(&["Win", "Ctrl"], "Q", "close"),
+                keybind(&["control", "Win"], "q", "minimize"),
+                keybind(&["Win", "Ctrl"], "Q", "exit"),
+            ],
+            vec![],
+        );
+        let errors: Vec<_> = diagnostics.iter().collect();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].path, "keybinds[1]");
+        assert_eq!(
+            errors[0].message,
+            "control+Win+q is the same keybinding as Win+Ctrl+Q at keybinds[0]"
+        );
+        assert_eq!(
+            errors[0].suggestion.as_deref(),
+            Some("modifier order, key case and aliases do not matter; both are Win+Ctrl+Q")
+        );
+        assert_eq!(
+            errors[1].message,
+            "Duplicate keybinding: Win+Ctrl+Q, also bound at keybinds[0]"
+        );
+        assert_eq!(diagnostics.error_count(), 2);
+    }
+
+    #[test]
+    fn test_conditional_keybinds_share_keys() {
+        let game = Some(WindowMatch {
+            match_process: Some(r"game\.exe".to_string()),
+            ..WindowMatch::default()
+        });
+        let diagnostics = analyze_keybinds(
+            vec![
+                Keybind {
+                    except_when: game.clone(),
+                    ..keybind(&["Win"], "Q", "close")
+                },
+                Keybind {
+                    only_when: game,
+                    ..keybind(&["Win"], "Q", "minimize")
+                },
+                keybind(&["Win"], "Q", "toggle-passthrough"),
+            ],
+            vec![],
+        );
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_mode_shadowing() {
+        let mode = BindingMode {
+            name: "resize".to_string(),
+            escape: "Escape".to_string(),
+            keybinds: vec![
+                keybind(&["Win"], "Q", "exit-mode"),
+                keybind(&["Win"], "1", "workspace-1"),
+                keybind(&[], "H", "decrease-master-factor"),
+            ],
+        };
+        let diagnostics = analyze_keybinds(
+            vec![
+                keybind(&["Win"], "Q", "close"),
+                keybind(&["Win"], "1", "workspace-1"),
+            ],
+            vec![mode],
+        );
+        let warnings: Vec<_> = diagnostics.iter().collect();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].severity, Severity::Warning);
+        assert_eq!(warnings[0].path, "modes[0].keybinds[0]");
+        assert_eq!(
+            warnings[0].message,
+            "Win+Q runs 'exit-mode' in mode 'resize', shadowing 'close' from keybinds[0]"
+        );
+    }
+
+    #[test]
+    fn test_unknown_commands() {
+        let diagnostics = analyze_keybinds(
+            vec![
+                keybind(&["Win"], "Q", "closse"),
+                keybind(&["Win"], "Enter", "exec"),
+            ],
+            vec![],
+        );
+        let warnings: Vec<_> = diagnostics.iter().collect();
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].path, "keybinds[0].command");
+        assert_eq!(warnings[0].message, "Unknown command: closse");
+        assert_eq!(
+            warnings[0].suggestion.as_deref(),
+            Some("did you mean 'close'?")
+        );
+        assert_eq!(warnings[1].message, "Unknown command: exec");
+        assert!(!diagnostics.has_errors());
+    }
+
+    #[test]
+    fn test_sequences_are_compared_whole() {
+        let mut first = keybind(&["Win"], "Space", "workspace-1");
+        first.sequence = vec!["W".to_string(), "1".to_string()];
+        let mut second = keybind(&["Super"], "space", "workspace-2");
+        second.sequence = vec!["w".to_string(), "2".to_string()];
+        let mut third = keybind(&["Win"], "Space", "workspace-3");
+        third.sequence = vec![" w".to_string(), "1".to_string()];
+
+        let diagnostics = analyze_keybinds(vec![first, second, third], vec![]);
+        let errors: Vec<_> = diagnostics.iter().collect();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path, "keybinds[2]");
+        assert_eq!(
+            errors[0].message,
+            "Win+Space, w, 1 is the same keybinding as Win+Space, W, 1 at keybinds[0]"
+        );
+    }
+}
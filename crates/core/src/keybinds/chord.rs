@@ -0,0 +1,448 @@
+//! Key chord sequences (leader key bindings).
+//!
+//! A keybinding with a `sequence` runs its command only after further keys
+//! are pressed, e.g. `Win+Space, W, 3`. The first chord is registered as a
+//! global hotkey; once it is pressed the `ChordMatcher` waits for one of the
+//! continuations until the sequence completes, an unrelated key cancels it
+//! or the timeout expires.
+//!
+//! # Example
+//!
+//! ```
+//! use std::time::{Duration, Instant};
+//! use tenraku_core::config::schema::Keybind;
+//! use tenraku_core::keybinds::chord::{Chord, ChordMatcher, ChordStep};
+//!
+//! let keybind = Keybind {
+//!     modifiers: vec!["Win".to_string()],
+//!     key: "Space".to_string(),
+//!     sequence: vec!["W".to_string(), "3".to_string()],
+//!     command: "workspace".to_string(),
+//!     args: vec!["3".to_string()],
+//!     only_when: None,
+//!     except_when: None,
+//! };
+//!
+//! let mut matcher = ChordMatcher::new(&[keybind], Duration::from_secs(1));
+//! let now = Instant::now();
+//! for keys in ["Win+Space", "W"] {
+//!     let step = matcher.press(&Chord::parse(keys).unwrap(), now);
+//!     assert_eq!(step, ChordStep::Pending);
+//! }
+//! match matcher.press(&Chord::parse("3").unwrap(), now) {
+//!     ChordStep::Matched(keybind) => assert_eq!(keybind.command, "workspace"),
+//!     step => panic!("unexpected step: {:?}", step),
+//! }
+//! ```
+
+use super::keys::{canonical_key, Modifier};
+use super::parser::parse_keybind_string;
+use crate::config::schema::Keybind;
+use anyhow::Result;
+use std::fmt;
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+/// A key pressed together with its modifiers, e.g. `Win+Space`
+#[derive(Debug, Clone)]
+pub struct Chord {
+    modifiers: Vec<String>,
+    key: String,
+}
+
+impl Chord {
+    /// Create a chord from modifier names and a key.
+    ///
+    /// Known modifiers and keys are given their canonical names, so equal
+    /// chords compare equal however they were written.
+    pub fn new(modifiers: &[String], key: &str) -> Self {
+        let mut known: Vec<Modifier> = modifiers
+            .iter()
+            .filter_map(|m| Modifier::parse(m))
+            .collect();
+        known.sort();
+        known.dedup();
+
+        // Unknown modifiers are kept as written, after the known ones
+        let unknown = modifiers
+            .iter()
+            .filter(|m| Modifier::parse(m).is_none())
+            .cloned();
+        let modifiers = known
+            .iter()
+            .map(|m| m.name().to_string())
+            .chain(unknown)
+            .collect();
+
+        Self {
+            modifiers,
+            key: canonical_key(key).unwrap_or(key).to_string(),
+        }
+    }
+
+    /// Parse a chord in the format "Modifier+Modifier+Key".
+    pub fn parse(text: &str) -> Result<Self> {
+        let (modifiers, key) = parse_keybind_string(text.trim())?;
+        let modifiers: Vec<String> = modifiers.iter().map(|m| m.trim().to_string()).collect();
+        let key = key.trim();
+        if key.is_empty() {
+            anyhow::bail!("Missing key in '{}'", text);
+        }
+        Ok(Self::new(&modifiers, key))
+    }
+
+    /// Get the modifier keys, in the order Win, Ctrl, Alt, Shift.
+    pub fn modifiers(&self) -> &[String] {
+        &self.modifiers
+    }
+
+    /// Get the key.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Get the full key sequence of a keybinding: its modifiers and key
+    /// followed by each chord of its `sequence`.
+    pub fn sequence_of(keybind: &Keybind) -> Result<Vec<Chord>> {
+        let mut chords = vec![Chord::new(&keybind.modifiers, &keybind.key)];
+        for step in &keybind.sequence {
+            chords.push(Chord::parse(step)?);
+        }
+        Ok(chords)
+    }
+}
+
+impl PartialEq for Chord {
+    fn eq(&self, other: &Self) -> bool {
+        self.modifiers == other.modifiers && self.key.eq_ignore_ascii_case(&other.key)
+    }
+}
+
+impl Eq for Chord {}
+
+impl fmt::Display for Chord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for modifier in &self.modifiers {
+            write!(f, "{}+", modifier)?;
+        }
+        f.write_str(&self.key)
+    }
+}
+
+/// Format a key sequence as its chords separated by commas, e.g. `Win+Space, W, 3`.
+pub fn format_sequence(chords: &[Chord]) -> String {
+    chords
+        .iter()
+        .map(Chord::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// A key that can follow the pending prefix
+#[derive(Debug, Clone, PartialEq)]
+pub struct Continuation {
+    /// The chord to press next
+    pub chord: Chord,
+    /// The command it runs with its arguments, or `None` if more keys follow
+    pub command: Option<String>,
+}
+
+/// Result of pressing a chord
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChordStep {
+    /// The chord continues a sequence; waiting for the next key
+    Pending,
+    /// The chord completed a sequence, whose keybinding should run
+    Matched(Box<Keybind>),
+    /// The chord does not continue any sequence; the prefix was dropped
+    Cancelled,
+}
+
+/// Change of the pending sequence reported to the chord listener
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChordEvent {
+    /// Keys of a sequence were pressed; one of the continuations is expected
+    Pending {
+        prefix: Vec<Chord>,
+        continuations: Vec<Continuation>,
+    },
+    /// The pending sequence ended; `completed` if its command ran, otherwise
+    /// it was cancelled or timed out
+    Finished { completed: bool },
+}
+
+/// Callback receiving changes of the pending key sequence.
+pub type ChordListener = Box<dyn Fn(ChordEvent) + Send>;
+
+/// State machine matching pressed chords against keybinding sequences
+#[derive(Debug, Clone)]
+pub struct ChordMatcher {
+    /// Full key sequences and the keybindings they run
+    sequences: Vec<(Vec<Chord>, Keybind)>,
+    /// Chords pressed so far
+    pending: Vec<Chord>,
+    /// When the pending prefix expires
+    deadline: Option<Instant>,
+    /// How long to wait for the next key; zero waits indefinitely
+    timeout: Duration,
+}
+
+impl ChordMatcher {
+    /// Create a matcher for the keybindings that have a `sequence`.
+    ///
+    /// Keybindings with chords that fail to parse are skipped.
+    pub fn new(keybinds: &[Keybind], timeout: Duration) -> Self {
+        let sequences = keybinds
+            .iter()
+            .filter(|keybind| !keybind.sequence.is_empty())
+            .filter_map(|keybind| match Chord::sequence_of(keybind) {
+                Ok(chords) => Some((chords, keybind.clone())),
+                Err(e) => {
+                    warn!("Skipping key sequence {:?}: {}", keybind.sequence, e);
+                    None
+                }
+            })
+            .collect();
+
+        Self {
+            sequences,
+            pending: Vec::new(),
+            deadline: None,
+            timeout,
+        }
+    }
+
+    /// Get the first chord of every sequence, each listed once.
+    pub fn prefixes(&self) -> Vec<Chord> {
+        let mut prefixes: Vec<Chord> = Vec::new();
+        for (chords, _) in &self.sequences {
+            if !prefixes.contains(&chords[0]) {
+                prefixes.push(chords[0].clone());
+            }
+        }
+        prefixes
+    }
+
+    /// Get the chords pressed so far.
+    pub fn pending(&self) -> &[Chord] {
+        &self.pending
+    }
+
+    /// Check if a sequence has been started and not finished.
+    pub fn is_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Get the keys that can follow the pending prefix, in configuration order.
+    pub fn continuations(&self) -> Vec<Continuation> {
+        let depth = self.pending.len();
+        let mut continuations: Vec<Continuation> = Vec::new();
+
+        for (chords, keybind) in &self.sequences {
+            if chords.len() <= depth || !chords.starts_with(&self.pending) {
+                continue;
+            }
+            let chord = &chords[depth];
+            if continuations.iter().any(|c| &c.chord == chord) {
+                continue;
+            }
+            let command = (chords.len() == depth + 1).then(|| {
+                std::iter::once(keybind.command.as_str())
+                    .chain(keybind.args.iter().map(String::as_str))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            });
+            continuations.push(Continuation {
+                chord: chord.clone(),
+                command,
+            });
+        }
+
+        continuations
+    }
+
+    /// Advance the state machine with a pressed chord.
+    pub fn press(&mut self, chord: &Chord, now: Instant) -> ChordStep {
+        self.expire(now);
+        self.pending.push(chord.clone());
+
+        if let Some((_, keybind)) = self
+            .sequences
+            .iter()
+            .find(|(chords, _)| chords == &self.pending)
+        {
+            let keybind = keybind.clone();
+            self.cancel();
+            return ChordStep::Matched(Box::new(keybind));
+        }
+
+        if self
+            .sequences
+            .iter()
+            .any(|(chords, _)| chords.starts_with(&self.pending))
+        {
+            self.deadline = (!self.timeout.is_zero()).then(|| now + self.timeout);
+            return ChordStep::Pending;
+        }
+
+        self.cancel();
+        ChordStep::Cancelled
+    }
+
+    /// Drop the pending prefix if the timeout expired.
+    ///
+    /// Returns true if a pending prefix was dropped.
+    pub fn expire(&mut self, now: Instant) -> bool {
+        match self.deadline {
+            Some(deadline) if now >= deadline => {
+                self.cancel();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Drop the pending prefix.
+    pub fn cancel(&mut self) {
+        self.pending.clear();
+        self.deadline = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sequence(keys: &[&str], command: &str, args: &[&str]) -> Keybind {
+        let first = Chord::parse(keys[0]).unwrap();
+        Keybind {
+            modifiers: first.modifiers().to_vec(),
+            key: first.key().to_string(),
+            sequence: keys[1..].iter().map(|k| k.to_string()).collect(),
+            command: command.to_string(),
+            args: args.iter().map(|a| a.to_string()).collect(),
+            only_when: None,
+            except_when: None,
+        }
+    }
+
+    fn chord(keys: &str) -> Chord {
+        Chord::parse(keys).unwrap()
+    }
+
+    fn matcher() -> ChordMatcher {
+        ChordMatcher::new(
+            &[
+                sequence(&["Win+Space", "W", "3"], "workspace", &["3"]),
+                sequence(&["Win+Space", "W", "4"], "workspace", &["4"]),
+                sequence(&["Win+Space", "Shift+Q"], "close", &[]),
+                sequence(&["Win+Q"], "close", &[]),
+            ],
+            Duration::from_millis(500),
+        )
+    }
+
+    #[test]
+    fn test_chord_parse_and_display() {
+        let chord = chord("Shift+Win+space");
+        assert_eq!(chord.modifiers(), &["Win", "Shift"]);
+        assert_eq!(chord.to_string(), "Win+Shift+Space");
+        assert_eq!(chord, Chord::parse("Win+Shift+SPACE").unwrap());
+        assert!(Chord::parse("Win+").is_err());
+
+        // Aliases get their canonical names
+        assert_eq!(
+            Chord::parse("super+control+esc").unwrap().to_string(),
+            "Win+Ctrl+Escape"
+        );
+    }
+
+    #[test]
+    fn test_prefixes_only_cover_sequences() {
+        assert_eq!(matcher().prefixes(), vec![chord("Win+Space")]);
+    }
+
+    #[test]
+    fn test_sequence_matches() {
+        let mut matcher = matcher();
+        let now = Instant::now();
+
+        assert_eq!(matcher.press(&chord("Win+Space"), now), ChordStep::Pending);
+        assert_eq!(matcher.press(&chord("w"), now), ChordStep::Pending);
+        assert_eq!(format_sequence(matcher.pending()), "Win+Space, W");
+
+        match matcher.press(&chord("4"), now) {
+            ChordStep::Matched(keybind) => assert_eq!(keybind.args, vec!["4"]),
+            step => panic!("unexpected step: {:?}", step),
+        }
+        assert!(!matcher.is_pending());
+    }
+
+    #[test]
+    fn test_continuations() {
+        let mut matcher = matcher();
+        matcher.press(&chord("Win+Space"), Instant::now());
+
+        let continuations = matcher.continuations();
+        assert_eq!(
+            continuations,
+            vec![
+                Continuation {
+                    chord: chord("W"),
+                    command: None,
+                },
+                Continuation {
+                    chord: chord("Shift+Q"),
+                    command: Some("close".to_string()),
+                },
+            ]
+        );
+
+        matcher.press(&chord("W"), Instant::now());
+        let commands: Vec<_> = matcher
+            .continuations()
+            .into_iter()
+            .filter_map(|c| c.command)
+            .collect();
+        assert_eq!(commands, vec!["workspace 3", "workspace 4"]);
+    }
+
+    #[test]
+    fn test_unknown_key_cancels() {
+        let mut matcher = matcher();
+        let now = Instant::now();
+
+        matcher.press(&chord("Win+Space"), now);
+        assert_eq!(matcher.press(&chord("X"), now), ChordStep::Cancelled);
+        assert!(!matcher.is_pending());
+    }
+
+    #[test]
+    fn test_pending_prefix_times_out() {
+        let mut matcher = matcher();
+        let now = Instant::now();
+
+        matcher.press(&chord("Win+Space"), now);
+        assert!(!matcher.expire(now + Duration::from_millis(100)));
+        assert!(matcher.expire(now + Duration::from_millis(500)));
+        assert!(!matcher.is_pending());
+
+        // A late key starts over instead of continuing the expired prefix
+        matcher.press(&chord("Win+Space"), now);
+        let late = now + Duration::from_secs(1);
+        assert_eq!(matcher.press(&chord("W"), late), ChordStep::Cancelled);
+    }
+
+    #[test]
+    fn test_zero_timeout_waits_indefinitely() {
+        let mut matcher = ChordMatcher::new(
+            &[sequence(&["Win+Space", "W"], "workspace", &["1"])],
+            Duration::ZERO,
+        );
+        let now = Instant::now();
+
+        matcher.press(&chord("Win+Space"), now);
+        assert!(!matcher.expire(now + Duration::from_secs(3600)));
+        assert!(matcher.is_pending());
+    }
+}
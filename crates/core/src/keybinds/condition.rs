@@ -0,0 +1,183 @@
+//! Per-application keybinding conditions and pass-through.
+//!
+//! A keybinding with `only_when` is only registered while the focused window
+//! matches it, and one with `except_when` is unregistered while the focused
+//! window matches it. An unregistered hotkey is not intercepted, so its keys
+//! are passed through to the focused application. `toggle-passthrough`
+//! suspends every keybinding except the ones running it.
+//!
+//! # Example
+//!
+//! ```
+//! use tenraku_core::config::schema::{Keybind, WindowMatch};
+//! use tenraku_core::keybinds::condition::ConditionalKeybind;
+//!
+//! let keybind = Keybind {
+//!     modifiers: vec!["Win".to_string()],
+//!     key: "Tab".to_string(),
+//!     sequence: vec![],
+//!     command: "focus-next".to_string(),
+//!     args: vec![],
+//!     only_when: None,
+//!     except_when: Some(WindowMatch {
+//!         match_process: Some("mstsc\\.exe".to_string()),
+//!         ..WindowMatch::default()
+//!     }),
+//! };
+//!
+//! let keybind = ConditionalKeybind::new(keybind).unwrap();
+//! // Registered while no window is focused, and outside of pass-through
+//! assert!(keybind.is_active(None, false));
+//! assert!(!keybind.is_active(None, true));
+//! ```
+
+use crate::config::schema::Keybind;
+use crate::rules::WindowMatcher;
+use crate::window_manager::ManagedWindow;
+use anyhow::{Context, Result};
+
+/// Command that suspends and resumes all other keybindings
+pub const PASSTHROUGH_COMMAND: &str = "toggle-passthrough";
+
+/// A keybinding with its compiled `only_when` and `except_when` criteria
+#[derive(Debug, Clone)]
+pub struct ConditionalKeybind {
+    /// The keybinding
+    pub keybind: Keybind,
+
+    /// Focused windows the keybinding is limited to
+    only_when: Option<WindowMatcher>,
+
+    /// Focused windows the keybinding's keys are passed through to
+    except_when: Option<WindowMatcher>,
+}
+
+impl ConditionalKeybind {
+    /// Compile the conditions of a keybinding.
+    ///
+    /// Returns an error if a condition has an invalid regex.
+    pub fn new(keybind: Keybind) -> Result<Self> {
+        let only_when = keybind
+            .only_when
+            .as_ref()
+            .map(WindowMatcher::new)
+            .transpose()
+            .context("Invalid only_when")?;
+        let except_when = keybind
+            .except_when
+            .as_ref()
+            .map(WindowMatcher::new)
+            .transpose()
+            .context("Invalid except_when")?;
+
+        Ok(Self {
+            keybind,
+            only_when,
+            except_when,
+        })
+    }
+
+    /// Check whether the keybinding should be registered.
+    ///
+    /// # Arguments
+    /// * `focused` - The focused window, if any; `only_when` never matches
+    ///   when there is none
+    /// * `passthrough` - Whether pass-through is on, which suspends every
+    ///   keybinding but `toggle-passthrough`
+    pub fn is_active(&self, focused: Option<&ManagedWindow>, passthrough: bool) -> bool {
+        if passthrough && self.keybind.command != PASSTHROUGH_COMMAND {
+            return false;
+        }
+        let matches =
+            |matcher: &WindowMatcher| focused.is_some_and(|window| matcher.matches(window));
+        self.only_when.as_ref().is_none_or(matches)
+            && !self.except_when.as_ref().is_some_and(matches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::schema::WindowMatch;
+    use crate::window_manager::test_utils::test_window;
+
+    fn process(pattern: &str) -> Option<WindowMatch> {
+        Some(WindowMatch {
+            match_process: Some(pattern.to_string()),
+            ..WindowMatch::default()
+        })
+    }
+
+    fn keybind(command: &str) -> Keybind {
+        Keybind {
+            modifiers: vec!["Win".to_string()],
+            key: "Q".to_string(),
+            sequence: vec![],
+            command: command.to_string(),
+            args: vec![],
+            only_when: None,
+            except_when: None,
+        }
+    }
+
+    #[test]
+    fn test_unconditional_keybind() {
+        let keybind = ConditionalKeybind::new(keybind("close")).unwrap();
+        assert!(keybind.is_active(None, false));
+        assert!(keybind.is_active(Some(&test_window(1, "game.exe", "Game")), false));
+    }
+
+    #[test]
+    fn test_except_when() {
+        let keybind = ConditionalKeybind::new(Keybind {
+            except_when: process(r"(?i)^(game|mstsc)\.exe$"),
+            ..keybind("close")
+        })
+        .unwrap();
+        assert!(!keybind.is_active(Some(&test_window(1, "game.exe", "Game")), false));
+        assert!(!keybind.is_active(Some(&test_window(1, "MSTSC.exe", "Remote")), false));
+        assert!(keybind.is_active(Some(&test_window(1, "code.exe", "Editor")), false));
+        assert!(keybind.is_active(None, false));
+    }
+
+    #[test]
+    fn test_only_when() {
+        let keybind = ConditionalKeybind::new(Keybind {
+            only_when: Some(WindowMatch {
+                match_process: Some(r"firefox\.exe".to_string()),
+                match_title: Some("YouTube".to_string()),
+                match_class: None,
+            }),
+            ..keybind("float-center")
+        })
+        .unwrap();
+        assert!(keybind.is_active(Some(&test_window(1, "firefox.exe", "YouTube - Mozilla")), false));
+        assert!(!keybind.is_active(Some(&test_window(1, "firefox.exe", "News - Mozilla")), false));
+        assert!(!keybind.is_active(None, false));
+    }
+
+    #[test]
+    fn test_passthrough() {
+        let close = ConditionalKeybind::new(keybind("close")).unwrap();
+        let toggle = ConditionalKeybind::new(keybind(PASSTHROUGH_COMMAND)).unwrap();
+        assert!(!close.is_active(None, true));
+        assert!(toggle.is_active(None, true));
+
+        let limited = ConditionalKeybind::new(Keybind {
+            only_when: process(r"game\.exe"),
+            ..keybind(PASSTHROUGH_COMMAND)
+        })
+        .unwrap();
+        assert!(!limited.is_active(Some(&test_window(1, "code.exe", "Editor")), true));
+    }
+
+    #[test]
+    fn test_invalid_condition() {
+        let result = ConditionalKeybind::new(Keybind {
+            except_when: process("game(.exe"),
+            ..keybind("close")
+        });
+        let error = format!("{:#}", result.unwrap_err());
+        assert!(error.starts_with("Invalid except_when: Invalid regex in match_process"));
+    }
+}
@@ -0,0 +1,286 @@
+//! Key and modifier names.
+//!
+//! Keybindings name keys and modifiers the same way on every platform; this
+//! module maps those names to Windows virtual-key codes. Names are
+//! case-insensitive and most keys have aliases (`Esc`, `Return`, `PgUp`).
+//! The first name listed for a key is its canonical name, used when
+//! keybindings are displayed or compared.
+
+/// A modifier key held together with a key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Modifier {
+    Win,
+    Ctrl,
+    Alt,
+    Shift,
+}
+
+impl Modifier {
+    /// Every modifier, in the order they are displayed
+    pub const ALL: [Modifier; 4] = [
+        Modifier::Win,
+        Modifier::Ctrl,
+        Modifier::Alt,
+        Modifier::Shift,
+    ];
+
+    /// Parse a modifier name.
+    ///
+    /// Accepts the canonical names and their aliases in any case:
+    /// `Super`/`Mod4`/`Meta`/`Windows` for Win, `Control` for Ctrl and
+    /// `Mod1`/`Option` for Alt. Left and right variants (`LCtrl`,
+    /// `RightShift`, ...) are accepted too; they match either side, since
+    /// Windows global hotkeys do not tell the sides apart.
+    pub fn parse(name: &str) -> Option<Modifier> {
+        let name = name.to_lowercase();
+        let name = ["left", "right", "l", "r"]
+            .iter()
+            .find_map(|side| name.strip_prefix(side))
+            .filter(|rest| Modifier::parse_base(rest).is_some())
+            .unwrap_or(&name);
+        Modifier::parse_base(name)
+    }
+
+    fn parse_base(name: &str) -> Option<Modifier> {
+        Modifier::ALL
+            .into_iter()
+            .find(|modifier| modifier.aliases().contains(&name))
+    }
+
+    /// Get the lowercase names accepted for the modifier, without the
+    /// left and right variants.
+    pub fn aliases(self) -> &'static [&'static str] {
+        match self {
+            Modifier::Win => &["win", "windows", "super", "mod4", "meta", "logo"],
+            Modifier::Ctrl => &["ctrl", "control", "ctl"],
+            Modifier::Alt => &["alt", "mod1", "option"],
+            Modifier::Shift => &["shift"],
+        }
+    }
+
+    /// Get the canonical name of the modifier.
+    pub fn name(self) -> &'static str {
+        match self {
+            Modifier::Win => "Win",
+            Modifier::Ctrl => "Ctrl",
+            Modifier::Alt => "Alt",
+            Modifier::Shift => "Shift",
+        }
+    }
+}
+
+/// Key names and their virtual-key codes; the first name is canonical
+const KEYS: &[(&[&str], u16)] = &[
+    // Letters
+    (&["A"], 0x41),
+    (&["B"], 0x42),
+    (&["C"], 0x43),
+    (&["D"], 0x44),
+    (&["E"], 0x45),
+    (&["F"], 0x46),
+    (&["G"], 0x47),
+    (&["H"], 0x48),
+    (&["I"], 0x49),
+    (&["J"], 0x4A),
+    (&["K"], 0x4B),
+    (&["L"], 0x4C),
+    (&["M"], 0x4D),
+    (&["N"], 0x4E),
+    (&["O"], 0x4F),
+    (&["P"], 0x50),
+    (&["Q"], 0x51),
+    (&["R"], 0x52),
+    (&["S"], 0x53),
+    (&["T"], 0x54),
+    (&["U"], 0x55),
+    (&["V"], 0x56),
+    (&["W"], 0x57),
+    (&["X"], 0x58),
+    (&["Y"], 0x59),
+    (&["Z"], 0x5A),
+    // Numbers
+    (&["0"], 0x30),
+    (&["1"], 0x31),
+    (&["2"], 0x32),
+    (&["3"], 0x33),
+    (&["4"], 0x34),
+    (&["5"], 0x35),
+    (&["6"], 0x36),
+    (&["7"], 0x37),
+    (&["8"], 0x38),
+    (&["9"], 0x39),
+    // Function keys
+    (&["F1"], 0x70),
+    (&["F2"], 0x71),
+    (&["F3"], 0x72),
+    (&["F4"], 0x73),
+    (&["F5"], 0x74),
+    (&["F6"], 0x75),
+    (&["F7"], 0x76),
+    (&["F8"], 0x77),
+    (&["F9"], 0x78),
+    (&["F10"], 0x79),
+    (&["F11"], 0x7A),
+    (&["F12"], 0x7B),
+    (&["F13"], 0x7C),
+    (&["F14"], 0x7D),
+    (&["F15"], 0x7E),
+    (&["F16"], 0x7F),
+    (&["F17"], 0x80),
+    (&["F18"], 0x81),
+    (&["F19"], 0x82),
+    (&["F20"], 0x83),
+    (&["F21"], 0x84),
+    (&["F22"], 0x85),
+    (&["F23"], 0x86),
+    (&["F24"], 0x87),
+    // Arrow keys
+    (&["Left"], 0x25),
+    (&["Up"], 0x26),
+    (&["Right"], 0x27),
+    (&["Down"], 0x28),
+    // Special keys
+    (&["Space"], 0x20),
+    (&["Enter", "Return"], 0x0D),
+    (&["Escape", "Esc"], 0x1B),
+    (&["Tab"], 0x09),
+    (&["Backspace", "Back"], 0x08),
+    (&["Delete", "Del"], 0x2E),
+    (&["Insert", "Ins"], 0x2D),
+    (&["Home"], 0x24),
+    (&["End"], 0x23),
+    (&["PageUp", "PgUp", "Prior"], 0x21),
+    (&["PageDown", "PgDn", "Next"], 0x22),
+    (&["CapsLock", "Caps"], 0x14),
+    (&["NumLock"], 0x90),
+    (&["ScrollLock"], 0x91),
+    (&["PrintScreen", "Print", "PrtSc"], 0x2C),
+    (&["Pause", "Break"], 0x13),
+    (&["Menu", "Apps", "ContextMenu"], 0x5D),
+    (&["Sleep"], 0x5F),
+    // Numpad
+    (&["Numpad0", "Num0", "KP0"], 0x60),
+    (&["Numpad1", "Num1", "KP1"], 0x61),
+    (&["Numpad2", "Num2", "KP2"], 0x62),
+    (&["Numpad3", "Num3", "KP3"], 0x63),
+    (&["Numpad4", "Num4", "KP4"], 0x64),
+    (&["Numpad5", "Num5", "KP5"], 0x65),
+    (&["Numpad6", "Num6", "KP6"], 0x66),
+    (&["Numpad7", "Num7", "KP7"], 0x67),
+    (&["Numpad8", "Num8", "KP8"], 0x68),
+    (&["Numpad9", "Num9", "KP9"], 0x69),
+    (&["NumpadMultiply", "KPMultiply"], 0x6A),
+    (&["NumpadAdd", "KPAdd"], 0x6B),
+    (&["NumpadSeparator"], 0x6C),
+    (&["NumpadSubtract", "KPSubtract"], 0x6D),
+    (&["NumpadDecimal", "KPDecimal"], 0x6E),
+    (&["NumpadDivide", "KPDivide"], 0x6F),
+    // Punctuation (US layout positions)
+    (&["Semicolon", ";"], 0xBA),
+    (&["Equals", "=", "Plus"], 0xBB),
+    (&["Comma", ","], 0xBC),
+    (&["Minus", "-"], 0xBD),
+    (&["Period", "."], 0xBE),
+    (&["Slash", "/"], 0xBF),
+    (&["Grave", "`", "Backtick", "Tilde"], 0xC0),
+    (&["BracketLeft", "["], 0xDB),
+    (&["Backslash", "\\"], 0xDC),
+    (&["BracketRight", "]"], 0xDD),
+    (&["Quote", "'", "Apostrophe"], 0xDE),
+    (&["IntlBackslash"], 0xE2),
+    // Browser keys
+    (&["BrowserBack"], 0xA6),
+    (&["BrowserForward"], 0xA7),
+    (&["BrowserRefresh"], 0xA8),
+    (&["BrowserStop"], 0xA9),
+    (&["BrowserSearch"], 0xAA),
+    (&["BrowserFavorites"], 0xAB),
+    (&["BrowserHome"], 0xAC),
+    // Media keys
+    (&["VolumeMute", "Mute", "AudioMute"], 0xAD),
+    (&["VolumeDown", "AudioLowerVolume"], 0xAE),
+    (&["VolumeUp", "AudioRaiseVolume"], 0xAF),
+    (&["MediaNext", "NextTrack", "AudioNext"], 0xB0),
+    (
+        &["MediaPrevious", "MediaPrev", "PrevTrack", "AudioPrev"],
+        0xB1,
+    ),
+    (&["MediaStop", "AudioStop"], 0xB2),
+    (&["MediaPlayPause", "PlayPause", "AudioPlay"], 0xB3),
+    (&["LaunchMail", "Mail"], 0xB4),
+    (&["LaunchMedia", "MediaSelect"], 0xB5),
+    (&["LaunchApp1", "MyComputer"], 0xB6),
+    (&["LaunchApp2", "Calculator"], 0xB7),
+];
+
+/// Find the entry of a key name, ignoring case.
+fn find(name: &str) -> Option<&'static (&'static [&'static str], u16)> {
+    KEYS.iter()
+        .find(|(names, _)| names.iter().any(|known| known.eq_ignore_ascii_case(name)))
+}
+
+/// Get the virtual-key code of a key name.
+pub fn virtual_key(name: &str) -> Option<u16> {
+    find(name).map(|(_, vk)| *vk)
+}
+
+/// Get the canonical name of a key, e.g. `Escape` for `esc`.
+pub fn canonical_key(name: &str) -> Option<&'static str> {
+    find(name).map(|(names, _)| names[0])
+}
+
+/// Get the canonical names of all known keys.
+pub fn key_names() -> impl Iterator<Item = &'static str> {
+    KEYS.iter().map(|(names, _)| names[0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_modifier_aliases() {
+        assert_eq!(Modifier::parse("Win"), Some(Modifier::Win));
+        assert_eq!(Modifier::parse("super"), Some(Modifier::Win));
+        assert_eq!(Modifier::parse("Mod4"), Some(Modifier::Win));
+        assert_eq!(Modifier::parse("META"), Some(Modifier::Win));
+        assert_eq!(Modifier::parse("Control"), Some(Modifier::Ctrl));
+        assert_eq!(Modifier::parse("shift"), Some(Modifier::Shift));
+        assert_eq!(Modifier::parse("Hyper"), None);
+        assert_eq!(Modifier::parse(""), None);
+    }
+
+    #[test]
+    fn test_left_and_right_modifiers() {
+        assert_eq!(Modifier::parse("LCtrl"), Some(Modifier::Ctrl));
+        assert_eq!(Modifier::parse("RightShift"), Some(Modifier::Shift));
+        assert_eq!(Modifier::parse("LWin"), Some(Modifier::Win));
+        assert_eq!(Modifier::parse("ralt"), Some(Modifier::Alt));
+        assert_eq!(Modifier::parse("Left"), None);
+    }
+
+    #[test]
+    fn test_key_lookup() {
+        assert_eq!(virtual_key("q"), Some(0x51));
+        assert_eq!(virtual_key("F24"), Some(0x87));
+        assert_eq!(virtual_key("esc"), Some(0x1B));
+        assert_eq!(virtual_key("VolumeUp"), Some(0xAF));
+        assert_eq!(virtual_key(";"), Some(0xBA));
+        assert_eq!(virtual_key("F25"), None);
+        assert_eq!(canonical_key("pgup"), Some("PageUp"));
+        assert_eq!(canonical_key("w"), Some("W"));
+    }
+
+    #[test]
+    fn test_key_names_are_unique() {
+        let mut names = HashSet::new();
+        let mut codes = HashSet::new();
+        for (aliases, vk) in KEYS {
+            assert!(codes.insert(vk), "duplicate code {:#x}", vk);
+            for name in aliases.iter() {
+                assert!(names.insert(name.to_lowercase()), "duplicate name {}", name);
+            }
+        }
+    }
+}
@@ -3,22 +3,41 @@
 //! This module provides the KeybindManager which registers global hotkeys
 //! with Windows and maps them to commands. It handles:
 //! - Hotkey registration with Windows API
-//! - Virtual key code translation
+//! - Virtual key code translation (see `keys` for the key names)
 //! - Modifier key parsing
 //! - Hotkey unregistration and cleanup
 //! - Conflict detection
-
-use crate::config::schema::Keybind;
+//! - Binding modes, which swap the registered hotkeys for a mode's own set
+//! - Key sequences, which register the next keys while a prefix is pending
+//! - Per-application conditions and pass-through, which unregister hotkeys
+//!   so their keys reach the focused window
+
+use super::chord::{Chord, ChordEvent, ChordListener, ChordMatcher, ChordStep};
+use super::condition::ConditionalKeybind;
+use super::keys::{self, Modifier};
+use crate::config::schema::{BindingMode, Keybind, DEFAULT_MODE};
+use crate::window_manager::ManagedWindow;
 use anyhow::{Context, Result};
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 use tracing::{debug, error, info, warn};
 use windows::Win32::UI::Input::KeyboardAndMouse::*;
 
+/// Callback receiving the name of the binding mode entered.
+pub type ModeListener = Box<dyn Fn(&str) + Send>;
+
 /// Keybinding manager that handles Windows hotkey registration.
 ///
 /// The KeybindManager registers global hotkeys with the Windows API and
 /// maintains a mapping from hotkey IDs to commands. When a hotkey is
 /// pressed, the ID can be used to look up which command to execute.
+///
+/// Only one set of keybindings is registered at a time: the main
+/// keybindings, or the keybindings of the active binding mode plus its
+/// escape key. While a key sequence is pending only the keys that can
+/// continue it and Escape are registered. Of that set, keybindings whose
+/// `only_when` or `except_when` exclude the focused window, or all but
+/// `toggle-passthrough` while pass-through is on, are left unregistered.
 pub struct KeybindManager {
     /// Map of hotkey ID to command string
     bindings: HashMap<i32, String>,
@@ -28,6 +47,43 @@ pub struct KeybindManager {
 
     /// Next hotkey ID to assign
     next_id: i32,
+
+    /// Main keybindings, registered outside of binding modes
+    keybinds: Vec<Keybind>,
+
+    /// Binding modes that can be entered
+    modes: Vec<BindingMode>,
+
+    /// Name of the active binding mode, if any
+    active_mode: Option<String>,
+
+    /// Listener notified when the binding mode changes
+    mode_listener: Option<ModeListener>,
+
+    /// Map of hotkey ID to the chord of a key sequence
+    chords: HashMap<i32, Chord>,
+
+    /// Sequence state of the active keybindings
+    matcher: ChordMatcher,
+
+    /// How long to wait for the next key of a sequence
+    chord_timeout: Duration,
+
+    /// Listener notified when a key sequence is pending or ends
+    chord_listener: Option<ChordListener>,
+
+    /// Keybindings of the active mode, or the main keybindings, with their
+    /// compiled conditions
+    candidates: Vec<ConditionalKeybind>,
+
+    /// Which of the candidates are registered
+    registered: Vec<bool>,
+
+    /// The focused window the conditions are checked against
+    focused: Option<ManagedWindow>,
+
+    /// Whether all keybindings but `toggle-passthrough` are suspended
+    passthrough: bool,
 }
 
 impl KeybindManager {
@@ -37,6 +93,18 @@ impl KeybindManager {
             bindings: HashMap::new(),
             arguments: HashMap::new(),
             next_id: 1,
+            keybinds: Vec::new(),
+            modes: Vec::new(),
+            active_mode: None,
+            mode_listener: None,
+            chords: HashMap::new(),
+            matcher: ChordMatcher::new(&[], Duration::ZERO),
+            chord_timeout: Duration::ZERO,
+            chord_listener: None,
+            candidates: Vec::new(),
+            registered: Vec::new(),
+            focused: None,
+            passthrough: false,
         }
     }
 
@@ -45,14 +113,283 @@ impl KeybindManager {
     /// This will first unregister all existing keybindings, then register
     /// the new ones. If any keybinding fails to register, an error is returned
     /// but previously registered bindings from this call remain registered.
+    ///
+    /// While a binding mode is active the keybindings are kept and registered
+    /// when the mode is left. If the active mode no longer exists, the main
+    /// keybindings are registered straight away.
     pub fn register_keybinds(&mut self, keybinds: Vec<Keybind>) -> Result<()> {
+        self.keybinds = keybinds;
+
+        let mode_exists = self
+            .active_mode
+            .as_ref()
+            .is_none_or(|name| self.modes.iter().any(|mode| &mode.name == name));
+        if !mode_exists {
+            self.active_mode = None;
+            self.notify_mode();
+        }
+
+        self.register_active()
+    }
+
+    /// Set the binding modes that can be entered.
+    ///
+    /// Call before `register_keybinds`, which registers the keybindings of
+    /// the active mode.
+    pub fn set_modes(&mut self, modes: Vec<BindingMode>) {
+        self.modes = modes;
+    }
+
+    /// Set how long to wait for the next key of a sequence; zero waits
+    /// indefinitely.
+    ///
+    /// Call before `register_keybinds`, which applies it.
+    pub fn set_chord_timeout(&mut self, timeout: Duration) {
+        self.chord_timeout = timeout;
+    }
+
+    /// Enter a binding mode, registering its keybindings instead of the
+    /// current ones. Entering `default` leaves the active mode.
+    pub fn enter_mode(&mut self, name: &str) -> Result<()> {
+        if name == DEFAULT_MODE {
+            return self.exit_mode();
+        }
+        if !self.modes.iter().any(|mode| mode.name == name) {
+            anyhow::bail!("Unknown mode: {}", name);
+        }
+        if self.active_mode.as_deref() == Some(name) {
+            return Ok(());
+        }
+
+        self.active_mode = Some(name.to_string());
+        info!("Entered binding mode: {}", name);
+        self.notify_mode();
+        self.register_active()
+    }
+
+    /// Leave the active binding mode and register the main keybindings again.
+    pub fn exit_mode(&mut self) -> Result<()> {
+        if self.active_mode.take().is_none() {
+            return Ok(());
+        }
+
+        info!("Returned to the main keybindings");
+        self.notify_mode();
+        self.register_active()
+    }
+
+    /// Get the name of the active binding mode, `default` outside of modes.
+    pub fn active_mode(&self) -> &str {
+        self.active_mode.as_deref().unwrap_or(DEFAULT_MODE)
+    }
+
+    /// Check whether any keybinding, main or of a mode, has `only_when` or
+    /// `except_when`.
+    pub fn has_conditions(&self) -> bool {
+        self.keybinds
+            .iter()
+            .chain(self.modes.iter().flat_map(|mode| &mode.keybinds))
+            .any(Keybind::is_conditional)
+    }
+
+    /// Set the focused window, registering or unregistering the keybindings
+    /// whose conditions it changes.
+    ///
+    /// While a key sequence is pending the change is applied once the
+    /// sequence ends.
+    pub fn set_focused_window(&mut self, window: Option<&ManagedWindow>) -> Result<()> {
+        self.focused = window.cloned();
+        if self.matcher.is_pending() || self.active_candidates() == self.registered {
+            return Ok(());
+        }
+
+        debug!(
+            "Focused window {:?} changes the active keybindings",
+            self.focused.as_ref().map(|window| &window.process_name)
+        );
+        self.register_candidates()
+    }
+
+    /// Suspend all keybindings but `toggle-passthrough`, or resume them.
+    pub fn toggle_passthrough(&mut self) -> Result<()> {
+        self.passthrough = !self.passthrough;
+        if self.passthrough {
+            info!("Pass-through on: keys go to the focused window");
+        } else {
+            info!("Pass-through off: keybindings resumed");
+        }
+
+        if self.matcher.is_pending() {
+            self.notify_chord(ChordEvent::Finished { completed: false });
+        }
+        self.register_candidates()
+    }
+
+    /// Check whether pass-through is on.
+    pub fn is_passthrough(&self) -> bool {
+        self.passthrough
+    }
+
+    /// Set the listener notified when the binding mode changes.
+    pub fn set_mode_listener(&mut self, listener: impl Fn(&str) + Send + 'static) {
+        self.mode_listener = Some(Box::new(listener));
+    }
+
+    /// Notify the mode listener, if one is connected.
+    fn notify_mode(&self) {
+        if let Some(ref listener) = self.mode_listener {
+            listener(self.active_mode());
+        }
+    }
+
+    /// Set the listener notified when a key sequence is pending or ends.
+    pub fn set_chord_listener(&mut self, listener: impl Fn(ChordEvent) + Send + 'static) {
+        self.chord_listener = Some(Box::new(listener));
+    }
+
+    /// Notify the chord listener, if one is connected.
+    fn notify_chord(&self, event: ChordEvent) {
+        if let Some(ref listener) = self.chord_listener {
+            listener(event);
+        }
+    }
+
+    /// Handle a pressed hotkey.
+    ///
+    /// Returns the command and arguments to execute, or None if the hotkey
+    /// started or continued a key sequence, cancelled one or is unknown.
+    pub fn press_hotkey(&mut self, hotkey_id: i32) -> Option<(String, Vec<String>)> {
+        if let Some((command, args)) = self.get_command(hotkey_id) {
+            return Some((command.clone(), args.clone()));
+        }
+
+        let Some(chord) = self.chords.get(&hotkey_id).cloned() else {
+            warn!("Received hotkey event for unknown ID: {}", hotkey_id);
+            return None;
+        };
+
+        let (result, command) = match self.matcher.press(&chord, Instant::now()) {
+            ChordStep::Pending => (self.register_continuations(), None),
+            ChordStep::Matched(keybind) => {
+                (self.end_sequence(true), Some((keybind.command, keybind.args)))
+            }
+            ChordStep::Cancelled => {
+                debug!("Key sequence cancelled by {}", chord);
+                (self.end_sequence(false), None)
+            }
+        };
+        if let Err(e) = result {
+            error!("Failed to register keybindings: {}", e);
+        }
+        command
+    }
+
+    /// Drop the pending key sequence if its timeout expired, registering
+    /// the keybindings again.
+    pub fn check_chord_timeout(&mut self) {
+        if self.matcher.expire(Instant::now()) {
+            debug!("Key sequence timed out");
+            if let Err(e) = self.end_sequence(false) {
+                error!("Failed to register keybindings: {}", e);
+            }
+        }
+    }
+
+    /// Register the keys that continue the pending sequence, and Escape to
+    /// cancel it, in place of the current ones.
+    fn register_continuations(&mut self) -> Result<()> {
+        let continuations = self.matcher.continuations();
+        self.unregister_all()?;
+
+        let escape = Chord::new(&[], "Escape");
+        let mut chords: Vec<Chord> = continuations.iter().map(|c| c.chord.clone()).collect();
+        if !chords.contains(&escape) {
+            chords.push(escape);
+        }
+        for chord in &chords {
+            if let Err(e) = self.register_chord(chord) {
+                warn!("Failed to register key sequence continuation {}: {}", chord, e);
+            }
+        }
+
+        self.notify_chord(ChordEvent::Pending {
+            prefix: self.matcher.pending().to_vec(),
+            continuations,
+        });
+        Ok(())
+    }
+
+    /// Register the active keybindings again once a sequence ended.
+    fn end_sequence(&mut self, completed: bool) -> Result<()> {
+        self.notify_chord(ChordEvent::Finished { completed });
+        self.register_candidates()
+    }
+
+    /// Get the keybindings of the active mode, or the main keybindings
+    /// outside of modes.
+    fn active_keybinds(&self) -> Vec<Keybind> {
+        self.active_mode
+            .as_ref()
+            .and_then(|name| self.modes.iter().find(|mode| &mode.name == name))
+            .map(BindingMode::active_keybinds)
+            .unwrap_or_else(|| self.keybinds.clone())
+    }
+
+    /// Register the keybindings of the active mode in place of the current ones.
+    fn register_active(&mut self) -> Result<()> {
+        self.candidates = self
+            .active_keybinds()
+            .into_iter()
+            .filter_map(|keybind| {
+                let keys = format!("{:?}+{}", keybind.modifiers, keybind.key);
+                ConditionalKeybind::new(keybind)
+                    .inspect_err(|e| warn!("Skipping keybind {}: {:#}", keys, e))
+                    .ok()
+            })
+            .collect();
+
+        // A pending sequence may no longer exist
+        if self.matcher.is_pending() {
+            self.notify_chord(ChordEvent::Finished { completed: false });
+        }
+
+        self.register_candidates()
+    }
+
+    /// Check which candidates apply to the focused window.
+    fn active_candidates(&self) -> Vec<bool> {
+        self.candidates
+            .iter()
+            .map(|candidate| candidate.is_active(self.focused.as_ref(), self.passthrough))
+            .collect()
+    }
+
+    /// Register the candidates that apply to the focused window in place of
+    /// the current keybindings.
+    fn register_candidates(&mut self) -> Result<()> {
+        self.registered = self.active_candidates();
+        let keybinds: Vec<Keybind> = self
+            .candidates
+            .iter()
+            .zip(&self.registered)
+            .filter(|(_, &active)| active)
+            .map(|(candidate, _)| candidate.keybind.clone())
+            .collect();
+        self.matcher = ChordMatcher::new(&keybinds, self.chord_timeout);
+
+        self.register_all(keybinds)
+    }
+
+    /// Register keybindings, and the first key of their sequences, in place
+    /// of the current ones.
+    fn register_all(&mut self, keybinds: Vec<Keybind>) -> Result<()> {
         // Unregister existing keybinds
         self.unregister_all()?;
 
         let mut registered = 0;
         let mut failed = Vec::new();
 
-        for keybind in keybinds {
+        for keybind in keybinds.into_iter().filter(|k| k.sequence.is_empty()) {
             match self.register_keybind(keybind.clone()) {
                 Ok(()) => registered += 1,
                 Err(e) => {
@@ -68,6 +405,16 @@ impl KeybindManager {
             }
         }
 
+        for chord in self.matcher.prefixes() {
+            match self.register_chord(&chord) {
+                Ok(()) => registered += 1,
+                Err(e) => {
+                    warn!("Failed to register key sequence prefix {}: {}", chord, e);
+                    failed.push(format!("{}: {}", chord, e));
+                }
+            }
+        }
+
         if !failed.is_empty() {
             error!(
                 "Failed to register {} keybinding(s): {:?}",
@@ -82,8 +429,33 @@ impl KeybindManager {
 
     /// Register a single keybinding.
     fn register_keybind(&mut self, keybind: Keybind) -> Result<()> {
-        let modifiers = self.parse_modifiers(&keybind.modifiers)?;
-        let vk_code = self.parse_key(&keybind.key)?;
+        let hotkey_id = self.register_hotkey(&keybind.modifiers, &keybind.key)?;
+
+        self.bindings.insert(hotkey_id, keybind.command.clone());
+        self.arguments.insert(hotkey_id, keybind.args.clone());
+
+        debug!(
+            "Registered hotkey {}: {:?}+{} -> {} {:?}",
+            hotkey_id, keybind.modifiers, keybind.key, keybind.command, keybind.args
+        );
+
+        Ok(())
+    }
+
+    /// Register a key of a sequence.
+    fn register_chord(&mut self, chord: &Chord) -> Result<()> {
+        let hotkey_id = self.register_hotkey(chord.modifiers(), chord.key())?;
+        self.chords.insert(hotkey_id, chord.clone());
+
+        debug!("Registered hotkey {}: {} (key sequence)", hotkey_id, chord);
+
+        Ok(())
+    }
+
+    /// Register a global hotkey with Windows and return its ID.
+    fn register_hotkey(&mut self, modifier_names: &[String], key: &str) -> Result<i32> {
+        let modifiers = self.parse_modifiers(modifier_names)?;
+        let vk_code = self.parse_key(key)?;
 
         let hotkey_id = self.next_id;
         self.next_id += 1;
@@ -93,25 +465,17 @@ impl KeybindManager {
                 .with_context(|| {
                     format!(
                         "Failed to register hotkey: {:?}+{} (may be in use)",
-                        keybind.modifiers, keybind.key
+                        modifier_names, key
                     )
                 })?;
         }
 
-        self.bindings.insert(hotkey_id, keybind.command.clone());
-        self.arguments.insert(hotkey_id, keybind.args.clone());
-
-        debug!(
-            "Registered hotkey {}: {:?}+{} -> {} {:?}",
-            hotkey_id, keybind.modifiers, keybind.key, keybind.command, keybind.args
-        );
-
-        Ok(())
+        Ok(hotkey_id)
     }
 
     /// Unregister all keybindings.
     pub fn unregister_all(&mut self) -> Result<()> {
-        for &hotkey_id in self.bindings.keys() {
+        for &hotkey_id in self.bindings.keys().chain(self.chords.keys()) {
             unsafe {
                 if let Err(e) = UnregisterHotKey(None, hotkey_id) {
                     warn!("Failed to unregister hotkey {}: {}", hotkey_id, e);
@@ -119,9 +483,10 @@ impl KeybindManager {
             }
         }
 
-        let count = self.bindings.len();
+        let count = self.bindings.len() + self.chords.len();
         self.bindings.clear();
         self.arguments.clear();
+        self.chords.clear();
         self.next_id = 1;
 
         if count > 0 {
@@ -144,17 +509,18 @@ impl KeybindManager {
 
     /// Parse modifier keys from string representations.
     ///
-    /// Supported modifiers: "Win", "Ctrl", "Alt", "Shift"
+    /// Supported modifiers: "Win", "Ctrl", "Alt", "Shift", in any case and
+    /// with the aliases accepted by `Modifier::parse`
     fn parse_modifiers(&self, modifiers: &[String]) -> Result<HOT_KEY_MODIFIERS> {
         let mut result = HOT_KEY_MODIFIERS(0);
 
         for modifier in modifiers {
-            match modifier.as_str() {
-                "Ctrl" => result |= MOD_CONTROL,
-                "Alt" => result |= MOD_ALT,
-                "Shift" => result |= MOD_SHIFT,
-                "Win" => result |= MOD_WIN,
-                _ => anyhow::bail!("Unknown modifier: {}", modifier),
+            match Modifier::parse(modifier) {
+                Some(Modifier::Ctrl) => result |= MOD_CONTROL,
+                Some(Modifier::Alt) => result |= MOD_ALT,
+                Some(Modifier::Shift) => result |= MOD_SHIFT,
+                Some(Modifier::Win) => result |= MOD_WIN,
+                None => anyhow::bail!("Unknown modifier: {}", modifier),
             }
         }
 
@@ -163,110 +529,18 @@ impl KeybindManager {
 
     /// Parse a key string to a virtual key code.
     ///
-    /// Supports:
-    /// - Letters A-Z
-    /// - Numbers 0-9
-    /// - Arrow keys (Left, Right, Up, Down)
-    /// - Special keys (Space, Enter, Escape, Tab, etc.)
-    /// - Function keys (F1-F12)
-    /// - Brackets and other symbols
+    /// Supports the key names of the `keys` table: letters, numbers,
+    /// function keys F1-F24, arrows, special, numpad, punctuation, browser
+    /// and media keys.
     fn parse_key(&self, key: &str) -> Result<VIRTUAL_KEY> {
-        let vk = match key.to_uppercase().as_str() {
-            // Letters
-            "A" => VK_A,
-            "B" => VK_B,
-            "C" => VK_C,
-            "D" => VK_D,
-            "E" => VK_E,
-            "F" => VK_F,
-            "G" => VK_G,
-            "H" => VK_H,
-            "I" => VK_I,
-            "J" => VK_J,
-            "K" => VK_K,
-            "L" => VK_L,
-            "M" => VK_M,
-            "N" => VK_N,
-            "O" => VK_O,
-            "P" => VK_P,
-            "Q" => VK_Q,
-            "R" => VK_R,
-            "S" => VK_S,
-            "T" => VK_T,
-            "U" => VK_U,
-            "V" => VK_V,
-            "W" => VK_W,
-            "X" => VK_X,
-            "Y" => VK_Y,
-            "Z" => VK_Z,
-
-            // Numbers
-            "0" => VK_0,
-            "1" => VK_1,
-            "2" => VK_2,
-            "3" => VK_3,
-            "4" => VK_4,
-            "5" => VK_5,
-            "6" => VK_6,
-            "7" => VK_7,
-            "8" => VK_8,
-            "9" => VK_9,
-
-            // Arrow keys
-            "LEFT" => VK_LEFT,
-            "RIGHT" => VK_RIGHT,
-            "UP" => VK_UP,
-            "DOWN" => VK_DOWN,
-
-            // Special keys
-            "SPACE" => VK_SPACE,
-            "ENTER" | "RETURN" => VK_RETURN,
-            "ESCAPE" | "ESC" => VK_ESCAPE,
-            "TAB" => VK_TAB,
-            "BACKSPACE" | "BACK" => VK_BACK,
-            "DELETE" | "DEL" => VK_DELETE,
-            "HOME" => VK_HOME,
-            "END" => VK_END,
-            "PAGEUP" | "PGUP" => VK_PRIOR,
-            "PAGEDOWN" | "PGDN" => VK_NEXT,
-            "INSERT" | "INS" => VK_INSERT,
-
-            // Brackets and symbols
-            "BRACKETLEFT" | "[" => VK_OEM_4,
-            "BRACKETRIGHT" | "]" => VK_OEM_6,
-            "SEMICOLON" | ";" => VK_OEM_1,
-            "QUOTE" | "'" => VK_OEM_7,
-            "COMMA" | "," => VK_OEM_COMMA,
-            "PERIOD" | "." => VK_OEM_PERIOD,
-            "SLASH" | "/" => VK_OEM_2,
-            "BACKSLASH" | "\\" => VK_OEM_5,
-            "MINUS" | "-" => VK_OEM_MINUS,
-            "EQUALS" | "=" => VK_OEM_PLUS,
-            "GRAVE" | "`" => VK_OEM_3,
-
-            // Function keys
-            "F1" => VK_F1,
-            "F2" => VK_F2,
-            "F3" => VK_F3,
-            "F4" => VK_F4,
-            "F5" => VK_F5,
-            "F6" => VK_F6,
-            "F7" => VK_F7,
-            "F8" => VK_F8,
-            "F9" => VK_F9,
-            "F10" => VK_F10,
-            "F11" => VK_F11,
-            "F12" => VK_F12,
-
-            _ => anyhow::bail!("Unknown key: {}", key),
-        };
-
-        Ok(vk)
+        keys::virtual_key(key)
+            .map(VIRTUAL_KEY)
+            .ok_or_else(|| anyhow::anyhow!("Unknown key: {}", key))
     }
 
     /// Get the number of registered keybindings.
     pub fn binding_count(&self) -> usize {
-        self.bindings.len()
+        self.bindings.len() + self.chords.len()
     }
 }
 
@@ -282,4 +556,4 @@ impl Drop for KeybindManager {
             error!("Failed to unregister hotkeys during drop: {}", e);
         }
     }
-}
\ No newline at end of file
+}
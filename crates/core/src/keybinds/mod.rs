@@ -4,7 +4,10 @@
 //! with Windows and mapping them to commands. It includes:
 //!
 //! - `KeybindManager`: Main struct for managing hotkey registration
-//! - Key and modifier parsing utilities
+//! - Key and modifier parsing utilities, and the key name table
+//! - `ChordMatcher`: Key sequences such as `Win+Space, W, 3` (leader keys)
+//! - `analyzer`: Report of conflicting, shadowed and reserved keybindings
+//! - `ConditionalKeybind`: Per-application `only_when`/`except_when` and pass-through
 //! - Integration with the Windows API for global hotkeys
 //!
 //! # Example
@@ -18,13 +21,20 @@
 //! let keybind = Keybind {
 //!     modifiers: vec!["Win".to_string()],
 //!     key: "Q".to_string(),
+//!     sequence: vec![],
 //!     command: "close".to_string(),
 //!     args: vec![],
+//!     only_when: None,
+//!     except_when: None,
 //! };
 //!
 //! manager.register_keybinds(vec![keybind]).ok();
 //! ```
 
+pub mod analyzer;
+pub mod chord;
+pub mod condition;
+pub mod keys;
 pub mod manager;
 pub mod parser;
 
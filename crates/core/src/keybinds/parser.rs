@@ -3,6 +3,7 @@
 //! This module provides utilities for parsing keybinding strings and
 //! validating key combinations.
 
+use super::keys::{self, Modifier};
 use anyhow::Result;
 
 /// Parse a keybinding string in the format "Modifier+Modifier+Key".
@@ -31,8 +32,16 @@ pub fn parse_keybind_string(keybind: &str) -> Result<(Vec<String>, String)> {
 }
 
 /// Check if a key is a valid modifier.
+///
+/// Modifiers are case-insensitive and accept aliases such as "Super" and
+/// "Control", and left/right variants such as "LCtrl".
 pub fn is_valid_modifier(modifier: &str) -> bool {
-    matches!(modifier, "Win" | "Ctrl" | "Alt" | "Shift")
+    Modifier::parse(modifier).is_some()
+}
+
+/// Check if a key name is known.
+pub fn is_valid_key(key: &str) -> bool {
+    keys::virtual_key(key).is_some()
 }
 
 /// Normalize a key string to uppercase for comparison.
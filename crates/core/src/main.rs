@@ -11,7 +11,8 @@ mod workspace;
 use anyhow::Result;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use tracing::info;
+use std::time::Duration;
+use tracing::{info, warn};
 
 use commands::CommandExecutor;
 use config::ConfigWatcher;
@@ -38,7 +39,7 @@ fn main() -> Result<()> {
     })?;
 
     // Load and validate configuration
-    let (config_loader, config) = app::load_and_validate_config()?;
+    let (config_loader, config, config_sources) = app::load_and_validate_config()?;
 
     // Initialize window manager with configuration
     info!("Initializing window manager...");
@@ -47,13 +48,16 @@ fn main() -> Result<()> {
 
     // Apply initial configuration
     if let Err(e) = wm.update_config(&config) {
-        use tracing::warn;
         warn!("Failed to apply configuration: {}", e);
         warn!("Continuing with default settings");
     } else {
         info!("Configuration applied successfully");
     }
 
+    if let Err(e) = wm.show_default_workspace() {
+        warn!("Failed to show default workspace: {}", e);
+    }
+
     // Set up event loop
     info!("Starting event loop...");
     let mut event_loop = EventLoop::new();
@@ -63,28 +67,46 @@ fn main() -> Result<()> {
     // Set up keybind manager
     info!("Registering keybindings...");
     let mut keybind_manager = KeybindManager::new();
+    keybind_manager.set_modes(config.modes.clone());
+    keybind_manager.set_chord_timeout(Duration::from_millis(config.input.chord_timeout));
     match keybind_manager.register_keybinds(config.keybinds.clone()) {
         Ok(()) => info!("Keybindings registered successfully"),
         Err(e) => {
-            use tracing::warn;
             warn!("Failed to register some keybindings: {}", e);
             warn!("Some hotkeys may not be available");
         }
     }
 
+    // Set up mouse bindings
+    if let Err(e) = event_loop.set_mouse_binds(&config.mousebinds) {
+        warn!("Failed to register mouse bindings: {}", e);
+    }
+
     // Scan and manage existing windows
     info!("Scanning for existing windows...");
     app::scan_and_manage_windows(&mut wm)?;
+    app::restore_saved_marks(&mut wm);
+
+    // Apply keybinding conditions to the window focused at startup
+    if keybind_manager.has_conditions() {
+        if let Err(e) = keybind_manager.set_focused_window(wm.get_active_window()) {
+            warn!("Failed to apply keybinding conditions: {}", e);
+        }
+    }
 
     // Set up configuration watcher for hot-reload
     info!("Starting configuration watcher...");
-    let config_watcher = match ConfigWatcher::new(config_loader.get_config_path().clone()) {
-        Ok(watcher) => {
+    let hot_reload = match ConfigWatcher::new(config_loader.get_config_path().clone()) {
+        Ok(mut watcher) => {
             info!("Configuration hot-reload enabled");
-            Some(watcher)
+            watcher.watch_sources(&config_sources);
+            Some(app::HotReload {
+                watcher,
+                loader: config_loader,
+                config,
+            })
         }
         Err(e) => {
-            use tracing::warn;
             warn!("Failed to start configuration watcher: {}", e);
             warn!("Hot-reload will not be available");
             None
@@ -93,7 +115,7 @@ fn main() -> Result<()> {
 
     info!("==============================================");
     info!("✓ Tenraku is now running");
-    if config_watcher.is_some() {
+    if hot_reload.is_some() {
         info!("✓ Configuration hot-reload enabled");
     }
     info!("  Press Ctrl+C to exit");
@@ -113,11 +135,14 @@ fn main() -> Result<()> {
         &executor,
         &mut keybind_manager,
         &running,
-        config_watcher,
-        &config_loader,
+        hot_reload,
     )?;
 
     // Clean shutdown
+    if let Err(e) = app::save_marks(&wm) {
+        warn!("Failed to save window marks: {}", e);
+    }
+
     info!("Stopping event loop...");
     event_loop.stop()?;
     info!("Tenraku stopped successfully");
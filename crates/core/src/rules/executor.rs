@@ -103,6 +103,27 @@ impl RuleExecutor {
                 // Pin functionality would require workspace manager integration
                 // This is a future enhancement
             }
+            RuleAction::Dialog => {
+                tracing::debug!("Window '{}' marked as dialog", window.title);
+                window.transient = true;
+                window.set_floating()?;
+            }
+            RuleAction::NoDialog => {
+                tracing::debug!("Window '{}' marked as not a dialog", window.title);
+                window.transient = false;
+            }
+            RuleAction::Mark(mark) => {
+                tracing::debug!("Marking window '{}' as '{}'", window.title, mark);
+                window.mark = Some(mark.clone());
+            }
+            RuleAction::SpecialWorkspace(name) => {
+                tracing::debug!(
+                    "Sending window '{}' to special workspace '{}' (deferred)",
+                    window.title,
+                    name
+                );
+                // Special workspace IDs are allocated by the window manager
+            }
         }
         Ok(())
     }
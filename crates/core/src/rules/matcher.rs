@@ -4,7 +4,7 @@
 //! compiled regex patterns. It supports matching on process name, window title,
 //! and window class name, with multiple simultaneous rule matches.
 
-use crate::config::schema::{WindowRule, RuleAction};
+use crate::config::schema::{WindowMatch, WindowRule, RuleAction};
 use crate::window_manager::window::ManagedWindow;
 use anyhow::Context;
 use regex::Regex;
@@ -30,6 +30,57 @@ pub struct CompiledRule {
     pub actions: Vec<RuleAction>,
 }
 
+/// Compiled window criteria, such as a keybinding's `only_when`
+/// 
+/// Matches like a window rule: every field given must match.
+#[derive(Debug, Clone)]
+pub struct WindowMatcher {
+    /// Compiled regex for process name matching
+    process_regex: Option<Regex>,
+    
+    /// Compiled regex for window title matching
+    title_regex: Option<Regex>,
+    
+    /// Compiled regex for window class matching
+    class_regex: Option<Regex>,
+}
+
+impl WindowMatcher {
+    /// Compile window criteria
+    /// 
+    /// # Returns
+    /// 
+    /// The compiled criteria, or an error if a regex pattern is invalid
+    pub fn new(criteria: &WindowMatch) -> anyhow::Result<Self> {
+        let compile = |pattern: &Option<String>, field: &str| {
+            pattern
+                .as_deref()
+                .map(|pattern| {
+                    Regex::new(pattern).with_context(|| format!("Invalid regex in {}", field))
+                })
+                .transpose()
+        };
+        
+        Ok(Self {
+            process_regex: compile(&criteria.match_process, "match_process")?,
+            title_regex: compile(&criteria.match_title, "match_title")?,
+            class_regex: compile(&criteria.match_class, "match_class")?,
+        })
+    }
+    
+    /// Check whether a window matches every field given
+    pub fn matches(&self, window: &ManagedWindow) -> bool {
+        let checks = [
+            (&self.process_regex, &window.process_name),
+            (&self.title_regex, &window.title),
+            (&self.class_regex, &window.class),
+        ];
+        checks
+            .into_iter()
+            .all(|(regex, value)| regex.as_ref().is_none_or(|regex| regex.is_match(value)))
+    }
+}
+
 /// Rule matcher that efficiently matches windows against rules
 /// 
 /// The RuleMatcher compiles window rules into efficient regex patterns
@@ -66,6 +117,12 @@ pub struct RuleMatch {
     pub should_not_focus: bool,
     /// Opacity setting (if specified)
     pub opacity: Option<f32>,
+    /// Dialog handling override (Some(true) for Dialog, Some(false) for NoDialog)
+    pub dialog: Option<bool>,
+    /// Mark to assign (if specified)
+    pub mark: Option<String>,
+    /// Special workspace to send the window to (if specified)
+    pub special_workspace: Option<String>,
 }
 
 impl RuleMatcher {
@@ -363,6 +420,78 @@ impl RuleMatcher {
         actions.iter().any(|a| matches!(a, RuleAction::NoFocus))
     }
     
+    /// Get the dialog handling override for a window based on rules
+    /// 
+    /// Returns the override from the first matching Dialog or NoDialog action.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `window` - The window to check
+    /// 
+    /// # Returns
+    /// 
+    /// Some(true) if a Dialog action matched, Some(false) if a NoDialog action
+    /// matched, None otherwise
+    pub fn get_dialog_override(&self, window: &ManagedWindow) -> Option<bool> {
+        let actions = self.match_window(window);
+        
+        for action in actions {
+            match action {
+                RuleAction::Dialog => return Some(true),
+                RuleAction::NoDialog => return Some(false),
+                _ => {}
+            }
+        }
+        
+        None
+    }
+    
+    /// Get the mark to assign to a window based on rules
+    /// 
+    /// Returns the mark from the first matching Mark action.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `window` - The window to check
+    /// 
+    /// # Returns
+    /// 
+    /// Some(mark) if a Mark action matched, None otherwise
+    pub fn get_mark(&self, window: &ManagedWindow) -> Option<String> {
+        let actions = self.match_window(window);
+        
+        for action in actions {
+            if let RuleAction::Mark(mark) = action {
+                return Some(mark);
+            }
+        }
+        
+        None
+    }
+    
+    /// Get the special workspace to send a window to based on rules
+    /// 
+    /// Returns the name from the first matching SpecialWorkspace action.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `window` - The window to check
+    /// 
+    /// # Returns
+    /// 
+    /// Some(name) if a SpecialWorkspace action matched, None otherwise
+    pub fn get_special_workspace(&self, window: &ManagedWindow) -> Option<String> {
+        let actions = self.match_window(window);
+        
+        for action in actions {
+            if let RuleAction::SpecialWorkspace(name) = action {
+                return Some(name);
+            }
+        }
+        
+        None
+    }
+    
     /// Get the number of rules
     /// 
     /// # Returns
@@ -420,6 +549,9 @@ impl RuleMatcher {
             should_pin: false,
             should_not_focus: false,
             opacity: None,
+            dialog: None,
+            mark: None,
+            special_workspace: None,
         };
         
         // Process actions to extract relevant properties
@@ -439,6 +571,14 @@ impl RuleMatcher {
                 RuleAction::Opacity(opacity) if result.opacity.is_none() => {
                     result.opacity = Some(*opacity);
                 }
+                RuleAction::Dialog if result.dialog.is_none() => result.dialog = Some(true),
+                RuleAction::NoDialog if result.dialog.is_none() => result.dialog = Some(false),
+                RuleAction::Mark(mark) if result.mark.is_none() => {
+                    result.mark = Some(mark.clone());
+                }
+                RuleAction::SpecialWorkspace(name) if result.special_workspace.is_none() => {
+                    result.special_workspace = Some(name.clone());
+                }
                 _ => {} // Ignore Tile and already-set values
             }
         }
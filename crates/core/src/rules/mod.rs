@@ -10,4 +10,4 @@
 pub mod matcher;
 pub mod executor;
 
-pub use matcher::RuleMatcher;
+pub use matcher::{RuleMatcher, WindowMatcher};
@@ -8,8 +8,9 @@ use windows::{
     Win32::UI::WindowsAndMessaging::{
         GetClassNameW, GetParent, GetWindow, GetWindowRect, GetWindowTextLengthW, GetWindowTextW,
         GetWindowThreadProcessId, IsIconic, IsWindowVisible, IsZoomed, PostMessageW,
-        SetForegroundWindow, SetWindowPos, ShowWindow, GW_OWNER, HWND_TOP, SHOW_WINDOW_CMD,
-        SWP_NOZORDER, SW_HIDE, SW_MAXIMIZE, SW_MINIMIZE, SW_RESTORE, WM_CLOSE,
+        SetForegroundWindow, SetWindowPos, ShowWindow, GW_HWNDNEXT, GW_OWNER, HWND_TOP,
+        SHOW_WINDOW_CMD, SWP_NOACTIVATE, SWP_NOMOVE, SWP_NOSIZE, SWP_NOZORDER, SW_HIDE,
+        SW_MAXIMIZE, SW_MINIMIZE, SW_RESTORE, WM_CLOSE,
     },
 };
 
@@ -260,6 +261,63 @@ impl WindowHandle {
         }
     }
 
+    /// Check if this window is above another window in z-order.
+    ///
+    /// Walks the windows below this one until `other` is found.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The window to compare against
+    ///
+    /// # Returns
+    ///
+    /// `true` if `other` is below this window, `false` otherwise.
+    pub fn is_above(&self, other: &WindowHandle) -> bool {
+        unsafe {
+            let mut next = GetWindow(self.0, GW_HWNDNEXT);
+            while next.0 != 0 {
+                if next == other.0 {
+                    return true;
+                }
+                next = GetWindow(next, GW_HWNDNEXT);
+            }
+            false
+        }
+    }
+
+    /// Check if this is a dialog-like window owned by another visible window.
+    ///
+    /// Owned windows with a caption are typically file pickers, message boxes
+    /// and property dialogs. Owned windows without a caption (menus, drop-downs)
+    /// and tool windows are not considered dialogs.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the window is visible, has a caption and a visible owner.
+    pub fn is_owned_dialog(&self) -> bool {
+        use windows::Win32::UI::WindowsAndMessaging::{
+            GetWindowLongPtrW, GWL_EXSTYLE, GWL_STYLE, WS_CAPTION, WS_EX_NOACTIVATE,
+            WS_EX_TOOLWINDOW,
+        };
+
+        if !self.is_visible() {
+            return false;
+        }
+
+        match self.get_owner() {
+            Some(owner) if owner.is_visible() => {}
+            _ => return false,
+        }
+
+        unsafe {
+            let ex_style = GetWindowLongPtrW(self.0, GWL_EXSTYLE) as u32;
+            let style = GetWindowLongPtrW(self.0, GWL_STYLE) as u32;
+
+            (style & WS_CAPTION.0) == WS_CAPTION.0
+                && (ex_style & (WS_EX_TOOLWINDOW.0 | WS_EX_NOACTIVATE.0)) == 0
+        }
+    }
+
     /// Show the window.
     ///
     /// # Arguments
@@ -407,6 +465,27 @@ impl WindowHandle {
         Ok(())
     }
 
+    /// Raise the window to the top of the Z order without activating it.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if the operation fails.
+    pub fn raise(&self) -> anyhow::Result<()> {
+        unsafe {
+            SetWindowPos(
+                self.0,
+                HWND_TOP,
+                0,
+                0,
+                0,
+                0,
+                SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE,
+            )?;
+        }
+
+        Ok(())
+    }
+
     /// Check if this is a standard application window (has title, is visible, has no owner).
     ///
     /// This is useful for filtering out non-application windows like tooltips, menu windows, etc.
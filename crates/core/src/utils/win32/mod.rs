@@ -39,8 +39,10 @@
 mod enumeration;
 mod filters;
 mod handle;
+mod overlay;
 
 pub use enumeration::{
     enumerate_app_windows, get_foreground_window,
 };
 pub use handle::WindowHandle;
+pub use overlay::OverlayWindow;
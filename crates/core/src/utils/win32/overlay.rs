@@ -0,0 +1,126 @@
+//! Translucent overlay window used for drop-target previews.
+//!
+//! The overlay is a click-through, always-on-top popup that never takes focus.
+//! It is created lazily the first time it is shown and destroyed on drop.
+
+use crate::window_manager::Rect;
+use windows::{
+    core::w,
+    Win32::Foundation::{COLORREF, HWND},
+    Win32::UI::WindowsAndMessaging::{
+        CreateWindowExW, DestroyWindow, SetLayeredWindowAttributes, SetWindowPos, ShowWindow,
+        HWND_TOPMOST, LWA_ALPHA, SWP_NOACTIVATE, SWP_SHOWWINDOW, SW_HIDE, WS_EX_LAYERED,
+        WS_EX_NOACTIVATE, WS_EX_TOOLWINDOW, WS_EX_TOPMOST, WS_EX_TRANSPARENT, WS_POPUP,
+    },
+};
+
+/// Opacity of the overlay (0-255).
+const OVERLAY_ALPHA: u8 = 96;
+
+/// A translucent highlight window.
+///
+/// # Examples
+///
+/// ```no_run
+/// use tenraku_core::utils::win32::OverlayWindow;
+/// use tenraku_core::window_manager::Rect;
+///
+/// let mut overlay = OverlayWindow::new();
+/// overlay.show(&Rect::new(0, 0, 960, 1080)).ok();
+/// overlay.hide();
+/// ```
+#[derive(Debug, Default)]
+pub struct OverlayWindow {
+    hwnd: Option<HWND>,
+}
+
+impl OverlayWindow {
+    /// Create a new overlay. No window is created until it is first shown.
+    pub fn new() -> Self {
+        OverlayWindow { hwnd: None }
+    }
+
+    /// Show the overlay covering the given rectangle.
+    ///
+    /// # Arguments
+    ///
+    /// * `rect` - Screen area to highlight
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the overlay window cannot be created or positioned.
+    pub fn show(&mut self, rect: &Rect) -> anyhow::Result<()> {
+        let hwnd = match self.hwnd {
+            Some(hwnd) => hwnd,
+            None => {
+                let hwnd = Self::create()?;
+                self.hwnd = Some(hwnd);
+                hwnd
+            }
+        };
+
+        unsafe {
+            SetWindowPos(
+                hwnd,
+                HWND_TOPMOST,
+                rect.x,
+                rect.y,
+                rect.width,
+                rect.height,
+                SWP_NOACTIVATE | SWP_SHOWWINDOW,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Hide the overlay if it is visible.
+    pub fn hide(&self) {
+        if let Some(hwnd) = self.hwnd {
+            unsafe {
+                ShowWindow(hwnd, SW_HIDE);
+            }
+        }
+    }
+
+    fn create() -> anyhow::Result<HWND> {
+        unsafe {
+            let hwnd = CreateWindowExW(
+                WS_EX_LAYERED
+                    | WS_EX_TOPMOST
+                    | WS_EX_TOOLWINDOW
+                    | WS_EX_TRANSPARENT
+                    | WS_EX_NOACTIVATE,
+                w!("STATIC"),
+                w!(""),
+                WS_POPUP,
+                0,
+                0,
+                0,
+                0,
+                None,
+                None,
+                None,
+                None,
+            );
+
+            if hwnd.0 == 0 {
+                anyhow::bail!("Failed to create overlay window");
+            }
+
+            SetLayeredWindowAttributes(hwnd, COLORREF(0), OVERLAY_ALPHA, LWA_ALPHA)?;
+
+            Ok(hwnd)
+        }
+    }
+}
+
+impl Drop for OverlayWindow {
+    fn drop(&mut self) {
+        if let Some(hwnd) = self.hwnd.take() {
+            unsafe {
+                let _ = DestroyWindow(hwnd);
+            }
+        }
+    }
+}
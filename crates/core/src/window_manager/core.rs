@@ -3,14 +3,26 @@
 //! This module contains the WindowManager struct definition and basic
 //! initialization and configuration operations.
 
-use crate::config::Config;
+use crate::config::reload::{ConfigDiff, ConfigSection};
+use crate::config::{Config, DialogsConfig, WorkspaceGroup, WorkspaceMode, WorkspaceRule};
 use crate::rules::RuleMatcher;
+use crate::utils::win32::OverlayWindow;
+use crate::window_manager::drag::DragSession;
+use crate::window_manager::monitor::{MonitorAdjacency, MonitorSplits};
+use crate::window_manager::special::SpecialWorkspaces;
+use crate::window_manager::tags::TagViews;
 use crate::window_manager::{
     DwindleLayout, FocusManager, LayoutType, MasterLayout, MonitorInfo, TreeNode, WindowRegistry,
 };
+use crate::workspace::core::WorkspaceEventListener;
+use crate::workspace::history::WorkspaceHistory;
 use std::collections::HashMap;
 use windows::Win32::Foundation::HWND;
 
+/// Number of workspaces created at startup. They are never destroyed, unlike
+/// workspaces created on demand.
+pub(super) const INITIAL_WORKSPACES: usize = 10;
+
 /// Central window manager that coordinates windows, workspaces, and monitors.
 ///
 /// The WindowManager maintains:
@@ -43,6 +55,10 @@ pub struct WindowManager {
     pub(super) active_workspace: usize,
     /// Information about connected monitors
     pub(super) monitors: Vec<MonitorInfo>,
+    /// Which monitor lies next to each monitor, rebuilt with the monitors
+    pub(super) monitor_adjacency: MonitorAdjacency,
+    /// Regions splitting physical monitors into logical monitors
+    pub(super) monitor_splits: MonitorSplits,
     /// Registry of all managed windows
     pub(super) registry: WindowRegistry,
     /// Dwindle layout configuration
@@ -57,6 +73,40 @@ pub struct WindowManager {
     pub(super) focus_manager: FocusManager,
     /// Flag to prevent recursive retiling
     pub(super) is_tiling: bool,
+    /// Distance (pixels) within which floating windows snap to edges
+    pub(super) snap_threshold: i32,
+    /// Dialog and transient window handling settings
+    pub(super) dialogs: DialogsConfig,
+    /// Mouse drag in progress, if any
+    pub(super) drag: Option<DragSession>,
+    /// Highlight shown over the drop target while dragging tiled windows
+    pub(super) drop_preview: OverlayWindow,
+    /// History of visited workspaces per monitor
+    pub(super) workspace_history: HashMap<usize, WorkspaceHistory>,
+    /// Whether switching to the active workspace returns to the previous one
+    pub(super) auto_back_and_forth: bool,
+    /// Named special workspaces toggled over the normal ones
+    pub(super) special_workspaces: SpecialWorkspaces,
+    /// Fraction of the work area used by special workspaces
+    pub(super) special_scale: f32,
+    /// Rules binding workspaces to monitors
+    pub(super) workspace_rules: Vec<WorkspaceRule>,
+    /// Windows moved off a disconnected monitor, with the key of that monitor
+    pub(super) displaced_windows: HashMap<isize, String>,
+    /// Monitors workspaces were moved to, by workspace ID
+    pub(super) workspace_monitors: HashMap<usize, usize>,
+    /// Groups of workspaces, one per monitor, switched together
+    pub(super) workspace_groups: Vec<WorkspaceGroup>,
+    /// Workspaces of the shown group besides the active one, by monitor
+    pub(super) group_workspaces: Vec<(usize, usize)>,
+    /// Whether windows carry tags instead of belonging to one workspace
+    pub(super) tag_mode: bool,
+    /// Number of tags in tag mode
+    pub(super) tag_count: usize,
+    /// The tags each monitor views in tag mode
+    pub(super) tag_views: TagViews,
+    /// Listener for workspace creation and deletion (if connected)
+    pub(super) workspace_listener: Option<WorkspaceEventListener>,
 }
 
 impl WindowManager {
@@ -77,6 +127,8 @@ impl WindowManager {
             trees: HashMap::new(),
             active_workspace: 1,
             monitors: Vec::new(),
+            monitor_adjacency: MonitorAdjacency::default(),
+            monitor_splits: MonitorSplits::default(),
             registry: WindowRegistry::new(),
             dwindle_layout: DwindleLayout::new(),
             master_layout: MasterLayout::new(),
@@ -84,6 +136,23 @@ impl WindowManager {
             rule_matcher: None,
             focus_manager: FocusManager::new(),
             is_tiling: false,
+            snap_threshold: 10,
+            dialogs: DialogsConfig::default(),
+            drag: None,
+            drop_preview: OverlayWindow::new(),
+            workspace_history: HashMap::new(),
+            auto_back_and_forth: false,
+            special_workspaces: SpecialWorkspaces::new(),
+            special_scale: 0.8,
+            workspace_rules: Vec::new(),
+            displaced_windows: HashMap::new(),
+            workspace_monitors: HashMap::new(),
+            workspace_groups: Vec::new(),
+            group_workspaces: Vec::new(),
+            tag_mode: false,
+            tag_count: 9,
+            tag_views: TagViews::new(),
+            workspace_listener: None,
         }
     }
 
@@ -119,7 +188,7 @@ impl WindowManager {
                 monitor.work_area.height - 2 * gaps_out,
             );
 
-            for workspace_id in 1..=10 {
+            for workspace_id in 1..=INITIAL_WORKSPACES {
                 // We don't create trees yet - they'll be created when windows are added
                 // Just reserve the workspace IDs for the primary monitor
                 self.trees.insert(
@@ -134,8 +203,9 @@ impl WindowManager {
 
     /// Update configuration and rebuild rule matcher.
     ///
-    /// This method updates layout settings and rebuilds the rule matcher
-    /// with the new window rules from the configuration.
+    /// This method applies every section of the configuration, updating
+    /// layout settings and rebuilding the rule matcher with the new window
+    /// rules from the configuration.
     ///
     /// # Arguments
     ///
@@ -159,22 +229,108 @@ impl WindowManager {
     /// wm.update_config(&config).unwrap();
     /// ```
     pub fn update_config(&mut self, config: &Config) -> anyhow::Result<()> {
+        self.apply_config_changes(config, &ConfigDiff::everything())?;
+
+        tracing::info!("Configuration updated successfully");
+        Ok(())
+    }
+
+    /// Apply the sections of a configuration listed in a diff.
+    ///
+    /// Only the subsystems affected by the changed sections are updated:
+    /// the rule matcher is rebuilt when window rules changed, monitors are
+    /// re-enumerated when their regions changed, and the active workspace is
+    /// retiled only when settings that move windows changed. The new rules
+    /// are compiled before anything is changed, so an invalid rule leaves the
+    /// running configuration untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The configuration to apply
+    /// * `diff` - The sections that differ from the running configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tenraku_core::window_manager::WindowManager;
+    /// use tenraku_core::config::ConfigLoader;
+    /// use tenraku_core::config::reload::ConfigDiff;
+    ///
+    /// let mut wm = WindowManager::new();
+    /// wm.initialize().unwrap();
+    ///
+    /// let loader = ConfigLoader::new().unwrap();
+    /// let old = loader.load().unwrap();
+    /// let new = loader.load().unwrap();
+    /// wm.apply_config_changes(&new, &ConfigDiff::between(&old, &new)).unwrap();
+    /// ```
+    pub fn apply_config_changes(
+        &mut self,
+        config: &Config,
+        diff: &ConfigDiff,
+    ) -> anyhow::Result<()> {
+        let rule_matcher = if diff.contains(ConfigSection::WindowRules) {
+            Some(RuleMatcher::new(config.window_rules.clone())?)
+        } else {
+            None
+        };
+
         // Update layout settings
-        self.dwindle_layout.ratio = config.layouts.dwindle.split_ratio;
-        self.dwindle_layout.smart_split = config.layouts.dwindle.smart_split;
-        self.dwindle_layout.no_gaps_when_only = config.layouts.dwindle.no_gaps_when_only;
-        self.dwindle_layout.gaps_in = config.general.gaps_in;
-        self.dwindle_layout.gaps_out = config.general.gaps_out;
+        if diff.contains_any(&[ConfigSection::General, ConfigSection::Layouts]) {
+            self.dwindle_layout.ratio = config.layouts.dwindle.split_ratio;
+            self.dwindle_layout.smart_split = config.layouts.dwindle.smart_split;
+            self.dwindle_layout.no_gaps_when_only = config.layouts.dwindle.no_gaps_when_only;
+            self.dwindle_layout.gaps_in = config.general.gaps_in;
+            self.dwindle_layout.gaps_out = config.general.gaps_out;
 
-        self.master_layout.master_factor = config.layouts.master.master_factor;
-        self.master_layout.master_count = config.layouts.master.master_count;
-        self.master_layout.gaps_in = config.general.gaps_in;
-        self.master_layout.gaps_out = config.general.gaps_out;
+            self.master_layout.master_factor = config.layouts.master.master_factor;
+            self.master_layout.master_count = config.layouts.master.master_count;
+            self.master_layout.gaps_in = config.general.gaps_in;
+            self.master_layout.gaps_out = config.general.gaps_out;
+        }
+
+        if diff.contains(ConfigSection::General) {
+            self.snap_threshold = config.general.snap_threshold;
+            self.auto_back_and_forth = config.general.auto_back_and_forth;
+            self.special_scale = config.general.special_workspace_scale;
+        }
+        if diff.contains(ConfigSection::Dialogs) {
+            self.dialogs = config.dialogs.clone();
+        }
 
-        // Rebuild rule matcher
-        self.rule_matcher = Some(RuleMatcher::new(config.window_rules.clone())?);
+        // Swap in the rule matcher compiled above
+        if rule_matcher.is_some() {
+            self.rule_matcher = rule_matcher;
+        }
+
+        // Re-enumerate monitors if the logical monitor regions changed
+        if diff.contains(ConfigSection::Monitors) {
+            let splits = MonitorSplits::from_config(&config.monitors);
+            if splits != self.monitor_splits {
+                self.monitor_splits = splits;
+                self.reconcile_monitors()?;
+            }
+        }
+
+        if diff.contains(ConfigSection::WorkspaceRules) {
+            self.workspace_rules = config.workspace_rules.clone();
+        }
+        if diff.contains(ConfigSection::WorkspaceGroups) {
+            self.workspace_groups = config.workspace_groups.clone();
+        }
+        if diff.contains(ConfigSection::General) {
+            self.tag_count = config.general.tag_count;
+            let tag_mode = config.general.workspace_mode == WorkspaceMode::Tags;
+            if tag_mode != self.tag_mode {
+                self.set_tag_mode(tag_mode)?;
+            }
+        }
+
+        // Retile so windows follow changed gaps, layouts and workspace-to-monitor bindings
+        if diff.needs_relayout() {
+            self.retile_workspace(self.active_workspace)?;
+        }
 
-        tracing::info!("Configuration updated successfully");
         Ok(())
     }
 
@@ -263,6 +419,18 @@ impl WindowManager {
         // Default to primary monitor if not found or on non-Windows
         0
     }
+
+    /// Determine which monitor the foreground window is on.
+    ///
+    /// # Returns
+    ///
+    /// The monitor index (0-based). Returns 0 if there is no foreground window.
+    pub(super) fn focused_monitor(&self) -> usize {
+        match crate::utils::win32::get_foreground_window() {
+            Some(window) => self.get_monitor_for_window(window.hwnd()),
+            None => 0,
+        }
+    }
 }
 
 impl Default for WindowManager {
@@ -0,0 +1,236 @@
+//! Mouse drag sessions and drop-zone hit testing.
+//!
+//! This module contains the platform-independent logic behind mouse bindings:
+//! tracking a drag from button press to release, computing where a floating
+//! window should follow the pointer, and deciding how a tiled window dropped
+//! onto another tile should be inserted into the layout tree.
+//!
+//! # Example
+//!
+//! ```
+//! use tenraku_core::window_manager::Rect;
+//! use tenraku_core::window_manager::drag::DropZone;
+//!
+//! let target = Rect::new(0, 0, 1000, 800);
+//!
+//! // Near the left edge of the target: split and place the window on the left
+//! assert_eq!(DropZone::from_point(&target, 50, 400), Some(DropZone::SplitLeft));
+//!
+//! // In the middle of the target: swap the two windows
+//! assert_eq!(DropZone::from_point(&target, 500, 400), Some(DropZone::Swap));
+//! ```
+
+use crate::config::MouseAction;
+use crate::window_manager::floating::MIN_FLOATING_SIZE;
+use crate::window_manager::{Rect, Split, TreeNode};
+use windows::Win32::Foundation::HWND;
+
+/// Fraction of the target rectangle on each side that counts as an edge zone.
+const EDGE_ZONE_FRACTION: f32 = 0.25;
+
+/// Where a dragged tiled window will be placed relative to the drop target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DropZone {
+    /// Split the target and place the window on the left
+    SplitLeft,
+    /// Split the target and place the window on the right
+    SplitRight,
+    /// Split the target and place the window on top
+    SplitTop,
+    /// Split the target and place the window at the bottom
+    SplitBottom,
+    /// Swap the dragged window with the target
+    Swap,
+}
+
+impl DropZone {
+    /// Determine the drop zone for a cursor position over a target rectangle.
+    ///
+    /// The outer quarter of the rectangle on each side maps to the split zone
+    /// for the nearest edge, and the center maps to a swap.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The rectangle of the tile under the cursor
+    /// * `x` - Cursor X coordinate
+    /// * `y` - Cursor Y coordinate
+    ///
+    /// # Returns
+    ///
+    /// The drop zone, or `None` if the cursor is outside the target.
+    pub fn from_point(target: &Rect, x: i32, y: i32) -> Option<Self> {
+        if !target.contains_point(x, y) || target.width <= 0 || target.height <= 0 {
+            return None;
+        }
+
+        let fx = (x - target.x) as f32 / target.width as f32;
+        let fy = (y - target.y) as f32 / target.height as f32;
+
+        let distances = [
+            (fx, DropZone::SplitLeft),
+            (1.0 - fx, DropZone::SplitRight),
+            (fy, DropZone::SplitTop),
+            (1.0 - fy, DropZone::SplitBottom),
+        ];
+
+        distances
+            .iter()
+            .filter(|(distance, _)| *distance < EDGE_ZONE_FRACTION)
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, zone)| *zone)
+            .or(Some(DropZone::Swap))
+    }
+
+    /// Get the split direction and placement for split zones.
+    ///
+    /// # Returns
+    ///
+    /// The split direction and whether the dragged window goes before
+    /// (left of/above) the target, or `None` for [`DropZone::Swap`].
+    pub fn split(&self) -> Option<(Split, bool)> {
+        match self {
+            DropZone::SplitLeft => Some((Split::Horizontal, true)),
+            DropZone::SplitRight => Some((Split::Horizontal, false)),
+            DropZone::SplitTop => Some((Split::Vertical, true)),
+            DropZone::SplitBottom => Some((Split::Vertical, false)),
+            DropZone::Swap => None,
+        }
+    }
+
+    /// Get the area to highlight as a drop preview.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The rectangle of the drop target tile
+    ///
+    /// # Returns
+    ///
+    /// The half of the target the window will occupy for split zones,
+    /// or the whole target for a swap.
+    pub fn preview_rect(&self, target: &Rect) -> Rect {
+        match self {
+            DropZone::SplitLeft => target.split_horizontal(0.5).0,
+            DropZone::SplitRight => target.split_horizontal(0.5).1,
+            DropZone::SplitTop => target.split_vertical(0.5).0,
+            DropZone::SplitBottom => target.split_vertical(0.5).1,
+            DropZone::Swap => *target,
+        }
+    }
+
+    /// Apply a drop to a layout tree that contains both windows.
+    ///
+    /// # Arguments
+    ///
+    /// * `tree` - The layout tree
+    /// * `dragged` - The window being dropped
+    /// * `target` - The window it was dropped onto
+    ///
+    /// # Returns
+    ///
+    /// The modified tree. The tree is left unchanged if the window is dropped
+    /// onto itself or if either window is missing from the tree.
+    pub fn apply(&self, mut tree: TreeNode, dragged: HWND, target: HWND) -> TreeNode {
+        if dragged == target || !tree.contains(dragged) || !tree.contains(target) {
+            return tree;
+        }
+
+        match self.split() {
+            None => {
+                tree.swap_windows(dragged, target);
+                tree
+            }
+            Some((split, before)) => {
+                // The target is still in the tree, so removal never empties it
+                let rect = tree.rect();
+                tree.remove(dragged)
+                    .unwrap_or_else(|| TreeNode::new_leaf(target, rect))
+                    .insert_beside(target, dragged, split, before)
+            }
+        }
+    }
+}
+
+/// The tile a dragged window is currently hovering over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DropTarget {
+    /// Monitor index of the tree holding the target
+    pub monitor: usize,
+    /// The target window
+    pub hwnd: HWND,
+    /// The target window's tile rectangle
+    pub rect: Rect,
+    /// The zone under the cursor
+    pub zone: DropZone,
+}
+
+/// State of an in-progress mouse drag.
+#[derive(Debug, Clone)]
+pub struct DragSession {
+    /// The window being dragged
+    pub hwnd: HWND,
+    /// What the drag does
+    pub action: MouseAction,
+    /// Cursor position when the drag started
+    pub origin: (i32, i32),
+    /// Window rectangle when the drag started
+    pub start_rect: Rect,
+    /// Whether the window was floating when the drag started
+    pub floating: bool,
+}
+
+impl DragSession {
+    /// Start a new drag session.
+    ///
+    /// # Arguments
+    ///
+    /// * `hwnd` - The window being dragged
+    /// * `action` - The bound mouse action
+    /// * `origin` - Cursor position at button press
+    /// * `start_rect` - Window rectangle at button press
+    /// * `floating` - Whether the window is floating
+    pub fn new(
+        hwnd: HWND,
+        action: MouseAction,
+        origin: (i32, i32),
+        start_rect: Rect,
+        floating: bool,
+    ) -> Self {
+        DragSession {
+            hwnd,
+            action,
+            origin,
+            start_rect,
+            floating,
+        }
+    }
+
+    /// Compute the rectangle of a floating window for the current cursor position.
+    ///
+    /// Moves translate the window with the pointer. Resizes keep the top-left
+    /// corner fixed and grow the window by the pointer offset, never shrinking
+    /// below [`MIN_FLOATING_SIZE`].
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Cursor X coordinate
+    /// * `y` - Cursor Y coordinate
+    ///
+    /// # Returns
+    ///
+    /// The new window rectangle.
+    pub fn floating_rect(&self, x: i32, y: i32) -> Rect {
+        let dx = x - self.origin.0;
+        let dy = y - self.origin.1;
+        let rect = self.start_rect;
+
+        match self.action {
+            MouseAction::Move => Rect::new(rect.x + dx, rect.y + dy, rect.width, rect.height),
+            MouseAction::Resize => Rect::new(
+                rect.x,
+                rect.y,
+                (rect.width + dx).max(MIN_FLOATING_SIZE),
+                (rect.height + dy).max(MIN_FLOATING_SIZE),
+            ),
+        }
+    }
+}
@@ -0,0 +1,277 @@
+//! Mouse drag operations.
+//!
+//! This module contains the WindowManager operations driven by mouse bindings:
+//! floating windows follow the pointer, and tiled windows can be dropped onto
+//! another tile to split it or swap places with it.
+
+use crate::config::MouseAction;
+use crate::utils::win32::WindowHandle;
+use crate::window_manager::drag::{DragSession, DropTarget, DropZone};
+use crate::window_manager::{Rect, WindowManager, WindowState};
+use windows::Win32::Foundation::HWND;
+
+impl WindowManager {
+    /// Start a mouse drag on the window under the cursor.
+    ///
+    /// Floating windows are preferred over tiled windows since they are
+    /// stacked above the layout. Resizing only applies to floating windows.
+    ///
+    /// # Arguments
+    ///
+    /// * `action` - The bound mouse action
+    /// * `x` - Cursor X coordinate
+    /// * `y` - Cursor Y coordinate
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if the window rectangle cannot be read.
+    pub fn begin_drag(&mut self, action: MouseAction, x: i32, y: i32) -> anyhow::Result<()> {
+        let (hwnd, rect, floating) = match self.window_at_point(x, y) {
+            Some(hit) => hit,
+            None => {
+                tracing::debug!("No managed window under cursor at ({}, {})", x, y);
+                return Ok(());
+            }
+        };
+
+        if !floating && action == MouseAction::Resize {
+            tracing::debug!("Ignoring resize drag on tiled window {:?}", hwnd.0);
+            return Ok(());
+        }
+
+        tracing::debug!("Starting {:?} drag on window {:?}", action, hwnd.0);
+        self.drag = Some(DragSession::new(hwnd, action, (x, y), rect, floating));
+        Ok(())
+    }
+
+    /// Update the drag in progress for a new cursor position.
+    ///
+    /// Floating windows are moved or resized to follow the pointer. For tiled
+    /// windows the drop target under the cursor is highlighted.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Cursor X coordinate
+    /// * `y` - Cursor Y coordinate
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if the window cannot be repositioned.
+    pub fn update_drag(&mut self, x: i32, y: i32) -> anyhow::Result<()> {
+        let session = match self.drag.as_ref() {
+            Some(session) => session,
+            None => return Ok(()),
+        };
+
+        if session.floating {
+            return Self::follow_pointer(session, x, y);
+        }
+
+        match self.drop_target_at(session.hwnd, x, y) {
+            Some(target) => self
+                .drop_preview
+                .show(&target.zone.preview_rect(&target.rect))?,
+            None => self.drop_preview.hide(),
+        }
+
+        Ok(())
+    }
+
+    /// Finish the drag in progress.
+    ///
+    /// Tiled windows dropped onto a tile are inserted next to it or swapped
+    /// with it, depending on the drop zone under the cursor.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Cursor X coordinate
+    /// * `y` - Cursor Y coordinate
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if the layout cannot be applied.
+    pub fn end_drag(&mut self, x: i32, y: i32) -> anyhow::Result<()> {
+        // Take the session first so a failed final move cannot leave it behind
+        let session = self.drag.take();
+        self.drop_preview.hide();
+
+        let session = match session {
+            Some(session) => session,
+            None => return Ok(()),
+        };
+
+        if session.floating {
+            return Self::follow_pointer(&session, x, y);
+        }
+
+        match self.drop_target_at(session.hwnd, x, y) {
+            Some(target) => self.drop_window(session.hwnd, target),
+            None => Ok(()),
+        }
+    }
+
+    /// Move or resize a dragged floating window to follow the pointer.
+    fn follow_pointer(session: &DragSession, x: i32, y: i32) -> anyhow::Result<()> {
+        let rect = session.floating_rect(x, y);
+        WindowHandle::from_hwnd(session.hwnd).set_pos(rect.x, rect.y, rect.width, rect.height)
+    }
+
+    /// Find the managed window under a screen point on the active workspace.
+    ///
+    /// Returns the window, its rectangle and whether it is floating. When
+    /// floating windows overlap, the topmost one in z-order wins.
+    fn window_at_point(&self, x: i32, y: i32) -> Option<(HWND, Rect, bool)> {
+        let floating_hit = self
+            .registry
+            .get_floating_in_workspace(self.active_workspace)
+            .into_iter()
+            .filter_map(|w| {
+                let raw = w.handle.get_rect().ok()?;
+                let rect = Rect::new(
+                    raw.left,
+                    raw.top,
+                    raw.right - raw.left,
+                    raw.bottom - raw.top,
+                );
+                rect.contains_point(x, y)
+                    .then_some((w.handle.hwnd(), rect, true))
+            })
+            .reduce(|top, hit| {
+                if WindowHandle::from_hwnd(hit.0).is_above(&WindowHandle::from_hwnd(top.0)) {
+                    hit
+                } else {
+                    top
+                }
+            });
+
+        floating_hit.or_else(|| {
+            self.get_workspace_trees(self.active_workspace)
+                .into_iter()
+                .filter_map(|(_, tree)| tree.leaf_at(x, y))
+                .find(|(hwnd, _)| hwnd.0 != 0)
+                .map(|(hwnd, rect)| (hwnd, rect, false))
+        })
+    }
+
+    /// Find the tile under the cursor that a dragged window would be dropped onto.
+    fn drop_target_at(&self, dragged: HWND, x: i32, y: i32) -> Option<DropTarget> {
+        self.get_workspace_trees(self.active_workspace)
+            .into_iter()
+            .find_map(|(monitor, tree)| {
+                let (hwnd, rect) = tree.leaf_at(x, y)?;
+                if hwnd.0 == 0 || hwnd == dragged {
+                    return None;
+                }
+                let zone = DropZone::from_point(&rect, x, y)?;
+                Some(DropTarget {
+                    monitor,
+                    hwnd,
+                    rect,
+                    zone,
+                })
+            })
+    }
+
+    /// Apply a drop to the layout trees of the active workspace.
+    fn drop_window(&mut self, dragged: HWND, target: DropTarget) -> anyhow::Result<()> {
+        let workspace = self.active_workspace;
+        let source_monitor = self
+            .get_workspace_trees(workspace)
+            .into_iter()
+            .find(|(_, tree)| tree.contains(dragged))
+            .map(|(monitor, _)| monitor);
+
+        let source_monitor = match source_monitor {
+            Some(monitor) => monitor,
+            None => {
+                tracing::debug!("Dragged window {:?} is not in a layout tree", dragged.0);
+                return Ok(());
+            }
+        };
+
+        tracing::info!(
+            "Dropping window {:?} onto {:?} ({:?})",
+            dragged.0,
+            target.hwnd.0,
+            target.zone
+        );
+
+        if source_monitor == target.monitor {
+            if let Some(tree) = self.trees.remove(&(workspace, target.monitor)) {
+                let tree = target.zone.apply(tree, dragged, target.hwnd);
+                self.trees.insert((workspace, target.monitor), tree);
+            }
+        } else {
+            self.move_between_trees(workspace, source_monitor, dragged, target)?;
+        }
+
+        self.apply_workspace_trees(workspace)
+    }
+
+    /// Move a dragged window into the tree of another monitor.
+    fn move_between_trees(
+        &mut self,
+        workspace: usize,
+        source_monitor: usize,
+        dragged: HWND,
+        target: DropTarget,
+    ) -> anyhow::Result<()> {
+        let source_key = (workspace, source_monitor);
+        let target_key = (workspace, target.monitor);
+
+        match target.zone.split() {
+            None => {
+                // Swap across monitors by exchanging the two leaves
+                if let Some(tree) = self.trees.get_mut(&source_key) {
+                    tree.swap_windows(dragged, target.hwnd);
+                }
+                if let Some(tree) = self.trees.get_mut(&target_key) {
+                    tree.swap_windows(dragged, target.hwnd);
+                }
+                self.set_window_monitor(target.hwnd, source_monitor);
+            }
+            Some((split, before)) => {
+                if let Some(tree) = self.trees.remove(&source_key) {
+                    match tree.remove(dragged) {
+                        Some(remaining) => {
+                            self.trees.insert(source_key, remaining);
+                        }
+                        None if source_monitor == 0 => {
+                            self.create_empty_workspace_placeholder(workspace)?
+                        }
+                        None => {}
+                    }
+                }
+                if let Some(tree) = self.trees.remove(&target_key) {
+                    self.trees.insert(
+                        target_key,
+                        tree.insert_beside(target.hwnd, dragged, split, before),
+                    );
+                }
+            }
+        }
+
+        self.set_window_monitor(dragged, target.monitor);
+        Ok(())
+    }
+
+    fn set_window_monitor(&mut self, hwnd: HWND, monitor: usize) {
+        if let Some(window) = self.registry.get_mut(hwnd.0) {
+            if window.state == WindowState::Tiled {
+                window.monitor = monitor;
+                // A window placed by hand no longer waits for its old monitor
+                self.displaced_windows.remove(&hwnd.0);
+            }
+        }
+    }
+
+    /// Position the windows of every layout tree on a workspace.
+    pub(super) fn apply_workspace_trees(&self, workspace: usize) -> anyhow::Result<()> {
+        for (_, tree) in self.get_workspace_trees(workspace) {
+            if tree.hwnd() != Some(HWND(0)) {
+                tree.apply_layout(self.dwindle_layout.gaps_in, self.dwindle_layout.gaps_out)?;
+            }
+        }
+        Ok(())
+    }
+}
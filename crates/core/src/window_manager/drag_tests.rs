@@ -0,0 +1,179 @@
+//! Tests for mouse drag sessions, drop zones and tree hit testing.
+
+#[cfg(test)]
+mod tests {
+    use super::super::drag::{DragSession, DropZone};
+    use super::super::floating::MIN_FLOATING_SIZE;
+    use super::super::{Rect, Split, TreeNode};
+    use crate::config::MouseAction;
+    use windows::Win32::Foundation::HWND;
+
+    fn test_hwnd(id: isize) -> HWND {
+        HWND(id as _)
+    }
+
+    /// Two windows side by side: 1 on the left, 2 on the right.
+    fn two_window_tree() -> TreeNode {
+        TreeNode::new_leaf(test_hwnd(1), Rect::new(0, 0, 1000, 800))
+            .insert(test_hwnd(2), Split::Horizontal)
+    }
+
+    #[test]
+    fn test_drop_zone_edges() {
+        let target = Rect::new(0, 0, 1000, 800);
+
+        assert_eq!(
+            DropZone::from_point(&target, 50, 400),
+            Some(DropZone::SplitLeft)
+        );
+        assert_eq!(
+            DropZone::from_point(&target, 950, 400),
+            Some(DropZone::SplitRight)
+        );
+        assert_eq!(
+            DropZone::from_point(&target, 500, 50),
+            Some(DropZone::SplitTop)
+        );
+        assert_eq!(
+            DropZone::from_point(&target, 500, 750),
+            Some(DropZone::SplitBottom)
+        );
+    }
+
+    #[test]
+    fn test_drop_zone_center_swaps() {
+        let target = Rect::new(100, 100, 1000, 800);
+        assert_eq!(
+            DropZone::from_point(&target, 600, 500),
+            Some(DropZone::Swap)
+        );
+    }
+
+    #[test]
+    fn test_drop_zone_corner_uses_nearest_edge() {
+        let target = Rect::new(0, 0, 1000, 800);
+        // 5% from the left, 10% from the top
+        assert_eq!(
+            DropZone::from_point(&target, 50, 80),
+            Some(DropZone::SplitLeft)
+        );
+    }
+
+    #[test]
+    fn test_drop_zone_outside_target() {
+        let target = Rect::new(0, 0, 1000, 800);
+        assert_eq!(DropZone::from_point(&target, 1200, 400), None);
+    }
+
+    #[test]
+    fn test_drop_zone_preview_rect() {
+        let target = Rect::new(0, 0, 1000, 800);
+
+        assert_eq!(
+            DropZone::SplitRight.preview_rect(&target),
+            Rect::new(500, 0, 500, 800)
+        );
+        assert_eq!(
+            DropZone::SplitTop.preview_rect(&target),
+            Rect::new(0, 0, 1000, 400)
+        );
+        assert_eq!(DropZone::Swap.preview_rect(&target), target);
+    }
+
+    #[test]
+    fn test_tree_leaf_at() {
+        let tree = two_window_tree();
+
+        assert_eq!(tree.leaf_at(100, 100).map(|(h, _)| h), Some(test_hwnd(1)));
+        assert_eq!(
+            tree.leaf_at(900, 100),
+            Some((test_hwnd(2), Rect::new(500, 0, 500, 800)))
+        );
+        assert_eq!(tree.leaf_at(2000, 100), None);
+    }
+
+    #[test]
+    fn test_tree_swap_windows() {
+        let mut tree = two_window_tree();
+        tree.swap_windows(test_hwnd(1), test_hwnd(2));
+
+        let windows = tree.collect();
+        assert_eq!(windows[0].0, test_hwnd(2));
+        assert_eq!(windows[1].0, test_hwnd(1));
+    }
+
+    #[test]
+    fn test_tree_insert_beside() {
+        let tree =
+            two_window_tree().insert_beside(test_hwnd(2), test_hwnd(3), Split::Vertical, true);
+
+        let windows = tree.collect();
+        assert_eq!(windows.len(), 3);
+        assert_eq!(windows[1], (test_hwnd(3), Rect::new(500, 0, 500, 400)));
+        assert_eq!(windows[2], (test_hwnd(2), Rect::new(500, 400, 500, 400)));
+    }
+
+    #[test]
+    fn test_drop_apply_split() {
+        let tree = TreeNode::new_leaf(test_hwnd(1), Rect::new(0, 0, 1000, 800))
+            .insert(test_hwnd(2), Split::Horizontal)
+            .insert(test_hwnd(3), Split::Vertical);
+
+        // Drop window 1 onto the bottom of window 3
+        let tree = DropZone::SplitBottom.apply(tree, test_hwnd(1), test_hwnd(3));
+
+        let windows = tree.collect();
+        assert_eq!(windows.len(), 3);
+        assert_eq!(windows[0].0, test_hwnd(2));
+        assert_eq!(windows[2].0, test_hwnd(1));
+        assert!(windows[2].1.y > windows[1].1.y);
+    }
+
+    #[test]
+    fn test_drop_apply_swap() {
+        let tree = DropZone::Swap.apply(two_window_tree(), test_hwnd(2), test_hwnd(1));
+
+        let windows = tree.collect();
+        assert_eq!(windows[0].0, test_hwnd(2));
+        assert_eq!(windows[1].0, test_hwnd(1));
+    }
+
+    #[test]
+    fn test_drop_apply_ignores_missing_window() {
+        let tree = DropZone::SplitLeft.apply(two_window_tree(), test_hwnd(9), test_hwnd(1));
+
+        let windows = tree.collect();
+        assert_eq!(windows.len(), 2);
+        assert_eq!(windows[0].0, test_hwnd(1));
+    }
+
+    #[test]
+    fn test_drag_session_floating_move() {
+        let session = DragSession::new(
+            test_hwnd(1),
+            MouseAction::Move,
+            (100, 100),
+            Rect::new(50, 50, 400, 300),
+            true,
+        );
+
+        assert_eq!(session.floating_rect(130, 80), Rect::new(80, 30, 400, 300));
+    }
+
+    #[test]
+    fn test_drag_session_floating_resize() {
+        let session = DragSession::new(
+            test_hwnd(1),
+            MouseAction::Resize,
+            (100, 100),
+            Rect::new(50, 50, 400, 300),
+            true,
+        );
+
+        assert_eq!(session.floating_rect(150, 120), Rect::new(50, 50, 450, 320));
+
+        let shrunk = session.floating_rect(-1000, -1000);
+        assert_eq!(shrunk.width, MIN_FLOATING_SIZE);
+        assert_eq!(shrunk.height, MIN_FLOATING_SIZE);
+    }
+}
@@ -0,0 +1,111 @@
+//! Dynamic workspace operations.
+//!
+//! This module contains the WindowManager operations for creating workspaces
+//! on demand when they are shown or receive windows, and for destroying them
+//! once they are empty and left. Creation and destruction are reported to the
+//! workspace listener.
+
+use crate::window_manager::core::INITIAL_WORKSPACES;
+use crate::window_manager::special::SpecialWorkspaces;
+use crate::window_manager::WindowManager;
+use crate::workspace::core::WorkspaceEvent;
+
+impl WindowManager {
+    /// Set the listener notified when workspaces are created or destroyed.
+    pub fn set_workspace_listener(&mut self, listener: impl Fn(WorkspaceEvent) + Send + 'static) {
+        self.workspace_listener = Some(Box::new(listener));
+    }
+
+    /// Make sure a workspace has a layout tree, creating it if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace ID
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the workspace exists afterwards, or an error if no monitor
+    /// is connected to create it on.
+    pub(super) fn ensure_workspace(&mut self, workspace_id: usize) -> anyhow::Result<()> {
+        if SpecialWorkspaces::is_special(workspace_id)
+            || self.trees.keys().any(|(ws_id, _)| *ws_id == workspace_id)
+        {
+            return Ok(());
+        }
+
+        self.create_empty_workspace_placeholder(workspace_id)?;
+
+        tracing::info!("Created workspace {}", workspace_id);
+        self.emit_workspace_event(WorkspaceEvent::Created {
+            id: workspace_id,
+            name: self.workspace_name(workspace_id),
+        });
+        Ok(())
+    }
+
+    /// Destroy a workspace if it was created on demand, is empty and is not
+    /// shown.
+    ///
+    /// The initial workspaces and workspaces named by a workspace rule or a
+    /// workspace group are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace ID
+    ///
+    /// # Returns
+    ///
+    /// `true` if the workspace was destroyed.
+    pub(super) fn cleanup_workspace(&mut self, workspace_id: usize) -> bool {
+        let configured = self
+            .workspace_rules
+            .iter()
+            .any(|rule| rule.id == workspace_id)
+            || self
+                .workspace_groups
+                .iter()
+                .flat_map(|group| &group.workspaces)
+                .any(|member| member.workspace == workspace_id);
+        let shown = workspace_id == self.active_workspace
+            || self
+                .group_workspaces
+                .iter()
+                .any(|(_, ws_id)| *ws_id == workspace_id);
+
+        if workspace_id <= INITIAL_WORKSPACES
+            || SpecialWorkspaces::is_special(workspace_id)
+            || configured
+            || shown
+            || self.registry.count_in_workspace(workspace_id) > 0
+            || !self.trees.keys().any(|(ws_id, _)| *ws_id == workspace_id)
+        {
+            return false;
+        }
+
+        self.trees.retain(|(ws_id, _), _| *ws_id != workspace_id);
+        self.workspace_monitors.remove(&workspace_id);
+        for history in self.workspace_history.values_mut() {
+            history.forget(workspace_id);
+        }
+
+        tracing::info!("Destroyed empty workspace {}", workspace_id);
+        self.emit_workspace_event(WorkspaceEvent::Deleted { id: workspace_id });
+        true
+    }
+
+    /// Get the name of a workspace, as set by its workspace rule.
+    fn workspace_name(&self, workspace_id: usize) -> String {
+        self.workspace_rules
+            .iter()
+            .find(|rule| rule.id == workspace_id)
+            .and_then(|rule| rule.name.clone())
+            .unwrap_or_else(|| workspace_id.to_string())
+    }
+
+    /// Notify the workspace listener, if one is connected.
+    fn emit_workspace_event(&self, event: WorkspaceEvent) {
+        if let Some(ref listener) = self.workspace_listener {
+            listener(event);
+        }
+    }
+}
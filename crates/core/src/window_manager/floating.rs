@@ -0,0 +1,338 @@
+//! Geometry helpers for keyboard-driven placement of floating windows.
+//!
+//! This module provides the pure rectangle math behind the `float-move`,
+//! `float-resize`, `float-center` and `float-snap` commands. All calculations
+//! are performed against a monitor's work area, so space reserved by AppBars
+//! (such as the status bar) is never covered.
+//!
+//! # Example
+//!
+//! ```
+//! use tenraku_core::window_manager::Rect;
+//! use tenraku_core::window_manager::floating::{FloatingGeometry, SnapTarget};
+//!
+//! let work_area = Rect::new(0, 30, 1920, 1050);
+//! let window = Rect::new(100, 100, 800, 600);
+//!
+//! let snapped = FloatingGeometry::snap(&window, SnapTarget::LeftHalf, &work_area);
+//! assert_eq!(snapped, Rect::new(0, 30, 960, 1050));
+//! ```
+
+use crate::window_manager::Rect;
+
+/// Smallest width or height a floating window can be resized to (pixels).
+pub const MIN_FLOATING_SIZE: i32 = 50;
+
+/// Target position for the `float-snap` command.
+///
+/// Edge targets keep the window size and move it flush against that edge,
+/// corner targets move it into the corner, and half targets resize the window
+/// to fill that half of the work area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapTarget {
+    /// Flush against the left edge
+    Left,
+    /// Flush against the right edge
+    Right,
+    /// Flush against the top edge
+    Top,
+    /// Flush against the bottom edge
+    Bottom,
+    /// Top-left corner
+    TopLeft,
+    /// Top-right corner
+    TopRight,
+    /// Bottom-left corner
+    BottomLeft,
+    /// Bottom-right corner
+    BottomRight,
+    /// Fill the left half of the work area
+    LeftHalf,
+    /// Fill the right half of the work area
+    RightHalf,
+    /// Fill the top half of the work area
+    TopHalf,
+    /// Fill the bottom half of the work area
+    BottomHalf,
+}
+
+impl SnapTarget {
+    /// Parse a snap target from its command-line name.
+    ///
+    /// Accepted names are `left`, `right`, `top`, `bottom`, `top-left`,
+    /// `top-right`, `bottom-left`, `bottom-right`, `left-half`, `right-half`,
+    /// `top-half` and `bottom-half`. Underscores are accepted in place of dashes.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The snap target name
+    ///
+    /// # Returns
+    ///
+    /// The matching `SnapTarget`, or `None` if the name is not recognized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tenraku_core::window_manager::floating::SnapTarget;
+    ///
+    /// assert_eq!(SnapTarget::from_name("top-left"), Some(SnapTarget::TopLeft));
+    /// assert_eq!(SnapTarget::from_name("middle"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().replace('_', "-").as_str() {
+            "left" => Some(SnapTarget::Left),
+            "right" => Some(SnapTarget::Right),
+            "top" => Some(SnapTarget::Top),
+            "bottom" => Some(SnapTarget::Bottom),
+            "top-left" => Some(SnapTarget::TopLeft),
+            "top-right" => Some(SnapTarget::TopRight),
+            "bottom-left" => Some(SnapTarget::BottomLeft),
+            "bottom-right" => Some(SnapTarget::BottomRight),
+            "left-half" => Some(SnapTarget::LeftHalf),
+            "right-half" => Some(SnapTarget::RightHalf),
+            "top-half" => Some(SnapTarget::TopHalf),
+            "bottom-half" => Some(SnapTarget::BottomHalf),
+            _ => None,
+        }
+    }
+}
+
+/// Rectangle calculations for floating window placement.
+pub struct FloatingGeometry;
+
+impl FloatingGeometry {
+    /// Move a rectangle by an offset, snapping to nearby edges.
+    ///
+    /// After applying the offset, each axis is snapped independently to the
+    /// closest edge within `threshold` pixels. Candidate edges are the work area
+    /// borders and the borders of `others` that overlap the moved rectangle on
+    /// the perpendicular axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `rect` - The current window rectangle
+    /// * `dx` - Horizontal offset in pixels
+    /// * `dy` - Vertical offset in pixels
+    /// * `work_area` - The monitor work area
+    /// * `others` - Rectangles of other floating windows to snap against
+    /// * `threshold` - Maximum snapping distance in pixels (0 disables snapping)
+    ///
+    /// # Returns
+    ///
+    /// The new window rectangle.
+    pub fn move_by(
+        rect: &Rect,
+        dx: i32,
+        dy: i32,
+        work_area: &Rect,
+        others: &[Rect],
+        threshold: i32,
+    ) -> Rect {
+        let moved = Rect::new(rect.x + dx, rect.y + dy, rect.width, rect.height);
+
+        if threshold <= 0 {
+            return moved;
+        }
+
+        let mut x_edges = vec![work_area.x, work_area.x + work_area.width];
+        let mut y_edges = vec![work_area.y, work_area.y + work_area.height];
+
+        for other in others {
+            if Self::ranges_overlap(moved.y, moved.height, other.y, other.height) {
+                x_edges.push(other.x);
+                x_edges.push(other.x + other.width);
+            }
+            if Self::ranges_overlap(moved.x, moved.width, other.x, other.width) {
+                y_edges.push(other.y);
+                y_edges.push(other.y + other.height);
+            }
+        }
+
+        let snap_x = Self::snap_offset(&[moved.x, moved.x + moved.width], &x_edges, threshold);
+        let snap_y = Self::snap_offset(&[moved.y, moved.y + moved.height], &y_edges, threshold);
+
+        Rect::new(
+            moved.x + snap_x,
+            moved.y + snap_y,
+            moved.width,
+            moved.height,
+        )
+    }
+
+    /// Resize a rectangle by a size delta, keeping its top-left corner fixed.
+    ///
+    /// The size never drops below [`MIN_FLOATING_SIZE`] and the right and bottom
+    /// edges snap to the work area borders within `threshold` pixels.
+    ///
+    /// # Arguments
+    ///
+    /// * `rect` - The current window rectangle
+    /// * `dw` - Width change in pixels
+    /// * `dh` - Height change in pixels
+    /// * `work_area` - The monitor work area
+    /// * `threshold` - Maximum snapping distance in pixels (0 disables snapping)
+    ///
+    /// # Returns
+    ///
+    /// The new window rectangle.
+    pub fn resize_by(rect: &Rect, dw: i32, dh: i32, work_area: &Rect, threshold: i32) -> Rect {
+        let mut width = (rect.width + dw).max(MIN_FLOATING_SIZE);
+        let mut height = (rect.height + dh).max(MIN_FLOATING_SIZE);
+
+        if threshold > 0 {
+            let right = work_area.x + work_area.width;
+            let bottom = work_area.y + work_area.height;
+
+            if (rect.x + width - right).abs() <= threshold {
+                width = (right - rect.x).max(MIN_FLOATING_SIZE);
+            }
+            if (rect.y + height - bottom).abs() <= threshold {
+                height = (bottom - rect.y).max(MIN_FLOATING_SIZE);
+            }
+        }
+
+        Rect::new(rect.x, rect.y, width, height)
+    }
+
+    /// Center a rectangle within the work area.
+    ///
+    /// Windows larger than the work area are shrunk to fit.
+    ///
+    /// # Arguments
+    ///
+    /// * `rect` - The current window rectangle
+    /// * `work_area` - The monitor work area
+    ///
+    /// # Returns
+    ///
+    /// The centered window rectangle.
+    pub fn center(rect: &Rect, work_area: &Rect) -> Rect {
+        let width = rect.width.min(work_area.width);
+        let height = rect.height.min(work_area.height);
+
+        Rect::new(
+            work_area.x + (work_area.width - width) / 2,
+            work_area.y + (work_area.height - height) / 2,
+            width,
+            height,
+        )
+    }
+
+    /// Center a rectangle over another window, keeping it inside the work area.
+    ///
+    /// Used to place dialogs over their owner. The rectangle keeps its size
+    /// unless it is larger than the work area, in which case it is shrunk to fit.
+    ///
+    /// # Arguments
+    ///
+    /// * `rect` - The current window rectangle
+    /// * `owner` - The rectangle to center over
+    /// * `work_area` - The monitor work area
+    ///
+    /// # Returns
+    ///
+    /// The centered window rectangle.
+    pub fn center_over(rect: &Rect, owner: &Rect, work_area: &Rect) -> Rect {
+        let width = rect.width.min(work_area.width);
+        let height = rect.height.min(work_area.height);
+        let x = owner.x + (owner.width - width) / 2;
+        let y = owner.y + (owner.height - height) / 2;
+
+        Rect::new(
+            x.clamp(work_area.x, work_area.x + work_area.width - width),
+            y.clamp(work_area.y, work_area.y + work_area.height - height),
+            width,
+            height,
+        )
+    }
+
+    /// Place a rectangle at a snap target within the work area.
+    ///
+    /// # Arguments
+    ///
+    /// * `rect` - The current window rectangle
+    /// * `target` - Where to snap the window
+    /// * `work_area` - The monitor work area
+    ///
+    /// # Returns
+    ///
+    /// The snapped window rectangle.
+    pub fn snap(rect: &Rect, target: SnapTarget, work_area: &Rect) -> Rect {
+        let width = rect.width.min(work_area.width);
+        let height = rect.height.min(work_area.height);
+        let left = work_area.x;
+        let top = work_area.y;
+        let right = work_area.x + work_area.width - width;
+        let bottom = work_area.y + work_area.height - height;
+
+        match target {
+            SnapTarget::Left => Rect::new(left, rect.y, width, height),
+            SnapTarget::Right => Rect::new(right, rect.y, width, height),
+            SnapTarget::Top => Rect::new(rect.x, top, width, height),
+            SnapTarget::Bottom => Rect::new(rect.x, bottom, width, height),
+            SnapTarget::TopLeft => Rect::new(left, top, width, height),
+            SnapTarget::TopRight => Rect::new(right, top, width, height),
+            SnapTarget::BottomLeft => Rect::new(left, bottom, width, height),
+            SnapTarget::BottomRight => Rect::new(right, bottom, width, height),
+            SnapTarget::LeftHalf => work_area.split_horizontal(0.5).0,
+            SnapTarget::RightHalf => work_area.split_horizontal(0.5).1,
+            SnapTarget::TopHalf => work_area.split_vertical(0.5).0,
+            SnapTarget::BottomHalf => work_area.split_vertical(0.5).1,
+        }
+    }
+
+    /// Map a rectangle from one work area to another, keeping its position
+    /// and size proportional to the work area.
+    ///
+    /// Used to carry floating windows between monitors of different sizes.
+    /// The result is kept inside the target work area.
+    ///
+    /// # Arguments
+    ///
+    /// * `rect` - The window rectangle within `from`
+    /// * `from` - The work area the window is on
+    /// * `to` - The work area to move the window to
+    ///
+    /// # Returns
+    ///
+    /// The rescaled window rectangle.
+    pub fn rescale(rect: &Rect, from: &Rect, to: &Rect) -> Rect {
+        if from.width <= 0 || from.height <= 0 {
+            return Self::center(rect, to);
+        }
+
+        let scale_x = to.width as f64 / from.width as f64;
+        let scale_y = to.height as f64 / from.height as f64;
+        let width = ((rect.width as f64 * scale_x).round() as i32)
+            .clamp(MIN_FLOATING_SIZE.min(to.width), to.width);
+        let height = ((rect.height as f64 * scale_y).round() as i32)
+            .clamp(MIN_FLOATING_SIZE.min(to.height), to.height);
+        let x = to.x + ((rect.x - from.x) as f64 * scale_x).round() as i32;
+        let y = to.y + ((rect.y - from.y) as f64 * scale_y).round() as i32;
+
+        Rect::new(
+            x.clamp(to.x, to.x + to.width - width),
+            y.clamp(to.y, to.y + to.height - height),
+            width,
+            height,
+        )
+    }
+
+    /// Find the smallest offset that aligns one of `positions` with one of `edges`.
+    ///
+    /// Returns 0 if no edge lies within `threshold` pixels.
+    fn snap_offset(positions: &[i32], edges: &[i32], threshold: i32) -> i32 {
+        positions
+            .iter()
+            .flat_map(|pos| edges.iter().map(move |edge| edge - pos))
+            .filter(|offset| offset.abs() <= threshold)
+            .min_by_key(|offset| offset.abs())
+            .unwrap_or(0)
+    }
+
+    /// Check whether two one-dimensional ranges overlap.
+    fn ranges_overlap(start_a: i32, len_a: i32, start_b: i32, len_b: i32) -> bool {
+        start_a < start_b + len_b && start_b < start_a + len_a
+    }
+}
@@ -0,0 +1,192 @@
+//! Floating window placement operations.
+//!
+//! This module contains the WindowManager operations behind the keyboard
+//! move, resize, center and snap commands for floating windows, and the
+//! placement of dialogs and other transient windows over their owner.
+
+use crate::utils::win32::WindowHandle;
+use crate::window_manager::floating::{FloatingGeometry, SnapTarget};
+use crate::window_manager::{Rect, WindowManager, WindowState};
+
+impl WindowManager {
+    /// Move a floating window by an offset.
+    ///
+    /// The window snaps to the monitor work area edges and to other floating
+    /// windows on the same workspace within the configured snap threshold.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - The floating window to move
+    /// * `dx` - Horizontal offset in pixels
+    /// * `dy` - Vertical offset in pixels
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if the window could not be repositioned.
+    /// Windows that are not managed or not floating are left untouched.
+    pub fn float_move(&mut self, window: &WindowHandle, dx: i32, dy: i32) -> anyhow::Result<()> {
+        let (rect, work_area) = match self.floating_geometry(window)? {
+            Some(geometry) => geometry,
+            None => return Ok(()),
+        };
+
+        let others = self.other_floating_rects(window);
+        let new_rect =
+            FloatingGeometry::move_by(&rect, dx, dy, &work_area, &others, self.snap_threshold);
+
+        Self::place_floating(window, &new_rect)
+    }
+
+    /// Resize a floating window by a size delta.
+    ///
+    /// The top-left corner stays fixed and the right and bottom edges snap to
+    /// the monitor work area within the configured snap threshold.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - The floating window to resize
+    /// * `dw` - Width change in pixels
+    /// * `dh` - Height change in pixels
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if the window could not be resized.
+    pub fn float_resize(&mut self, window: &WindowHandle, dw: i32, dh: i32) -> anyhow::Result<()> {
+        let (rect, work_area) = match self.floating_geometry(window)? {
+            Some(geometry) => geometry,
+            None => return Ok(()),
+        };
+
+        let new_rect = FloatingGeometry::resize_by(&rect, dw, dh, &work_area, self.snap_threshold);
+
+        Self::place_floating(window, &new_rect)
+    }
+
+    /// Center a floating window on its monitor's work area.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - The floating window to center
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if the window could not be moved.
+    pub fn float_center(&mut self, window: &WindowHandle) -> anyhow::Result<()> {
+        let (rect, work_area) = match self.floating_geometry(window)? {
+            Some(geometry) => geometry,
+            None => return Ok(()),
+        };
+
+        Self::place_floating(window, &FloatingGeometry::center(&rect, &work_area))
+    }
+
+    /// Snap a floating window to an edge, corner or half of its monitor.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - The floating window to snap
+    /// * `target` - Where to place the window
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if the window could not be moved.
+    pub fn float_snap(&mut self, window: &WindowHandle, target: SnapTarget) -> anyhow::Result<()> {
+        let (rect, work_area) = match self.floating_geometry(window)? {
+            Some(geometry) => geometry,
+            None => return Ok(()),
+        };
+
+        Self::place_floating(window, &FloatingGeometry::snap(&rect, target, &work_area))
+    }
+
+    /// Center a transient window (dialog, splash screen) over its owner.
+    ///
+    /// Windows without an owner are centered on their monitor. The window is
+    /// raised so it is not hidden behind the owner after placement.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - The transient window to place
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if the window could not be moved.
+    /// Windows that are not managed transient floating windows are left untouched.
+    pub fn place_transient(&mut self, window: &WindowHandle) -> anyhow::Result<()> {
+        let owner = match self.registry.get(window.hwnd().0) {
+            Some(managed) if managed.transient => managed.owner,
+            _ => return Ok(()),
+        };
+
+        let (rect, work_area) = match self.floating_geometry(window)? {
+            Some(geometry) => geometry,
+            None => return Ok(()),
+        };
+
+        let placed = match owner.map(|owner| Self::window_rect(&owner)) {
+            Some(Ok(owner_rect)) => FloatingGeometry::center_over(&rect, &owner_rect, &work_area),
+            _ => FloatingGeometry::center(&rect, &work_area),
+        };
+
+        Self::place_floating(window, &placed)?;
+        window.raise()
+    }
+
+    /// Look up the current rectangle and monitor work area of a floating window.
+    ///
+    /// Returns `None` if the window is not managed or not floating.
+    fn floating_geometry(&self, window: &WindowHandle) -> anyhow::Result<Option<(Rect, Rect)>> {
+        let managed = match self.registry.get(window.hwnd().0) {
+            Some(managed) => managed,
+            None => {
+                tracing::debug!("Window {:?} is not managed", window.hwnd().0);
+                return Ok(None);
+            }
+        };
+
+        if managed.state != WindowState::Floating {
+            tracing::debug!("Window '{}' is not floating", managed.title);
+            return Ok(None);
+        }
+
+        let work_area = self
+            .monitors
+            .get(self.get_monitor_for_window(window.hwnd()))
+            .map(|m| m.work_area)
+            .unwrap_or_else(|| self.get_primary_monitor_work_area());
+
+        Ok(Some((Self::window_rect(window)?, work_area)))
+    }
+
+    /// Collect the rectangles of the other floating windows on the active workspace.
+    fn other_floating_rects(&self, window: &WindowHandle) -> Vec<Rect> {
+        self.registry
+            .get_floating_in_workspace(self.active_workspace)
+            .into_iter()
+            .filter(|w| w.handle != *window)
+            .filter_map(|w| Self::window_rect(&w.handle).ok())
+            .collect()
+    }
+
+    pub(super) fn window_rect(window: &WindowHandle) -> anyhow::Result<Rect> {
+        let raw = window.get_rect()?;
+        Ok(Rect::new(
+            raw.left,
+            raw.top,
+            raw.right - raw.left,
+            raw.bottom - raw.top,
+        ))
+    }
+
+    pub(super) fn place_floating(window: &WindowHandle, rect: &Rect) -> anyhow::Result<()> {
+        tracing::debug!(
+            "Placing floating window {:?} at ({}, {}) {}x{}",
+            window.hwnd().0,
+            rect.x,
+            rect.y,
+            rect.width,
+            rect.height
+        );
+        window.set_pos(rect.x, rect.y, rect.width, rect.height)
+    }
+}
@@ -0,0 +1,178 @@
+//! Tests for floating window placement geometry.
+
+#[cfg(test)]
+mod tests {
+    use super::super::floating::{FloatingGeometry, SnapTarget, MIN_FLOATING_SIZE};
+    use super::super::Rect;
+
+    fn work_area() -> Rect {
+        // Work area below a 30px status bar
+        Rect::new(0, 30, 1920, 1050)
+    }
+
+    #[test]
+    fn test_snap_target_from_name() {
+        assert_eq!(SnapTarget::from_name("left"), Some(SnapTarget::Left));
+        assert_eq!(
+            SnapTarget::from_name("bottom-right"),
+            Some(SnapTarget::BottomRight)
+        );
+        assert_eq!(SnapTarget::from_name("top_half"), Some(SnapTarget::TopHalf));
+        assert_eq!(
+            SnapTarget::from_name("Right-Half"),
+            Some(SnapTarget::RightHalf)
+        );
+        assert_eq!(SnapTarget::from_name("middle"), None);
+    }
+
+    #[test]
+    fn test_move_without_snapping() {
+        let rect = Rect::new(500, 500, 400, 300);
+        let moved = FloatingGeometry::move_by(&rect, 50, -20, &work_area(), &[], 10);
+        assert_eq!(moved, Rect::new(550, 480, 400, 300));
+    }
+
+    #[test]
+    fn test_move_snaps_to_work_area_edges() {
+        let rect = Rect::new(20, 50, 400, 300);
+        let moved = FloatingGeometry::move_by(&rect, -15, -15, &work_area(), &[], 10);
+        // Snaps to the work area, not the monitor top at y = 0
+        assert_eq!(moved, Rect::new(0, 30, 400, 300));
+    }
+
+    #[test]
+    fn test_move_snaps_right_edge() {
+        let rect = Rect::new(1400, 500, 500, 300);
+        let moved = FloatingGeometry::move_by(&rect, 15, 0, &work_area(), &[], 10);
+        assert_eq!(moved.x + moved.width, 1920);
+    }
+
+    #[test]
+    fn test_move_snaps_to_other_window() {
+        let other = Rect::new(100, 400, 300, 300);
+        let rect = Rect::new(420, 450, 200, 200);
+        let moved = FloatingGeometry::move_by(&rect, -15, 0, &work_area(), &[other], 10);
+        assert_eq!(moved.x, 400);
+    }
+
+    #[test]
+    fn test_move_ignores_non_overlapping_window() {
+        let other = Rect::new(100, 900, 300, 100);
+        let rect = Rect::new(420, 100, 200, 200);
+        let moved = FloatingGeometry::move_by(&rect, -15, 0, &work_area(), &[other], 10);
+        assert_eq!(moved.x, 405);
+    }
+
+    #[test]
+    fn test_move_with_zero_threshold_disables_snapping() {
+        let rect = Rect::new(20, 50, 400, 300);
+        let moved = FloatingGeometry::move_by(&rect, -15, -15, &work_area(), &[], 0);
+        assert_eq!(moved, Rect::new(5, 35, 400, 300));
+    }
+
+    #[test]
+    fn test_resize_respects_minimum_size() {
+        let rect = Rect::new(100, 100, 200, 200);
+        let resized = FloatingGeometry::resize_by(&rect, -500, -500, &work_area(), 10);
+        assert_eq!(resized.width, MIN_FLOATING_SIZE);
+        assert_eq!(resized.height, MIN_FLOATING_SIZE);
+    }
+
+    #[test]
+    fn test_resize_snaps_to_work_area_edges() {
+        let rect = Rect::new(1000, 600, 900, 460);
+        let resized = FloatingGeometry::resize_by(&rect, 15, 15, &work_area(), 10);
+        assert_eq!(resized, Rect::new(1000, 600, 920, 480));
+    }
+
+    #[test]
+    fn test_center() {
+        let rect = Rect::new(0, 0, 800, 600);
+        let centered = FloatingGeometry::center(&rect, &work_area());
+        assert_eq!(centered, Rect::new(560, 255, 800, 600));
+    }
+
+    #[test]
+    fn test_center_shrinks_oversized_window() {
+        let rect = Rect::new(0, 0, 2560, 1440);
+        let centered = FloatingGeometry::center(&rect, &work_area());
+        assert_eq!(centered, work_area());
+    }
+
+    #[test]
+    fn test_center_over_owner() {
+        let owner = Rect::new(100, 100, 1000, 800);
+        let dialog = Rect::new(0, 0, 400, 200);
+        let centered = FloatingGeometry::center_over(&dialog, &owner, &work_area());
+        assert_eq!(centered, Rect::new(400, 400, 400, 200));
+    }
+
+    #[test]
+    fn test_center_over_small_owner_stays_in_work_area() {
+        // Owner near the top-left corner, smaller than the dialog
+        let owner = Rect::new(0, 30, 200, 100);
+        let dialog = Rect::new(0, 0, 600, 400);
+        let centered = FloatingGeometry::center_over(&dialog, &owner, &work_area());
+        assert_eq!(centered, Rect::new(0, 30, 600, 400));
+    }
+
+    #[test]
+    fn test_snap_edges_and_corners() {
+        let rect = Rect::new(500, 500, 400, 300);
+        let area = work_area();
+
+        assert_eq!(
+            FloatingGeometry::snap(&rect, SnapTarget::Left, &area),
+            Rect::new(0, 500, 400, 300)
+        );
+        assert_eq!(
+            FloatingGeometry::snap(&rect, SnapTarget::Top, &area),
+            Rect::new(500, 30, 400, 300)
+        );
+        assert_eq!(
+            FloatingGeometry::snap(&rect, SnapTarget::BottomRight, &area),
+            Rect::new(1520, 780, 400, 300)
+        );
+    }
+
+    #[test]
+    fn test_snap_halves() {
+        let rect = Rect::new(500, 500, 400, 300);
+        let area = work_area();
+
+        assert_eq!(
+            FloatingGeometry::snap(&rect, SnapTarget::RightHalf, &area),
+            Rect::new(960, 30, 960, 1050)
+        );
+        assert_eq!(
+            FloatingGeometry::snap(&rect, SnapTarget::BottomHalf, &area),
+            Rect::new(0, 555, 1920, 525)
+        );
+    }
+
+    #[test]
+    fn test_rescale_between_work_areas() {
+        let laptop = Rect::new(0, 0, 1280, 800);
+        let external = Rect::new(1280, 30, 2560, 1410);
+
+        let rect = Rect::new(320, 200, 640, 400);
+        assert_eq!(
+            FloatingGeometry::rescale(&rect, &laptop, &external),
+            Rect::new(1920, 383, 1280, 705)
+        );
+        assert_eq!(
+            FloatingGeometry::rescale(&Rect::new(1920, 383, 1280, 705), &external, &laptop),
+            rect
+        );
+    }
+
+    #[test]
+    fn test_rescale_stays_in_work_area() {
+        let from = Rect::new(0, 0, 1920, 1080);
+        let to = Rect::new(0, 0, 1280, 720);
+
+        // Partly off-screen windows are pulled back inside
+        let rescaled = FloatingGeometry::rescale(&Rect::new(1800, 1000, 600, 400), &from, &to);
+        assert_eq!(rescaled, Rect::new(880, 453, 400, 267));
+    }
+}
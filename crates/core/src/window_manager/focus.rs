@@ -33,7 +33,7 @@ use windows::Win32::UI::WindowsAndMessaging::*;
 /// Direction for directional focus navigation.
 ///
 /// Used to navigate focus between windows based on their spatial relationship.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Direction {
     /// Focus the window to the left
     Left,
@@ -0,0 +1,142 @@
+//! Workspace group operations.
+//!
+//! This module contains the WindowManager operations for workspace groups.
+//! Switching to a group makes the group's workspace on the focused monitor
+//! the active workspace and shows the group's other workspaces on their
+//! monitors next to it, until another workspace is shown.
+
+use crate::config::WorkspaceGroup;
+use crate::window_manager::WindowManager;
+
+impl WindowManager {
+    /// Switch every monitor to its workspace of a group.
+    ///
+    /// The workspace on the focused monitor (or the first one of the group)
+    /// becomes the active workspace. The other workspaces show their windows
+    /// on their monitors, and each workspace is laid out once. Every workspace
+    /// is laid out before anything is shown, so a failure leaves the current
+    /// workspaces in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The group to switch to
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if the group is not configured or
+    /// none of its monitors is connected.
+    pub fn switch_group(&mut self, group_id: usize) -> anyhow::Result<()> {
+        let members = match self
+            .workspace_groups
+            .iter()
+            .find(|group| group.id == group_id)
+        {
+            Some(group) => self.group_members(group),
+            None => anyhow::bail!("Workspace group {} is not configured", group_id),
+        };
+        let focused = self.focused_monitor();
+        let active = match members
+            .iter()
+            .find(|(monitor, _)| *monitor == focused)
+            .or_else(|| members.first())
+        {
+            Some(&(_, workspace_id)) => workspace_id,
+            None => anyhow::bail!("No monitor of workspace group {} is connected", group_id),
+        };
+
+        tracing::info!("Switching to workspace group {}", group_id);
+        for &(_, workspace_id) in &members {
+            self.ensure_workspace(workspace_id)?;
+        }
+        self.apply_workspace_trees(active)?;
+        for &(monitor, workspace_id) in &members {
+            if workspace_id == active || self.tree_windows(workspace_id, monitor).is_empty() {
+                continue;
+            }
+            if let Some(tree) = self.trees.get(&(workspace_id, monitor)) {
+                tree.apply_layout(self.dwindle_layout.gaps_in, self.dwindle_layout.gaps_out)?;
+            }
+        }
+
+        // Everything is laid out, switch in one step
+        let previous = self.active_workspace;
+        self.make_active(active);
+        for &(monitor, workspace_id) in &members {
+            if let Some(info) = self.monitors.get_mut(monitor) {
+                info.active_workspace = Some(workspace_id);
+            }
+            if workspace_id == active {
+                continue;
+            }
+            self.set_tree_visible(active, monitor, false);
+            self.set_tree_visible(workspace_id, monitor, true);
+            self.group_workspaces.push((monitor, workspace_id));
+        }
+        if previous != active {
+            self.record_switch(focused, previous, active);
+        }
+        self.cleanup_workspace(previous);
+        Ok(())
+    }
+
+    /// Get the workspace shown on a monitor.
+    ///
+    /// # Returns
+    ///
+    /// The workspace of the shown group on that monitor, or the active
+    /// workspace.
+    pub(super) fn shown_workspace(&self, monitor: usize) -> usize {
+        self.group_workspaces
+            .iter()
+            .find(|(group_monitor, _)| *group_monitor == monitor)
+            .map(|(_, workspace_id)| *workspace_id)
+            .unwrap_or(self.active_workspace)
+    }
+
+    /// Hide the workspaces shown next to the active one by `switch_group`.
+    pub(super) fn hide_group_workspaces(&mut self) {
+        for (monitor, workspace_id) in std::mem::take(&mut self.group_workspaces) {
+            self.set_tree_visible(workspace_id, monitor, false);
+        }
+    }
+
+    /// Resolve the workspaces of a group to connected monitors.
+    ///
+    /// # Returns
+    ///
+    /// `(monitor index, workspace ID)` pairs, ordered by monitor.
+    fn group_members(&self, group: &WorkspaceGroup) -> Vec<(usize, usize)> {
+        let mut members: Vec<(usize, usize)> = group
+            .workspaces
+            .iter()
+            .filter_map(|member| {
+                self.monitors
+                    .iter()
+                    .enumerate()
+                    .find(|(idx, monitor)| member.monitor.matches(*idx, &monitor.name))
+                    .map(|(idx, _)| (idx, member.workspace))
+            })
+            .collect();
+        members.sort_unstable();
+        members
+    }
+
+    /// Show or hide the windows of a workspace's layout tree on a monitor.
+    fn set_tree_visible(&self, workspace_id: usize, monitor: usize, visible: bool) {
+        #[cfg(target_os = "windows")]
+        for (hwnd, _) in self.tree_windows(workspace_id, monitor) {
+            use crate::utils::win32::WindowHandle;
+            use windows::Win32::Foundation::HWND;
+            use windows::Win32::UI::WindowsAndMessaging::SW_SHOW;
+
+            let window = WindowHandle::from_hwnd(HWND(hwnd));
+            if visible {
+                window.show(SW_SHOW);
+            } else {
+                window.hide();
+            }
+        }
+        #[cfg(not(target_os = "windows"))]
+        let _ = (workspace_id, monitor, visible);
+    }
+}
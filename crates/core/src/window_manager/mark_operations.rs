@@ -0,0 +1,195 @@
+//! Window mark operations.
+//!
+//! This module contains the WindowManager operations behind i3-style marks:
+//! labelling a window with a unique name, jumping to it from any workspace or
+//! monitor, swapping tree positions with it, and saving marks across sessions.
+
+use crate::utils::win32::WindowHandle;
+use crate::window_manager::{WindowManager, WindowState};
+use crate::workspace::persistence::MarkState;
+use windows::Win32::Foundation::HWND;
+
+impl WindowManager {
+    /// Assign a mark to a managed window.
+    ///
+    /// Marks are unique, so the mark is moved if another window already has it.
+    /// Any previous mark on the window is replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - The window to mark
+    /// * `mark` - The mark name
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success. Windows that are not managed are left untouched.
+    pub fn mark_window(&mut self, window: &WindowHandle, mark: &str) -> anyhow::Result<()> {
+        if self.registry.set_mark(window.hwnd().0, mark) {
+            tracing::info!("Marked window {:?} as '{}'", window.hwnd().0, mark);
+        } else {
+            tracing::debug!("Window {:?} is not managed, not marking", window.hwnd().0);
+        }
+        Ok(())
+    }
+
+    /// Remove a mark, or all marks.
+    ///
+    /// # Arguments
+    ///
+    /// * `mark` - The mark to remove, or `None` to remove every mark
+    pub fn unmark(&mut self, mark: Option<&str>) {
+        match mark {
+            Some(mark) => {
+                if !self.registry.remove_mark(mark) {
+                    tracing::debug!("No window is marked '{}'", mark);
+                }
+            }
+            None => self.registry.clear_marks(),
+        }
+    }
+
+    /// Focus the window carrying a mark.
+    ///
+    /// Switches to the window's workspace first if it is not active, or shows
+    /// its special workspace, so this works across workspaces and monitors.
+    ///
+    /// # Arguments
+    ///
+    /// * `mark` - The mark name
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if the workspace switch or focus fails.
+    pub fn focus_mark(&mut self, mark: &str) -> anyhow::Result<()> {
+        let (handle, workspace) = match self.registry.find_by_mark(mark) {
+            Some(window) => (window.handle, window.workspace),
+            None => {
+                tracing::warn!("No window is marked '{}'", mark);
+                return Ok(());
+            }
+        };
+
+        self.reveal_workspace(workspace)?;
+
+        self.focus_manager.focus_window(&handle)
+    }
+
+    /// Swap the tree positions of a window and the window carrying a mark.
+    ///
+    /// Both windows must be tiled. Windows on different monitors or workspaces
+    /// exchange their monitor and workspace as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - The window to swap
+    /// * `mark` - The mark of the window to swap with
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if the layout cannot be applied.
+    pub fn swap_with_mark(&mut self, window: &WindowHandle, mark: &str) -> anyhow::Result<()> {
+        let target = match self.registry.find_by_mark(mark) {
+            Some(target) => target,
+            None => {
+                tracing::warn!("No window is marked '{}'", mark);
+                return Ok(());
+            }
+        };
+
+        let source = match self.registry.get(window.hwnd().0) {
+            Some(source) => source,
+            None => {
+                tracing::debug!("Window {:?} is not managed", window.hwnd().0);
+                return Ok(());
+            }
+        };
+
+        if source.handle == target.handle {
+            return Ok(());
+        }
+
+        if source.state != WindowState::Tiled || target.state != WindowState::Tiled {
+            tracing::debug!("Only tiled windows can be swapped with a mark");
+            return Ok(());
+        }
+
+        let a = source.handle.hwnd();
+        let b = target.handle.hwnd();
+        let source_key = (source.workspace, source.monitor);
+        let target_key = (target.workspace, target.monitor);
+
+        tracing::info!("Swapping window {:?} with mark '{}' ({:?})", a.0, mark, b.0);
+
+        for key in [source_key, target_key] {
+            if let Some(tree) = self.trees.get_mut(&key) {
+                tree.swap_windows(a, b);
+            }
+            if source_key == target_key {
+                break;
+            }
+        }
+
+        if source_key != target_key {
+            self.place_on(a, target_key);
+            self.place_on(b, source_key);
+        }
+
+        self.tile_workspace(source_key.0)?;
+        if target_key.0 != source_key.0 {
+            self.tile_workspace(target_key.0)?;
+        }
+
+        Ok(())
+    }
+
+    /// Collect the marks of all managed windows for persistence.
+    ///
+    /// # Returns
+    ///
+    /// The saved marks, sorted by mark name.
+    pub fn mark_states(&self) -> Vec<MarkState> {
+        self.registry.mark_states()
+    }
+
+    /// Restore saved marks onto managed windows.
+    ///
+    /// # Arguments
+    ///
+    /// * `marks` - The saved marks
+    ///
+    /// # Returns
+    ///
+    /// The number of marks that were restored.
+    pub fn restore_marks(&mut self, marks: &[MarkState]) -> usize {
+        let restored = marks
+            .iter()
+            .filter(|saved| self.registry.restore_mark(saved))
+            .count();
+
+        tracing::info!("Restored {} of {} saved mark(s)", restored, marks.len());
+        restored
+    }
+
+    /// Move a window's registry entry to another workspace and monitor,
+    /// hiding or showing it to match the active workspace.
+    fn place_on(&mut self, hwnd: HWND, (workspace, monitor): (usize, usize)) {
+        #[cfg(target_os = "windows")]
+        let active = self.active_workspace;
+
+        if let Some(window) = self.registry.get_mut(hwnd.0) {
+            #[cfg(target_os = "windows")]
+            {
+                use windows::Win32::UI::WindowsAndMessaging::SW_SHOW;
+
+                if window.workspace == active && workspace != active {
+                    window.handle.hide();
+                } else if window.workspace != active && workspace == active {
+                    window.handle.show(SW_SHOW);
+                }
+            }
+
+            window.workspace = workspace;
+            window.monitor = monitor;
+        }
+    }
+}
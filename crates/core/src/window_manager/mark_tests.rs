@@ -0,0 +1,126 @@
+//! Tests for window marks and mark persistence.
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::test_window;
+    use super::super::WindowRegistry;
+    use crate::workspace::persistence::MarkState;
+
+    fn saved_mark(mark: &str, hwnd: &str, process_name: &str, title: &str) -> MarkState {
+        MarkState {
+            mark: mark.to_string(),
+            hwnd: hwnd.to_string(),
+            process_name: process_name.to_string(),
+            title: title.to_string(),
+            class_name: "TestClass".to_string(),
+        }
+    }
+
+    fn test_registry() -> WindowRegistry {
+        let mut registry = WindowRegistry::new();
+        registry.register(test_window(1, "firefox.exe", "Firefox"));
+        registry.register(test_window(2, "code.exe", "Code"));
+        registry
+    }
+
+    #[test]
+    fn test_set_and_find_mark() {
+        let mut registry = test_registry();
+
+        assert!(registry.set_mark(1, "web"));
+        assert_eq!(
+            registry.find_by_mark("web").map(|w| w.handle.hwnd().0),
+            Some(1)
+        );
+        assert!(registry.find_by_mark("editor").is_none());
+    }
+
+    #[test]
+    fn test_set_mark_on_unmanaged_window() {
+        let mut registry = test_registry();
+        assert!(!registry.set_mark(99, "web"));
+        assert!(registry.find_by_mark("web").is_none());
+    }
+
+    #[test]
+    fn test_marks_are_unique() {
+        let mut registry = test_registry();
+
+        registry.set_mark(1, "main");
+        registry.set_mark(2, "main");
+
+        assert_eq!(registry.get(1).unwrap().mark, None);
+        assert_eq!(registry.get(2).unwrap().mark.as_deref(), Some("main"));
+    }
+
+    #[test]
+    fn test_mark_replaces_previous_mark() {
+        let mut registry = test_registry();
+
+        registry.set_mark(1, "a");
+        registry.set_mark(1, "b");
+
+        assert!(registry.find_by_mark("a").is_none());
+        assert_eq!(registry.get(1).unwrap().mark.as_deref(), Some("b"));
+    }
+
+    #[test]
+    fn test_remove_and_clear_marks() {
+        let mut registry = test_registry();
+        registry.set_mark(1, "a");
+        registry.set_mark(2, "b");
+
+        assert!(registry.remove_mark("a"));
+        assert!(!registry.remove_mark("a"));
+        assert!(registry.find_by_mark("b").is_some());
+
+        registry.clear_marks();
+        assert!(registry.find_by_mark("b").is_none());
+    }
+
+    #[test]
+    fn test_mark_states_round_trip() {
+        let mut registry = test_registry();
+        registry.set_mark(2, "editor");
+        registry.set_mark(1, "web");
+
+        let saved = registry.mark_states();
+        assert_eq!(saved.len(), 2);
+        assert_eq!(saved[0].mark, "editor");
+        assert_eq!(saved[1].hwnd, "1");
+
+        registry.clear_marks();
+        assert!(saved.iter().all(|mark| registry.restore_mark(mark)));
+        assert_eq!(registry.get(1).unwrap().mark.as_deref(), Some("web"));
+        assert_eq!(registry.get(2).unwrap().mark.as_deref(), Some("editor"));
+    }
+
+    #[test]
+    fn test_restore_mark_matches_by_metadata() {
+        let mut registry = WindowRegistry::new();
+        registry.register(test_window(7, "code.exe", "Code"));
+
+        // Saved in a previous session with a different handle
+        assert!(registry.restore_mark(&saved_mark("editor", "2", "code.exe", "Code")));
+        assert_eq!(registry.get(7).unwrap().mark.as_deref(), Some("editor"));
+    }
+
+    #[test]
+    fn test_restore_mark_prefers_same_handle() {
+        let mut registry = WindowRegistry::new();
+        registry.register(test_window(3, "code.exe", "Code"));
+        registry.register(test_window(4, "code.exe", "Code"));
+
+        assert!(registry.restore_mark(&saved_mark("editor", "4", "code.exe", "Code")));
+        assert_eq!(registry.get(3).unwrap().mark, None);
+        assert_eq!(registry.get(4).unwrap().mark.as_deref(), Some("editor"));
+    }
+
+    #[test]
+    fn test_restore_mark_skips_missing_windows() {
+        let mut registry = test_registry();
+
+        assert!(!registry.restore_mark(&saved_mark("notes", "5", "notepad.exe", "Notes")));
+        assert!(registry.find_by_mark("notes").is_none());
+    }
+}
@@ -18,21 +18,48 @@
 //! ```
 
 // Public submodules
+pub mod drag;
+pub mod floating;
 pub mod focus;
 pub mod layout;
 pub mod monitor;
+pub mod special;
+pub mod tags;
 pub mod tree;
 pub mod window;
 
 // Internal implementation modules
 mod core;
+mod drag_operations;
+mod dynamic_operations;
+mod floating_operations;
+mod group_operations;
 mod layout_operations;
+mod mark_operations;
+mod monitor_navigation_operations;
 mod monitor_ops;
+mod navigation_operations;
+mod placement_operations;
+mod special_operations;
+mod tag_operations;
+mod urgency_operations;
 mod window_operations;
 mod workspace_operations;
 
+#[cfg(test)]
+mod drag_tests;
+#[cfg(test)]
+mod floating_tests;
+#[cfg(test)]
+mod mark_tests;
+#[cfg(test)]
+mod monitor_tests;
+#[cfg(test)]
+pub(crate) mod test_utils;
 #[cfg(test)]
 mod tree_tests;
+#[cfg(test)]
+mod urgency_tests;
 
 // Layout types are exported for public API use in later integration tasks
 pub use focus::FocusManager;
@@ -3,9 +3,15 @@
 //! This module provides monitor tracking and management capabilities:
 //! - MonitorInfo: Information about each monitor including assigned workspaces
 //! - MonitorManager: Centralized management of all connected monitors
+//! - MonitorTarget: A monitor named by direction, index or name in commands
+//! - MonitorAdjacency: Which monitor lies next to each monitor
+//! - MonitorSplits: Regions dividing physical monitors into logical monitors
 
+use crate::config::{MonitorConfig, MonitorRegion, RegionSize};
+use crate::window_manager::focus::Direction;
 use crate::window_manager::tree::Rect;
 use std::collections::HashMap;
+use std::fmt;
 
 #[cfg(target_os = "windows")]
 use windows::Win32::Graphics::Gdi::HMONITOR;
@@ -30,6 +36,10 @@ pub struct MonitorInfo {
     /// Monitor name (e.g., "\\.\DISPLAY1")
     pub name: String,
 
+    /// Stable key of the attached display (EDID-based device path, or the
+    /// monitor name if unavailable)
+    pub key: String,
+
     /// Work area rectangle (excludes taskbar)
     pub work_area: Rect,
 
@@ -44,6 +54,9 @@ pub struct MonitorInfo {
 
     /// Currently active workspace on this monitor
     pub active_workspace: Option<usize>,
+
+    /// Key of the physical monitor this logical monitor was split from
+    pub parent: Option<String>,
 }
 
 impl MonitorInfo {
@@ -60,7 +73,7 @@ impl MonitorInfo {
     ///
     /// # Returns
     ///
-    /// A new MonitorInfo with empty workspace assignments.
+    /// A new MonitorInfo with empty workspace assignments, keyed by its name.
     #[cfg(target_os = "windows")]
     pub fn new(
         id: usize,
@@ -73,12 +86,14 @@ impl MonitorInfo {
         Self {
             id,
             handle,
+            key: name.clone(),
             name,
             work_area,
             full_area,
             dpi_scale,
             workspaces: Vec::new(),
             active_workspace: None,
+            parent: None,
         }
     }
 
@@ -95,14 +110,328 @@ impl MonitorInfo {
         Self {
             id,
             handle,
+            key: name.clone(),
             name,
             work_area,
             full_area,
             dpi_scale,
             workspaces: Vec::new(),
             active_workspace: None,
+            parent: None,
+        }
+    }
+
+    /// Set the stable key of the attached display.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Key identifying the display across reconnects
+    pub fn with_key(mut self, key: String) -> Self {
+        self.key = key;
+        self
+    }
+
+    /// Get the key of the physical display showing this monitor.
+    ///
+    /// Logical monitors split from one display share its key.
+    pub fn display_key(&self) -> &str {
+        self.parent.as_deref().unwrap_or(&self.key)
+    }
+}
+
+/// A monitor referred to by a command.
+///
+/// Commands such as `move-workspace-to-monitor` accept a direction relative
+/// to the focused monitor, a monitor index, or a monitor name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MonitorTarget {
+    /// The nearest monitor in a direction from the focused monitor
+    Direction(Direction),
+    /// A monitor by index (0-based)
+    Index(usize),
+    /// A monitor by name (e.g., "\\.\DISPLAY2"), compared case-insensitively
+    Name(String),
+}
+
+impl MonitorTarget {
+    /// Parse a monitor target from a command argument.
+    ///
+    /// `left`, `right`, `up` and `down` are directions, numbers are monitor
+    /// indices, and anything else is a monitor name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tenraku_core::window_manager::focus::Direction;
+    /// use tenraku_core::window_manager::monitor::MonitorTarget;
+    ///
+    /// assert_eq!(MonitorTarget::parse("left"), MonitorTarget::Direction(Direction::Left));
+    /// assert_eq!(MonitorTarget::parse("1"), MonitorTarget::Index(1));
+    /// ```
+    pub fn parse(value: &str) -> Self {
+        match value.to_lowercase().as_str() {
+            "left" => MonitorTarget::Direction(Direction::Left),
+            "right" => MonitorTarget::Direction(Direction::Right),
+            "up" => MonitorTarget::Direction(Direction::Up),
+            "down" => MonitorTarget::Direction(Direction::Down),
+            _ => match value.parse::<usize>() {
+                Ok(index) => MonitorTarget::Index(index),
+                Err(_) => MonitorTarget::Name(value.to_string()),
+            },
+        }
+    }
+
+    /// Find the monitor this target refers to.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - ID of the focused monitor, used for directions
+    /// * `monitors` - Connected monitors
+    ///
+    /// # Returns
+    ///
+    /// The ID of the matching monitor, or `None` if there is none.
+    pub fn resolve<'a>(
+        &self,
+        from: usize,
+        monitors: impl IntoIterator<Item = &'a MonitorInfo>,
+    ) -> Option<usize> {
+        let mut monitors = monitors.into_iter();
+        match self {
+            MonitorTarget::Index(index) => monitors.find(|m| m.id == *index).map(|m| m.id),
+            MonitorTarget::Name(name) => monitors
+                .find(|m| m.name.eq_ignore_ascii_case(name))
+                .map(|m| m.id),
+            MonitorTarget::Direction(direction) => {
+                MonitorAdjacency::new(monitors).neighbor(from, *direction)
+            }
+        }
+    }
+}
+
+impl fmt::Display for MonitorTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MonitorTarget::Direction(Direction::Left) => write!(f, "left"),
+            MonitorTarget::Direction(Direction::Right) => write!(f, "right"),
+            MonitorTarget::Direction(Direction::Up) => write!(f, "up"),
+            MonitorTarget::Direction(Direction::Down) => write!(f, "down"),
+            MonitorTarget::Index(index) => write!(f, "{}", index),
+            MonitorTarget::Name(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// The monitors next to each monitor, computed from their work areas.
+///
+/// The neighbor of a monitor in a direction is the monitor beyond it that
+/// shares the most of the facing edge, or the nearest monitor in that
+/// direction if none lines up with it.
+#[derive(Debug, Clone, Default)]
+pub struct MonitorAdjacency {
+    /// Neighbor monitor ID by monitor ID and direction
+    neighbors: HashMap<(usize, Direction), usize>,
+}
+
+impl MonitorAdjacency {
+    /// Build the adjacency graph of a set of monitors.
+    pub fn new<'a>(monitors: impl IntoIterator<Item = &'a MonitorInfo>) -> Self {
+        let monitors: Vec<&MonitorInfo> = monitors.into_iter().collect();
+        let mut neighbors = HashMap::new();
+
+        for monitor in &monitors {
+            for direction in [
+                Direction::Left,
+                Direction::Right,
+                Direction::Up,
+                Direction::Down,
+            ] {
+                if let Some(neighbor) = find_neighbor(monitor, direction, &monitors) {
+                    neighbors.insert((monitor.id, direction), neighbor);
+                }
+            }
+        }
+
+        Self { neighbors }
+    }
+
+    /// Get the monitor next to a monitor in a direction.
+    ///
+    /// # Returns
+    ///
+    /// The neighbor's ID, or `None` if no monitor lies in that direction.
+    pub fn neighbor(&self, monitor: usize, direction: Direction) -> Option<usize> {
+        self.neighbors.get(&(monitor, direction)).copied()
+    }
+}
+
+/// Find the neighbor of a monitor in a direction.
+///
+/// Candidates are monitors whose center lies beyond the monitor's center.
+/// Monitors overlapping it on the other axis win over those that do not,
+/// then the smallest gap between the facing edges and the largest overlap.
+fn find_neighbor(
+    from: &MonitorInfo,
+    direction: Direction,
+    monitors: &[&MonitorInfo],
+) -> Option<usize> {
+    let a = from.work_area;
+    let (ax, ay) = (a.x + a.width / 2, a.y + a.height / 2);
+
+    monitors
+        .iter()
+        .filter(|monitor| monitor.id != from.id)
+        .filter_map(|monitor| {
+            let b = monitor.work_area;
+            let (bx, by) = (b.x + b.width / 2, b.y + b.height / 2);
+            let horizontal_overlap = (a.x + a.width).min(b.x + b.width) - a.x.max(b.x);
+            let vertical_overlap = (a.y + a.height).min(b.y + b.height) - a.y.max(b.y);
+
+            let (ahead, gap, overlap) = match direction {
+                Direction::Left => (ax - bx, a.x - (b.x + b.width), vertical_overlap),
+                Direction::Right => (bx - ax, b.x - (a.x + a.width), vertical_overlap),
+                Direction::Up => (ay - by, a.y - (b.y + b.height), horizontal_overlap),
+                Direction::Down => (by - ay, b.y - (a.y + a.height), horizontal_overlap),
+            };
+            let distance = (bx - ax).abs() + (by - ay).abs();
+
+            (ahead > 0).then_some((overlap <= 0, gap.max(0), -overlap, distance, monitor.id))
+        })
+        .min()
+        .map(|(.., id)| id)
+}
+
+/// Regions dividing physical monitors into logical monitors.
+///
+/// Each logical monitor is a column of the physical monitor and is treated
+/// like a monitor of its own, with its own workspaces and active workspace.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MonitorSplits {
+    /// Regions by physical monitor name or key
+    regions: Vec<(String, Vec<MonitorRegion>)>,
+}
+
+impl MonitorSplits {
+    /// Collect the regions configured for each monitor.
+    ///
+    /// # Arguments
+    ///
+    /// * `monitors` - Monitor configurations
+    pub fn from_config(monitors: &[MonitorConfig]) -> Self {
+        Self {
+            regions: monitors
+                .iter()
+                .filter(|monitor| !monitor.regions.is_empty())
+                .map(|monitor| (monitor.name.clone(), monitor.regions.clone()))
+                .collect(),
         }
     }
+
+    /// Check whether no monitor is split.
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    /// Split a physical monitor into its logical monitors.
+    ///
+    /// Regions are laid out from left to right. Pixel and fraction widths are
+    /// taken first and regions without a width share the rest; regions that
+    /// do not fit on the monitor are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `monitor` - The physical monitor
+    ///
+    /// # Returns
+    ///
+    /// The logical monitors, or the monitor itself if it is not split.
+    pub fn split(&self, monitor: MonitorInfo) -> Vec<MonitorInfo> {
+        let regions = match self.regions.iter().find(|(name, _)| {
+            name.eq_ignore_ascii_case(&monitor.name) || name.eq_ignore_ascii_case(&monitor.key)
+        }) {
+            Some((_, regions)) => regions,
+            None => return vec![monitor],
+        };
+
+        let full = monitor.full_area;
+        let widths = region_widths(regions, full.width);
+        let right = full.x + full.width;
+        let mut left = full.x;
+        let mut logical = Vec::new();
+
+        for (index, (region, width)) in regions.iter().zip(widths).enumerate() {
+            let end = (left + width).min(right);
+            if end <= left {
+                tracing::warn!(
+                    "Region {} does not fit on monitor {}, skipping it",
+                    index,
+                    monitor.name
+                );
+                continue;
+            }
+
+            let work = monitor.work_area;
+            let work_left = left.max(work.x);
+            let work_right = end.min(work.x + work.width);
+
+            let mut info = monitor.clone();
+            info.name = region
+                .name
+                .clone()
+                .unwrap_or_else(|| format!("{}:{}", monitor.name, index));
+            info.key = format!("{}#{}", monitor.key, index);
+            info.parent = Some(monitor.key.clone());
+            info.full_area = Rect::new(left, full.y, end - left, full.height);
+            info.work_area = Rect::new(
+                work_left,
+                work.y,
+                (work_right - work_left).max(0),
+                work.height,
+            );
+            logical.push(info);
+            left = end;
+        }
+
+        if logical.is_empty() {
+            return vec![monitor];
+        }
+        logical
+    }
+}
+
+/// Resolve the widths of monitor regions in pixels.
+fn region_widths(regions: &[MonitorRegion], total: i32) -> Vec<i32> {
+    let fixed: Vec<Option<i32>> = regions
+        .iter()
+        .map(|region| match region.width {
+            Some(RegionSize::Pixels(pixels)) => Some(pixels.min(i32::MAX as u32) as i32),
+            Some(RegionSize::Fraction(fraction)) => {
+                Some((fraction * total as f32).round() as i32)
+            }
+            None => None,
+        })
+        .collect();
+
+    let taken: i32 = fixed.iter().flatten().sum();
+    let flexible = fixed.iter().filter(|width| width.is_none()).count() as i32;
+    let remaining = (total - taken).max(0);
+    let mut assigned = 0;
+
+    fixed
+        .iter()
+        .map(|width| match width {
+            Some(width) => *width,
+            None => {
+                // The last flexible region takes the rounding remainder
+                assigned += 1;
+                if assigned == flexible {
+                    remaining - remaining / flexible * (flexible - 1)
+                } else {
+                    remaining / flexible
+                }
+            }
+        })
+        .collect()
 }
 
 /// Manages all connected monitors.
@@ -115,6 +444,9 @@ pub struct MonitorManager {
 
     /// Next monitor ID to assign
     next_id: usize,
+
+    /// Regions splitting physical monitors into logical monitors
+    splits: MonitorSplits,
 }
 
 impl MonitorManager {
@@ -127,6 +459,7 @@ impl MonitorManager {
         Self {
             monitors: HashMap::new(),
             next_id: 0,
+            splits: MonitorSplits::default(),
         }
     }
 
@@ -147,6 +480,34 @@ impl MonitorManager {
         id
     }
 
+    /// Set the regions splitting physical monitors into logical monitors.
+    ///
+    /// Applies to monitors added with `add_physical_monitor` afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `splits` - Regions by monitor
+    pub fn set_splits(&mut self, splits: MonitorSplits) {
+        self.splits = splits;
+    }
+
+    /// Add a physical monitor, split into logical monitors if configured.
+    ///
+    /// # Arguments
+    ///
+    /// * `monitor` - MonitorInfo of the physical monitor
+    ///
+    /// # Returns
+    ///
+    /// The IDs assigned to the logical monitors, from left to right.
+    pub fn add_physical_monitor(&mut self, monitor: MonitorInfo) -> Vec<usize> {
+        self.splits
+            .split(monitor)
+            .into_iter()
+            .map(|logical| self.add_monitor(logical))
+            .collect()
+    }
+
     /// Get a monitor by ID.
     ///
     /// # Arguments
@@ -0,0 +1,197 @@
+//! Cross-monitor focus and window movement operations.
+//!
+//! This module contains the WindowManager operations that move focus to
+//! another monitor and move a window onto another monitor's workspace. The
+//! monitor next to the focused one is looked up in the monitor adjacency
+//! graph built from the monitor work areas.
+
+use crate::utils::win32::WindowHandle;
+use crate::window_manager::focus::{Direction, DirectionalFocus};
+use crate::window_manager::{Rect, WindowManager};
+
+impl WindowManager {
+    /// Get the monitor next to the focused monitor in a direction.
+    ///
+    /// # Returns
+    ///
+    /// The monitor index, or `None` if no monitor lies in that direction.
+    pub fn monitor_in_direction(&self, direction: Direction) -> Option<usize> {
+        self.monitor_adjacency
+            .neighbor(self.focused_monitor(), direction)
+    }
+
+    /// Focus a window on another monitor.
+    ///
+    /// With `from`, the window closest to the focused window in that
+    /// direction is chosen; otherwise the most recently focused window on the
+    /// monitor. If the monitor shows no windows, the cursor is moved to its
+    /// center instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `monitor` - Index of the monitor to focus
+    /// * `from` - Rectangle of the focused window and the direction moved in
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if the monitor does not exist.
+    pub fn focus_monitor(
+        &mut self,
+        monitor: usize,
+        from: Option<(&Rect, Direction)>,
+    ) -> anyhow::Result<()> {
+        let work_area = match self.monitors.get(monitor) {
+            Some(info) => info.work_area,
+            None => anyhow::bail!("Monitor {} not found", monitor),
+        };
+
+        let candidates = self.monitor_window_candidates(monitor);
+        let recent = || {
+            self.focus_manager
+                .get_history()
+                .iter()
+                .find(|hwnd| candidates.iter().any(|(candidate, _)| candidate == *hwnd))
+                .copied()
+        };
+        let target = match from {
+            Some((rect, direction)) => {
+                DirectionalFocus::find_window_in_direction(rect, direction, &candidates)
+            }
+            None => recent(),
+        }
+        .or_else(|| candidates.first().map(|(hwnd, _)| *hwnd));
+
+        match target {
+            Some(hwnd) => {
+                tracing::debug!("Focusing window {} on monitor {}", hwnd, monitor);
+                let window = WindowHandle::from_hwnd(windows::Win32::Foundation::HWND(hwnd));
+                self.focus_manager.focus_window(&window)
+            }
+            None => {
+                tracing::debug!("Monitor {} shows no windows, moving the cursor", monitor);
+                #[cfg(target_os = "windows")]
+                unsafe {
+                    use windows::Win32::UI::WindowsAndMessaging::SetCursorPos;
+                    let _ = SetCursorPos(
+                        work_area.x + work_area.width / 2,
+                        work_area.y + work_area.height / 2,
+                    );
+                }
+                #[cfg(not(target_os = "windows"))]
+                let _ = work_area;
+                Ok(())
+            }
+        }
+    }
+
+    /// Move a window onto the workspace shown on another monitor.
+    ///
+    /// The window joins the workspace shown on the target monitor and keeps
+    /// its position and size proportional to the work area. A workspace moved
+    /// to one monitor with `move_workspace_to_monitor` is released, so its
+    /// windows can be spread over monitors again.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - The window to move
+    /// * `monitor` - Index of the target monitor
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if the monitor does not exist, the
+    /// window is not managed, or a workspace rule binds the workspace to
+    /// another monitor.
+    pub fn move_window_to_monitor(
+        &mut self,
+        window: &WindowHandle,
+        monitor: usize,
+    ) -> anyhow::Result<()> {
+        if monitor >= self.monitors.len() {
+            anyhow::bail!("Monitor {} not found", monitor);
+        }
+        let hwnd = window.hwnd();
+        let old_workspace = match self.registry.get(hwnd.0) {
+            Some(managed) => managed.workspace,
+            None => anyhow::bail!("Window {:?} is not managed", hwnd.0),
+        };
+        let from = self.get_monitor_for_window(hwnd);
+        if from == monitor {
+            return Ok(());
+        }
+
+        let workspace_id = self.shown_workspace(monitor);
+        if let Some(bound) = self.bound_monitor(workspace_id) {
+            if bound != monitor {
+                anyhow::bail!(
+                    "Workspace {} is bound to monitor {} by a workspace rule",
+                    workspace_id,
+                    bound
+                );
+            }
+        }
+        if self.workspace_monitors.remove(&workspace_id).is_some() {
+            tracing::info!("Workspace {} no longer placed on one monitor", workspace_id);
+        }
+
+        tracing::info!("Moving window {:?} to monitor {}", hwnd.0, monitor);
+        let viewed = self.tag_views.viewed(monitor);
+        if let Some(managed) = self.registry.get_mut(hwnd.0) {
+            managed.monitor = monitor;
+            managed.workspace = workspace_id;
+            // Take on the target monitor's view so the window stays shown
+            if self.tag_mode {
+                managed.tags = viewed;
+            }
+        }
+        self.displaced_windows.remove(&hwnd.0);
+
+        let from_area = self.monitors[from].work_area;
+        let to_area = self.monitors[monitor].work_area;
+        self.carry_window(window, from_area, &to_area)?;
+
+        let mut workspaces = vec![old_workspace, workspace_id];
+        workspaces.dedup();
+        self.relayout_workspaces(&workspaces)
+    }
+
+    /// Collect the windows shown on a monitor with their rectangles.
+    ///
+    /// A special workspace shown on the monitor covers the workspace below
+    /// it, so only its windows are returned.
+    fn monitor_window_candidates(&self, monitor: usize) -> Vec<(isize, Rect)> {
+        let special = self
+            .special_workspaces
+            .shown_on(monitor)
+            .map(|special| self.tree_windows(special, monitor))
+            .filter(|windows| !windows.is_empty());
+        if let Some(windows) = special {
+            return windows;
+        }
+
+        let workspace_id = self.shown_workspace(monitor);
+        let mut windows = self.tree_windows(workspace_id, monitor);
+        for managed in self.registry.get_floating_in_workspace(workspace_id) {
+            if managed.monitor != monitor {
+                continue;
+            }
+            if let Ok(rect) = Self::window_rect(&managed.handle) {
+                windows.push((managed.handle.hwnd().0, rect));
+            }
+        }
+        windows
+    }
+
+    /// Collect the windows of a workspace's layout tree on a monitor.
+    pub(super) fn tree_windows(&self, workspace_id: usize, monitor: usize) -> Vec<(isize, Rect)> {
+        self.trees
+            .get(&(workspace_id, monitor))
+            .map(|tree| {
+                tree.collect()
+                    .into_iter()
+                    .filter(|(hwnd, _)| hwnd.0 != 0)
+                    .map(|(hwnd, rect)| (hwnd.0, rect))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
@@ -1,7 +1,12 @@
 //! Monitor enumeration and management operations.
 //!
-//! This module handles monitor detection, enumeration, and refresh operations.
+//! This module handles monitor detection, enumeration, and refresh operations,
+//! and moves windows between monitors when displays are connected or
+//! disconnected.
 
+use crate::utils::win32::WindowHandle;
+use crate::window_manager::floating::FloatingGeometry;
+use crate::window_manager::monitor::MonitorAdjacency;
 use crate::window_manager::{MonitorInfo, Rect, WindowManager};
 
 #[cfg(target_os = "windows")]
@@ -65,6 +70,15 @@ impl WindowManager {
             ));
         }
 
+        // Split physical monitors into their logical monitors
+        if !self.monitor_splits.is_empty() {
+            let physical = std::mem::take(&mut self.monitors);
+            self.monitors = physical
+                .into_iter()
+                .flat_map(|monitor| self.monitor_splits.split(monitor))
+                .collect();
+        }
+
         // Sort monitors by position for consistent ordering
         self.monitors
             .sort_by_key(|m| (m.work_area.x, m.work_area.y));
@@ -73,9 +87,202 @@ impl WindowManager {
         for (idx, monitor) in self.monitors.iter_mut().enumerate() {
             monitor.id = idx;
         }
+        self.monitor_adjacency = MonitorAdjacency::new(&self.monitors);
+
+        Ok(())
+    }
+
+    /// Refresh the monitors after a display change and move windows to follow.
+    ///
+    /// Windows on a disconnected monitor move to the first monitor and
+    /// remember the display they came from, then move back once that display
+    /// is connected again. Windows are rescaled proportionally between the
+    /// work areas, special workspaces shown on a disconnected monitor are
+    /// hidden, and all workspaces are retiled.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if enumeration or retiling fails.
+    pub fn reconcile_monitors(&mut self) -> anyhow::Result<()> {
+        let previous: Vec<(String, Rect)> = self
+            .monitors
+            .iter()
+            .map(|monitor| (monitor.key.clone(), monitor.work_area))
+            .collect();
+        let previous_displays: Vec<String> = self
+            .monitors
+            .iter()
+            .map(|monitor| monitor.display_key().to_string())
+            .collect();
+        self.refresh_monitors()?;
+
+        for (key, _) in &previous {
+            if self.monitor_index(key).is_none() {
+                tracing::info!("Monitor disconnected: {}", key);
+            }
+        }
+        for monitor in &self.monitors {
+            if !previous.iter().any(|(key, _)| *key == monitor.key) {
+                tracing::info!("Monitor connected: {} ({})", monitor.name, monitor.key);
+            }
+        }
+
+        let windows: Vec<(WindowHandle, usize)> = self
+            .registry
+            .get_all()
+            .into_iter()
+            .map(|window| (window.handle, window.monitor))
+            .collect();
+
+        for (handle, monitor) in windows {
+            let hwnd = handle.hwnd().0;
+            let (from_key, from_area) = match previous.get(monitor) {
+                Some(entry) => entry.clone(),
+                None => continue,
+            };
+
+            let home = self
+                .displaced_windows
+                .get(&hwnd)
+                .and_then(|key| self.monitor_index(key));
+            let target = match home {
+                Some(index) => {
+                    self.displaced_windows.remove(&hwnd);
+                    index
+                }
+                None => match self.monitor_index(&from_key) {
+                    Some(index) => index,
+                    None => match self.resplit_monitor(&handle, &previous_displays[monitor]) {
+                        // The display is still connected but split differently,
+                        // so the window stays where it is
+                        Some(index) => {
+                            if let Some(window) = self.registry.get_mut(hwnd) {
+                                window.monitor = index;
+                            }
+                            continue;
+                        }
+                        None => {
+                            self.displaced_windows.entry(hwnd).or_insert(from_key);
+                            0
+                        }
+                    },
+                },
+            };
+
+            let to_area = match self.monitors.get(target) {
+                Some(monitor) => monitor.work_area,
+                None => continue,
+            };
+            if let Some(window) = self.registry.get_mut(hwnd) {
+                window.monitor = target;
+            }
+            self.carry_window(&handle, from_area, &to_area)?;
+        }
+
+        // Placements on a monitor that is gone fall back to the window positions
+        let monitor_count = self.monitors.len();
+        self.workspace_monitors
+            .retain(|_, monitor| *monitor < monitor_count);
+
+        let hidden_specials: Vec<usize> = self
+            .special_workspaces
+            .all()
+            .iter()
+            .filter(|ws| {
+                ws.monitor
+                    .is_some_and(|monitor| monitor >= self.monitors.len())
+            })
+            .map(|ws| ws.id)
+            .collect();
+        for workspace_id in hidden_specials {
+            self.hide_special(workspace_id);
+        }
+
+        // Rebuild every layout tree for the new monitor indices and work areas
+        let mut workspaces: Vec<usize> = self
+            .trees
+            .keys()
+            .map(|(ws_id, _)| *ws_id)
+            .chain(self.registry.get_all().iter().map(|window| window.workspace))
+            .collect();
+        workspaces.sort_unstable();
+        workspaces.dedup();
+
+        self.trees.clear();
+        for workspace_id in workspaces {
+            self.retile_workspace(workspace_id)?;
+        }
 
         Ok(())
     }
+
+    /// Find the index of the connected monitor with a display key.
+    pub(super) fn monitor_index(&self, key: &str) -> Option<usize> {
+        self.monitors.iter().position(|monitor| monitor.key == key)
+    }
+
+    /// Find the logical monitor of a still connected display that now shows
+    /// a window.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - The window
+    /// * `display` - Key of the physical display the window was on
+    ///
+    /// # Returns
+    ///
+    /// The index of the monitor containing the window's center, or the first
+    /// monitor of the display, or `None` if the display is disconnected.
+    fn resplit_monitor(&self, window: &WindowHandle, display: &str) -> Option<usize> {
+        let candidates: Vec<usize> = self
+            .monitors
+            .iter()
+            .enumerate()
+            .filter(|(_, monitor)| monitor.display_key() == display)
+            .map(|(index, _)| index)
+            .collect();
+
+        let center = Self::window_rect(window)
+            .ok()
+            .map(|rect| (rect.x + rect.width / 2, rect.y + rect.height / 2));
+        center
+            .and_then(|(x, y)| {
+                candidates
+                    .iter()
+                    .copied()
+                    .find(|index| self.monitors[*index].full_area.contains_point(x, y))
+            })
+            .or_else(|| candidates.first().copied())
+    }
+
+    /// Move a window from one work area to another, keeping its position and
+    /// size proportional.
+    ///
+    /// If the window is no longer inside `from` (Windows may already have
+    /// moved it off a disconnected monitor), the work area it is on now is
+    /// used instead.
+    pub(super) fn carry_window(&self, window: &WindowHandle, from: Rect, to: &Rect) -> anyhow::Result<()> {
+        let rect = match Self::window_rect(window) {
+            Ok(rect) => rect,
+            Err(_) => return Ok(()),
+        };
+        let (center_x, center_y) = (rect.x + rect.width / 2, rect.y + rect.height / 2);
+
+        let from = if from.contains_point(center_x, center_y) {
+            from
+        } else {
+            self.monitors
+                .iter()
+                .map(|monitor| monitor.work_area)
+                .find(|area| area.contains_point(center_x, center_y))
+                .unwrap_or(*to)
+        };
+
+        if from == *to {
+            return Ok(());
+        }
+        Self::place_floating(window, &FloatingGeometry::rescale(&rect, &from, to))
+    }
 }
 
 /// Callback function for EnumDisplayMonitors.
@@ -134,14 +341,61 @@ fn get_monitor_info(hmonitor: HMONITOR) -> Option<MonitorInfo> {
         .trim_end_matches('\0')
         .to_string();
 
-    Some(MonitorInfo::new(
-        0, // Will be assigned later
-        hmonitor,
-        device_name,
-        work_rect,
-        full_rect,
-        1.0, // DPI scaling - could be enhanced with GetDpiForMonitor API
-    ))
+    let key = display_device_key(&monitor_info_ex.szDevice).unwrap_or_else(|| device_name.clone());
+
+    Some(
+        MonitorInfo::new(
+            0, // Will be assigned later
+            hmonitor,
+            device_name,
+            work_rect,
+            full_rect,
+            1.0, // DPI scaling - could be enhanced with GetDpiForMonitor API
+        )
+        .with_key(key),
+    )
+}
+
+/// Get a stable key for the display attached to a monitor.
+///
+/// Uses the device interface path of the display, which is derived from its
+/// EDID and stays the same when displays are reconnected or reordered.
+///
+/// # Arguments
+///
+/// * `device_name` - Null-terminated device name of the monitor
+#[cfg(target_os = "windows")]
+fn display_device_key(device_name: &[u16]) -> Option<String> {
+    use windows::core::PCWSTR;
+    use windows::Win32::Graphics::Gdi::{EnumDisplayDevicesW, DISPLAY_DEVICEW};
+    use windows::Win32::UI::WindowsAndMessaging::EDD_GET_DEVICE_INTERFACE_NAME;
+
+    let mut device = DISPLAY_DEVICEW {
+        cb: std::mem::size_of::<DISPLAY_DEVICEW>() as u32,
+        ..Default::default()
+    };
+
+    unsafe {
+        if !EnumDisplayDevicesW(
+            PCWSTR(device_name.as_ptr()),
+            0,
+            &mut device,
+            EDD_GET_DEVICE_INTERFACE_NAME,
+        )
+        .as_bool()
+        {
+            return None;
+        }
+    }
+
+    let key = String::from_utf16_lossy(&device.DeviceID)
+        .trim_end_matches('\0')
+        .to_string();
+    if key.is_empty() {
+        None
+    } else {
+        Some(key)
+    }
 }
 
 #[cfg(target_os = "windows")]
@@ -0,0 +1,204 @@
+//! Tests for monitor targets, adjacency and logical monitor splits.
+
+#[cfg(test)]
+mod tests {
+    use super::super::focus::Direction;
+    use super::super::monitor::{
+        MonitorAdjacency, MonitorInfo, MonitorManager, MonitorSplits, MonitorTarget,
+    };
+    use super::super::Rect;
+    use crate::config::{MonitorConfig, MonitorRegion, RegionSize};
+
+    fn monitor(id: usize, name: &str, x: i32, y: i32) -> MonitorInfo {
+        let rect = Rect::new(x, y, 1920, 1080);
+        MonitorInfo::new(id, Default::default(), name.to_string(), rect, rect, 1.0)
+    }
+
+    /// Two monitors side by side and one above the left one.
+    fn layout() -> Vec<MonitorInfo> {
+        vec![
+            monitor(0, "LEFT", 0, 0),
+            monitor(1, "RIGHT", 1920, 0),
+            monitor(2, "TOP", 0, -1080),
+        ]
+    }
+
+    #[test]
+    fn test_parse_monitor_target() {
+        assert_eq!(
+            MonitorTarget::parse("Right"),
+            MonitorTarget::Direction(Direction::Right)
+        );
+        assert_eq!(MonitorTarget::parse("2"), MonitorTarget::Index(2));
+        assert_eq!(
+            MonitorTarget::parse("\\\\.\\DISPLAY2"),
+            MonitorTarget::Name("\\\\.\\DISPLAY2".to_string())
+        );
+        assert_eq!(MonitorTarget::parse("down").to_string(), "down");
+    }
+
+    #[test]
+    fn test_resolve_monitor_target() {
+        let monitors = layout();
+
+        assert_eq!(MonitorTarget::Index(1).resolve(0, &monitors), Some(1));
+        assert_eq!(MonitorTarget::Index(5).resolve(0, &monitors), None);
+        assert_eq!(
+            MonitorTarget::Name("top".to_string()).resolve(0, &monitors),
+            Some(2)
+        );
+        assert_eq!(
+            MonitorTarget::Name("CENTER".to_string()).resolve(0, &monitors),
+            None
+        );
+    }
+
+    #[test]
+    fn test_resolve_monitor_direction() {
+        let monitors = layout();
+        let resolve =
+            |from, direction| MonitorTarget::Direction(direction).resolve(from, &monitors);
+
+        assert_eq!(resolve(0, Direction::Right), Some(1));
+        assert_eq!(resolve(0, Direction::Up), Some(2));
+        assert_eq!(resolve(0, Direction::Left), None);
+        assert_eq!(resolve(1, Direction::Left), Some(0));
+        assert_eq!(resolve(2, Direction::Down), Some(0));
+        assert_eq!(resolve(1, Direction::Up), Some(2));
+    }
+
+    #[test]
+    fn test_adjacency_prefers_aligned_monitors() {
+        // A laptop below the right half of a wide monitor, and a side
+        // monitor to the right of both
+        let monitors = vec![
+            MonitorInfo::new(
+                0,
+                Default::default(),
+                "WIDE".to_string(),
+                Rect::new(0, 0, 3440, 1440),
+                Rect::new(0, 0, 3440, 1440),
+                1.0,
+            ),
+            MonitorInfo::new(
+                1,
+                Default::default(),
+                "LAPTOP".to_string(),
+                Rect::new(1720, 1440, 1920, 1080),
+                Rect::new(1720, 1440, 1920, 1080),
+                1.0,
+            ),
+            MonitorInfo::new(
+                2,
+                Default::default(),
+                "SIDE".to_string(),
+                Rect::new(3640, 0, 1080, 1440),
+                Rect::new(3640, 0, 1080, 1440),
+                1.0,
+            ),
+        ];
+        let adjacency = MonitorAdjacency::new(&monitors);
+
+        assert_eq!(adjacency.neighbor(0, Direction::Down), Some(1));
+        assert_eq!(adjacency.neighbor(1, Direction::Up), Some(0));
+        assert_eq!(adjacency.neighbor(0, Direction::Right), Some(2));
+        // The laptop touches the side monitor at a corner, the wide one shares an edge
+        assert_eq!(adjacency.neighbor(2, Direction::Left), Some(0));
+        assert_eq!(adjacency.neighbor(1, Direction::Right), Some(2));
+        assert_eq!(adjacency.neighbor(0, Direction::Left), None);
+    }
+
+    /// A 5120x1440 ultrawide with a 40 pixel taskbar at the bottom.
+    fn ultrawide() -> MonitorInfo {
+        MonitorInfo::new(
+            0,
+            Default::default(),
+            "\\\\.\\DISPLAY1".to_string(),
+            Rect::new(0, 0, 5120, 1400),
+            Rect::new(0, 0, 5120, 1440),
+            1.0,
+        )
+    }
+
+    fn splits(regions: Vec<(Option<&str>, Option<RegionSize>)>) -> MonitorSplits {
+        MonitorSplits::from_config(&[MonitorConfig {
+            name: "\\\\.\\display1".to_string(),
+            resolution: None,
+            position: None,
+            scale: None,
+            refresh_rate: None,
+            rotation: None,
+            regions: regions
+                .into_iter()
+                .map(|(name, width)| MonitorRegion {
+                    name: name.map(str::to_string),
+                    width,
+                })
+                .collect(),
+        }])
+    }
+
+    #[test]
+    fn test_split_monitor_into_regions() {
+        let splits = splits(vec![
+            (Some("side-left"), Some(RegionSize::Pixels(1280))),
+            (None, None),
+            (Some("side-right"), Some(RegionSize::Fraction(0.25))),
+        ]);
+        let logical = splits.split(ultrawide());
+
+        let names: Vec<&str> = logical.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, ["side-left", "\\\\.\\DISPLAY1:1", "side-right"]);
+        assert_eq!(logical[0].work_area, Rect::new(0, 0, 1280, 1400));
+        assert_eq!(logical[1].work_area, Rect::new(1280, 0, 2560, 1400));
+        assert_eq!(logical[2].work_area, Rect::new(3840, 0, 1280, 1400));
+        assert_eq!(logical[2].full_area, Rect::new(3840, 0, 1280, 1440));
+
+        assert_eq!(logical[1].key, "\\\\.\\DISPLAY1#1");
+        assert!(logical.iter().all(|m| m.display_key() == "\\\\.\\DISPLAY1"));
+    }
+
+    #[test]
+    fn test_split_shares_remaining_space() {
+        let logical = splits(vec![(None, None), (None, None), (None, None)]).split(ultrawide());
+        let widths: Vec<i32> = logical.iter().map(|m| m.work_area.width).collect();
+        assert_eq!(widths, [1706, 1706, 1708]);
+
+        // Regions beyond the right edge are dropped
+        let logical = splits(vec![
+            (None, Some(RegionSize::Pixels(4000))),
+            (None, Some(RegionSize::Pixels(2000))),
+            (None, Some(RegionSize::Pixels(1000))),
+        ])
+        .split(ultrawide());
+        assert_eq!(logical.len(), 2);
+        assert_eq!(logical[1].work_area, Rect::new(4000, 0, 1120, 1400));
+
+        // Other monitors are left alone
+        let other = monitor(1, "OTHER", 5120, 0);
+        let logical = splits(vec![(None, None), (None, None)]).split(other);
+        assert_eq!(logical.len(), 1);
+        assert_eq!(logical[0].parent, None);
+    }
+
+    #[test]
+    fn test_manager_adds_logical_monitors() {
+        let mut manager = MonitorManager::new();
+        manager.set_splits(splits(vec![
+            (None, Some(RegionSize::Fraction(0.25))),
+            (None, Some(RegionSize::Fraction(0.5))),
+            (None, Some(RegionSize::Fraction(0.25))),
+        ]));
+
+        assert_eq!(manager.add_physical_monitor(ultrawide()), vec![0, 1, 2]);
+        assert_eq!(
+            manager.add_physical_monitor(monitor(0, "OTHER", 5120, 0)),
+            vec![3]
+        );
+        assert_eq!(manager.monitor_count(), 4);
+        assert_eq!(
+            manager.get_by_id(1).map(|m| m.work_area),
+            Some(Rect::new(1280, 0, 2560, 1400))
+        );
+    }
+}
@@ -0,0 +1,159 @@
+//! Workspace navigation operations.
+//!
+//! This module contains the WindowManager operations for moving between
+//! workspaces relative to the current one: back-and-forth switching, stepping
+//! through the workspace history, and cycling to the next or previous
+//! workspace.
+
+use crate::window_manager::special::SpecialWorkspaces;
+use crate::window_manager::WindowManager;
+use crate::workspace::history::cycle_workspace;
+
+impl WindowManager {
+    /// Switch to a workspace as requested by the user.
+    ///
+    /// Behaves like `switch_workspace`, except that requesting the active
+    /// workspace returns to the previous one when `auto_back_and_forth` is
+    /// enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace to switch to
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if the switch fails.
+    pub fn goto_workspace(&mut self, workspace_id: usize) -> anyhow::Result<()> {
+        if workspace_id == self.active_workspace && self.auto_back_and_forth {
+            return self.workspace_back_and_forth();
+        }
+        self.switch_workspace(workspace_id)
+    }
+
+    /// Switch to the workspace that was active before the current one on the
+    /// focused monitor.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success (including when there is no previous workspace),
+    /// or an error if the switch fails.
+    pub fn workspace_back_and_forth(&mut self) -> anyhow::Result<()> {
+        let previous = self
+            .workspace_history
+            .get(&self.focused_monitor())
+            .and_then(|history| history.previous());
+
+        match previous {
+            Some(workspace_id) => self.switch_workspace(workspace_id),
+            None => {
+                tracing::debug!("No previous workspace");
+                Ok(())
+            }
+        }
+    }
+
+    /// Go back to the previously visited workspace in the history of the
+    /// focused monitor.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success (including at the start of the history), or an
+    /// error if the switch fails.
+    pub fn workspace_history_back(&mut self) -> anyhow::Result<()> {
+        let monitor = self.focused_monitor();
+        let target = self
+            .workspace_history
+            .get_mut(&monitor)
+            .and_then(|history| history.back());
+        self.switch_from_history(target)
+    }
+
+    /// Go forward again after stepping back in the workspace history of the
+    /// focused monitor.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success (including at the end of the history), or an
+    /// error if the switch fails.
+    pub fn workspace_history_forward(&mut self) -> anyhow::Result<()> {
+        let monitor = self.focused_monitor();
+        let target = self
+            .workspace_history
+            .get_mut(&monitor)
+            .and_then(|history| history.forward());
+        self.switch_from_history(target)
+    }
+
+    /// Switch to the next or previous workspace in ID order, wrapping around.
+    ///
+    /// # Arguments
+    ///
+    /// * `forward` - `true` for the next workspace, `false` for the previous one
+    /// * `skip_empty` - Skip workspaces without managed windows
+    /// * `same_monitor` - Only consider workspaces on the focused monitor
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if the switch fails.
+    pub fn cycle_workspace(
+        &mut self,
+        forward: bool,
+        skip_empty: bool,
+        same_monitor: bool,
+    ) -> anyhow::Result<()> {
+        let monitor = self.focused_monitor();
+
+        let occupied: Vec<usize> = self
+            .registry
+            .get_all()
+            .into_iter()
+            .filter(|window| !same_monitor || window.monitor == monitor)
+            .map(|window| window.workspace)
+            .filter(|ws_id| !SpecialWorkspaces::is_special(*ws_id))
+            .collect();
+
+        let mut workspaces: Vec<usize> = self
+            .trees
+            .keys()
+            .filter(|(ws_id, mon_idx)| {
+                !SpecialWorkspaces::is_special(*ws_id) && (!same_monitor || *mon_idx == monitor)
+            })
+            .map(|(ws_id, _)| *ws_id)
+            .filter(|ws_id| !skip_empty || occupied.contains(ws_id))
+            .chain(occupied.iter().copied())
+            .chain(std::iter::once(self.active_workspace))
+            .collect();
+        workspaces.sort_unstable();
+        workspaces.dedup();
+
+        match cycle_workspace(&workspaces, self.active_workspace, forward) {
+            Some(workspace_id) => self.switch_workspace(workspace_id),
+            None => Ok(()),
+        }
+    }
+
+    /// Record a switch in the workspace history of a monitor.
+    pub(super) fn record_switch(&mut self, monitor: usize, from: usize, to: usize) {
+        self.workspace_history
+            .entry(monitor)
+            .or_default()
+            .visit(Some(from), to);
+    }
+
+    /// Switch to a workspace picked from the history without recording it.
+    fn switch_from_history(&mut self, target: Option<usize>) -> anyhow::Result<()> {
+        match target {
+            Some(workspace_id) if workspace_id != self.active_workspace => {
+                let previous = self.active_workspace;
+                self.show_workspace(workspace_id)?;
+                self.cleanup_workspace(previous);
+                Ok(())
+            }
+            Some(_) => Ok(()),
+            None => {
+                tracing::debug!("No workspace history in that direction");
+                Ok(())
+            }
+        }
+    }
+}
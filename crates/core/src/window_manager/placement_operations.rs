@@ -0,0 +1,198 @@
+//! Workspace placement operations.
+//!
+//! This module contains the WindowManager operations that move a workspace
+//! to another monitor and swap the workspaces of two monitors. Windows are
+//! carried over with their position and size rescaled to the new work area,
+//! then the affected workspaces are laid out again.
+
+use crate::window_manager::monitor::MonitorTarget;
+use crate::window_manager::special::SpecialWorkspaces;
+use crate::window_manager::{ManagedWindow, WindowManager};
+
+impl WindowManager {
+    /// Find the monitor a command target refers to.
+    ///
+    /// Directions are resolved from the focused monitor.
+    ///
+    /// # Returns
+    ///
+    /// The monitor index, or `None` if no monitor matches.
+    pub fn resolve_monitor(&self, target: &MonitorTarget) -> Option<usize> {
+        target.resolve(self.focused_monitor(), &self.monitors)
+    }
+
+    /// Find the monitors `swap_monitor_workspaces` should swap.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The monitor to swap with, or `None` for the next monitor
+    ///
+    /// # Returns
+    ///
+    /// The focused monitor index and the index of the monitor to swap it
+    /// with, or `None` if no monitor matches.
+    pub fn resolve_swap_monitors(&self, target: Option<&MonitorTarget>) -> Option<(usize, usize)> {
+        let focused = self.focused_monitor();
+        let other = match target {
+            Some(target) => self.resolve_monitor(target)?,
+            None => (focused + 1) % self.monitors.len().max(1),
+        };
+        Some((focused, other))
+    }
+
+    /// Get the monitor a workspace is placed on.
+    ///
+    /// A workspace moved with `move_workspace_to_monitor` stays there;
+    /// otherwise the monitor bound by a workspace rule is used.
+    pub(super) fn workspace_monitor(&self, workspace_id: usize) -> Option<usize> {
+        self.workspace_monitors
+            .get(&workspace_id)
+            .copied()
+            .or_else(|| self.bound_monitor(workspace_id))
+    }
+
+    /// Move a workspace and all of its windows to another monitor.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace to move
+    /// * `monitor` - Index of the target monitor
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if the monitor does not exist or the
+    /// workspace is a special workspace.
+    pub fn move_workspace_to_monitor(
+        &mut self,
+        workspace_id: usize,
+        monitor: usize,
+    ) -> anyhow::Result<()> {
+        if monitor >= self.monitors.len() {
+            anyhow::bail!("Monitor {} not found", monitor);
+        }
+        if SpecialWorkspaces::is_special(workspace_id) {
+            anyhow::bail!("Special workspaces are shown on the focused monitor");
+        }
+
+        tracing::info!("Moving workspace {} to monitor {}", workspace_id, monitor);
+        self.workspace_monitors.insert(workspace_id, monitor);
+        self.relocate_windows(|window| window.workspace == workspace_id, |_| Some(monitor))?;
+
+        let active = workspace_id == self.active_workspace;
+        for (idx, info) in self.monitors.iter_mut().enumerate() {
+            if idx == monitor && active {
+                info.active_workspace = Some(workspace_id);
+            } else if info.active_workspace == Some(workspace_id) {
+                info.active_workspace = None;
+            }
+        }
+
+        self.relayout_workspaces(&[workspace_id])
+    }
+
+    /// Swap the workspaces shown on two monitors.
+    ///
+    /// Windows on one monitor move to the other and back, and workspaces
+    /// placed on one of them are placed on the other.
+    ///
+    /// # Arguments
+    ///
+    /// * `first` - Index of the first monitor
+    /// * `second` - Index of the second monitor
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if a monitor does not exist or both
+    /// are the same monitor.
+    pub fn swap_monitor_workspaces(&mut self, first: usize, second: usize) -> anyhow::Result<()> {
+        for monitor in [first, second] {
+            if monitor >= self.monitors.len() {
+                anyhow::bail!("Monitor {} not found", monitor);
+            }
+        }
+        if first == second {
+            anyhow::bail!("Cannot swap monitor {} with itself", first);
+        }
+
+        tracing::info!("Swapping workspaces of monitors {} and {}", first, second);
+        let swap = |monitor: usize| {
+            if monitor == first {
+                Some(second)
+            } else if monitor == second {
+                Some(first)
+            } else {
+                None
+            }
+        };
+
+        for monitor in self.workspace_monitors.values_mut() {
+            if let Some(other) = swap(*monitor) {
+                *monitor = other;
+            }
+        }
+        let mut workspaces = self.relocate_windows(
+            |window| !SpecialWorkspaces::is_special(window.workspace),
+            swap,
+        )?;
+        workspaces.push(self.active_workspace);
+
+        let active_first = self.monitors[first].active_workspace;
+        self.monitors[first].active_workspace = self.monitors[second].active_workspace;
+        self.monitors[second].active_workspace = active_first;
+
+        workspaces.sort_unstable();
+        workspaces.dedup();
+        self.relayout_workspaces(&workspaces)
+    }
+
+    /// Move windows to other monitors.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - Selects the windows to move
+    /// * `target` - Maps a window's monitor to its new monitor, if it moves
+    ///
+    /// # Returns
+    ///
+    /// The workspaces of the windows that moved.
+    fn relocate_windows(
+        &mut self,
+        filter: impl Fn(&ManagedWindow) -> bool,
+        target: impl Fn(usize) -> Option<usize>,
+    ) -> anyhow::Result<Vec<usize>> {
+        let moves: Vec<_> = self
+            .registry
+            .get_all()
+            .into_iter()
+            .filter(|window| filter(window))
+            .filter_map(|window| {
+                let to = target(window.monitor).filter(|to| *to != window.monitor)?;
+                Some((window.handle, window.workspace, window.monitor, to))
+            })
+            .collect();
+
+        let mut workspaces = Vec::new();
+        for (handle, workspace_id, from, to) in moves {
+            if let Some(window) = self.registry.get_mut(handle.hwnd().0) {
+                window.monitor = to;
+            }
+            if let (Some(from), Some(to)) = (self.monitors.get(from), self.monitors.get(to)) {
+                let (from_area, to_area) = (from.work_area, to.work_area);
+                self.carry_window(&handle, from_area, &to_area)?;
+            }
+            workspaces.push(workspace_id);
+        }
+
+        Ok(workspaces)
+    }
+
+    /// Rebuild the layout trees of workspaces on their current monitors.
+    pub(super) fn relayout_workspaces(&mut self, workspaces: &[usize]) -> anyhow::Result<()> {
+        self.trees
+            .retain(|(workspace_id, _), _| !workspaces.contains(workspace_id));
+        for &workspace_id in workspaces {
+            self.retile_workspace(workspace_id)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,161 @@
+//! Special workspaces.
+//!
+//! A special workspace is a named workspace outside the normal numbering that
+//! is toggled on top of the current workspace of a monitor, like a scratchpad.
+//! Its windows are tiled in an inset area while the normal workspace stays
+//! visible behind it.
+//!
+//! # Example
+//!
+//! ```
+//! use tenraku_core::window_manager::special::SpecialWorkspaces;
+//!
+//! let mut specials = SpecialWorkspaces::new();
+//! let id = specials.get_or_create("scratch");
+//!
+//! specials.set_monitor(id, Some(0));
+//! assert_eq!(specials.shown_on(0), Some(id));
+//! assert!(SpecialWorkspaces::is_special(id));
+//! ```
+
+use crate::window_manager::tree::Rect;
+
+/// First workspace ID used for special workspaces.
+///
+/// Special workspaces share the ID space of normal workspaces so windows and
+/// layout trees can refer to them the same way; the offset keeps them clear of
+/// any workspace number a user would switch to.
+pub const SPECIAL_WORKSPACE_BASE: usize = 1 << 20;
+
+/// A named special workspace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecialWorkspace {
+    /// Workspace ID (at least `SPECIAL_WORKSPACE_BASE`)
+    pub id: usize,
+    /// Name used to toggle the workspace
+    pub name: String,
+    /// Monitor the workspace is shown on, if it is shown
+    pub monitor: Option<usize>,
+}
+
+/// Registry of special workspaces by name.
+#[derive(Debug, Clone, Default)]
+pub struct SpecialWorkspaces {
+    workspaces: Vec<SpecialWorkspace>,
+}
+
+impl SpecialWorkspaces {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check whether a workspace ID belongs to a special workspace.
+    pub fn is_special(workspace_id: usize) -> bool {
+        workspace_id >= SPECIAL_WORKSPACE_BASE
+    }
+
+    /// Get the ID of a special workspace, creating it if needed.
+    pub fn get_or_create(&mut self, name: &str) -> usize {
+        if let Some(workspace) = self.workspaces.iter().find(|ws| ws.name == name) {
+            return workspace.id;
+        }
+
+        let id = SPECIAL_WORKSPACE_BASE + self.workspaces.len();
+        self.workspaces.push(SpecialWorkspace {
+            id,
+            name: name.to_string(),
+            monitor: None,
+        });
+        id
+    }
+
+    /// Get a special workspace by ID.
+    pub fn get(&self, workspace_id: usize) -> Option<&SpecialWorkspace> {
+        self.workspaces.iter().find(|ws| ws.id == workspace_id)
+    }
+
+    /// Get the special workspace shown on a monitor, if any.
+    pub fn shown_on(&self, monitor: usize) -> Option<usize> {
+        self.workspaces
+            .iter()
+            .find(|ws| ws.monitor == Some(monitor))
+            .map(|ws| ws.id)
+    }
+
+    /// Set the monitor a special workspace is shown on, or `None` to mark it
+    /// hidden.
+    pub fn set_monitor(&mut self, workspace_id: usize, monitor: Option<usize>) {
+        if let Some(workspace) = self.workspaces.iter_mut().find(|ws| ws.id == workspace_id) {
+            workspace.monitor = monitor;
+        }
+    }
+
+    /// Get all special workspaces in creation order.
+    pub fn all(&self) -> &[SpecialWorkspace] {
+        &self.workspaces
+    }
+}
+
+/// Shrink an area around its center.
+///
+/// # Arguments
+///
+/// * `area` - The area to shrink
+/// * `scale` - Fraction of the width and height to keep (clamped to 0.1-1.0)
+pub fn inset_area(area: &Rect, scale: f32) -> Rect {
+    let scale = scale.clamp(0.1, 1.0);
+    let width = (area.width as f32 * scale).round() as i32;
+    let height = (area.height as f32 * scale).round() as i32;
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_or_create_reuses_names() {
+        let mut specials = SpecialWorkspaces::new();
+        let scratch = specials.get_or_create("scratch");
+        let music = specials.get_or_create("music");
+
+        assert_eq!(scratch, SPECIAL_WORKSPACE_BASE);
+        assert_eq!(music, SPECIAL_WORKSPACE_BASE + 1);
+        assert_eq!(specials.get_or_create("scratch"), scratch);
+        assert_eq!(specials.get(music).unwrap().name, "music");
+    }
+
+    #[test]
+    fn test_is_special() {
+        assert!(!SpecialWorkspaces::is_special(1));
+        assert!(!SpecialWorkspaces::is_special(100));
+        assert!(SpecialWorkspaces::is_special(SPECIAL_WORKSPACE_BASE));
+    }
+
+    #[test]
+    fn test_shown_on_monitor() {
+        let mut specials = SpecialWorkspaces::new();
+        let scratch = specials.get_or_create("scratch");
+        assert_eq!(specials.shown_on(0), None);
+
+        specials.set_monitor(scratch, Some(1));
+        assert_eq!(specials.shown_on(1), Some(scratch));
+        assert_eq!(specials.shown_on(0), None);
+
+        specials.set_monitor(scratch, None);
+        assert_eq!(specials.shown_on(1), None);
+    }
+
+    #[test]
+    fn test_inset_area() {
+        let area = Rect::new(100, 0, 1000, 800);
+        assert_eq!(inset_area(&area, 0.8), Rect::new(200, 80, 800, 640));
+        assert_eq!(inset_area(&area, 1.0), area);
+    }
+}
@@ -0,0 +1,201 @@
+//! Special workspace operations.
+//!
+//! This module contains the WindowManager operations for special workspaces:
+//! toggling them over the current workspace of the focused monitor, moving
+//! windows into them, and tiling their windows in an inset area.
+
+use crate::utils::win32::WindowHandle;
+use crate::window_manager::special::SpecialWorkspaces;
+use crate::window_manager::WindowManager;
+use windows::Win32::Foundation::HWND;
+
+impl WindowManager {
+    /// Toggle a special workspace on the focused monitor.
+    ///
+    /// The special workspace is created on first use. Showing it hides any
+    /// other special workspace on the same monitor, or moves it over from the
+    /// monitor it was shown on.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The special workspace name
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if the layout cannot be applied.
+    pub fn toggle_special(&mut self, name: &str) -> anyhow::Result<()> {
+        let monitor = self.focused_monitor();
+        let workspace_id = self.special_workspaces.get_or_create(name);
+        let shown_on = self
+            .special_workspaces
+            .get(workspace_id)
+            .and_then(|ws| ws.monitor);
+
+        if shown_on == Some(monitor) {
+            tracing::info!("Hiding special workspace '{}'", name);
+            self.hide_special(workspace_id);
+            Ok(())
+        } else {
+            tracing::info!("Showing special workspace '{}' on monitor {}", name, monitor);
+            self.show_special(workspace_id, monitor)
+        }
+    }
+
+    /// Move a window to a special workspace.
+    ///
+    /// The window is hidden unless the special workspace is currently shown.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - The window to move
+    /// * `name` - The special workspace name
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if the layout cannot be applied.
+    pub fn move_to_special(&mut self, window: &WindowHandle, name: &str) -> anyhow::Result<()> {
+        let workspace_id = self.special_workspaces.get_or_create(name);
+        let shown_on = self
+            .special_workspaces
+            .get(workspace_id)
+            .and_then(|ws| ws.monitor);
+
+        let old_workspace = match self.registry.get_mut(window.hwnd().0) {
+            Some(managed) if managed.workspace == workspace_id => return Ok(()),
+            Some(managed) => {
+                let old_workspace = managed.workspace;
+                managed.workspace = workspace_id;
+                if let Some(monitor) = shown_on {
+                    managed.monitor = monitor;
+                }
+                old_workspace
+            }
+            None => {
+                tracing::warn!("Window {:?} is not managed", window.hwnd().0);
+                return Ok(());
+            }
+        };
+
+        tracing::info!(
+            "Moved window {:?} from workspace {} to special workspace '{}'",
+            window.hwnd().0,
+            old_workspace,
+            name
+        );
+
+        self.retile_workspace(old_workspace)?;
+        if shown_on.is_some() {
+            self.retile_workspace(workspace_id)?;
+            #[cfg(target_os = "windows")]
+            window.raise()?;
+        } else {
+            #[cfg(target_os = "windows")]
+            window.hide();
+        }
+        Ok(())
+    }
+
+    /// Get the special workspaces.
+    pub fn special_workspaces(&self) -> &SpecialWorkspaces {
+        &self.special_workspaces
+    }
+
+    /// Show a special workspace on a monitor, on top of its normal workspace.
+    pub(super) fn show_special(&mut self, workspace_id: usize, monitor: usize) -> anyhow::Result<()> {
+        if let Some(other) = self.special_workspaces.shown_on(monitor) {
+            if other != workspace_id {
+                self.hide_special(other);
+            }
+        }
+
+        self.trees.retain(|(ws_id, _), _| *ws_id != workspace_id);
+        self.special_workspaces.set_monitor(workspace_id, Some(monitor));
+
+        let handles: Vec<WindowHandle> = self
+            .registry
+            .get_by_workspace(workspace_id)
+            .into_iter()
+            .map(|window| window.handle)
+            .collect();
+        for handle in &handles {
+            if let Some(window) = self.registry.get_mut(handle.hwnd().0) {
+                window.monitor = monitor;
+            }
+        }
+
+        self.retile_workspace(workspace_id)?;
+
+        #[cfg(target_os = "windows")]
+        {
+            use windows::Win32::UI::WindowsAndMessaging::SW_SHOW;
+            for handle in &handles {
+                handle.show(SW_SHOW);
+                handle.raise()?;
+            }
+        }
+
+        if let Some(first) = handles.first() {
+            self.focus_manager.focus_window(first)?;
+        }
+        Ok(())
+    }
+
+    /// Hide a special workspace and its windows.
+    pub(super) fn hide_special(&mut self, workspace_id: usize) {
+        #[cfg(target_os = "windows")]
+        for window in self.registry.get_by_workspace(workspace_id) {
+            window.handle.hide();
+        }
+
+        self.special_workspaces.set_monitor(workspace_id, None);
+        self.trees.retain(|(ws_id, _), _| *ws_id != workspace_id);
+    }
+
+    /// Tile the windows of a special workspace in the inset area of the
+    /// monitor it is shown on.
+    pub(super) fn retile_special(&mut self, workspace_id: usize) -> anyhow::Result<()> {
+        let monitor = match self
+            .special_workspaces
+            .get(workspace_id)
+            .and_then(|ws| ws.monitor)
+        {
+            Some(monitor) => monitor,
+            None => return Ok(()),
+        };
+
+        let windows: Vec<HWND> = self
+            .registry
+            .get_tiled_in_workspace(workspace_id)
+            .into_iter()
+            .map(|window| window.handle.hwnd())
+            .collect();
+
+        if windows.is_empty() {
+            self.trees.remove(&(workspace_id, monitor));
+            return Ok(());
+        }
+
+        self.tile_monitor(workspace_id, monitor, &windows)
+    }
+
+    /// Bring a workspace into view: switch to a normal workspace, or show a
+    /// hidden special workspace on the focused monitor.
+    pub(super) fn reveal_workspace(&mut self, workspace_id: usize) -> anyhow::Result<()> {
+        if SpecialWorkspaces::is_special(workspace_id) {
+            let hidden = self
+                .special_workspaces
+                .get(workspace_id)
+                .is_some_and(|ws| ws.monitor.is_none());
+            if hidden {
+                let monitor = self.focused_monitor();
+                return self.show_special(workspace_id, monitor);
+            }
+            return Ok(());
+        }
+
+        if workspace_id != self.active_workspace {
+            self.switch_workspace(workspace_id)?;
+        }
+        Ok(())
+    }
+}
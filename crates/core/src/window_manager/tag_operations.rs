@@ -0,0 +1,238 @@
+//! Tag operations.
+//!
+//! This module contains the WindowManager operations for dwm-style tag mode.
+//! In tag mode every window stays in the active workspace and carries a set
+//! of tags; a monitor shows the windows carrying any tag it views and tiles
+//! them together.
+
+use crate::utils::win32::WindowHandle;
+use crate::window_manager::special::SpecialWorkspaces;
+use crate::window_manager::tags::{tag_mask, tags_in, TagMask, DEFAULT_TAGS};
+use crate::window_manager::WindowManager;
+
+impl WindowManager {
+    /// Check whether windows carry tags instead of belonging to one workspace.
+    pub fn is_tag_mode(&self) -> bool {
+        self.tag_mode
+    }
+
+    /// Get the number of tags available in tag mode.
+    pub fn tag_count(&self) -> usize {
+        self.tag_count
+    }
+
+    /// Get the tags a monitor views.
+    pub fn viewed_tags(&self, monitor: usize) -> TagMask {
+        self.tag_views.viewed(monitor)
+    }
+
+    /// Make the focused monitor view only one tag.
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - The tag to view (1-based)
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if tag mode is off or the tag does
+    /// not exist.
+    pub fn view_tag(&mut self, tag: usize) -> anyhow::Result<()> {
+        let mask = self.tag_bit(tag)?;
+        let monitor = self.focused_monitor();
+        if self.tag_views.view(monitor, mask) {
+            tracing::info!("Monitor {} viewing tag {}", monitor, tag);
+            self.apply_tag_views()?;
+        }
+        Ok(())
+    }
+
+    /// Add a tag to or remove it from the focused monitor's view.
+    ///
+    /// The last viewed tag cannot be removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - The tag to toggle (1-based)
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if tag mode is off or the tag does
+    /// not exist.
+    pub fn toggle_view_tag(&mut self, tag: usize) -> anyhow::Result<()> {
+        let mask = self.tag_bit(tag)?;
+        let monitor = self.focused_monitor();
+        if self.tag_views.toggle(monitor, mask) {
+            tracing::info!(
+                "Monitor {} viewing tags {:?}",
+                monitor,
+                tags_in(self.tag_views.viewed(monitor))
+            );
+            self.apply_tag_views()?;
+        }
+        Ok(())
+    }
+
+    /// Give a window exactly one tag.
+    ///
+    /// The window is hidden if its monitor does not view the tag.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - The window to tag
+    /// * `tag` - The tag to set (1-based)
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if tag mode is off or the tag does
+    /// not exist. Windows that are not managed are left untouched.
+    pub fn tag_window(&mut self, window: &WindowHandle, tag: usize) -> anyhow::Result<()> {
+        let mask = self.tag_bit(tag)?;
+        self.set_window_tags(window, |_| mask)
+    }
+
+    /// Add a tag to or remove it from a window.
+    ///
+    /// A window keeps at least one tag, so removing its last tag is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - The window to tag
+    /// * `tag` - The tag to toggle (1-based)
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if tag mode is off or the tag does
+    /// not exist. Windows that are not managed are left untouched.
+    pub fn toggle_window_tag(&mut self, window: &WindowHandle, tag: usize) -> anyhow::Result<()> {
+        let mask = self.tag_bit(tag)?;
+        self.set_window_tags(window, |tags| tags ^ mask)
+    }
+
+    /// Switch between workspace mode and tag mode.
+    ///
+    /// Entering tag mode gathers the windows of all workspaces into the
+    /// active one and gives each the tag numbered like its workspace.
+    /// Leaving it sends each window to the workspace numbered like its
+    /// lowest tag. Special workspaces are left alone.
+    pub(super) fn set_tag_mode(&mut self, enabled: bool) -> anyhow::Result<()> {
+        tracing::info!(
+            "Switching to {} mode",
+            if enabled { "tag" } else { "workspace" }
+        );
+        self.tag_mode = enabled;
+        self.tag_views.clear();
+
+        let active = self.active_workspace;
+        let tag_count = self.tag_count;
+        let windows: Vec<_> = self
+            .registry
+            .get_all()
+            .iter()
+            .filter(|window| !SpecialWorkspaces::is_special(window.workspace))
+            .map(|window| (window.handle, window.workspace, window.tags))
+            .collect();
+
+        let mut workspaces = vec![active];
+        for (handle, workspace, tags) in windows {
+            let target = if enabled {
+                active
+            } else {
+                tags_in(tags).first().copied().unwrap_or(active)
+            };
+            if let Some(managed) = self.registry.get_mut(handle.hwnd().0) {
+                if enabled {
+                    managed.tags = tag_mask(workspace)
+                        .filter(|_| workspace <= tag_count)
+                        .unwrap_or(DEFAULT_TAGS);
+                }
+                managed.workspace = target;
+            }
+            if !enabled {
+                set_window_visible(&handle, target == active);
+            }
+            workspaces.push(workspace);
+            workspaces.push(target);
+        }
+        workspaces.sort_unstable();
+        workspaces.dedup();
+        self.relayout_workspaces(&workspaces)?;
+
+        if enabled {
+            self.apply_tag_views()?;
+        }
+        Ok(())
+    }
+
+    /// Get the mask of a tag, checking that tag mode is on and the tag exists.
+    fn tag_bit(&self, tag: usize) -> anyhow::Result<TagMask> {
+        if !self.tag_mode {
+            anyhow::bail!("Tag mode is not enabled (set general.workspace_mode = \"tags\")");
+        }
+        match tag_mask(tag).filter(|_| tag <= self.tag_count) {
+            Some(mask) => Ok(mask),
+            None => anyhow::bail!("Tag {} does not exist (1-{})", tag, self.tag_count),
+        }
+    }
+
+    /// Change a window's tags and show the result.
+    ///
+    /// Changes that would leave the window without tags are ignored.
+    fn set_window_tags(
+        &mut self,
+        window: &WindowHandle,
+        change: impl FnOnce(TagMask) -> TagMask,
+    ) -> anyhow::Result<()> {
+        let hwnd = window.hwnd().0;
+        let tags = match self.registry.get_mut(hwnd) {
+            Some(managed) => {
+                let tags = change(managed.tags);
+                if tags == 0 || tags == managed.tags {
+                    return Ok(());
+                }
+                managed.tags = tags;
+                tags
+            }
+            None => {
+                tracing::debug!("Window {:?} is not managed, not tagging", hwnd);
+                return Ok(());
+            }
+        };
+
+        tracing::info!("Tagged window {:?} with {:?}", hwnd, tags_in(tags));
+        self.apply_tag_views()
+    }
+
+    /// Show the windows of the active workspace that carry a tag viewed by
+    /// their monitor, hide the others, and lay the workspace out again.
+    pub(super) fn apply_tag_views(&mut self) -> anyhow::Result<()> {
+        let active = self.active_workspace;
+        let placed_monitor = self.workspace_monitor(active);
+
+        for window in self.registry.get_by_workspace(active) {
+            let monitor =
+                placed_monitor.unwrap_or_else(|| self.get_monitor_for_window(window.handle.hwnd()));
+            set_window_visible(
+                &window.handle,
+                self.tag_views.is_visible(monitor, window.tags),
+            );
+        }
+
+        self.relayout_workspaces(&[active])
+    }
+}
+
+/// Show or hide a window.
+fn set_window_visible(window: &WindowHandle, visible: bool) {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::UI::WindowsAndMessaging::SW_SHOW;
+
+        if visible {
+            window.show(SW_SHOW);
+        } else {
+            window.hide();
+        }
+    }
+    #[cfg(not(target_os = "windows"))]
+    let _ = (window, visible);
+}
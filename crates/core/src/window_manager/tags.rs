@@ -0,0 +1,152 @@
+//! dwm-style tags.
+//!
+//! In tag mode a window carries a set of tags instead of belonging to exactly
+//! one workspace, and each monitor views one or more tags at once. A window
+//! is shown on its monitor when it carries any of the viewed tags, and the
+//! layout tiles the union of those windows.
+//!
+//! Tag sets are bitmasks with tag 1 in the lowest bit.
+//!
+//! # Example
+//!
+//! ```
+//! use tenraku_core::window_manager::tags::{tag_mask, TagViews};
+//!
+//! let mut views = TagViews::new();
+//! assert_eq!(views.viewed(0), tag_mask(1).unwrap());
+//!
+//! views.toggle(0, tag_mask(3).unwrap());
+//! assert!(views.is_visible(0, tag_mask(3).unwrap()));
+//! assert!(!views.is_visible(1, tag_mask(3).unwrap()));
+//! ```
+
+use std::collections::HashMap;
+
+/// A set of tags, with tag 1 in the lowest bit.
+pub type TagMask = u32;
+
+/// Highest tag number a mask can hold.
+pub const MAX_TAGS: usize = TagMask::BITS as usize;
+
+/// Tags viewed by a monitor that has not changed its view, and carried by
+/// windows managed outside tag mode.
+pub const DEFAULT_TAGS: TagMask = 1;
+
+/// Get the mask of a single tag.
+///
+/// # Returns
+///
+/// The mask, or `None` if the tag is not between 1 and `MAX_TAGS`.
+pub fn tag_mask(tag: usize) -> Option<TagMask> {
+    match tag {
+        1..=MAX_TAGS => Some(1 << (tag - 1)),
+        _ => None,
+    }
+}
+
+/// List the tags in a mask in ascending order.
+pub fn tags_in(mask: TagMask) -> Vec<usize> {
+    (1..=MAX_TAGS)
+        .filter(|&tag| mask & (1 << (tag - 1)) != 0)
+        .collect()
+}
+
+/// The tags each monitor views.
+#[derive(Debug, Clone, Default)]
+pub struct TagViews {
+    views: HashMap<usize, TagMask>,
+}
+
+impl TagViews {
+    /// Create views in which every monitor views tag 1.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the tags a monitor views.
+    pub fn viewed(&self, monitor: usize) -> TagMask {
+        self.views.get(&monitor).copied().unwrap_or(DEFAULT_TAGS)
+    }
+
+    /// Check whether a window with the given tags is shown on a monitor.
+    pub fn is_visible(&self, monitor: usize, tags: TagMask) -> bool {
+        self.viewed(monitor) & tags != 0
+    }
+
+    /// Make a monitor view exactly the given tags.
+    ///
+    /// An empty mask is ignored, so a monitor always views at least one tag.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the view changed.
+    pub fn view(&mut self, monitor: usize, mask: TagMask) -> bool {
+        if mask == 0 || self.viewed(monitor) == mask {
+            return false;
+        }
+        self.views.insert(monitor, mask);
+        true
+    }
+
+    /// Add tags to or remove them from a monitor's view.
+    ///
+    /// Removing the last viewed tag is ignored.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the view changed.
+    pub fn toggle(&mut self, monitor: usize, mask: TagMask) -> bool {
+        self.view(monitor, self.viewed(monitor) ^ mask)
+    }
+
+    /// Forget every monitor's view, e.g. after the monitors were re-enumerated.
+    pub fn clear(&mut self) {
+        self.views.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tag_mask() {
+        assert_eq!(tag_mask(1), Some(0b1));
+        assert_eq!(tag_mask(4), Some(0b1000));
+        assert_eq!(tag_mask(32), Some(1 << 31));
+        assert_eq!(tag_mask(0), None);
+        assert_eq!(tag_mask(33), None);
+    }
+
+    #[test]
+    fn test_tags_in() {
+        assert_eq!(tags_in(0b1010), vec![2, 4]);
+        assert_eq!(tags_in(0), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_view_replaces_tags() {
+        let mut views = TagViews::new();
+        assert!(views.view(0, 0b110));
+        assert!(!views.view(0, 0b110));
+        assert!(!views.view(0, 0));
+
+        assert_eq!(views.viewed(0), 0b110);
+        assert_eq!(views.viewed(1), DEFAULT_TAGS);
+        assert!(views.is_visible(0, 0b011));
+        assert!(!views.is_visible(0, 0b001));
+    }
+
+    #[test]
+    fn test_toggle_keeps_one_tag() {
+        let mut views = TagViews::new();
+        assert!(views.toggle(0, 0b100));
+        assert_eq!(views.viewed(0), 0b101);
+
+        assert!(views.toggle(0, 0b001));
+        assert_eq!(views.viewed(0), 0b100);
+
+        assert!(!views.toggle(0, 0b100));
+        assert_eq!(views.viewed(0), 0b100);
+    }
+}
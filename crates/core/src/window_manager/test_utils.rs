@@ -0,0 +1,29 @@
+//! Fixtures shared by the window manager tests and other modules' tests.
+
+use super::{ManagedWindow, WindowState};
+use crate::utils::win32::WindowHandle;
+use windows::Win32::Foundation::HWND;
+
+/// Create a managed, tiled window on workspace 1 of monitor 0
+///
+/// Tests that need other values can override fields with struct update
+/// syntax, e.g. `ManagedWindow { workspace: 2, ..test_window(1, "a.exe", "A") }`.
+pub fn test_window(hwnd: isize, process_name: &str, title: &str) -> ManagedWindow {
+    ManagedWindow {
+        handle: WindowHandle::from_hwnd(HWND(hwnd)),
+        state: WindowState::Tiled,
+        workspace: 1,
+        monitor: 0,
+        title: title.to_string(),
+        class: "TestClass".to_string(),
+        process_name: process_name.to_string(),
+        original_rect: None,
+        managed: true,
+        user_floating: false,
+        owner: None,
+        transient: false,
+        mark: None,
+        urgent: false,
+        tags: 1,
+    }
+}
@@ -682,6 +682,137 @@ impl TreeNode {
         }
     }
 
+    /// Find the leaf window at a screen point.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - X coordinate of the point
+    /// * `y` - Y coordinate of the point
+    ///
+    /// # Returns
+    ///
+    /// The window handle and rectangle of the leaf containing the point,
+    /// or `None` if the point lies outside the tree.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tenraku_core::window_manager::{TreeNode, Rect, Split};
+    /// use windows::Win32::Foundation::HWND;
+    ///
+    /// let rect = Rect::new(0, 0, 1920, 1080);
+    /// let root = TreeNode::new_leaf(HWND(1 as _), rect).insert(HWND(2 as _), Split::Horizontal);
+    ///
+    /// let (hwnd, _) = root.leaf_at(1500, 500).unwrap();
+    /// assert_eq!(hwnd, HWND(2 as _));
+    /// ```
+    pub fn leaf_at(&self, x: i32, y: i32) -> Option<(HWND, Rect)> {
+        if !self.rect.contains_point(x, y) {
+            return None;
+        }
+
+        match &self.node_type {
+            NodeType::Leaf { hwnd } => Some((*hwnd, self.rect)),
+            NodeType::Container { left, right, .. } => {
+                left.leaf_at(x, y).or_else(|| right.leaf_at(x, y))
+            }
+        }
+    }
+
+    /// Check whether the tree contains a window.
+    ///
+    /// # Arguments
+    ///
+    /// * `hwnd` - The window handle to look for
+    ///
+    /// # Returns
+    ///
+    /// `true` if a leaf holds the window, `false` otherwise.
+    pub fn contains(&self, hwnd: HWND) -> bool {
+        match &self.node_type {
+            NodeType::Leaf { hwnd: leaf_hwnd } => *leaf_hwnd == hwnd,
+            NodeType::Container { left, right, .. } => left.contains(hwnd) || right.contains(hwnd),
+        }
+    }
+
+    /// Swap the positions of two windows in the tree.
+    ///
+    /// Every leaf holding `a` is changed to hold `b` and vice versa, so this
+    /// can also be used to replace a window by passing a handle that is not
+    /// in the tree.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first window handle
+    /// * `b` - The second window handle
+    pub fn swap_windows(&mut self, a: HWND, b: HWND) {
+        match &mut self.node_type {
+            NodeType::Leaf { hwnd } => {
+                if *hwnd == a {
+                    *hwnd = b;
+                } else if *hwnd == b {
+                    *hwnd = a;
+                }
+            }
+            NodeType::Container { left, right, .. } => {
+                left.swap_windows(a, b);
+                right.swap_windows(a, b);
+            }
+        }
+    }
+
+    /// Insert a window next to an existing window, splitting its leaf.
+    ///
+    /// The leaf holding `target` becomes a container with an even split between
+    /// the target and the new window. If `target` is not in the tree, the tree
+    /// is returned unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The window to split
+    /// * `hwnd` - The window handle to insert
+    /// * `split` - The split direction for the new container
+    /// * `before` - Place the new window left of/above the target instead of right of/below
+    ///
+    /// # Returns
+    ///
+    /// The modified tree.
+    pub fn insert_beside(self, target: HWND, hwnd: HWND, split: Split, before: bool) -> Self {
+        let rect = self.rect;
+        match self.node_type {
+            NodeType::Leaf { hwnd: leaf_hwnd } if leaf_hwnd == target => {
+                let (first, second) = if before {
+                    (hwnd, target)
+                } else {
+                    (target, hwnd)
+                };
+                let (left_rect, right_rect) = match split {
+                    Split::Horizontal => rect.split_horizontal(0.5),
+                    Split::Vertical => rect.split_vertical(0.5),
+                };
+
+                TreeNode::new_container(
+                    split,
+                    TreeNode::new_leaf(first, left_rect),
+                    TreeNode::new_leaf(second, right_rect),
+                    rect,
+                    0.5,
+                )
+            }
+            NodeType::Leaf { .. } => self,
+            NodeType::Container {
+                split: current_split,
+                left,
+                right,
+                ratio,
+            } => {
+                let new_left = left.insert_beside(target, hwnd, split, before);
+                let new_right = right.insert_beside(target, hwnd, split, before);
+                TreeNode::new_container(current_split, new_left, new_right, rect, ratio)
+            }
+        }
+    }
+
     /// Apply the tree's window layout using Win32 API.
     ///
     /// This method positions and sizes all windows according to the tree structure.
@@ -0,0 +1,58 @@
+//! Window urgency operations.
+//!
+//! This module contains the WindowManager operations for windows demanding
+//! attention: flagging windows whose taskbar button flashes, clearing the flag
+//! once they are focused, and jumping to the oldest urgent window.
+
+use crate::utils::win32::WindowHandle;
+use crate::window_manager::WindowManager;
+
+impl WindowManager {
+    /// Set or clear a window's urgent flag.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - The window demanding (or no longer demanding) attention
+    /// * `urgent` - Whether the window is urgent
+    ///
+    /// # Returns
+    ///
+    /// `true` if the flag changed, `false` if the window is not managed or
+    /// already had that state.
+    pub fn set_urgent(&mut self, window: &WindowHandle, urgent: bool) -> bool {
+        let changed = self.registry.set_urgent(window.hwnd().0, urgent);
+        if changed {
+            tracing::debug!(
+                "Window {:?} is {}",
+                window.hwnd().0,
+                if urgent { "urgent" } else { "no longer urgent" }
+            );
+        }
+        changed
+    }
+
+    /// Focus the window that has been urgent the longest.
+    ///
+    /// Switches to the window's workspace first if it is not active, or shows
+    /// its special workspace. The window's urgent flag is cleared once it is
+    /// focused.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if the workspace switch or focus fails.
+    pub fn focus_urgent(&mut self) -> anyhow::Result<()> {
+        let (handle, workspace) = match self.registry.oldest_urgent() {
+            Some(window) => (window.handle, window.workspace),
+            None => {
+                tracing::debug!("No urgent windows");
+                return Ok(());
+            }
+        };
+
+        self.reveal_workspace(workspace)?;
+
+        self.focus_manager.focus_window(&handle)?;
+        self.set_urgent(&handle, false);
+        Ok(())
+    }
+}
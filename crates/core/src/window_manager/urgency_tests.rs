@@ -0,0 +1,75 @@
+//! Tests for window urgency tracking.
+
+#[cfg(test)]
+mod tests {
+    use super::super::{test_utils, ManagedWindow, WindowRegistry};
+
+    fn test_window(id: isize, workspace: usize) -> ManagedWindow {
+        ManagedWindow {
+            workspace,
+            ..test_utils::test_window(id, "test.exe", &format!("Window {}", id))
+        }
+    }
+
+    fn test_registry() -> WindowRegistry {
+        let mut registry = WindowRegistry::new();
+        registry.register(test_window(1, 1));
+        registry.register(test_window(2, 2));
+        registry.register(test_window(3, 2));
+        registry
+    }
+
+    fn oldest(registry: &WindowRegistry) -> Option<isize> {
+        registry.oldest_urgent().map(|w| w.handle.hwnd().0)
+    }
+
+    #[test]
+    fn test_set_urgent() {
+        let mut registry = test_registry();
+
+        assert!(registry.set_urgent(2, true));
+        assert!(registry.get(2).unwrap().urgent);
+        assert!(!registry.set_urgent(2, true));
+        assert!(!registry.set_urgent(99, true));
+
+        assert!(registry.set_urgent(2, false));
+        assert!(!registry.get(2).unwrap().urgent);
+        assert_eq!(oldest(&registry), None);
+    }
+
+    #[test]
+    fn test_oldest_urgent_order() {
+        let mut registry = test_registry();
+
+        registry.set_urgent(3, true);
+        registry.set_urgent(1, true);
+        assert_eq!(oldest(&registry), Some(3));
+
+        // Flashing again keeps the original position
+        registry.set_urgent(3, true);
+        assert_eq!(oldest(&registry), Some(3));
+
+        registry.set_urgent(3, false);
+        assert_eq!(oldest(&registry), Some(1));
+    }
+
+    #[test]
+    fn test_unregister_clears_urgency() {
+        let mut registry = test_registry();
+        registry.set_urgent(2, true);
+        registry.set_urgent(3, true);
+
+        registry.unregister(2);
+        assert_eq!(oldest(&registry), Some(3));
+    }
+
+    #[test]
+    fn test_has_urgent_in_workspace() {
+        let mut registry = test_registry();
+        assert!(!registry.has_urgent_in_workspace(2));
+
+        registry.set_urgent(3, true);
+        assert!(registry.has_urgent_in_workspace(2));
+        assert!(!registry.has_urgent_in_workspace(1));
+    }
+}
@@ -4,7 +4,9 @@
 //! including tiled, floating, fullscreen, and minimized states.
 
 use crate::utils::win32::WindowHandle;
+use crate::window_manager::tags::{TagMask, DEFAULT_TAGS};
 use crate::window_manager::tree::Rect;
+use crate::workspace::persistence::MarkState;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use windows::Win32::Foundation::RECT;
@@ -54,6 +56,17 @@ pub struct ManagedWindow {
     pub managed: bool,
     /// User-specified floating state preference
     pub user_floating: bool,
+    /// Visible owner window, if this window is owned (e.g. a dialog)
+    pub owner: Option<WindowHandle>,
+    /// Whether this is a transient window (dialog, splash screen) that
+    /// floats over its owner instead of being tiled
+    pub transient: bool,
+    /// User-assigned mark, unique across all managed windows
+    pub mark: Option<String>,
+    /// Whether the window is demanding attention (flashing taskbar button)
+    pub urgent: bool,
+    /// Tags the window carries in tag mode
+    pub tags: TagMask,
 }
 
 impl ManagedWindow {
@@ -72,6 +85,8 @@ impl ManagedWindow {
         let title = handle.get_title().unwrap_or_default();
         let class = handle.get_class_name().unwrap_or_default();
         let process_name = handle.get_process_name().unwrap_or_default();
+        let owner = handle.get_owner().filter(|owner| owner.is_visible());
+        let transient = handle.is_owned_dialog();
 
         Ok(Self {
             handle,
@@ -84,6 +99,11 @@ impl ManagedWindow {
             original_rect: None,
             managed: true,
             user_floating: false,
+            owner,
+            transient,
+            mark: None,
+            urgent: false,
+            tags: DEFAULT_TAGS,
         })
     }
 
@@ -243,6 +263,8 @@ impl ManagedWindow {
 pub struct WindowRegistry {
     /// Map of window handles (hwnd.0) to managed windows
     windows: HashMap<isize, ManagedWindow>,
+    /// Urgent windows (hwnd.0) in the order they became urgent
+    urgent_queue: Vec<isize>,
 }
 
 impl WindowRegistry {
@@ -250,6 +272,7 @@ impl WindowRegistry {
     pub fn new() -> Self {
         Self {
             windows: HashMap::new(),
+            urgent_queue: Vec::new(),
         }
     }
 
@@ -259,7 +282,11 @@ impl WindowRegistry {
     ///
     /// * `window` - The managed window to register
     pub fn register(&mut self, window: ManagedWindow) {
-        self.windows.insert(window.handle.hwnd().0, window);
+        let hwnd = window.handle.hwnd().0;
+        if window.urgent && !self.urgent_queue.contains(&hwnd) {
+            self.urgent_queue.push(hwnd);
+        }
+        self.windows.insert(hwnd, window);
     }
 
     /// Unregister a window.
@@ -272,6 +299,7 @@ impl WindowRegistry {
     ///
     /// The unregistered ManagedWindow, or None if not found.
     pub fn unregister(&mut self, hwnd: isize) -> Option<ManagedWindow> {
+        self.urgent_queue.retain(|&urgent| urgent != hwnd);
         self.windows.remove(&hwnd)
     }
 
@@ -383,6 +411,194 @@ impl WindowRegistry {
         self.windows.values().collect()
     }
 
+    /// Find the window carrying a mark.
+    ///
+    /// # Arguments
+    ///
+    /// * `mark` - The mark name
+    ///
+    /// # Returns
+    ///
+    /// A reference to the marked window, or None if no window has the mark.
+    pub fn find_by_mark(&self, mark: &str) -> Option<&ManagedWindow> {
+        self.windows
+            .values()
+            .find(|w| w.mark.as_deref() == Some(mark))
+    }
+
+    /// Assign a mark to a window.
+    ///
+    /// Marks are unique: the mark is removed from any other window first.
+    /// A window carries at most one mark, so any previous mark is replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `hwnd` - The window handle value (HWND.0)
+    /// * `mark` - The mark name
+    ///
+    /// # Returns
+    ///
+    /// `true` if the window is registered and was marked, `false` otherwise.
+    pub fn set_mark(&mut self, hwnd: isize, mark: &str) -> bool {
+        if !self.windows.contains_key(&hwnd) {
+            return false;
+        }
+
+        self.remove_mark(mark);
+
+        if let Some(window) = self.windows.get_mut(&hwnd) {
+            window.mark = Some(mark.to_string());
+        }
+        true
+    }
+
+    /// Remove a mark from whichever window carries it.
+    ///
+    /// # Arguments
+    ///
+    /// * `mark` - The mark name
+    ///
+    /// # Returns
+    ///
+    /// `true` if a window had the mark, `false` otherwise.
+    pub fn remove_mark(&mut self, mark: &str) -> bool {
+        let mut removed = false;
+        for window in self.windows.values_mut() {
+            if window.mark.as_deref() == Some(mark) {
+                window.mark = None;
+                removed = true;
+            }
+        }
+        removed
+    }
+
+    /// Remove all marks from all windows.
+    pub fn clear_marks(&mut self) {
+        for window in self.windows.values_mut() {
+            window.mark = None;
+        }
+    }
+
+    /// Collect the marks of all windows for persistence.
+    ///
+    /// # Returns
+    ///
+    /// The saved marks, sorted by mark name.
+    pub fn mark_states(&self) -> Vec<MarkState> {
+        let mut marks: Vec<MarkState> = self
+            .windows
+            .values()
+            .filter_map(|w| {
+                w.mark.as_ref().map(|mark| MarkState {
+                    mark: mark.clone(),
+                    hwnd: format!("{}", w.handle.hwnd().0),
+                    process_name: w.process_name.clone(),
+                    title: w.title.clone(),
+                    class_name: w.class.clone(),
+                })
+            })
+            .collect();
+
+        marks.sort_by(|a, b| a.mark.cmp(&b.mark));
+        marks
+    }
+
+    /// Restore a saved mark onto a window.
+    ///
+    /// The mark is matched to the window with the same handle and process
+    /// first. If the handle changed (e.g. after a restart), the first unmarked
+    /// window with the same process, class and title is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `saved` - The saved mark
+    ///
+    /// # Returns
+    ///
+    /// `true` if a matching window was found and marked, `false` otherwise.
+    pub fn restore_mark(&mut self, saved: &MarkState) -> bool {
+        let by_handle = self.windows.values().find(|w| {
+            format!("{}", w.handle.hwnd().0) == saved.hwnd && w.process_name == saved.process_name
+        });
+        let by_metadata = || {
+            self.windows.values().find(|w| {
+                w.mark.is_none()
+                    && w.process_name == saved.process_name
+                    && w.class == saved.class_name
+                    && w.title == saved.title
+            })
+        };
+
+        match by_handle.or_else(by_metadata) {
+            Some(window) => {
+                let hwnd = window.handle.hwnd().0;
+                self.set_mark(hwnd, &saved.mark)
+            }
+            None => {
+                tracing::debug!("No window found for saved mark '{}'", saved.mark);
+                false
+            }
+        }
+    }
+
+    /// Set or clear a window's urgent flag.
+    ///
+    /// Urgent windows are remembered in the order they became urgent, so
+    /// re-flashing an already urgent window keeps its place.
+    ///
+    /// # Arguments
+    ///
+    /// * `hwnd` - The window handle value (HWND.0)
+    /// * `urgent` - Whether the window demands attention
+    ///
+    /// # Returns
+    ///
+    /// `true` if the window is registered and its flag changed, `false` otherwise.
+    pub fn set_urgent(&mut self, hwnd: isize, urgent: bool) -> bool {
+        let window = match self.windows.get_mut(&hwnd) {
+            Some(window) => window,
+            None => return false,
+        };
+
+        if window.urgent == urgent {
+            return false;
+        }
+
+        window.urgent = urgent;
+        if urgent {
+            self.urgent_queue.push(hwnd);
+        } else {
+            self.urgent_queue.retain(|&queued| queued != hwnd);
+        }
+        true
+    }
+
+    /// Get the window that has been urgent the longest.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the oldest urgent window, or None if no window is urgent.
+    pub fn oldest_urgent(&self) -> Option<&ManagedWindow> {
+        self.urgent_queue
+            .first()
+            .and_then(|hwnd| self.windows.get(hwnd))
+    }
+
+    /// Check whether a workspace contains an urgent window.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace` - The workspace ID
+    ///
+    /// # Returns
+    ///
+    /// `true` if any window on the workspace is urgent.
+    pub fn has_urgent_in_workspace(&self, workspace: usize) -> bool {
+        self.windows
+            .values()
+            .any(|w| w.urgent && w.workspace == workspace)
+    }
+
     /// Check if a window is registered.
     ///
     /// # Arguments
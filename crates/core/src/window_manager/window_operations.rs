@@ -3,6 +3,8 @@
 //! This module contains operations for adding, removing, and manipulating windows.
 
 use crate::utils::win32::WindowHandle;
+use crate::window_manager::special::SpecialWorkspaces;
+use crate::window_manager::tags::tag_mask;
 use crate::window_manager::{ManagedWindow, WindowManager, WindowState};
 
 impl WindowManager {
@@ -14,6 +16,9 @@ impl WindowManager {
     /// - Tool windows
     /// - Windows without owners or parents in special cases
     ///
+    /// Owned dialogs and windows with a configured dialog class are accepted
+    /// when dialog floating is enabled, so they can be floated over their owner.
+    ///
     /// # Arguments
     ///
     /// * `window` - The window to check
@@ -41,7 +46,7 @@ impl WindowManager {
         // Use the is_app_window heuristic from WindowHandle
         // This already filters for visible windows with titles and no owners
         if !window.is_app_window() {
-            return Ok(false);
+            return Ok(self.is_dialog_window(window));
         }
 
         // Don't manage AppBars (taskbar, status bars, etc.)
@@ -57,6 +62,29 @@ impl WindowManager {
         Ok(true)
     }
 
+    /// Check if a window that is not a regular app window is a dialog to float.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - The window to check
+    ///
+    /// # Returns
+    ///
+    /// `true` if dialog floating is enabled and the window is an owned dialog
+    /// or has one of the configured dialog classes.
+    fn is_dialog_window(&self, window: &WindowHandle) -> bool {
+        if !self.dialogs.float_dialogs || !window.is_visible() || window.is_minimized() {
+            return false;
+        }
+
+        let dialog_class = window
+            .get_class_name()
+            .map(|class| self.dialogs.is_dialog_class(&class))
+            .unwrap_or(false);
+
+        dialog_class || window.is_owned_dialog()
+    }
+
     /// Add a window to be managed by the window manager.
     ///
     /// The window is added to the current workspace's tree and tiled accordingly.
@@ -97,6 +125,11 @@ impl WindowManager {
 
         // Create managed window
         let mut managed = ManagedWindow::new(window, self.active_workspace, monitor_index)?;
+        managed.transient = self.dialogs.float_dialogs
+            && (managed.transient || self.dialogs.is_dialog_class(&managed.class));
+
+        let mut mark = None;
+        let mut rule_workspace = None;
 
         // Apply rules if rule matcher is available
         if let Some(ref matcher) = self.rule_matcher {
@@ -112,14 +145,15 @@ impl WindowManager {
 
             // Get initial workspace from rules
             if let Some(workspace_id) = matcher.get_initial_workspace(&managed) {
-                // Validate workspace exists (workspace IDs start at 1)
-                if workspace_id > 0 && workspace_id <= 10 {
+                // Workspace IDs start at 1; other workspaces are created on demand
+                if workspace_id > 0 {
                     tracing::info!(
                         "Assigning window '{}' to workspace {} per rule",
                         managed.title,
                         workspace_id
                     );
                     managed.workspace = workspace_id;
+                    rule_workspace = Some(workspace_id);
                 } else {
                     tracing::warn!(
                         "Invalid workspace ID {} in rule for window '{}', using current workspace",
@@ -149,6 +183,36 @@ impl WindowManager {
                 }
             }
 
+            // Dialog rules override the owner/class detection either way
+            if let Some(dialog) = matcher.get_dialog_override(&managed) {
+                tracing::debug!(
+                    "Window '{}' dialog handling set to {} per rule",
+                    managed.title,
+                    dialog
+                );
+                managed.transient = dialog;
+            }
+
+            // Marks are assigned after registration to keep them unique
+            mark = matcher.get_mark(&managed);
+
+            // Send to a special workspace, on the monitor it is shown on
+            if let Some(name) = matcher.get_special_workspace(&managed) {
+                tracing::info!(
+                    "Assigning window '{}' to special workspace '{}' per rule",
+                    managed.title,
+                    name
+                );
+                managed.workspace = self.special_workspaces.get_or_create(&name);
+                if let Some(monitor) = self
+                    .special_workspaces
+                    .get(managed.workspace)
+                    .and_then(|ws| ws.monitor)
+                {
+                    managed.monitor = monitor;
+                }
+            }
+
             // Check if should be floating
             if matcher.should_float(&managed) {
                 tracing::info!("Setting window '{}' to floating per rule", managed.title);
@@ -171,9 +235,67 @@ impl WindowManager {
             }
         }
 
+        // In tag mode windows stay in the active workspace; a workspace rule
+        // picks the tag of the same number instead
+        if self.tag_mode && !SpecialWorkspaces::is_special(managed.workspace) {
+            managed.tags = rule_workspace
+                .filter(|&workspace_id| workspace_id <= self.tag_count)
+                .and_then(tag_mask)
+                .unwrap_or_else(|| self.tag_views.viewed(managed.monitor));
+            managed.workspace = self.active_workspace;
+        }
+
+        // Float transient windows on the same workspace and monitor as their owner
+        if managed.transient {
+            if let Some(owner) = managed.owner.and_then(|o| self.registry.get(o.hwnd().0)) {
+                managed.workspace = owner.workspace;
+                managed.monitor = owner.monitor;
+                managed.tags = owner.tags;
+            }
+            tracing::info!("Floating dialog '{}' over its owner", managed.title);
+            managed.set_floating()?;
+        }
+
+        let transient = managed.transient;
+        let workspace = managed.workspace;
+
         // Register the window
         self.registry.register(managed);
 
+        if let Some(mark) = mark {
+            tracing::info!("Marking window {:?} as '{}' per rule", hwnd.0, mark);
+            self.registry.set_mark(hwnd.0, &mark);
+        }
+
+        if transient {
+            self.place_transient(&window)?;
+        }
+
+        if SpecialWorkspaces::is_special(workspace) {
+            let shown = self
+                .special_workspaces
+                .get(workspace)
+                .is_some_and(|ws| ws.monitor.is_some());
+            if shown {
+                self.retile_workspace(workspace)?;
+            } else {
+                #[cfg(target_os = "windows")]
+                window.hide();
+            }
+        }
+
+        // Windows tagged with a tag their monitor does not view start hidden
+        if self.tag_mode && workspace == self.active_workspace {
+            let hidden = self
+                .registry
+                .get(hwnd.0)
+                .is_some_and(|managed| !self.tag_views.is_visible(managed.monitor, managed.tags));
+            if hidden {
+                #[cfg(target_os = "windows")]
+                window.hide();
+            }
+        }
+
         // Retile the workspace
         self.retile_workspace(self.active_workspace)?;
 
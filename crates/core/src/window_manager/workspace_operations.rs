@@ -4,6 +4,7 @@
 //! switching, tiling, and retiling operations.
 
 use crate::utils::win32::WindowHandle;
+use crate::window_manager::special::{inset_area, SpecialWorkspaces};
 use crate::window_manager::{LayoutType, Rect, Split, TreeNode, WindowManager};
 use std::collections::HashMap;
 use windows::Win32::Foundation::HWND;
@@ -12,6 +13,9 @@ impl WindowManager {
     /// Switch to a different workspace.
     ///
     /// This hides windows in the current workspace and shows windows in the target workspace.
+    /// The switch is recorded in the workspace history of the focused monitor. Missing
+    /// workspaces are created, and the workspace being left is destroyed if it was created
+    /// on demand and is empty.
     ///
     /// # Arguments
     ///
@@ -35,6 +39,31 @@ impl WindowManager {
             return Ok(());
         }
 
+        let previous = self.active_workspace;
+        let monitor = self.focused_monitor();
+        self.show_workspace(workspace_id)?;
+        self.record_switch(monitor, previous, workspace_id);
+        self.cleanup_workspace(previous);
+        Ok(())
+    }
+
+    /// Hide the active workspace and show another one without recording the
+    /// switch in the workspace history.
+    pub(super) fn show_workspace(&mut self, workspace_id: usize) -> anyhow::Result<()> {
+        self.ensure_workspace(workspace_id)?;
+        self.make_active(workspace_id);
+
+        // Re-tile the new workspace to ensure proper layout
+        self.tile_workspace(workspace_id)?;
+
+        Ok(())
+    }
+
+    /// Hide the windows of the active workspace and show those of another
+    /// one, making it the active workspace.
+    pub(super) fn make_active(&mut self, workspace_id: usize) {
+        self.hide_group_workspaces();
+
         // Hide windows in current workspace across all monitors
         #[cfg(target_os = "windows")]
         {
@@ -63,11 +92,48 @@ impl WindowManager {
         }
 
         self.active_workspace = workspace_id;
+    }
 
-        // Re-tile the new workspace to ensure proper layout
-        self.tile_workspace(workspace_id)?;
+    /// Switch to the default workspace of the first monitor, if a workspace
+    /// rule sets one.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if the switch fails.
+    pub fn show_default_workspace(&mut self) -> anyhow::Result<()> {
+        let default = self
+            .workspace_rules
+            .iter()
+            .filter(|rule| rule.default)
+            .find(|rule| self.bound_monitor(rule.id) == Some(0))
+            .map(|rule| rule.id);
+
+        match default {
+            Some(workspace_id) => {
+                tracing::info!("Showing default workspace {}", workspace_id);
+                self.switch_workspace(workspace_id)
+            }
+            None => Ok(()),
+        }
+    }
 
-        Ok(())
+    /// Get the monitor a workspace is bound to by a workspace rule.
+    ///
+    /// # Returns
+    ///
+    /// The monitor index, or `None` if the workspace is not bound or its
+    /// monitor is not connected.
+    pub(super) fn bound_monitor(&self, workspace_id: usize) -> Option<usize> {
+        let rule = self
+            .workspace_rules
+            .iter()
+            .find(|rule| rule.id == workspace_id)?;
+
+        self.monitors
+            .iter()
+            .enumerate()
+            .find(|(idx, monitor)| rule.monitor.matches(*idx, &monitor.name))
+            .map(|(idx, _)| idx)
     }
 
     /// Get the currently active workspace ID.
@@ -192,6 +258,11 @@ impl WindowManager {
     }
 
     fn retile_workspace_impl(&mut self, workspace_id: usize) -> anyhow::Result<()> {
+        if SpecialWorkspaces::is_special(workspace_id) {
+            return self.retile_special(workspace_id);
+        }
+
+        self.ensure_workspace(workspace_id)?;
         let windows_by_monitor = self.group_windows_by_monitor(workspace_id)?;
 
         if windows_by_monitor.is_empty() {
@@ -220,9 +291,18 @@ impl WindowManager {
 
         let mut windows_by_monitor: HashMap<usize, Vec<HWND>> = HashMap::new();
 
+        let placed_monitor = self.workspace_monitor(workspace_id);
+        let tag_filter = self.tag_mode && !SpecialWorkspaces::is_special(workspace_id);
+
         for window in tiled_windows {
             let hwnd = window.handle.hwnd();
-            let monitor_idx = self.get_monitor_for_window(hwnd);
+            let monitor_idx =
+                placed_monitor.unwrap_or_else(|| self.get_monitor_for_window(hwnd));
+
+            // In tag mode only windows carrying a viewed tag are tiled
+            if tag_filter && !self.tag_views.is_visible(monitor_idx, window.tags) {
+                continue;
+            }
 
             tracing::debug!(
                 "Window {} on monitor {} (stored: {})",
@@ -278,7 +358,10 @@ impl WindowManager {
             .get(monitor_idx)
             .ok_or_else(|| anyhow::anyhow!("Monitor {} not found", monitor_idx))?;
 
-        let work_area_with_gaps = self.apply_outer_gaps(&monitor.work_area);
+        let mut work_area_with_gaps = self.apply_outer_gaps(&monitor.work_area);
+        if SpecialWorkspaces::is_special(workspace_id) {
+            work_area_with_gaps = inset_area(&work_area_with_gaps, self.special_scale);
+        }
 
         tracing::debug!(
             "Tiling {} windows on monitor {} (work area: {}x{} at {}, {})",
@@ -3,7 +3,10 @@
 //! This module contains the core Workspace and WorkspaceManager structs,
 //! along with basic initialization and CRUD operations.
 
+use crate::config::{WorkspaceGroup, WorkspaceRule};
 use crate::window_manager::tree::{Rect, TreeNode};
+use crate::workspace::history::WorkspaceHistory;
+use crate::workspace::monitor_integration::ConnectedMonitor;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -45,6 +48,14 @@ pub struct Workspace {
 
     /// Last time this workspace was active
     pub last_active: std::time::Instant,
+
+    /// Whether this workspace is kept when empty (false for workspaces
+    /// created on demand, which are destroyed once empty and left)
+    pub persistent: bool,
+
+    /// Key of the monitor this workspace belongs on, kept while that monitor
+    /// is disconnected so the workspace can return to it
+    pub preferred_monitor: Option<String>,
 }
 
 impl Workspace {
@@ -59,6 +70,8 @@ impl Workspace {
             virtual_desktop_id: None,
             visible: false,
             last_active: std::time::Instant::now(),
+            persistent: true,
+            preferred_monitor: None,
         }
     }
 
@@ -91,6 +104,39 @@ impl Workspace {
     }
 }
 
+/// Workspace and monitor changes reported to the event listener.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WorkspaceEvent {
+    /// A workspace was created
+    Created { id: usize, name: String },
+    /// A workspace was destroyed
+    Deleted { id: usize },
+    /// A monitor was connected and the listed workspaces returned to it
+    MonitorAdded {
+        id: usize,
+        name: String,
+        key: String,
+        workspaces: Vec<usize>,
+    },
+    /// A monitor was disconnected and its workspaces moved to `fallback`
+    MonitorRemoved {
+        id: usize,
+        name: String,
+        key: String,
+        workspaces: Vec<usize>,
+        fallback: usize,
+    },
+    /// A workspace group was switched to, showing the listed workspaces
+    GroupChanged {
+        from: Option<usize>,
+        to: usize,
+        workspaces: Vec<usize>,
+    },
+}
+
+/// Callback receiving workspace lifecycle changes.
+pub type WorkspaceEventListener = Box<dyn Fn(WorkspaceEvent) + Send>;
+
 /// Configuration for the workspace management system.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkspaceConfig {
@@ -103,11 +149,24 @@ pub struct WorkspaceConfig {
     /// Whether to persist workspace state across restarts
     pub persist_state: bool,
 
-    /// Whether to create workspaces on demand when accessed
+    /// Whether to create workspaces on demand when switched to or when a
+    /// window is moved to them
     pub create_on_demand: bool,
 
+    /// Whether switching to the current workspace jumps back to the
+    /// previously shown one
+    pub auto_back_and_forth: bool,
+
     /// Whether to use Windows Virtual Desktop integration
     pub use_virtual_desktops: bool,
+
+    /// Rules binding workspaces to monitors and naming them
+    #[serde(default)]
+    pub rules: Vec<WorkspaceRule>,
+
+    /// Groups of workspaces, one per monitor, switched together
+    #[serde(default)]
+    pub groups: Vec<WorkspaceGroup>,
 }
 
 impl Default for WorkspaceConfig {
@@ -116,8 +175,11 @@ impl Default for WorkspaceConfig {
             default_count: 10,
             names: (1..=10).map(|i| i.to_string()).collect(),
             persist_state: true,
-            create_on_demand: false,
+            create_on_demand: true,
+            auto_back_and_forth: false,
             use_virtual_desktops: false,
+            rules: Vec::new(),
+            groups: Vec::new(),
         }
     }
 }
@@ -141,6 +203,18 @@ pub struct WorkspaceManager {
 
     /// Map of windows to their workspaces
     pub(super) window_to_workspace: HashMap<isize, usize>,
+
+    /// Listener for workspace creation and deletion (if connected)
+    pub(super) event_listener: Option<WorkspaceEventListener>,
+
+    /// Workspace navigation history per monitor
+    pub(super) history: HashMap<usize, WorkspaceHistory>,
+
+    /// Connected monitors, used to resolve workspace rules and placements
+    pub(super) monitors: Vec<ConnectedMonitor>,
+
+    /// Workspace group switched to last, while its workspaces are shown
+    pub(super) active_group: Option<usize>,
 }
 
 impl WorkspaceManager {
@@ -153,9 +227,18 @@ impl WorkspaceManager {
             config,
             vd_manager: None,
             window_to_workspace: HashMap::new(),
+            event_listener: None,
+            history: HashMap::new(),
+            monitors: Vec::new(),
+            active_group: None,
         }
     }
 
+    /// Set the listener notified when workspaces are created or destroyed.
+    pub fn set_event_listener(&mut self, listener: impl Fn(WorkspaceEvent) + Send + 'static) {
+        self.event_listener = Some(Box::new(listener));
+    }
+
     /// Get the currently active workspace ID.
     pub fn active_workspace(&self) -> usize {
         self.active_workspace
@@ -166,6 +249,22 @@ impl WorkspaceManager {
         self.workspaces.get(&id)
     }
 
+    /// Get all workspaces, sorted by ID.
+    pub fn workspaces(&self) -> Vec<&Workspace> {
+        let mut workspaces: Vec<&Workspace> = self.workspaces.values().collect();
+        workspaces.sort_by_key(|ws| ws.id);
+        workspaces
+    }
+
+    /// Find a workspace by name.
+    pub fn find_workspace_by_name(&self, name: &str) -> Option<usize> {
+        self.workspaces
+            .values()
+            .filter(|ws| ws.name == name)
+            .map(|ws| ws.id)
+            .min()
+    }
+
     /// Get the workspace ID for a given window, if it exists.
     pub fn get_window_workspace(&self, hwnd: isize) -> Option<usize> {
         self.window_to_workspace.get(&hwnd).copied()
@@ -182,6 +281,9 @@ impl WorkspaceManager {
     }
 
     /// Initialize the workspace manager with default workspaces
+    ///
+    /// Workspace rules matching monitors by index are applied right away,
+    /// including the default workspace of each monitor.
     pub fn initialize(&mut self, monitor_areas: &[(usize, Rect)]) -> anyhow::Result<()> {
         for (monitor_id, area) in monitor_areas {
             for i in 0..self.config.default_count {
@@ -206,6 +308,19 @@ impl WorkspaceManager {
             }
         }
 
+        // Monitor names are not known yet; rules matching names are applied
+        // once the monitors are assigned
+        let monitors = monitor_areas
+            .iter()
+            .map(|(monitor_id, _)| ConnectedMonitor {
+                id: *monitor_id,
+                name: String::new(),
+                key: String::new(),
+            })
+            .collect();
+        let added = self.set_monitors(monitors);
+        self.apply_workspace_rules(&added);
+
         if self.vd_manager.is_some() {
             self.sync_with_virtual_desktops()?;
         }
@@ -215,19 +330,27 @@ impl WorkspaceManager {
 
     /// Create a new workspace
     pub fn create_workspace(&mut self, name: String, monitor: usize, area: Rect) -> usize {
+        while self.workspaces.contains_key(&self.next_id) {
+            self.next_id += 1;
+        }
         let id = self.next_id;
         self.next_id += 1;
 
-        let mut workspace = Workspace::new(id, name, monitor, area);
+        self.insert_workspace(Workspace::new(id, name, monitor, area));
+        id
+    }
 
+    /// Insert a workspace, creating its Virtual Desktop if enabled.
+    pub(super) fn insert_workspace(&mut self, mut workspace: Workspace) {
         if let Some(ref vd_manager) = self.vd_manager {
             if let Ok(vd_id) = vd_manager.create_desktop() {
                 workspace.virtual_desktop_id = Some(vd_id);
             }
         }
 
+        let (id, name) = (workspace.id, workspace.name.clone());
         self.workspaces.insert(id, workspace);
-        id
+        self.emit(WorkspaceEvent::Created { id, name });
     }
 
     /// Delete a workspace (moves windows to fallback workspace)
@@ -282,7 +405,11 @@ impl WorkspaceManager {
             self.switch_to(fallback_id)?;
         }
 
-        self.workspaces.remove(&workspace_id);
+        // Workspaces created on demand may already be gone once emptied
+        if self.workspaces.remove(&workspace_id).is_some() {
+            self.forget_in_history(workspace_id);
+            self.emit(WorkspaceEvent::Deleted { id: workspace_id });
+        }
         Ok(())
     }
 
@@ -300,6 +427,21 @@ impl WorkspaceManager {
         }
     }
 
+    /// Get the monitor of the active workspace.
+    pub(super) fn active_monitor(&self) -> usize {
+        self.workspaces
+            .get(&self.active_workspace)
+            .map(|ws| ws.monitor)
+            .unwrap_or(0)
+    }
+
+    /// Notify the event listener, if one is connected.
+    pub(super) fn emit(&self, event: WorkspaceEvent) {
+        if let Some(ref listener) = self.event_listener {
+            listener(event);
+        }
+    }
+
     /// Get sorted list of workspace IDs on a specific monitor
     pub(super) fn get_monitor_workspaces(&self, monitor: usize) -> Vec<usize> {
         let mut workspaces: Vec<usize> = self
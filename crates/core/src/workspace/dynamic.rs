@@ -0,0 +1,322 @@
+//! Dynamic workspace operations.
+//!
+//! This module contains operations for creating workspaces on demand when they
+//! are switched to or receive a window, and for destroying non-persistent
+//! workspaces once they are empty and no longer visible.
+
+use crate::window_manager::tree::Rect;
+use crate::workspace::core::{Workspace, WorkspaceEvent};
+use crate::workspace::WorkspaceManager;
+
+impl WorkspaceManager {
+    /// Make sure a workspace exists, creating it on demand if enabled.
+    ///
+    /// Workspaces created on demand are placed on the monitor their workspace
+    /// rule binds them to, or else on the given monitor. They are named after
+    /// the configured name for their ID and are not persistent.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace ID
+    /// * `monitor` - Monitor to create the workspace on if it does not exist
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the workspace exists afterwards, or an error if it does not
+    /// exist and on-demand creation is disabled.
+    pub fn ensure_workspace(&mut self, workspace_id: usize, monitor: usize) -> anyhow::Result<()> {
+        if self.workspaces.contains_key(&workspace_id) {
+            return Ok(());
+        }
+
+        if !self.config.create_on_demand || workspace_id == 0 {
+            anyhow::bail!("Workspace {} does not exist", workspace_id);
+        }
+
+        let name = self.configured_name(workspace_id);
+        let monitor = self.bound_monitor(workspace_id).unwrap_or(monitor);
+
+        self.create_on_demand(workspace_id, name, monitor);
+        Ok(())
+    }
+
+    /// Resolve a workspace by ID or name, creating it on demand if enabled.
+    ///
+    /// Numeric input is treated as a workspace ID. Otherwise the workspace
+    /// with that name is used, or a new one is created on the active monitor.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - A workspace ID or name
+    ///
+    /// # Returns
+    ///
+    /// The workspace ID, or an error if it does not exist and on-demand
+    /// creation is disabled.
+    pub fn resolve_workspace(&mut self, target: &str) -> anyhow::Result<usize> {
+        let id = self.lookup_workspace(target)?;
+        if self.workspaces.contains_key(&id) {
+            return Ok(id);
+        }
+
+        let monitor = self.active_monitor();
+        if target.parse::<usize>().is_ok() {
+            self.ensure_workspace(id, monitor)?;
+        } else {
+            self.create_on_demand(id, target.to_string(), monitor);
+        }
+        Ok(id)
+    }
+
+    /// Look up a workspace by ID or name without creating it.
+    ///
+    /// Like `resolve_workspace`, but a workspace that would be created on
+    /// demand is only given its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - A workspace ID or name
+    ///
+    /// # Returns
+    ///
+    /// The workspace ID, or an error if it does not exist and on-demand
+    /// creation is disabled.
+    pub fn lookup_workspace(&self, target: &str) -> anyhow::Result<usize> {
+        if let Ok(id) = target.parse::<usize>() {
+            if !self.workspaces.contains_key(&id) && (!self.config.create_on_demand || id == 0) {
+                anyhow::bail!("Workspace {} does not exist", id);
+            }
+            return Ok(id);
+        }
+
+        if let Some(id) = self.find_workspace_by_name(target) {
+            return Ok(id);
+        }
+
+        if !self.config.create_on_demand || target.trim().is_empty() {
+            anyhow::bail!("Workspace '{}' does not exist", target);
+        }
+
+        let mut id = self.next_id;
+        while self.workspaces.contains_key(&id) {
+            id += 1;
+        }
+        Ok(id)
+    }
+
+    /// Switch to a workspace by ID or name, creating it on demand if enabled.
+    pub fn switch_to_name(&mut self, target: &str) -> anyhow::Result<()> {
+        let workspace_id = self.resolve_workspace(target)?;
+        self.switch_to(workspace_id)
+    }
+
+    /// Destroy a workspace if it was created on demand, is empty and is not
+    /// visible.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace ID
+    ///
+    /// # Returns
+    ///
+    /// `true` if the workspace was destroyed.
+    pub fn cleanup_workspace(&mut self, workspace_id: usize) -> bool {
+        let disposable = match self.workspaces.get(&workspace_id) {
+            Some(ws) => {
+                !ws.persistent
+                    && ws.windows.is_empty()
+                    && !ws.visible
+                    && ws.id != self.active_workspace
+            }
+            None => false,
+        };
+
+        if !disposable {
+            return false;
+        }
+
+        if let Some(workspace) = self.workspaces.remove(&workspace_id) {
+            if let (Some(vd_manager), Some(vd_id)) = (&self.vd_manager, workspace.virtual_desktop_id)
+            {
+                let fallback = self
+                    .workspaces
+                    .get(&self.active_workspace)
+                    .and_then(|ws| ws.virtual_desktop_id);
+                if let Some(fallback_vd_id) = fallback {
+                    let _ = vd_manager.remove_desktop(&vd_id, &fallback_vd_id);
+                }
+            }
+        }
+
+        tracing::info!("Destroyed empty workspace {}", workspace_id);
+        self.forget_in_history(workspace_id);
+        self.emit(WorkspaceEvent::Deleted { id: workspace_id });
+        true
+    }
+
+    /// Create a non-persistent workspace with a specific ID.
+    fn create_on_demand(&mut self, workspace_id: usize, name: String, monitor: usize) {
+        tracing::info!(
+            "Creating workspace {} ('{}') on monitor {}",
+            workspace_id,
+            name,
+            monitor
+        );
+
+        // The area is only used by the layout tree, which is built when windows arrive
+        let mut workspace = Workspace::new(workspace_id, name, monitor, Rect::new(0, 0, 0, 0));
+        workspace.persistent = false;
+
+        self.next_id = self.next_id.max(workspace_id + 1);
+        self.insert_workspace(workspace);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::window_manager::tree::Rect;
+    use crate::workspace::core::{WorkspaceConfig, WorkspaceEvent};
+    use crate::workspace::switching::WorkspaceSwitch;
+    use crate::workspace::WorkspaceManager;
+    use std::sync::{Arc, Mutex};
+
+    fn test_manager() -> WorkspaceManager {
+        let config = WorkspaceConfig {
+            default_count: 2,
+            names: vec!["web".to_string(), "code".to_string()],
+            ..WorkspaceConfig::default()
+        };
+        let mut manager = WorkspaceManager::new(config);
+        manager
+            .initialize(&[(0, Rect::new(0, 0, 1920, 1080))])
+            .unwrap();
+        manager
+    }
+
+    fn ids(manager: &WorkspaceManager) -> Vec<usize> {
+        manager.workspaces().iter().map(|ws| ws.id).collect()
+    }
+
+    #[test]
+    fn test_switch_creates_workspace_on_demand() {
+        let mut manager = test_manager();
+
+        manager.switch_to(25).unwrap();
+        assert_eq!(manager.active_workspace(), 25);
+        assert_eq!(ids(&manager), vec![1, 2, 25]);
+
+        let ws = manager.get_workspace(25).unwrap();
+        assert_eq!(ws.name, "25");
+        assert_eq!(ws.monitor, 0);
+        assert!(!ws.persistent);
+    }
+
+    #[test]
+    fn test_switch_target_does_not_switch() {
+        let mut manager = test_manager();
+        let switch = WorkspaceSwitch::To("notes".to_string());
+
+        assert_eq!(manager.switch_target(&switch).unwrap(), 3);
+        assert_eq!(ids(&manager), vec![1, 2]);
+        assert_eq!(manager.active_workspace(), 1);
+
+        manager.apply_switch(&switch).unwrap();
+        assert_eq!(manager.active_workspace(), 3);
+        assert_eq!(manager.get_workspace(3).unwrap().name, "notes");
+    }
+
+    #[test]
+    fn test_switch_fails_without_create_on_demand() {
+        let mut manager = WorkspaceManager::new(WorkspaceConfig {
+            create_on_demand: false,
+            ..WorkspaceConfig::default()
+        });
+        manager
+            .initialize(&[(0, Rect::new(0, 0, 1920, 1080))])
+            .unwrap();
+
+        assert!(manager.switch_to(25).is_err());
+        assert!(manager.get_workspace(25).is_none());
+    }
+
+    #[test]
+    fn test_empty_workspace_destroyed_when_left() {
+        let mut manager = test_manager();
+
+        manager.switch_to(5).unwrap();
+        manager.switch_to(1).unwrap();
+        assert!(manager.get_workspace(5).is_none());
+
+        // Persistent workspaces are kept
+        assert!(manager.get_workspace(2).is_some());
+    }
+
+    #[test]
+    fn test_workspace_with_windows_is_kept() {
+        let mut manager = test_manager();
+
+        manager.switch_to(5).unwrap();
+        manager.add_window_to_workspace(100, 5).unwrap();
+        manager.switch_to(1).unwrap();
+        assert!(manager.get_workspace(5).is_some());
+
+        // Moving the last window away destroys it
+        manager.move_window_to_workspace(100, 5, 1).unwrap();
+        assert!(manager.get_workspace(5).is_none());
+    }
+
+    #[test]
+    fn test_move_window_creates_workspace() {
+        let mut manager = test_manager();
+        manager.add_window_to_workspace(100, 1).unwrap();
+
+        manager.move_window_to_workspace(100, 1, 7).unwrap();
+        assert_eq!(manager.get_window_workspace(100), Some(7));
+        assert!(manager.get_workspace(7).is_some());
+    }
+
+    #[test]
+    fn test_resolve_workspace_by_name() {
+        let mut manager = test_manager();
+
+        assert_eq!(manager.resolve_workspace("code").unwrap(), 2);
+        assert_eq!(manager.resolve_workspace("2").unwrap(), 2);
+
+        let id = manager.resolve_workspace("music").unwrap();
+        assert_eq!(id, 3);
+        assert_eq!(manager.get_workspace(id).unwrap().name, "music");
+        assert_eq!(manager.resolve_workspace("music").unwrap(), id);
+    }
+
+    #[test]
+    fn test_created_ids_skip_dynamic_workspaces() {
+        let mut manager = test_manager();
+        manager.switch_to(3).unwrap();
+
+        let id = manager.create_workspace("new".to_string(), 0, Rect::new(0, 0, 0, 0));
+        assert_eq!(id, 4);
+        assert!(manager.get_workspace(id).unwrap().persistent);
+    }
+
+    #[test]
+    fn test_workspace_events() {
+        let mut manager = test_manager();
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&events);
+        manager.set_event_listener(move |event| sink.lock().unwrap().push(event));
+
+        manager.switch_to(9).unwrap();
+        manager.switch_to(1).unwrap();
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                WorkspaceEvent::Created {
+                    id: 9,
+                    name: "9".to_string()
+                },
+                WorkspaceEvent::Deleted { id: 9 },
+            ]
+        );
+    }
+}
@@ -0,0 +1,255 @@
+//! Workspace groups.
+//!
+//! This module applies the `workspace_groups` from the configuration. A group
+//! names one workspace per monitor; switching to the group shows all of them
+//! at once, so "group 2" can mean the same set of workspaces on every monitor.
+
+use crate::config::WorkspaceGroup;
+use crate::workspace::core::WorkspaceEvent;
+use crate::workspace::WorkspaceManager;
+
+impl WorkspaceManager {
+    /// Replace the workspace groups.
+    pub fn set_workspace_groups(&mut self, groups: Vec<WorkspaceGroup>) {
+        self.config.groups = groups;
+    }
+
+    /// Get a workspace group by ID, if it is configured.
+    pub fn workspace_group(&self, group_id: usize) -> Option<&WorkspaceGroup> {
+        self.config.groups.iter().find(|group| group.id == group_id)
+    }
+
+    /// Get the workspace group that is shown.
+    ///
+    /// # Returns
+    ///
+    /// The group switched to last, or `None` if none was switched to or one
+    /// of its monitors has switched to another workspace since.
+    pub fn active_group(&self) -> Option<usize> {
+        let group = self.workspace_group(self.active_group?)?;
+        let all_visible = self.group_members(group).iter().all(|(_, workspace_id)| {
+            self.workspaces
+                .get(workspace_id)
+                .is_some_and(|ws| ws.visible)
+        });
+        all_visible.then_some(group.id)
+    }
+
+    /// Switch every monitor to its workspace of a group.
+    ///
+    /// All workspaces are checked before anything changes, so either every
+    /// monitor switches or none does. Layouts are applied once, after all
+    /// monitors have switched. Monitors the group has no workspace for, and
+    /// workspaces whose monitor is not connected, are left alone.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The group to switch to
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if the group is not configured, none
+    /// of its monitors is connected, or one of its workspaces cannot be shown
+    /// on its monitor.
+    pub fn switch_group(&mut self, group_id: usize) -> anyhow::Result<()> {
+        let members = match self.workspace_group(group_id) {
+            Some(group) => self.group_members(group),
+            None => anyhow::bail!("Workspace group {} is not configured", group_id),
+        };
+        if members.is_empty() {
+            anyhow::bail!("No monitor of workspace group {} is connected", group_id);
+        }
+
+        for &(monitor_id, workspace_id) in &members {
+            self.check_group_workspace(workspace_id, monitor_id)?;
+        }
+
+        tracing::info!("Switching to workspace group {}", group_id);
+        let active_monitor = self.active_monitor();
+        for &(monitor_id, workspace_id) in &members {
+            self.ensure_workspace(workspace_id, monitor_id)?;
+            self.show_on_monitor(monitor_id, workspace_id);
+        }
+        if let Some(&(_, workspace_id)) = members
+            .iter()
+            .find(|(monitor_id, _)| *monitor_id == active_monitor)
+        {
+            self.active_workspace = workspace_id;
+        }
+
+        let workspaces: Vec<usize> = members.iter().map(|(_, ws)| *ws).collect();
+        self.apply_layouts(&workspaces)?;
+
+        let from = self.active_group.replace(group_id);
+        self.emit(WorkspaceEvent::GroupChanged {
+            from,
+            to: group_id,
+            workspaces,
+        });
+        Ok(())
+    }
+
+    /// Resolve the workspaces of a group to connected monitors.
+    ///
+    /// # Returns
+    ///
+    /// `(monitor ID, workspace ID)` pairs, ordered by monitor.
+    fn group_members(&self, group: &WorkspaceGroup) -> Vec<(usize, usize)> {
+        let mut members: Vec<(usize, usize)> = group
+            .workspaces
+            .iter()
+            .filter_map(|member| {
+                self.monitors
+                    .iter()
+                    .find(|monitor| member.monitor.matches(monitor.id, &monitor.name))
+                    .map(|monitor| (monitor.id, member.workspace))
+            })
+            .collect();
+        members.sort_unstable();
+        members
+    }
+
+    /// Check that a workspace of a group can be shown on its monitor without
+    /// changing anything.
+    fn check_group_workspace(&self, workspace_id: usize, monitor_id: usize) -> anyhow::Result<()> {
+        if self.workspaces.contains_key(&workspace_id) {
+            return self.check_workspace_monitor(workspace_id, monitor_id);
+        }
+        if !self.config.create_on_demand || workspace_id == 0 {
+            anyhow::bail!("Workspace {} does not exist", workspace_id);
+        }
+        match self.bound_monitor(workspace_id) {
+            Some(bound) if bound != monitor_id => anyhow::bail!(
+                "Workspace {} is bound to monitor {}, not monitor {}",
+                workspace_id,
+                bound,
+                monitor_id
+            ),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{GroupWorkspace, MonitorRef, WorkspaceGroup};
+    use crate::window_manager::monitor::{MonitorInfo, MonitorManager};
+    use crate::window_manager::tree::Rect;
+    use crate::workspace::core::{WorkspaceConfig, WorkspaceEvent};
+    use crate::workspace::WorkspaceManager;
+    use std::sync::{Arc, Mutex};
+
+    fn group(id: usize, workspaces: &[(MonitorRef, usize)]) -> WorkspaceGroup {
+        WorkspaceGroup {
+            id,
+            name: None,
+            workspaces: workspaces
+                .iter()
+                .map(|(monitor, workspace)| GroupWorkspace {
+                    monitor: monitor.clone(),
+                    workspace: *workspace,
+                })
+                .collect(),
+        }
+    }
+
+    fn docked() -> WorkspaceManager {
+        let mut manager = WorkspaceManager::new(WorkspaceConfig {
+            default_count: 4,
+            ..WorkspaceConfig::default()
+        });
+        manager
+            .initialize(&[
+                (0, Rect::new(0, 0, 1920, 1080)),
+                (1, Rect::new(1920, 0, 1920, 1080)),
+            ])
+            .unwrap();
+
+        let mut monitors = MonitorManager::new();
+        for (name, x) in [("LAPTOP", 0), ("EXTERNAL", 1920)] {
+            let rect = Rect::new(x, 0, 1920, 1080);
+            monitors.add_monitor(MonitorInfo::new(
+                0,
+                Default::default(),
+                name.to_string(),
+                rect,
+                rect,
+                1.0,
+            ));
+        }
+        manager
+            .assign_workspaces_to_monitors(&mut monitors)
+            .unwrap();
+        manager
+    }
+
+    #[test]
+    fn test_switch_group_shows_all_workspaces() {
+        let mut manager = docked();
+        manager.set_workspace_groups(vec![group(
+            2,
+            &[
+                (MonitorRef::Name("external".to_string()), 12),
+                (MonitorRef::Index(0), 11),
+            ],
+        )]);
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&events);
+        manager.set_event_listener(move |event| sink.lock().unwrap().push(event));
+
+        manager.switch_group(2).unwrap();
+        assert_eq!(manager.get_active_workspace_for_monitor(0), Some(11));
+        assert_eq!(manager.get_active_workspace_for_monitor(1), Some(12));
+        assert_eq!(manager.active_workspace(), 11);
+        assert_eq!(manager.active_group(), Some(2));
+
+        let group_events: Vec<WorkspaceEvent> = events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|event| matches!(event, WorkspaceEvent::GroupChanged { .. }))
+            .cloned()
+            .collect();
+        assert_eq!(
+            group_events,
+            vec![WorkspaceEvent::GroupChanged {
+                from: None,
+                to: 2,
+                workspaces: vec![11, 12],
+            }]
+        );
+
+        // The group is no longer shown once one of its monitors switches away
+        let other = manager.get_monitor_workspaces(1)[0];
+        manager.switch_workspace_on_monitor(1, other).unwrap();
+        assert_eq!(manager.active_group(), None);
+    }
+
+    #[test]
+    fn test_switch_group_is_all_or_nothing() {
+        let mut manager = docked();
+        let laptop = manager.get_monitor_workspaces(0);
+        let shown = (
+            manager.get_active_workspace_for_monitor(0),
+            manager.get_active_workspace_for_monitor(1),
+        );
+
+        // The second workspace already lives on the laptop, not the external monitor
+        manager.set_workspace_groups(vec![group(
+            3,
+            &[
+                (MonitorRef::Index(0), 13),
+                (MonitorRef::Index(1), laptop[0]),
+            ],
+        )]);
+        assert!(manager.switch_group(3).is_err());
+
+        assert!(manager.get_workspace(13).is_none());
+        assert_eq!(manager.get_active_workspace_for_monitor(0), shown.0);
+        assert_eq!(manager.get_active_workspace_for_monitor(1), shown.1);
+        assert_eq!(manager.active_group(), None);
+
+        assert!(manager.switch_group(4).is_err());
+    }
+}
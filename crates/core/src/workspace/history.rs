@@ -0,0 +1,392 @@
+//! Workspace navigation history.
+//!
+//! This module contains the per-monitor history of visited workspaces used for
+//! back-and-forth switching and for stepping backwards and forwards through
+//! previously visited workspaces, much like a browser history.
+
+use crate::workspace::WorkspaceManager;
+
+/// Maximum number of entries kept in a workspace history.
+const MAX_HISTORY: usize = 100;
+
+/// History of workspaces visited on one monitor.
+#[derive(Debug, Clone, Default)]
+pub struct WorkspaceHistory {
+    /// Visited workspaces, oldest first
+    entries: Vec<usize>,
+    /// Index of the current workspace in `entries`
+    position: usize,
+    /// Workspace shown before the current one
+    previous: Option<usize>,
+}
+
+impl WorkspaceHistory {
+    /// Create an empty history.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a switch from one workspace to another.
+    ///
+    /// Entries after the current position are discarded, like navigating to a
+    /// new page after going back in a browser.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The workspace shown before the switch, if any
+    /// * `to` - The workspace switched to
+    pub fn visit(&mut self, from: Option<usize>, to: usize) {
+        if from == Some(to) {
+            return;
+        }
+
+        self.entries.truncate(self.position + 1);
+        if let Some(from) = from {
+            if self.entries.last() != Some(&from) {
+                self.entries.push(from);
+            }
+        }
+        self.entries.push(to);
+
+        if self.entries.len() > MAX_HISTORY {
+            self.entries.remove(0);
+        }
+        self.position = self.entries.len() - 1;
+        self.previous = from;
+    }
+
+    /// Step back in the history.
+    ///
+    /// # Returns
+    ///
+    /// The workspace to switch to, or `None` at the start of the history.
+    pub fn back(&mut self) -> Option<usize> {
+        let target = self.peek_back()?;
+        self.previous = Some(self.entries[self.position]);
+        self.position -= 1;
+        Some(target)
+    }
+
+    /// Step forward in the history.
+    ///
+    /// # Returns
+    ///
+    /// The workspace to switch to, or `None` at the end of the history.
+    pub fn forward(&mut self) -> Option<usize> {
+        let target = self.peek_forward()?;
+        self.previous = Some(self.entries[self.position]);
+        self.position += 1;
+        Some(target)
+    }
+
+    /// Get the workspace `back` would return, without stepping.
+    pub fn peek_back(&self) -> Option<usize> {
+        if self.position == 0 || self.entries.is_empty() {
+            return None;
+        }
+        Some(self.entries[self.position - 1])
+    }
+
+    /// Get the workspace `forward` would return, without stepping.
+    pub fn peek_forward(&self) -> Option<usize> {
+        self.entries.get(self.position + 1).copied()
+    }
+
+    /// Get the workspace shown before the current one.
+    pub fn previous(&self) -> Option<usize> {
+        self.previous
+    }
+
+    /// Remove a workspace that no longer exists from the history.
+    pub fn forget(&mut self, workspace_id: usize) {
+        let mut entries = Vec::with_capacity(self.entries.len());
+        let mut position = 0;
+
+        for (index, &id) in self.entries.iter().enumerate() {
+            if id != workspace_id && entries.last() != Some(&id) {
+                entries.push(id);
+            }
+            if index == self.position {
+                position = entries.len().saturating_sub(1);
+            }
+        }
+
+        self.entries = entries;
+        self.position = position;
+        if self.previous == Some(workspace_id) {
+            self.previous = None;
+        }
+    }
+}
+
+/// Pick the workspace before or after `current` in a sorted list, wrapping
+/// around at either end.
+///
+/// # Returns
+///
+/// The neighbouring workspace, or `None` if `current` is not in the list or
+/// it is the only entry.
+pub fn cycle_workspace(workspaces: &[usize], current: usize, forward: bool) -> Option<usize> {
+    if workspaces.len() < 2 {
+        return None;
+    }
+
+    let index = workspaces.iter().position(|&id| id == current)?;
+    let target = if forward {
+        (index + 1) % workspaces.len()
+    } else if index == 0 {
+        workspaces.len() - 1
+    } else {
+        index - 1
+    };
+    Some(workspaces[target])
+}
+
+impl WorkspaceManager {
+    /// Switch to the workspace shown before the current one on the active
+    /// monitor.
+    pub fn back_and_forth(&mut self) -> anyhow::Result<()> {
+        match self.previous_workspace() {
+            Some(workspace_id) => self.switch_recorded(workspace_id),
+            None => {
+                tracing::debug!("No previous workspace");
+                Ok(())
+            }
+        }
+    }
+
+    /// Go back to the previously visited workspace on the active monitor.
+    pub fn history_back(&mut self) -> anyhow::Result<()> {
+        let target = self
+            .history
+            .get_mut(&self.active_monitor())
+            .and_then(|history| history.back());
+        self.switch_from_history(target)
+    }
+
+    /// Go forward again after stepping back in the history of the active
+    /// monitor.
+    pub fn history_forward(&mut self) -> anyhow::Result<()> {
+        let target = self
+            .history
+            .get_mut(&self.active_monitor())
+            .and_then(|history| history.forward());
+        self.switch_from_history(target)
+    }
+
+    /// Get the workspace shown before the current one on the active monitor.
+    pub fn previous_workspace(&self) -> Option<usize> {
+        self.history
+            .get(&self.active_monitor())
+            .and_then(|history| history.previous())
+    }
+
+    /// Get the workspace `history_back` or `history_forward` would switch
+    /// to, without switching.
+    ///
+    /// # Arguments
+    ///
+    /// * `forward` - `true` to look forward, `false` to look back
+    pub fn history_target(&self, forward: bool) -> Option<usize> {
+        let history = self.history.get(&self.active_monitor())?;
+        if forward {
+            history.peek_forward()
+        } else {
+            history.peek_back()
+        }
+    }
+
+    /// Record a switch in the history of a monitor.
+    pub(super) fn record_switch(&mut self, monitor: usize, from: Option<usize>, to: usize) {
+        self.history.entry(monitor).or_default().visit(from, to);
+    }
+
+    /// Remove a destroyed workspace from all monitor histories.
+    pub(super) fn forget_in_history(&mut self, workspace_id: usize) {
+        for history in self.history.values_mut() {
+            history.forget(workspace_id);
+        }
+    }
+
+    /// Switch to a workspace picked from the history without recording it.
+    fn switch_from_history(&mut self, target: Option<usize>) -> anyhow::Result<()> {
+        let workspace_id = match target {
+            Some(id) => id,
+            None => {
+                tracing::debug!("No workspace history in that direction");
+                return Ok(());
+            }
+        };
+
+        let previous = self.show_workspace(workspace_id)?;
+        self.cleanup_workspace(previous);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cycle_workspace, WorkspaceHistory};
+    use crate::window_manager::tree::Rect;
+    use crate::workspace::core::WorkspaceConfig;
+    use crate::workspace::WorkspaceManager;
+
+    fn test_manager(config: WorkspaceConfig) -> WorkspaceManager {
+        let mut manager = WorkspaceManager::new(WorkspaceConfig {
+            default_count: 4,
+            ..config
+        });
+        manager
+            .initialize(&[(0, Rect::new(0, 0, 1920, 1080))])
+            .unwrap();
+        manager
+    }
+
+    #[test]
+    fn test_history_back_and_forward() {
+        let mut history = WorkspaceHistory::new();
+        history.visit(Some(1), 2);
+        history.visit(Some(2), 3);
+
+        assert_eq!(history.back(), Some(2));
+        assert_eq!(history.back(), Some(1));
+        assert_eq!(history.back(), None);
+        assert_eq!(history.forward(), Some(2));
+        assert_eq!(history.previous(), Some(1));
+
+        // Visiting a new workspace drops the forward entries
+        history.visit(Some(2), 4);
+        assert_eq!(history.forward(), None);
+        assert_eq!(history.back(), Some(2));
+        assert_eq!(history.back(), Some(1));
+    }
+
+    #[test]
+    fn test_history_forget() {
+        let mut history = WorkspaceHistory::new();
+        history.visit(Some(1), 2);
+        history.visit(Some(2), 1);
+        history.visit(Some(1), 3);
+
+        history.forget(2);
+        assert_eq!(history.back(), Some(1));
+        assert_eq!(history.back(), None);
+        assert_eq!(history.forward(), Some(3));
+    }
+
+    #[test]
+    fn test_cycle_workspace() {
+        assert_eq!(cycle_workspace(&[1, 2, 5], 2, true), Some(5));
+        assert_eq!(cycle_workspace(&[1, 2, 5], 5, true), Some(1));
+        assert_eq!(cycle_workspace(&[1, 2, 5], 1, false), Some(5));
+        assert_eq!(cycle_workspace(&[1, 2, 5], 3, true), None);
+        assert_eq!(cycle_workspace(&[1], 1, true), None);
+    }
+
+    #[test]
+    fn test_back_and_forth() {
+        let mut manager = test_manager(WorkspaceConfig::default());
+
+        manager.switch_to(3).unwrap();
+        manager.back_and_forth().unwrap();
+        assert_eq!(manager.active_workspace(), 1);
+        manager.back_and_forth().unwrap();
+        assert_eq!(manager.active_workspace(), 3);
+    }
+
+    #[test]
+    fn test_auto_back_and_forth() {
+        let mut manager = test_manager(WorkspaceConfig {
+            auto_back_and_forth: true,
+            ..WorkspaceConfig::default()
+        });
+
+        manager.switch_to(2).unwrap();
+        manager.switch_to(2).unwrap();
+        assert_eq!(manager.active_workspace(), 1);
+
+        let mut manager = test_manager(WorkspaceConfig::default());
+        manager.switch_to(2).unwrap();
+        manager.switch_to(2).unwrap();
+        assert_eq!(manager.active_workspace(), 2);
+    }
+
+    #[test]
+    fn test_manager_history_navigation() {
+        let mut manager = test_manager(WorkspaceConfig::default());
+        manager.switch_to(2).unwrap();
+        manager.switch_to(4).unwrap();
+
+        manager.history_back().unwrap();
+        assert_eq!(manager.active_workspace(), 2);
+        manager.history_back().unwrap();
+        assert_eq!(manager.active_workspace(), 1);
+        manager.history_back().unwrap();
+        assert_eq!(manager.active_workspace(), 1);
+
+        manager.history_forward().unwrap();
+        manager.history_forward().unwrap();
+        assert_eq!(manager.active_workspace(), 4);
+    }
+
+    #[test]
+    fn test_history_target_does_not_step() {
+        let mut manager = test_manager(WorkspaceConfig::default());
+        manager.switch_to(2).unwrap();
+        manager.switch_to(4).unwrap();
+
+        assert_eq!(manager.history_target(false), Some(2));
+        assert_eq!(manager.history_target(false), Some(2));
+        assert_eq!(manager.history_target(true), None);
+
+        manager.history_back().unwrap();
+        assert_eq!(manager.history_target(true), Some(4));
+    }
+
+    #[test]
+    fn test_destroyed_workspace_leaves_history() {
+        let mut manager = test_manager(WorkspaceConfig::default());
+        manager.switch_to(7).unwrap();
+        manager.switch_to(2).unwrap();
+        assert!(manager.get_workspace(7).is_none());
+
+        manager.history_back().unwrap();
+        assert_eq!(manager.active_workspace(), 1);
+    }
+
+    #[test]
+    fn test_switch_skipping_empty_workspaces() {
+        let mut manager = test_manager(WorkspaceConfig::default());
+        manager.add_window_to_workspace(100, 3).unwrap();
+
+        manager.switch_to_next_with(true, true).unwrap();
+        assert_eq!(manager.active_workspace(), 3);
+        manager.switch_to_next_with(true, true).unwrap();
+        assert_eq!(manager.active_workspace(), 3);
+
+        manager.switch_to_previous_with(false, true).unwrap();
+        assert_eq!(manager.active_workspace(), 2);
+    }
+
+    #[test]
+    fn test_switch_across_monitors() {
+        let mut manager = WorkspaceManager::new(WorkspaceConfig {
+            default_count: 2,
+            ..WorkspaceConfig::default()
+        });
+        manager
+            .initialize(&[
+                (0, Rect::new(0, 0, 1920, 1080)),
+                (1, Rect::new(1920, 0, 1920, 1080)),
+            ])
+            .unwrap();
+
+        manager.switch_to_next_with(false, true).unwrap();
+        assert_eq!(manager.active_workspace(), 2);
+        manager.switch_to_next_with(false, true).unwrap();
+        assert_eq!(manager.active_workspace(), 1);
+
+        manager.switch_to_previous_with(false, false).unwrap();
+        assert_eq!(manager.active_workspace(), 4);
+    }
+}
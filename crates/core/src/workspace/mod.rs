@@ -5,8 +5,12 @@
 
 pub mod auto_save;
 pub mod core;
+pub mod dynamic;
+pub mod groups;
+pub mod history;
 pub mod monitor_integration;
 pub mod persistence;
+pub mod rules;
 pub mod state;
 pub mod switching;
 pub mod virtual_desktop;
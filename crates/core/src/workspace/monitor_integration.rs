@@ -1,33 +1,38 @@
 //! Monitor integration operations.
 //!
 //! This module contains operations for managing workspace-monitor relationships
-//! and handling monitor changes.
+//! and handling monitor changes. Each workspace remembers the monitor it was
+//! placed on by a stable display key, so docking and undocking moves
+//! workspaces off a disconnected monitor and back again once it reappears.
 
+use crate::window_manager::monitor::MonitorManager;
+use crate::workspace::core::{Workspace, WorkspaceEvent};
 use crate::workspace::WorkspaceManager;
 
+/// A connected monitor as known to the workspace manager.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectedMonitor {
+    /// Monitor ID
+    pub id: usize,
+    /// Monitor name (e.g., "\\.\DISPLAY1")
+    pub name: String,
+    /// Stable key of the attached display (empty if unknown)
+    pub key: String,
+}
+
 impl WorkspaceManager {
     /// Assign workspaces to monitors.
+    ///
+    /// Workspace rules are applied first, so workspaces bound to a monitor by
+    /// name end up on that monitor.
     pub fn assign_workspaces_to_monitors(
         &mut self,
-        monitor_manager: &mut crate::window_manager::monitor::MonitorManager,
+        monitor_manager: &mut MonitorManager,
     ) -> anyhow::Result<()> {
-        // Clear existing workspace assignments
-        for monitor in monitor_manager.monitors.values_mut() {
-            monitor.workspaces.clear();
-        }
-
-        // Assign workspaces to their respective monitors
-        for workspace in self.workspaces.values() {
-            if let Some(monitor) = monitor_manager.get_by_id_mut(workspace.monitor) {
-                monitor.workspaces.push(workspace.id);
-
-                // Set as active workspace if this workspace is visible
-                if workspace.visible {
-                    monitor.active_workspace = Some(workspace.id);
-                }
-            }
-        }
-
+        let added = self.set_monitors(connected_monitors(monitor_manager));
+        self.apply_workspace_rules(&added);
+        self.remember_placements();
+        self.sync_monitor_workspaces(monitor_manager);
         Ok(())
     }
 
@@ -40,35 +45,262 @@ impl WorkspaceManager {
     }
 
     /// Redistribute workspaces when monitors change.
+    ///
+    /// Workspaces on a disconnected monitor move to the first remaining
+    /// monitor but keep their preferred monitor. When a monitor is connected,
+    /// the workspaces that prefer it (or are bound to it by a workspace rule)
+    /// return to it, and it shows its default workspace. Emits
+    /// `MonitorRemoved` and `MonitorAdded` events describing the migration.
     pub fn handle_monitor_change(
         &mut self,
-        monitor_manager: &crate::window_manager::monitor::MonitorManager,
+        monitor_manager: &MonitorManager,
     ) -> anyhow::Result<()> {
-        // Reassign workspaces from disconnected monitors and update geometries
+        let previous = self.monitors.clone();
+        let added = self.set_monitors(connected_monitors(monitor_manager));
+        let fallback = monitor_manager
+            .get_monitor_ids()
+            .into_iter()
+            .min()
+            .unwrap_or(0);
+
+        // Move workspaces off disconnected monitors
+        for removed in previous
+            .iter()
+            .filter(|monitor| monitor_manager.get_by_id(monitor.id).is_none())
+        {
+            let workspaces = self.migrate_workspaces(|ws| ws.monitor == removed.id, fallback);
+            tracing::info!(
+                "Monitor {} ('{}') removed, moved workspaces {:?} to monitor {}",
+                removed.id,
+                removed.name,
+                workspaces,
+                fallback
+            );
+            self.emit(WorkspaceEvent::MonitorRemoved {
+                id: removed.id,
+                name: removed.name.clone(),
+                key: removed.key.clone(),
+                workspaces,
+                fallback,
+            });
+        }
+
+        // Return workspaces to the monitors they prefer
+        let connected: Vec<_> = self
+            .monitors
+            .iter()
+            .filter(|monitor| added.contains(&monitor.id))
+            .cloned()
+            .collect();
+        for monitor in connected {
+            let workspaces = if monitor.key.is_empty() {
+                Vec::new()
+            } else {
+                self.migrate_workspaces(
+                    |ws| {
+                        ws.monitor != monitor.id
+                            && ws.preferred_monitor.as_deref() == Some(monitor.key.as_str())
+                    },
+                    monitor.id,
+                )
+            };
+            tracing::info!(
+                "Monitor {} ('{}') added, returned workspaces {:?}",
+                monitor.id,
+                monitor.name,
+                workspaces
+            );
+            self.emit(WorkspaceEvent::MonitorAdded {
+                id: monitor.id,
+                name: monitor.name,
+                key: monitor.key,
+                workspaces,
+            });
+        }
+
+        self.bind_workspaces();
+
+        // Update geometries, catching workspaces on monitors that were never known
         for workspace in self.workspaces.values_mut() {
             if monitor_manager.get_by_id(workspace.monitor).is_none() {
-                // Monitor was disconnected, move workspace to primary monitor (ID 0)
-                workspace.monitor = 0;
-
-                // Update tree rectangle if it exists
-                if let Some(monitor) = monitor_manager.get_by_id(0) {
-                    if let Some(ref mut tree) = workspace.tree {
-                        tree.set_rect(monitor.work_area);
-                    }
+                workspace.monitor = fallback;
+            }
+            if let Some(monitor) = monitor_manager.get_by_id(workspace.monitor) {
+                if let Some(ref mut tree) = workspace.tree {
+                    tree.set_rect(monitor.work_area);
                 }
-            } else {
-                // Monitor still exists, update tree rectangle to match current dimensions
-                if let Some(monitor) = monitor_manager.get_by_id(workspace.monitor) {
-                    if let Some(ref mut tree) = workspace.tree {
-                        tree.set_rect(monitor.work_area);
-                    }
+            }
+        }
+
+        self.remember_placements();
+        self.show_monitor_workspaces(&added);
+        Ok(())
+    }
+
+    /// Get the monitors known to the workspace manager.
+    pub fn connected_monitors(&self) -> &[ConnectedMonitor] {
+        &self.monitors
+    }
+
+    /// Move a workspace to another monitor.
+    ///
+    /// The workspace now prefers the target monitor and its layout is resized
+    /// to the new work area. A visible workspace stays visible, replacing the
+    /// workspace shown on the target monitor, and the monitor it left shows
+    /// another of its workspaces.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace to move
+    /// * `monitor_id` - The target monitor
+    /// * `monitor_manager` - Connected monitors, updated with the new assignment
+    pub fn move_workspace_to_monitor(
+        &mut self,
+        workspace_id: usize,
+        monitor_id: usize,
+        monitor_manager: &mut MonitorManager,
+    ) -> anyhow::Result<()> {
+        let visible = match self.workspaces.get(&workspace_id) {
+            Some(ws) if ws.monitor == monitor_id => return Ok(()),
+            Some(ws) => ws.visible,
+            None => anyhow::bail!("Workspace {} does not exist", workspace_id),
+        };
+        self.place_workspace(workspace_id, monitor_id, monitor_manager)?;
+
+        if visible {
+            for id in self.get_monitor_workspaces(monitor_id) {
+                if id != workspace_id {
+                    self.set_visible(id, false);
                 }
             }
         }
+        self.show_monitor_workspaces(&[]);
+        self.sync_monitor_workspaces(monitor_manager);
+        Ok(())
+    }
+
+    /// Swap the workspaces shown on two monitors.
+    ///
+    /// # Arguments
+    ///
+    /// * `first` - The first monitor
+    /// * `second` - The second monitor
+    /// * `monitor_manager` - Connected monitors, updated with the new assignment
+    pub fn swap_monitor_workspaces(
+        &mut self,
+        first: usize,
+        second: usize,
+        monitor_manager: &mut MonitorManager,
+    ) -> anyhow::Result<()> {
+        if first == second {
+            anyhow::bail!("Cannot swap monitor {} with itself", first);
+        }
+
+        let first_workspace = self.get_active_workspace_for_monitor(first);
+        let second_workspace = self.get_active_workspace_for_monitor(second);
+        if let Some(id) = first_workspace {
+            self.place_workspace(id, second, monitor_manager)?;
+        }
+        if let Some(id) = second_workspace {
+            self.place_workspace(id, first, monitor_manager)?;
+        }
 
+        self.sync_monitor_workspaces(monitor_manager);
         Ok(())
     }
 
+    /// Put a workspace on a monitor and lay it out in the monitor's work area.
+    fn place_workspace(
+        &mut self,
+        workspace_id: usize,
+        monitor_id: usize,
+        monitor_manager: &MonitorManager,
+    ) -> anyhow::Result<()> {
+        let monitor = match monitor_manager.get_by_id(monitor_id) {
+            Some(monitor) => monitor,
+            None => anyhow::bail!("Monitor {} not found", monitor_id),
+        };
+        let workspace = match self.workspaces.get_mut(&workspace_id) {
+            Some(ws) => ws,
+            None => anyhow::bail!("Workspace {} does not exist", workspace_id),
+        };
+
+        tracing::info!("Moving workspace {} to monitor {}", workspace_id, monitor_id);
+        workspace.monitor = monitor_id;
+        if !monitor.key.is_empty() {
+            workspace.preferred_monitor = Some(monitor.key.clone());
+        }
+        if let Some(ref mut tree) = workspace.tree {
+            tree.set_rect(monitor.work_area);
+
+            #[cfg(target_os = "windows")]
+            {
+                if workspace.visible {
+                    tree.apply_layout(0, 0)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Record which workspaces each monitor holds and shows.
+    fn sync_monitor_workspaces(&self, monitor_manager: &mut MonitorManager) {
+        for monitor in monitor_manager.monitors.values_mut() {
+            monitor.workspaces.clear();
+            monitor.active_workspace = None;
+        }
+
+        for workspace in self.workspaces.values() {
+            if let Some(monitor) = monitor_manager.get_by_id_mut(workspace.monitor) {
+                monitor.workspaces.push(workspace.id);
+
+                // Set as active workspace if this workspace is visible
+                if workspace.visible {
+                    monitor.active_workspace = Some(workspace.id);
+                }
+            }
+        }
+    }
+
+    /// Move the workspaces matching a predicate to another monitor.
+    ///
+    /// # Returns
+    ///
+    /// The IDs of the moved workspaces, sorted.
+    fn migrate_workspaces(
+        &mut self,
+        matches: impl Fn(&Workspace) -> bool,
+        monitor: usize,
+    ) -> Vec<usize> {
+        let mut moved: Vec<usize> = self
+            .workspaces
+            .values_mut()
+            .filter(|ws| matches(ws))
+            .map(|ws| {
+                ws.monitor = monitor;
+                ws.id
+            })
+            .collect();
+        moved.sort_unstable();
+        moved
+    }
+
+    /// Remember the monitor of every workspace that has no preferred monitor.
+    pub(super) fn remember_placements(&mut self) {
+        for workspace in self.workspaces.values_mut() {
+            if workspace.preferred_monitor.is_some() {
+                continue;
+            }
+            if let Some(monitor) = self
+                .monitors
+                .iter()
+                .find(|monitor| monitor.id == workspace.monitor && !monitor.key.is_empty())
+            {
+                workspace.preferred_monitor = Some(monitor.key.clone());
+            }
+        }
+    }
+
     /// Update workspace geometries based on DPI scaling.
     pub fn update_dpi_scaling(
         &mut self,
@@ -101,3 +333,147 @@ impl WorkspaceManager {
         }
     }
 }
+
+/// Describe every monitor of a monitor manager, sorted by ID.
+fn connected_monitors(monitor_manager: &MonitorManager) -> Vec<ConnectedMonitor> {
+    let mut monitors: Vec<ConnectedMonitor> = monitor_manager
+        .monitors
+        .values()
+        .map(|monitor| ConnectedMonitor {
+            id: monitor.id,
+            name: monitor.name.clone(),
+            key: monitor.key.clone(),
+        })
+        .collect();
+    monitors.sort_by_key(|monitor| monitor.id);
+    monitors
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::window_manager::monitor::{MonitorInfo, MonitorManager};
+    use crate::window_manager::tree::Rect;
+    use crate::workspace::core::{WorkspaceConfig, WorkspaceEvent};
+    use crate::workspace::WorkspaceManager;
+    use std::sync::{Arc, Mutex};
+
+    fn monitor(name: &str, x: i32) -> MonitorInfo {
+        let rect = Rect::new(x, 0, 1920, 1080);
+        MonitorInfo::new(0, Default::default(), name.to_string(), rect, rect, 1.0)
+    }
+
+    fn docked() -> (WorkspaceManager, MonitorManager) {
+        let mut manager = WorkspaceManager::new(WorkspaceConfig {
+            default_count: 4,
+            ..WorkspaceConfig::default()
+        });
+        manager
+            .initialize(&[
+                (0, Rect::new(0, 0, 1920, 1080)),
+                (1, Rect::new(1920, 0, 1920, 1080)),
+            ])
+            .unwrap();
+
+        let mut monitors = MonitorManager::new();
+        monitors.add_monitor(monitor("LAPTOP", 0));
+        monitors.add_monitor(monitor("EXTERNAL", 1920));
+        manager
+            .assign_workspaces_to_monitors(&mut monitors)
+            .unwrap();
+        (manager, monitors)
+    }
+
+    #[test]
+    fn test_workspaces_follow_monitor_across_hotplug() {
+        let (mut manager, mut monitors) = docked();
+
+        let external = manager.get_monitor_workspaces(1);
+        assert!(!external.is_empty());
+        assert_eq!(
+            manager.get_workspace(external[0]).unwrap().preferred_monitor,
+            Some("EXTERNAL".to_string())
+        );
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&events);
+        manager.set_event_listener(move |event| sink.lock().unwrap().push(event));
+
+        // Undock: the external monitor's workspaces move to the laptop
+        let display = monitors.remove_monitor(1).unwrap();
+        manager.handle_monitor_change(&monitors).unwrap();
+        assert!(manager.get_monitor_workspaces(1).is_empty());
+
+        // Dock again: they return to the external monitor under its new ID
+        let id = monitors.add_monitor(display);
+        manager.handle_monitor_change(&monitors).unwrap();
+        assert_eq!(manager.get_monitor_workspaces(id), external);
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                WorkspaceEvent::MonitorRemoved {
+                    id: 1,
+                    name: "EXTERNAL".to_string(),
+                    key: "EXTERNAL".to_string(),
+                    workspaces: external.clone(),
+                    fallback: 0,
+                },
+                WorkspaceEvent::MonitorAdded {
+                    id,
+                    name: "EXTERNAL".to_string(),
+                    key: "EXTERNAL".to_string(),
+                    workspaces: external.clone(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_move_workspace_to_monitor() {
+        let (mut manager, mut monitors) = docked();
+        let active = manager.active_workspace();
+        let laptop = manager.get_workspace(active).unwrap().monitor;
+        let external = 1 - laptop;
+
+        manager
+            .move_workspace_to_monitor(active, external, &mut monitors)
+            .unwrap();
+
+        let workspace = manager.get_workspace(active).unwrap();
+        assert_eq!(workspace.monitor, external);
+        assert!(workspace.visible);
+        assert_eq!(
+            workspace.preferred_monitor,
+            Some(monitors.get_by_id(external).unwrap().key.clone())
+        );
+        assert_eq!(manager.active_workspace(), active);
+
+        // Both monitors still show exactly one workspace
+        assert_eq!(manager.get_active_workspace_for_monitor(external), Some(active));
+        let shown = manager.get_active_workspace_for_monitor(laptop).unwrap();
+        assert_ne!(shown, active);
+        assert_eq!(monitors.get_by_id(external).unwrap().active_workspace, Some(active));
+        assert_eq!(monitors.get_by_id(laptop).unwrap().active_workspace, Some(shown));
+        assert!(monitors.get_by_id(external).unwrap().workspaces.contains(&active));
+    }
+
+    #[test]
+    fn test_swap_monitor_workspaces() {
+        let (mut manager, mut monitors) = docked();
+        let second = manager.get_monitor_workspaces(1)[0];
+        manager.switch_workspace_on_monitor(1, second).unwrap();
+        let first = manager.get_active_workspace_for_monitor(0).unwrap();
+
+        manager
+            .swap_monitor_workspaces(0, 1, &mut monitors)
+            .unwrap();
+
+        assert_eq!(manager.get_active_workspace_for_monitor(0), Some(second));
+        assert_eq!(manager.get_active_workspace_for_monitor(1), Some(first));
+        assert_eq!(monitors.get_by_id(0).unwrap().active_workspace, Some(second));
+        assert_eq!(monitors.get_by_id(1).unwrap().active_workspace, Some(first));
+        assert!(manager
+            .swap_monitor_workspaces(1, 1, &mut monitors)
+            .is_err());
+    }
+}
@@ -53,6 +53,28 @@ pub struct WindowState {
     pub workspace: usize,
 }
 
+/// Represents a window mark saved across sessions.
+///
+/// Window handles usually change when windows are recreated, so the window
+/// metadata is stored as well to find the window again after a restart.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct MarkState {
+    /// Mark name
+    pub mark: String,
+    
+    /// Window handle as a string (for cross-platform compatibility)
+    pub hwnd: String,
+    
+    /// Process name of the window owner
+    pub process_name: String,
+    
+    /// Window title
+    pub title: String,
+    
+    /// Window class name
+    pub class_name: String,
+}
+
 /// Represents the complete session state including all workspaces.
 ///
 /// This is the top-level structure that gets serialized to disk,
@@ -73,6 +95,10 @@ pub struct SessionState {
     
     /// Mapping of window handles (as strings) to workspace IDs
     pub window_to_workspace: HashMap<String, usize>,
+    
+    /// Window marks
+    #[serde(default)]
+    pub marks: Vec<MarkState>,
 }
 
 impl Default for SessionState {
@@ -86,6 +112,7 @@ impl Default for SessionState {
             workspaces: Vec::new(),
             active_workspace: 1,
             window_to_workspace: HashMap::new(),
+            marks: Vec::new(),
         }
     }
 }
@@ -0,0 +1,354 @@
+//! Workspace rules.
+//!
+//! This module applies the `workspace_rules` from the configuration: it binds
+//! workspaces to monitors (by index or by name), applies workspace names, and
+//! shows the default workspace of a monitor when the monitor appears. Bindings
+//! are kept while a monitor is disconnected, so its workspaces return to it
+//! once it is connected again.
+
+use crate::config::WorkspaceRule;
+use crate::window_manager::tree::Rect;
+use crate::workspace::core::Workspace;
+use crate::workspace::monitor_integration::ConnectedMonitor;
+use crate::workspace::WorkspaceManager;
+
+/// Find the connected monitor a workspace rule refers to.
+///
+/// # Arguments
+///
+/// * `rule` - The workspace rule
+/// * `monitors` - Connected monitors
+///
+/// # Returns
+///
+/// The monitor ID, or `None` if the monitor is not connected.
+pub fn resolve_rule_monitor(rule: &WorkspaceRule, monitors: &[ConnectedMonitor]) -> Option<usize> {
+    monitors
+        .iter()
+        .find(|monitor| rule.monitor.matches(monitor.id, &monitor.name))
+        .map(|monitor| monitor.id)
+}
+
+impl WorkspaceManager {
+    /// Replace the workspace rules and apply them to the connected monitors.
+    ///
+    /// Used on configuration reload: workspaces are moved to the monitors
+    /// they are bound to and renamed, but a monitor only switches to its
+    /// default workspace if it would otherwise show none.
+    pub fn set_workspace_rules(&mut self, rules: Vec<WorkspaceRule>) {
+        self.config.rules = rules;
+        self.apply_workspace_rules(&[]);
+    }
+
+    /// Get the rule for a workspace, if there is one.
+    pub fn workspace_rule(&self, workspace_id: usize) -> Option<&WorkspaceRule> {
+        self.config
+            .rules
+            .iter()
+            .find(|rule| rule.id == workspace_id)
+    }
+
+    /// Get the connected monitor a workspace is bound to by its rule.
+    pub fn bound_monitor(&self, workspace_id: usize) -> Option<usize> {
+        self.workspace_rule(workspace_id)
+            .and_then(|rule| resolve_rule_monitor(rule, &self.monitors))
+    }
+
+    /// Get the name for a workspace ID from its rule or the configured names.
+    pub(super) fn configured_name(&self, workspace_id: usize) -> String {
+        self.workspace_rule(workspace_id)
+            .and_then(|rule| rule.name.clone())
+            .or_else(|| {
+                workspace_id
+                    .checked_sub(1)
+                    .and_then(|index| self.config.names.get(index).cloned())
+            })
+            .unwrap_or_else(|| workspace_id.to_string())
+    }
+
+    /// Record the connected monitors.
+    ///
+    /// # Returns
+    ///
+    /// The IDs of monitors that were not connected (with that identity) before.
+    pub(super) fn set_monitors(&mut self, mut monitors: Vec<ConnectedMonitor>) -> Vec<usize> {
+        monitors.sort_by_key(|monitor| monitor.id);
+        let added = monitors
+            .iter()
+            .filter(|monitor| !self.monitors.contains(monitor))
+            .map(|monitor| monitor.id)
+            .collect();
+        self.monitors = monitors;
+        added
+    }
+
+    /// Bind and name workspaces, then make sure every monitor shows one.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_monitors` - Monitors that just appeared and should show their
+    ///   default workspace
+    pub(super) fn apply_workspace_rules(&mut self, new_monitors: &[usize]) {
+        if self.config.rules.is_empty() {
+            return;
+        }
+        self.bind_workspaces();
+        self.show_monitor_workspaces(new_monitors);
+    }
+
+    /// Move workspaces to the monitors their rules bind them to and apply
+    /// the rule names.
+    ///
+    /// Workspaces that do not exist yet are created on their monitor if it is
+    /// connected. Workspaces bound to a disconnected monitor are left where
+    /// they are.
+    pub(super) fn bind_workspaces(&mut self) {
+        for rule in self.config.rules.clone() {
+            let monitor = resolve_rule_monitor(&rule, &self.monitors);
+
+            match self.workspaces.get_mut(&rule.id) {
+                Some(workspace) => {
+                    if let Some(ref name) = rule.name {
+                        workspace.name = name.clone();
+                    }
+                    if let Some(monitor) = monitor {
+                        if workspace.monitor != monitor {
+                            tracing::info!("Moving workspace {} to monitor {}", rule.id, monitor);
+                            workspace.monitor = monitor;
+                        }
+                    }
+                }
+                None => {
+                    if let Some(monitor) = monitor {
+                        let name = self.configured_name(rule.id);
+                        self.next_id = self.next_id.max(rule.id + 1);
+                        self.insert_workspace(Workspace::new(
+                            rule.id,
+                            name,
+                            monitor,
+                            Rect::new(0, 0, 0, 0),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Make sure every connected monitor shows exactly one workspace.
+    ///
+    /// Monitors in `new_monitors` switch to their default workspace. Other
+    /// monitors keep the workspace they show, falling back to their default
+    /// workspace or the one with the lowest ID.
+    pub(super) fn show_monitor_workspaces(&mut self, new_monitors: &[usize]) {
+        let monitor_ids: Vec<usize> = self.monitors.iter().map(|monitor| monitor.id).collect();
+        for monitor in monitor_ids {
+            let workspaces = self.get_monitor_workspaces(monitor);
+            let first = match workspaces.first() {
+                Some(&id) => id,
+                None => continue,
+            };
+
+            let default = self
+                .config
+                .rules
+                .iter()
+                .filter(|rule| rule.default && workspaces.contains(&rule.id))
+                .find(|rule| resolve_rule_monitor(rule, &self.monitors) == Some(monitor))
+                .map(|rule| rule.id);
+            let has_active = workspaces.contains(&self.active_workspace);
+            let visible = if has_active {
+                Some(self.active_workspace)
+            } else {
+                workspaces
+                    .iter()
+                    .copied()
+                    .find(|id| self.workspaces.get(id).is_some_and(|ws| ws.visible))
+            };
+
+            let target = if new_monitors.contains(&monitor) {
+                default.or(visible)
+            } else {
+                visible.or(default)
+            }
+            .unwrap_or(first);
+
+            for &id in &workspaces {
+                self.set_visible(id, id == target);
+            }
+            if has_active {
+                self.active_workspace = target;
+            }
+        }
+    }
+
+    /// Show or hide a workspace and its windows.
+    pub(super) fn set_visible(&mut self, workspace_id: usize, visible: bool) {
+        let workspace = match self.workspaces.get_mut(&workspace_id) {
+            Some(ws) if ws.visible != visible => ws,
+            _ => return,
+        };
+
+        if visible {
+            workspace.mark_active();
+        } else {
+            workspace.mark_inactive();
+        }
+
+        #[cfg(target_os = "windows")]
+        {
+            use windows::Win32::Foundation::HWND;
+            use windows::Win32::UI::WindowsAndMessaging::*;
+
+            let command = if visible { SW_SHOW } else { SW_HIDE };
+            for &hwnd in &workspace.windows {
+                unsafe {
+                    ShowWindow(HWND(hwnd), command);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{MonitorRef, WorkspaceRule};
+    use crate::window_manager::monitor::{MonitorInfo, MonitorManager};
+    use crate::window_manager::tree::Rect;
+    use crate::workspace::core::WorkspaceConfig;
+    use crate::workspace::WorkspaceManager;
+
+    fn rule(id: usize, monitor: MonitorRef, default: bool, name: Option<&str>) -> WorkspaceRule {
+        WorkspaceRule {
+            id,
+            monitor,
+            default,
+            name: name.map(str::to_string),
+        }
+    }
+
+    fn monitor(name: &str, x: i32) -> MonitorInfo {
+        let rect = Rect::new(x, 0, 1920, 1080);
+        MonitorInfo::new(0, Default::default(), name.to_string(), rect, rect, 1.0)
+    }
+
+    fn two_monitors() -> MonitorManager {
+        let mut monitors = MonitorManager::new();
+        monitors.add_monitor(monitor("DISPLAY1", 0));
+        monitors.add_monitor(monitor("DISPLAY2", 1920));
+        monitors
+    }
+
+    fn manager(rules: Vec<WorkspaceRule>) -> WorkspaceManager {
+        let mut manager = WorkspaceManager::new(WorkspaceConfig {
+            default_count: 3,
+            rules,
+            ..WorkspaceConfig::default()
+        });
+        manager
+            .initialize(&[
+                (0, Rect::new(0, 0, 1920, 1080)),
+                (1, Rect::new(1920, 0, 1920, 1080)),
+            ])
+            .unwrap();
+        manager
+    }
+
+    #[test]
+    fn test_rules_applied_at_initialize() {
+        let manager = manager(vec![
+            rule(2, MonitorRef::Index(0), true, Some("Web")),
+            rule(3, MonitorRef::Index(1), false, None),
+            rule(5, MonitorRef::Index(1), true, Some("Chat")),
+        ]);
+
+        assert_eq!(manager.active_workspace(), 2);
+        assert_eq!(manager.get_workspace(2).unwrap().name, "Web");
+        assert_eq!(manager.get_workspace(3).unwrap().monitor, 1);
+        assert_eq!(manager.get_workspace(5).unwrap().name, "Chat");
+        assert_eq!(manager.get_active_workspace_for_monitor(0), Some(2));
+        assert_eq!(manager.get_active_workspace_for_monitor(1), Some(5));
+    }
+
+    #[test]
+    fn test_rules_match_monitor_names() {
+        let mut manager = manager(vec![rule(
+            1,
+            MonitorRef::Name("display2".to_string()),
+            true,
+            None,
+        )]);
+        assert_eq!(manager.get_workspace(1).unwrap().monitor, 0);
+
+        let mut monitors = two_monitors();
+        manager
+            .assign_workspaces_to_monitors(&mut monitors)
+            .unwrap();
+
+        assert_eq!(manager.bound_monitor(1), Some(1));
+        assert_eq!(manager.get_workspace(1).unwrap().monitor, 1);
+        assert_eq!(manager.get_active_workspace_for_monitor(1), Some(1));
+        assert!(monitors.get_by_id(1).unwrap().workspaces.contains(&1));
+    }
+
+    #[test]
+    fn test_workspace_returns_to_reconnected_monitor() {
+        let mut manager = manager(vec![rule(
+            2,
+            MonitorRef::Name("DISPLAY2".to_string()),
+            false,
+            Some("Docs"),
+        )]);
+        let mut monitors = two_monitors();
+        manager
+            .assign_workspaces_to_monitors(&mut monitors)
+            .unwrap();
+        assert_eq!(manager.get_workspace(2).unwrap().monitor, 1);
+
+        let display2 = monitors.remove_monitor(1).unwrap();
+        manager.handle_monitor_change(&monitors).unwrap();
+        assert_eq!(manager.get_workspace(2).unwrap().monitor, 0);
+
+        let id = monitors.add_monitor(display2);
+        manager.handle_monitor_change(&monitors).unwrap();
+        assert_eq!(manager.get_workspace(2).unwrap().monitor, id);
+        assert_eq!(manager.get_workspace(2).unwrap().name, "Docs");
+    }
+
+    #[test]
+    fn test_set_workspace_rules_on_reload() {
+        let mut manager = manager(Vec::new());
+        manager.switch_to(2).unwrap();
+
+        manager.set_workspace_rules(vec![
+            rule(1, MonitorRef::Index(0), true, Some("Main")),
+            rule(2, MonitorRef::Index(0), false, Some("Web")),
+        ]);
+
+        // Reloading renames workspaces without switching away
+        assert_eq!(manager.active_workspace(), 2);
+        assert_eq!(manager.get_workspace(1).unwrap().name, "Main");
+        assert_eq!(manager.get_workspace(2).unwrap().name, "Web");
+    }
+
+    #[test]
+    fn test_rules_create_and_place_workspaces() {
+        let mut manager = manager(vec![
+            rule(9, MonitorRef::Index(1), false, Some("Music")),
+            rule(
+                10,
+                MonitorRef::Name("DISPLAY3".to_string()),
+                false,
+                Some("Mail"),
+            ),
+        ]);
+
+        let workspace = manager.get_workspace(9).unwrap();
+        assert_eq!(workspace.monitor, 1);
+        assert_eq!(workspace.name, "Music");
+
+        // Bound to a disconnected monitor: created on demand where requested
+        assert!(manager.get_workspace(10).is_none());
+        manager.ensure_workspace(10, 0).unwrap();
+        assert_eq!(manager.get_workspace(10).unwrap().name, "Mail");
+    }
+}
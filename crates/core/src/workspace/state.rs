@@ -16,6 +16,11 @@ impl WorkspaceManager {
 
         let mut state = crate::workspace::persistence::SessionState {
             active_workspace: self.active_workspace,
+            // Window marks are owned by the window manager; keep the saved ones
+            marks: persistence
+                .load_state()
+                .map(|state| state.marks)
+                .unwrap_or_default(),
             ..Default::default()
         };
 
@@ -2,19 +2,80 @@
 //!
 //! This module contains all operations related to switching between workspaces.
 
+use crate::workspace::history::cycle_workspace;
 use crate::workspace::WorkspaceManager;
 
+/// A workspace switch requested by the user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WorkspaceSwitch {
+    /// Switch to a workspace by ID or name
+    To(String),
+    /// Switch to the workspace shown before the current one
+    BackAndForth,
+    /// Go back in the workspace history
+    HistoryBack,
+    /// Go forward in the workspace history
+    HistoryForward,
+    /// Switch to the next or previous workspace in ID order
+    Cycle {
+        /// `true` for the next workspace, `false` for the previous one
+        forward: bool,
+        /// Skip workspaces without windows
+        skip_empty: bool,
+        /// Only consider workspaces on the active monitor
+        same_monitor: bool,
+    },
+}
+
 impl WorkspaceManager {
     /// Switch to a different workspace
+    ///
+    /// Missing workspaces are created on the current monitor if on-demand
+    /// creation is enabled, and the workspace being left is destroyed if it
+    /// was created on demand and is empty. With `auto_back_and_forth`
+    /// enabled, switching to the current workspace returns to the previous
+    /// one instead.
     pub fn switch_to(&mut self, workspace_id: usize) -> anyhow::Result<()> {
-        if !self.workspaces.contains_key(&workspace_id) {
-            anyhow::bail!("Workspace {} does not exist", workspace_id);
-        }
+        self.ensure_workspace(workspace_id, self.active_monitor())?;
 
         if self.active_workspace == workspace_id {
+            if self.config.auto_back_and_forth {
+                return self.back_and_forth();
+            }
             return Ok(());
         }
 
+        self.switch_recorded(workspace_id)
+    }
+
+    /// Switch to a workspace and record the switch in the monitor's history.
+    pub(super) fn switch_recorded(&mut self, workspace_id: usize) -> anyhow::Result<()> {
+        let monitor = match self.workspaces.get(&workspace_id) {
+            Some(ws) => ws.monitor,
+            None => anyhow::bail!("Workspace {} does not exist", workspace_id),
+        };
+        let from = self
+            .workspaces
+            .values()
+            .find(|ws| ws.monitor == monitor && ws.visible)
+            .map(|ws| ws.id);
+
+        let previous = self.show_workspace(workspace_id)?;
+        self.record_switch(monitor, from, workspace_id);
+        self.cleanup_workspace(previous);
+        Ok(())
+    }
+
+    /// Make a workspace the active one, hiding the previously active one.
+    ///
+    /// # Returns
+    ///
+    /// The previously active workspace ID.
+    pub(super) fn show_workspace(&mut self, workspace_id: usize) -> anyhow::Result<usize> {
+        if self.active_workspace == workspace_id {
+            return Ok(workspace_id);
+        }
+
         tracing::info!(
             "Switching from workspace {} to {}",
             self.active_workspace,
@@ -67,56 +128,115 @@ impl WorkspaceManager {
             }
         }
 
+        let previous = self.active_workspace;
         self.active_workspace = workspace_id;
-        Ok(())
+        Ok(previous)
     }
 
     /// Switch to the next workspace
     pub fn switch_to_next(&mut self) -> anyhow::Result<()> {
-        let current_monitor = self
-            .workspaces
-            .get(&self.active_workspace)
-            .map(|ws| ws.monitor)
-            .unwrap_or(0);
-
-        let monitor_workspaces = self.get_monitor_workspaces(current_monitor);
-
-        if let Some(current_idx) = monitor_workspaces
-            .iter()
-            .position(|&id| id == self.active_workspace)
-        {
-            let next_idx = (current_idx + 1) % monitor_workspaces.len();
-            let next_id = monitor_workspaces[next_idx];
-            self.switch_to(next_id)?;
-        }
-
-        Ok(())
+        self.switch_to_next_with(false, true)
     }
 
     /// Switch to the previous workspace
     pub fn switch_to_previous(&mut self) -> anyhow::Result<()> {
-        let current_monitor = self
-            .workspaces
-            .get(&self.active_workspace)
-            .map(|ws| ws.monitor)
-            .unwrap_or(0);
+        self.switch_to_previous_with(false, true)
+    }
 
-        let monitor_workspaces = self.get_monitor_workspaces(current_monitor);
+    /// Switch to the next workspace, optionally skipping empty workspaces
+    /// and staying on the current monitor.
+    ///
+    /// # Arguments
+    ///
+    /// * `skip_empty` - Skip workspaces without windows
+    /// * `same_monitor` - Only consider workspaces on the active monitor
+    pub fn switch_to_next_with(&mut self, skip_empty: bool, same_monitor: bool) -> anyhow::Result<()> {
+        self.cycle(true, skip_empty, same_monitor)
+    }
+
+    /// Switch to the previous workspace, optionally skipping empty workspaces
+    /// and staying on the current monitor.
+    ///
+    /// # Arguments
+    ///
+    /// * `skip_empty` - Skip workspaces without windows
+    /// * `same_monitor` - Only consider workspaces on the active monitor
+    pub fn switch_to_previous_with(
+        &mut self,
+        skip_empty: bool,
+        same_monitor: bool,
+    ) -> anyhow::Result<()> {
+        self.cycle(false, skip_empty, same_monitor)
+    }
 
-        if let Some(current_idx) = monitor_workspaces
-            .iter()
-            .position(|&id| id == self.active_workspace)
-        {
-            let prev_idx = if current_idx == 0 {
-                monitor_workspaces.len() - 1
-            } else {
-                current_idx - 1
-            };
-            let prev_id = monitor_workspaces[prev_idx];
-            self.switch_to(prev_id)?;
+    /// Switch to the neighbouring workspace in ID order.
+    fn cycle(&mut self, forward: bool, skip_empty: bool, same_monitor: bool) -> anyhow::Result<()> {
+        match self.cycle_target(forward, skip_empty, same_monitor) {
+            Some(target) => self.switch_recorded(target),
+            None => Ok(()),
         }
+    }
 
-        Ok(())
+    /// Get the neighbouring workspace in ID order.
+    fn cycle_target(&self, forward: bool, skip_empty: bool, same_monitor: bool) -> Option<usize> {
+        let current_monitor = self.active_monitor();
+
+        let candidates: Vec<usize> = self
+            .workspaces()
+            .into_iter()
+            .filter(|ws| !same_monitor || ws.monitor == current_monitor)
+            .filter(|ws| !skip_empty || !ws.windows.is_empty() || ws.id == self.active_workspace)
+            .map(|ws| ws.id)
+            .collect();
+
+        cycle_workspace(&candidates, self.active_workspace, forward)
+    }
+
+    /// Find the workspace a switch would make active, without switching.
+    ///
+    /// Nothing is created or recorded, so the switch can be checked against
+    /// the window manager before `apply_switch` makes it.
+    ///
+    /// # Returns
+    ///
+    /// The workspace active after the switch (the current one if the switch
+    /// would do nothing), or an error if the target does not exist and
+    /// on-demand creation is disabled.
+    pub fn switch_target(&self, switch: &WorkspaceSwitch) -> anyhow::Result<usize> {
+        let target = match switch {
+            WorkspaceSwitch::To(target) => {
+                let id = self.lookup_workspace(target)?;
+                if id == self.active_workspace && self.config.auto_back_and_forth {
+                    self.previous_workspace()
+                } else {
+                    Some(id)
+                }
+            }
+            WorkspaceSwitch::BackAndForth => self.previous_workspace(),
+            WorkspaceSwitch::HistoryBack => self.history_target(false),
+            WorkspaceSwitch::HistoryForward => self.history_target(true),
+            WorkspaceSwitch::Cycle {
+                forward,
+                skip_empty,
+                same_monitor,
+            } => self.cycle_target(*forward, *skip_empty, *same_monitor),
+        };
+        Ok(target.unwrap_or(self.active_workspace))
+    }
+
+    /// Make a workspace switch.
+    pub fn apply_switch(&mut self, switch: &WorkspaceSwitch) -> anyhow::Result<()> {
+        match switch {
+            WorkspaceSwitch::To(target) => self.switch_to_name(target),
+            WorkspaceSwitch::BackAndForth => self.back_and_forth(),
+            WorkspaceSwitch::HistoryBack => self.history_back(),
+            WorkspaceSwitch::HistoryForward => self.history_forward(),
+            WorkspaceSwitch::Cycle {
+                forward,
+                skip_empty,
+                same_monitor,
+            } => self.cycle(*forward, *skip_empty, *same_monitor),
+        }
     }
 
     /// Switch to a workspace by index on the current monitor (1-based)
@@ -125,11 +245,7 @@ impl WorkspaceManager {
             anyhow::bail!("Workspace index must be >= 1");
         }
 
-        let current_monitor = self
-            .workspaces
-            .get(&self.active_workspace)
-            .map(|ws| ws.monitor)
-            .unwrap_or(0);
+        let current_monitor = self.active_monitor();
 
         let monitor_workspaces = self.get_monitor_workspaces(current_monitor);
 
@@ -151,20 +267,34 @@ impl WorkspaceManager {
         monitor_id: usize,
         workspace_id: usize,
     ) -> anyhow::Result<()> {
-        // Validate that the workspace exists and is on the correct monitor
-        if let Some(workspace) = self.workspaces.get(&workspace_id) {
-            if workspace.monitor != monitor_id {
-                anyhow::bail!(
-                    "Workspace {} is on monitor {}, not monitor {}",
-                    workspace_id,
-                    workspace.monitor,
-                    monitor_id
-                );
-            }
-        } else {
-            anyhow::bail!("Workspace {} does not exist", workspace_id);
+        self.ensure_workspace(workspace_id, monitor_id)?;
+        self.check_workspace_monitor(workspace_id, monitor_id)?;
+
+        self.show_on_monitor(monitor_id, workspace_id);
+        self.apply_layouts(&[workspace_id])
+    }
+
+    /// Check that a workspace exists and is on a monitor.
+    pub(super) fn check_workspace_monitor(
+        &self,
+        workspace_id: usize,
+        monitor_id: usize,
+    ) -> anyhow::Result<()> {
+        match self.workspaces.get(&workspace_id) {
+            Some(workspace) if workspace.monitor != monitor_id => anyhow::bail!(
+                "Workspace {} is on monitor {}, not monitor {}",
+                workspace_id,
+                workspace.monitor,
+                monitor_id
+            ),
+            Some(_) => Ok(()),
+            None => anyhow::bail!("Workspace {} does not exist", workspace_id),
         }
+    }
 
+    /// Hide the workspace visible on a monitor and show another one there,
+    /// without applying its layout.
+    pub(super) fn show_on_monitor(&mut self, monitor_id: usize, workspace_id: usize) {
         // Find and hide the currently visible workspace on this monitor (if any)
         let current_visible = self
             .workspaces
@@ -205,13 +335,31 @@ impl WorkspaceManager {
                         ShowWindow(HWND(hwnd), SW_SHOW);
                     }
                 }
+            }
+        }
 
-                // Apply layout geometry if tree exists
-                if let Some(ref tree) = workspace.tree {
-                    tree.apply_layout(0, 0)?;
-                }
+        self.record_switch(monitor_id, current_visible, workspace_id);
+        if let Some(ws_id) = current_visible {
+            if ws_id != workspace_id {
+                self.cleanup_workspace(ws_id);
+            }
+        }
+    }
+
+    /// Apply the layout geometry of workspaces that have a tree.
+    pub(super) fn apply_layouts(&self, workspace_ids: &[usize]) -> anyhow::Result<()> {
+        #[cfg(target_os = "windows")]
+        for workspace_id in workspace_ids {
+            if let Some(tree) = self
+                .workspaces
+                .get(workspace_id)
+                .and_then(|ws| ws.tree.as_ref())
+            {
+                tree.apply_layout(0, 0)?;
             }
         }
+        #[cfg(not(target_os = "windows"))]
+        let _ = workspace_ids;
 
         Ok(())
     }
@@ -11,6 +11,8 @@ impl WorkspaceManager {
         hwnd: isize,
         workspace_id: usize,
     ) -> anyhow::Result<()> {
+        self.ensure_workspace(workspace_id, self.active_monitor())?;
+
         if let Some(workspace) = self.workspaces.get_mut(&workspace_id) {
             workspace.add_window(hwnd);
             self.window_to_workspace.insert(hwnd, workspace_id);
@@ -40,6 +42,7 @@ impl WorkspaceManager {
                 workspace.remove_window(hwnd);
             }
             self.window_to_workspace.remove(&hwnd);
+            self.cleanup_workspace(workspace_id);
             Ok(Some(workspace_id))
         } else {
             Ok(None)
@@ -57,6 +60,8 @@ impl WorkspaceManager {
             return Ok(());
         }
 
+        self.ensure_workspace(to_workspace, self.active_monitor())?;
+
         if let Some(from_ws) = self.workspaces.get_mut(&from_workspace) {
             from_ws.remove_window(hwnd);
         }
@@ -91,6 +96,7 @@ impl WorkspaceManager {
             }
         }
 
+        self.cleanup_workspace(from_workspace);
         Ok(())
     }
 
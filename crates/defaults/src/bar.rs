@@ -0,0 +1,37 @@
+//! Default settings of the status bar
+//!
+//! The status bar's `BarConfig` and its JSON Schema in `tenraku-core` both
+//! take their defaults from here, so the schema cannot drift from the bar.
+
+/// Bar height in pixels
+pub const HEIGHT: u32 = 30;
+
+/// Bar position, `top` or `bottom`
+pub const POSITION: &str = "top";
+
+/// Whether the bar stays on top of other windows
+pub const ALWAYS_ON_TOP: bool = true;
+
+/// Whether the bar reserves screen space so windows do not overlap it
+pub const RESERVE_SPACE: bool = true;
+
+/// Background color (hex)
+pub const BACKGROUND_COLOR: &str = "#1e1e2e";
+
+/// Foreground/text color (hex)
+pub const FOREGROUND_COLOR: &str = "#cdd6f4";
+
+/// Font family
+pub const FONT_FAMILY: &str = "Segoe UI";
+
+/// Font size
+pub const FONT_SIZE: f32 = 12.0;
+
+/// Border width in pixels
+pub const BORDER_WIDTH: u32 = 0;
+
+/// Modules on the left side
+pub const LEFT_MODULES: &[&str] = &["workspaces"];
+
+/// Modules on the right side
+pub const RIGHT_MODULES: &[&str] = &["cpu", "memory", "battery", "clock"];
@@ -0,0 +1,6 @@
+//! Tenraku Shared Defaults
+//!
+//! Default settings shared between the crates, kept free of dependencies so
+//! that the status bar can use them without linking the window manager core.
+
+pub mod bar;
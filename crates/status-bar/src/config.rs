@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use std::fs;
 use anyhow::{Result, Context};
+use tenraku_defaults::bar as bar_defaults;
 
 /// Main status bar configuration
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
@@ -36,11 +37,11 @@ pub struct BarSettings {
     pub monitor: Option<usize>,
     
     /// Layer (always on top)
-    #[serde(default = "default_true")]
+    #[serde(default = "default_always_on_top")]
     pub always_on_top: bool,
     
     /// Reserve screen space (no windows overlap)
-    #[serde(default = "default_true")]
+    #[serde(default = "default_reserve_space")]
     pub reserve_space: bool,
 }
 
@@ -75,7 +76,7 @@ pub struct StyleSettings {
     pub border_color: Option<String>,
     
     /// Border width (pixels)
-    #[serde(default)]
+    #[serde(default = "default_border_width")]
     pub border_width: u32,
 }
 
@@ -99,46 +100,52 @@ pub struct ModulesConfig {
     pub module_configs: std::collections::HashMap<String, serde_json::Value>,
 }
 
-// Default values
+// Default values, shared with the configuration schema
 fn default_height() -> u32 {
-    30
+    bar_defaults::HEIGHT
 }
 
 fn default_position() -> BarPosition {
-    BarPosition::Top
+    match bar_defaults::POSITION {
+        "bottom" => BarPosition::Bottom,
+        _ => BarPosition::Top,
+    }
+}
+
+fn default_always_on_top() -> bool {
+    bar_defaults::ALWAYS_ON_TOP
 }
 
-fn default_true() -> bool {
-    true
+fn default_reserve_space() -> bool {
+    bar_defaults::RESERVE_SPACE
 }
 
 fn default_background() -> String {
-    "#1e1e2e".to_string()
+    bar_defaults::BACKGROUND_COLOR.to_string()
 }
 
 fn default_foreground() -> String {
-    "#cdd6f4".to_string()
+    bar_defaults::FOREGROUND_COLOR.to_string()
 }
 
 fn default_font() -> String {
-    "Segoe UI".to_string()
+    bar_defaults::FONT_FAMILY.to_string()
 }
 
 fn default_font_size() -> f32 {
-    12.0
+    bar_defaults::FONT_SIZE
+}
+
+fn default_border_width() -> u32 {
+    bar_defaults::BORDER_WIDTH
 }
 
 fn default_left_modules() -> Vec<String> {
-    vec!["workspaces".to_string()]
+    bar_defaults::LEFT_MODULES.iter().map(|m| m.to_string()).collect()
 }
 
 fn default_right_modules() -> Vec<String> {
-    vec![
-        "cpu".to_string(),
-        "memory".to_string(),
-        "battery".to_string(),
-        "clock".to_string(),
-    ]
+    bar_defaults::RIGHT_MODULES.iter().map(|m| m.to_string()).collect()
 }
 
 impl Default for BarSettings {
@@ -147,8 +154,8 @@ impl Default for BarSettings {
             height: default_height(),
             position: default_position(),
             monitor: None,
-            always_on_top: default_true(),
-            reserve_space: default_true(),
+            always_on_top: default_always_on_top(),
+            reserve_space: default_reserve_space(),
         }
     }
 }
@@ -161,7 +168,7 @@ impl Default for StyleSettings {
             font_family: default_font(),
             font_size: default_font_size(),
             border_color: None,
-            border_width: 0,
+            border_width: default_border_width(),
         }
     }
 }
@@ -448,4 +455,27 @@ height = 50
         assert!(config.bar.always_on_top);
         assert_eq!(config.style.background_color, "#1e1e2e");
     }
+    
+    #[test]
+    fn test_schema_matches_config() {
+        let schema = tenraku_core::config::json_schema::bar_config_schema();
+        let config = serde_json::to_value(BarConfig::default()).unwrap();
+        
+        // Every setting is described, with the same default
+        for (section, settings) in config.as_object().unwrap() {
+            let definition = schema["properties"][section]["$ref"]
+                .as_str()
+                .and_then(|reference| reference.strip_prefix("#/definitions/"))
+                .unwrap_or_else(|| panic!("[{}] is not in the schema", section));
+            let properties = &schema["definitions"][definition]["properties"];
+            
+            for (key, value) in settings.as_object().unwrap() {
+                let property = &properties[key];
+                assert!(!property.is_null(), "{}.{} is not in the schema", section, key);
+                if let Some(default) = property.get("default") {
+                    assert_eq!(default, value, "default of {}.{}", section, key);
+                }
+            }
+        }
+    }
 }
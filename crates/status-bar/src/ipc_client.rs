@@ -117,9 +117,11 @@ impl IpcClient {
             "events": [
                 "workspace_changed",
                 "window_focused",
+                "window_urgent",
                 "window_created",
                 "window_closed",
-                "config_reloaded"
+                "config_reloaded",
+                "mode_changed"
             ]
         });
 
@@ -146,6 +148,24 @@ impl IpcClient {
         }
     }
 
+    /// Get list of tags (empty unless the window manager runs in tag mode)
+    pub async fn get_tags(&self) -> Result<Vec<TagData>> {
+        let request = serde_json::json!({
+            "type": "get_tags"
+        });
+
+        let response = self.send_request(&request).await?;
+
+        // Outside tag mode the response is an error without data
+        if let Some(data) = response.get("data") {
+            let tags =
+                serde_json::from_value(data.clone()).context("Failed to parse tag data")?;
+            Ok(tags)
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
     /// Get active window information
     pub async fn get_active_window(&self) -> Result<Option<WindowData>> {
         let request = serde_json::json!({
@@ -180,6 +200,19 @@ impl IpcClient {
         Ok(())
     }
 
+    /// View only one tag on the focused monitor
+    pub async fn view_tag(&self, tag: usize) -> Result<()> {
+        let request = serde_json::json!({
+            "type": "view_tag",
+            "tag": tag
+        });
+
+        let _response = self.send_request(&request).await?;
+        debug!("Viewing tag {}", tag);
+
+        Ok(())
+    }
+
     /// Send a command to the window manager
     pub async fn execute_command(&self, command: &str, args: Vec<String>) -> Result<()> {
         let request = serde_json::json!({
@@ -397,6 +430,10 @@ impl IpcClient {
                     hwnd: data.get("hwnd")?.as_str()?.to_string(),
                     title: data.get("title")?.as_str()?.to_string(),
                 }),
+                "window_urgent" => Some(IpcEvent::WindowUrgent {
+                    hwnd: data.get("hwnd")?.as_str()?.to_string(),
+                    urgent: data.get("urgent")?.as_bool()?,
+                }),
                 "window_created" => Some(IpcEvent::WindowCreated {
                     hwnd: data.get("hwnd")?.as_str()?.to_string(),
                     title: data.get("title")?.as_str()?.to_string(),
@@ -405,6 +442,9 @@ impl IpcClient {
                     hwnd: data.get("hwnd")?.as_str()?.to_string(),
                 }),
                 "config_reloaded" => Some(IpcEvent::ConfigReloaded),
+                "mode_changed" => Some(IpcEvent::ModeChanged {
+                    mode: data.get("mode")?.as_str()?.to_string(),
+                }),
                 _ => {
                     warn!("Unknown event type: {}", name);
                     None
@@ -430,6 +470,20 @@ pub struct WorkspaceData {
     pub monitor: usize,
     pub window_count: usize,
     pub active: bool,
+    #[serde(default)]
+    pub urgent: bool,
+    #[serde(default)]
+    pub special: bool,
+}
+
+/// Tag data from window manager (tag mode)
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct TagData {
+    pub id: usize,
+    pub monitors: Vec<usize>,
+    pub window_count: usize,
+    #[serde(default)]
+    pub urgent: bool,
 }
 
 /// Window data from window manager
@@ -501,6 +555,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_event_window_urgent() {
+        let json = serde_json::json!({
+            "type": "event",
+            "name": "window_urgent",
+            "data": {
+                "hwnd": "12345",
+                "urgent": true
+            }
+        });
+
+        let event = IpcClient::parse_event(&json);
+        assert!(event.is_some());
+
+        if let Some(IpcEvent::WindowUrgent { hwnd, urgent }) = event {
+            assert_eq!(hwnd, "12345");
+            assert!(urgent);
+        } else {
+            panic!("Wrong event type");
+        }
+    }
+
     #[test]
     fn test_parse_event_window_created() {
         let json = serde_json::json!({
@@ -556,6 +632,25 @@ mod tests {
         assert!(matches!(event, Some(IpcEvent::ConfigReloaded)));
     }
 
+    #[test]
+    fn test_parse_event_mode_changed() {
+        let json = serde_json::json!({
+            "type": "event",
+            "name": "mode_changed",
+            "data": {
+                "mode": "resize"
+            }
+        });
+
+        let event = IpcClient::parse_event(&json);
+
+        if let Some(IpcEvent::ModeChanged { mode }) = event {
+            assert_eq!(mode, "resize");
+        } else {
+            panic!("Wrong event type");
+        }
+    }
+
     #[test]
     fn test_parse_event_unknown_type() {
         let json = serde_json::json!({
@@ -14,13 +14,15 @@ mod monitor;
 
 use config::{BarConfig, ConfigLoader};
 use ipc_client::IpcClient;
+use module::IpcEvent;
 use modules::{
     battery::{is_battery_available, Battery, BatteryConfig},
     clock::{Clock, ClockConfig},
     cpu::{Cpu, CpuConfig},
     memory::{Memory, MemoryConfig},
+    mode::{Mode, ModeConfig, DEFAULT_MODE},
     window_title::{WindowTitle, WindowTitleConfig},
-    workspaces::{WorkspaceInfo, Workspaces, WorkspacesConfig},
+    workspaces::{update_workspaces, WorkspaceInfo, Workspaces, WorkspacesConfig},
 };
 
 // Global CSS that will be injected
@@ -37,6 +39,16 @@ enum IpcMessage {
     RefreshWindow,
 }
 
+/// Fetch the workspace list and copy window counts and urgency into the bar
+async fn refresh_workspaces(client: &IpcClient, mut workspaces: Signal<Vec<WorkspaceInfo>>) {
+    match client.get_workspaces().await {
+        Ok(ws_list) => update_workspaces(&mut workspaces.write(), &ws_list),
+        Err(e) => {
+            error!("Failed to get workspaces: {}", e);
+        }
+    }
+}
+
 fn main() -> anyhow::Result<()> {
     // Initialize logging
     tracing_subscriber::fmt()
@@ -216,19 +228,42 @@ fn App() -> Element {
                 id: i,
                 name: i.to_string(),
                 window_count: 0,
+                urgent: false,
             })
             .collect::<Vec<_>>()
     });
     let window_title = use_signal(|| String::new());
+    let mut binding_mode = use_signal(|| DEFAULT_MODE.to_string());
 
     // IPC integration - spawn coroutine to handle window manager communication
-    let ipc_tx = use_coroutine(|mut rx: UnboundedReceiver<IpcMessage>| async move {
-        let client = IpcClient::new();
+    let ipc_tx = use_coroutine(move |mut rx: UnboundedReceiver<IpcMessage>| async move {
+        let mut client = IpcClient::new();
+
+        // Route window manager events to the bar's state
+        let (event_tx, mut event_rx) = tokio::sync::mpsc::unbounded_channel();
+        client.set_event_sender(event_tx);
+        let event_client = client.clone();
+        spawn(async move {
+            while let Some(event) = event_rx.recv().await {
+                match event {
+                    IpcEvent::ModeChanged { mode } => binding_mode.set(mode),
+                    // Window counts and urgency live in the workspace list
+                    IpcEvent::WindowUrgent { .. }
+                    | IpcEvent::WindowCreated { .. }
+                    | IpcEvent::WindowClosed { .. }
+                    | IpcEvent::WorkspaceChanged { .. } => {
+                        refresh_workspaces(&event_client, workspaces).await
+                    }
+                    _ => {}
+                }
+            }
+        });
 
         // Try to connect to window manager
         match client.connect().await {
             Ok(_) => {
                 info!("IPC client connected");
+                refresh_workspaces(&client, workspaces).await;
 
                 // Start event listener in background
                 let client_clone = client.clone();
@@ -247,20 +282,19 @@ fn App() -> Element {
         while let Some(msg) = rx.next().await {
             match msg {
                 IpcMessage::SwitchWorkspace(id) => {
-                    if let Err(e) = client.switch_workspace(id).await {
+                    // In tag mode the buttons view tags instead
+                    let tag_mode = matches!(client.get_tags().await, Ok(tags) if !tags.is_empty());
+                    let result = if tag_mode {
+                        client.view_tag(id).await
+                    } else {
+                        client.switch_workspace(id).await
+                    };
+                    if let Err(e) = result {
                         error!("Failed to switch workspace: {}", e);
                     }
                 }
                 IpcMessage::RefreshWorkspaces => {
-                    // Fetch workspace list periodically
-                    match client.get_workspaces().await {
-                        Ok(_ws_list) => {
-                            // TODO: Update workspaces signal
-                        }
-                        Err(e) => {
-                            error!("Failed to get workspaces: {}", e);
-                        }
-                    }
+                    refresh_workspaces(&client, workspaces).await;
                 }
                 IpcMessage::RefreshWindow => {
                     // Fetch active window info
@@ -287,6 +321,7 @@ fn App() -> Element {
     let battery_config = BatteryConfig::default();
     let workspaces_config = WorkspacesConfig::default();
     let window_title_config = WindowTitleConfig::default();
+    let mode_config = ModeConfig::default();
 
     // Check if battery is available
     let has_battery = is_battery_available();
@@ -308,6 +343,10 @@ fn App() -> Element {
                     workspaces,
                     on_workspace_click,
                 }
+                Mode {
+                    config: mode_config.clone(),
+                    mode: binding_mode,
+                }
             }
 
             // Center modules
@@ -56,9 +56,11 @@ pub enum Message {
 pub enum IpcEvent {
     WorkspaceChanged { from: usize, to: usize },
     WindowFocused { hwnd: String, title: String },
+    WindowUrgent { hwnd: String, urgent: bool },
     WindowCreated { hwnd: String, title: String },
     WindowClosed { hwnd: String },
     ConfigReloaded,
+    ModeChanged { mode: String },
 }
 
 /// Module registry for managing loaded modules
@@ -8,4 +8,5 @@ pub mod clock;
 pub mod cpu;
 pub mod memory;
 pub mod battery;
+pub mod mode;
 
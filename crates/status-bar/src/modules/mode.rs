@@ -0,0 +1,86 @@
+//! Mode module - displays the active keybinding mode
+
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Name of the main keybindings, reported when no mode is active
+pub const DEFAULT_MODE: &str = "default";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ModeConfig {
+    #[serde(default = "default_format")]
+    pub format: String,
+
+    /// Hide the module while the main keybindings are active
+    #[serde(default = "default_hide_default")]
+    pub hide_default: bool,
+}
+
+fn default_format() -> String {
+    "{mode}".to_string()
+}
+
+fn default_hide_default() -> bool {
+    true
+}
+
+impl Default for ModeConfig {
+    fn default() -> Self {
+        Self {
+            format: default_format(),
+            hide_default: default_hide_default(),
+        }
+    }
+}
+
+fn format_mode(config: &ModeConfig, mode: &str) -> Option<String> {
+    if config.hide_default && (mode.is_empty() || mode == DEFAULT_MODE) {
+        return None;
+    }
+    Some(config.format.replace("{mode}", mode))
+}
+
+#[component]
+pub fn Mode(
+    config: ModeConfig,
+    mode: Signal<String>,
+) -> Element {
+    let Some(text) = format_mode(&config, &mode()) else {
+        return rsx! {};
+    };
+
+    rsx! {
+        div { class: "module module-mode",
+            "{text}"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mode_config_defaults() {
+        let config = ModeConfig::default();
+        assert_eq!(config.format, "{mode}");
+        assert!(config.hide_default);
+    }
+
+    #[test]
+    fn test_default_mode_is_hidden() {
+        let config = ModeConfig::default();
+        assert_eq!(format_mode(&config, DEFAULT_MODE), None);
+        assert_eq!(format_mode(&config, ""), None);
+    }
+
+    #[test]
+    fn test_format_mode() {
+        let config = ModeConfig {
+            format: "mode: {mode}".to_string(),
+            hide_default: false,
+        };
+        assert_eq!(format_mode(&config, "resize").as_deref(), Some("mode: resize"));
+        assert_eq!(format_mode(&config, DEFAULT_MODE).as_deref(), Some("mode: default"));
+    }
+}
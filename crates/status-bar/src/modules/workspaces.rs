@@ -1,5 +1,6 @@
 //! Workspaces module - displays workspace indicators
 
+use crate::ipc_client::WorkspaceData;
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -47,6 +48,19 @@ pub struct WorkspaceInfo {
     pub id: usize,
     pub name: String,
     pub window_count: usize,
+    pub urgent: bool,
+}
+
+/// Copy window counts and urgency from the window manager's workspace list
+///
+/// Workspaces the window manager does not report are shown as empty. Special
+/// workspaces have no button and are skipped.
+pub fn update_workspaces(workspaces: &mut [WorkspaceInfo], data: &[WorkspaceData]) {
+    for ws in workspaces.iter_mut() {
+        let reported = data.iter().find(|d| d.id == ws.id && !d.special);
+        ws.window_count = reported.map_or(0, |d| d.window_count);
+        ws.urgent = reported.is_some_and(|d| d.urgent);
+    }
 }
 
 #[component]
@@ -69,6 +83,8 @@ pub fn Workspaces(
                     
                     let class_name = if is_active {
                         "workspace-button workspace-button-active"
+                    } else if ws.urgent {
+                        "workspace-button workspace-button-urgent"
                     } else if ws.window_count > 0 {
                         "workspace-button workspace-button-occupied"
                     } else {
@@ -122,16 +138,60 @@ mod tests {
         assert_eq!(deserialized.inactive_color, config.inactive_color);
     }
 
+    #[test]
+    fn test_update_workspaces_copies_urgency_and_counts() {
+        let mut workspaces: Vec<WorkspaceInfo> = (1..=3)
+            .map(|i| WorkspaceInfo {
+                id: i,
+                name: i.to_string(),
+                window_count: 5,
+                urgent: true,
+            })
+            .collect();
+        let data = vec![
+            WorkspaceData {
+                id: 1,
+                name: "1".to_string(),
+                monitor: 0,
+                window_count: 2,
+                active: true,
+                urgent: false,
+                special: false,
+            },
+            WorkspaceData {
+                id: 2,
+                name: "2".to_string(),
+                monitor: 0,
+                window_count: 1,
+                active: false,
+                urgent: true,
+                special: false,
+            },
+        ];
+
+        update_workspaces(&mut workspaces, &data);
+
+        assert_eq!(workspaces[0].window_count, 2);
+        assert!(!workspaces[0].urgent);
+        assert_eq!(workspaces[1].window_count, 1);
+        assert!(workspaces[1].urgent);
+        // Not reported, so empty
+        assert_eq!(workspaces[2].window_count, 0);
+        assert!(!workspaces[2].urgent);
+    }
+
     #[test]
     fn test_workspace_info_creation() {
         let ws = WorkspaceInfo {
             id: 1,
             name: "1".to_string(),
             window_count: 3,
+            urgent: true,
         };
         
         assert_eq!(ws.id, 1);
         assert_eq!(ws.name, "1");
         assert_eq!(ws.window_count, 3);
+        assert!(ws.urgent);
     }
 }